@@ -27,12 +27,18 @@ pub fn default_db_path() -> PathBuf {
     dirs::home_dir().unwrap().join(".fuel").join("db")
 }
 
+#[cfg(feature = "rocksdb")]
+pub mod db;
 pub mod fee_contract;
 #[cfg(feature = "rocksdb")]
+pub mod reindex;
+#[cfg(feature = "rocksdb")]
 pub mod rollback;
 pub mod run;
 #[cfg(feature = "rocksdb")]
 pub mod snapshot;
+#[cfg(feature = "rocksdb")]
+pub mod verify;
 
 // Default database cache is 1 GB
 pub const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
@@ -57,6 +63,12 @@ pub enum Fuel {
     Snapshot(snapshot::Command),
     #[cfg(feature = "rocksdb")]
     Rollback(rollback::Command),
+    #[cfg(feature = "rocksdb")]
+    Verify(verify::Command),
+    #[cfg(feature = "rocksdb")]
+    Db(db::Command),
+    #[cfg(feature = "rocksdb")]
+    Reindex(reindex::Command),
     GenerateFeeContract(fee_contract::Command),
 }
 
@@ -139,6 +151,12 @@ pub async fn run_cli() -> anyhow::Result<()> {
             Fuel::Snapshot(command) => snapshot::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
             Fuel::Rollback(command) => rollback::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Verify(command) => verify::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Db(command) => db::exec(command).await,
+            #[cfg(feature = "rocksdb")]
+            Fuel::Reindex(command) => reindex::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
@@ -675,5 +693,206 @@ mod tests {
                 panic!("Expected a run command without a snapshot");
             };
         }
+
+        #[test]
+        fn can_give_history_retention_in_blocks() {
+            // given
+            let line = "./core run --history-retention-blocks 100";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the run command")
+                .command;
+
+            // then
+            let super::Fuel::Run(run::Command {
+                history_retention_blocks,
+                history_retention_duration,
+                ..
+            }) = command
+            else {
+                panic!("Expected a run command");
+            };
+
+            assert_eq!(history_retention_blocks, Some(100));
+            assert!(history_retention_duration.is_none());
+        }
+
+        #[test]
+        fn history_retention_in_blocks_and_duration_conflict() {
+            // given
+            let line =
+                "./core run --history-retention-blocks 100 --history-retention-duration 1d";
+
+            // when
+            let result = super::parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
+    }
+
+    mod verify_arg_tests {
+        use crate::cli::verify;
+
+        #[test]
+        fn can_verify_range() {
+            // given
+            let line = "./core verify --from 10 --to 20";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the verify command")
+                .command;
+
+            // then
+            let super::Fuel::Verify(verify::Command { from, to, .. }) = command else {
+                panic!("Expected a verify command");
+            };
+
+            assert_eq!(from, 10);
+            assert_eq!(to, 20);
+        }
+
+        #[test]
+        fn range_is_required() {
+            // given
+            let line = "./core verify --from 10";
+
+            // when
+            let result = super::parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
+    }
+
+    mod reindex_arg_tests {
+        use crate::cli::reindex;
+        use std::path::PathBuf;
+
+        #[test]
+        fn can_reindex_database() {
+            // given
+            let line = "./core reindex --db-path ./db --snapshot ./snapshot";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the reindex command")
+                .command;
+
+            // then
+            let super::Fuel::Reindex(reindex::Command {
+                database_path,
+                snapshot,
+                da_compression,
+                ..
+            }) = command
+            else {
+                panic!("Expected a reindex command");
+            };
+
+            assert_eq!(database_path, PathBuf::from("./db"));
+            assert_eq!(snapshot, Some(PathBuf::from("./snapshot")));
+            assert!(da_compression.is_none());
+        }
+    }
+
+    mod db_arg_tests {
+        use crate::cli::db;
+        use std::path::PathBuf;
+
+        #[test]
+        fn can_backup_database() {
+            // given
+            let line = "./core db backup --db-path ./db --backup-path ./backup";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the db backup command")
+                .command;
+
+            // then
+            let super::Fuel::Db(db::Command {
+                subcommand:
+                    db::SubCommands::Backup {
+                        database_path,
+                        backup_path,
+                        ..
+                    },
+            }) = command
+            else {
+                panic!("Expected a db backup command");
+            };
+
+            assert_eq!(database_path, PathBuf::from("./db"));
+            assert_eq!(backup_path, PathBuf::from("./backup"));
+        }
+
+        #[test]
+        fn can_restore_database() {
+            // given
+            let line = "./core db restore --backup-path ./backup --db-path ./db";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the db restore command")
+                .command;
+
+            // then
+            let super::Fuel::Db(db::Command {
+                subcommand:
+                    db::SubCommands::Restore {
+                        database_path,
+                        backup_path,
+                        ..
+                    },
+            }) = command
+            else {
+                panic!("Expected a db restore command");
+            };
+
+            assert_eq!(database_path, PathBuf::from("./db"));
+            assert_eq!(backup_path, PathBuf::from("./backup"));
+        }
+
+        #[test]
+        fn can_check_and_repair_database() {
+            // given
+            let line = "./core db check --db-path ./db --repair";
+
+            // when
+            let command = super::parse_cli(line, "")
+                .expect("should parse the db check command")
+                .command;
+
+            // then
+            let super::Fuel::Db(db::Command {
+                subcommand:
+                    db::SubCommands::Check {
+                        database_path,
+                        repair,
+                        ..
+                    },
+            }) = command
+            else {
+                panic!("Expected a db check command");
+            };
+
+            assert_eq!(database_path, PathBuf::from("./db"));
+            assert!(repair);
+        }
+
+        #[test]
+        fn backup_path_is_required() {
+            // given
+            let line = "./core db restore --db-path ./db";
+
+            // when
+            let result = super::parse_cli(line, "");
+
+            // then
+            assert!(result.is_err());
+        }
     }
 }
@@ -0,0 +1,218 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    service::integrity::IntegrityChecker,
+    state::historical_rocksdb::StateRewindPolicy,
+};
+use rlimit::{
+    getrlimit,
+    Resource,
+};
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+/// Maintenance of the databases of the node.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    pub subcommand: SubCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SubCommands {
+    /// Creates a consistent backup of all databases of the stopped node.
+    ///
+    /// The running node holds the lock of the database, so the backup
+    /// of the running node is created via the `backupDatabase` GraphQL mutation.
+    Backup {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// The directory for the backup. It must not exist or be empty.
+        #[arg(name = "BACKUP_PATH", long = "backup-path")]
+        backup_path: PathBuf,
+
+        /// Defines a specific number of file descriptors that RocksDB can use.
+        ///
+        /// If defined as -1 no limit will be applied and will use the OS limits.
+        /// If not defined the system default divided by two is used.
+        #[clap(
+            long = "rocksdb-max-fds",
+            env,
+            default_value = get_default_max_fds().to_string()
+        )]
+        rocksdb_max_fds: i32,
+    },
+    /// Restores the databases from the backup after the validation
+    /// of the version metadata and the heights of the databases.
+    Restore {
+        /// The directory with the backup.
+        #[arg(name = "BACKUP_PATH", long = "backup-path")]
+        backup_path: PathBuf,
+
+        /// The path to the restored database. It must not exist or be empty.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// Defines a specific number of file descriptors that RocksDB can use.
+        ///
+        /// If defined as -1 no limit will be applied and will use the OS limits.
+        /// If not defined the system default divided by two is used.
+        #[clap(
+            long = "rocksdb-max-fds",
+            env,
+            default_value = get_default_max_fds().to_string()
+        )]
+        rocksdb_max_fds: i32,
+    },
+    /// Checks the integrity of the databases of the stopped node: the Merkle roots
+    /// of the contracts, the off-chain indexes of the coins and messages, the consensus
+    /// of the blocks, and the heights of the databases.
+    Check {
+        /// The path to the database.
+        #[clap(
+            name = "DB_PATH",
+            long = "db-path",
+            value_parser,
+            default_value = default_db_path().into_os_string()
+        )]
+        database_path: PathBuf,
+
+        /// Defines a specific number of file descriptors that RocksDB can use.
+        ///
+        /// If defined as -1 no limit will be applied and will use the OS limits.
+        /// If not defined the system default divided by two is used.
+        #[clap(
+            long = "rocksdb-max-fds",
+            env,
+            default_value = get_default_max_fds().to_string()
+        )]
+        rocksdb_max_fds: i32,
+
+        /// Rebuilds the derived data that doesn't match the data it is derived from,
+        /// and rolls back the auxiliary databases that are ahead of the on-chain database.
+        #[arg(long = "repair")]
+        repair: bool,
+    },
+}
+
+fn get_default_max_fds() -> i32 {
+    getrlimit(Resource::NOFILE)
+        .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
+        .expect("Our supported platforms should return max FD.")
+}
+
+const DATABASE_CACHE_SIZE: usize = 64 * 1024 * 1024;
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    match command.subcommand {
+        SubCommands::Backup {
+            database_path,
+            backup_path,
+            rocksdb_max_fds,
+        } => {
+            let path = database_path.as_path();
+            let db = CombinedDatabase::open(
+                path,
+                DATABASE_CACHE_SIZE,
+                StateRewindPolicy::NoRewind,
+                rocksdb_max_fds,
+                &Default::default(),
+            )
+            .map_err(Into::<anyhow::Error>::into)
+            .context(format!("failed to open combined database at path {path:?}"))?;
+
+            // Nothing writes into the database of the stopped node,
+            // so the databases are expected to be at the same height.
+            let height = db.backup(&backup_path, Duration::ZERO)?;
+            tracing::info!("Created the backup at height {height} in {backup_path:?}");
+        }
+        SubCommands::Restore {
+            backup_path,
+            database_path,
+            rocksdb_max_fds,
+        } => {
+            let height = CombinedDatabase::restore(
+                &backup_path,
+                &database_path,
+                DATABASE_CACHE_SIZE,
+                rocksdb_max_fds,
+            )
+            .context(format!("failed to restore the backup from {backup_path:?}"))?;
+            tracing::info!(
+                "Restored the databases at height {height} into {database_path:?}"
+            );
+        }
+        SubCommands::Check {
+            database_path,
+            rocksdb_max_fds,
+            repair,
+        } => check(&database_path, rocksdb_max_fds, repair)?,
+    }
+
+    Ok(())
+}
+
+fn check(database_path: &Path, rocksdb_max_fds: i32, repair: bool) -> anyhow::Result<()> {
+    use crate::cli::ShutdownListener;
+
+    // The rollback of the auxiliary databases requires the history of the changes.
+    let db = CombinedDatabase::open(
+        database_path,
+        DATABASE_CACHE_SIZE,
+        StateRewindPolicy::RewindFullRange,
+        rocksdb_max_fds,
+        &Default::default(),
+    )
+    .map_err(Into::<anyhow::Error>::into)
+    .context(format!(
+        "failed to open combined database at path {database_path:?}"
+    ))?;
+
+    let mut shutdown_listener = ShutdownListener::spawn();
+    let checker = IntegrityChecker::new(&db);
+    let inconsistencies = if repair {
+        checker.repair(&mut shutdown_listener)?
+    } else {
+        checker.check(&shutdown_listener)?
+    };
+
+    if inconsistencies.is_empty() {
+        tracing::info!("No inconsistencies found");
+        return Ok(())
+    }
+
+    for inconsistency in &inconsistencies {
+        if inconsistency.is_repairable() {
+            tracing::error!("{inconsistency}");
+        } else {
+            tracing::error!("{inconsistency} (can't be repaired)");
+        }
+    }
+    Err(anyhow::anyhow!(
+        "{} inconsistencies found",
+        inconsistencies.len()
+    ))
+}
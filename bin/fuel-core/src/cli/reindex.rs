@@ -0,0 +1,160 @@
+use crate::cli::{
+    default_db_path,
+    DEFAULT_DATABASE_CACHE_SIZE,
+};
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::{
+    combined_database::{
+        CombinedDatabase,
+        CombinedDatabaseConfig,
+    },
+    fuel_core_graphql_api::worker_service::DaCompressionConfig,
+    service::{
+        adapters::ExecutorAdapter,
+        reindex,
+        DbType,
+    },
+    state::{
+        historical_rocksdb::StateRewindPolicy,
+        rocks_db::config::RocksDbConfig,
+    },
+    upgradable_executor,
+};
+use fuel_core_chain_config::{
+    SnapshotMetadata,
+    SnapshotReader,
+};
+use fuel_core_types::blockchain::header::StateTransitionBytecodeVersion;
+use rlimit::{
+    getrlimit,
+    Resource,
+};
+use std::path::PathBuf;
+
+/// Rebuilds the off-chain database from the snapshot and the stored blocks.
+/// The blocks are re-executed, so the database should have all blocks and
+/// the state history of the whole chain. The current off-chain database is
+/// replaced only after the successful reindexing. The interrupted reindexing
+/// continues on the next run.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// The maximum database cache size in bytes.
+    #[arg(
+        long = "max-database-cache-size",
+        default_value_t = DEFAULT_DATABASE_CACHE_SIZE,
+        env
+    )]
+    pub max_database_cache_size: usize,
+
+    /// Defines a specific number of file descriptors that RocksDB can use.
+    ///
+    /// If defined as -1 no limit will be applied and will use the OS limits.
+    /// If not defined the system default divided by two is used.
+    #[clap(
+        long = "rocksdb-max-fds",
+        env,
+        default_value = get_default_max_fds().to_string()
+    )]
+    pub rocksdb_max_fds: i32,
+
+    /// The path to the TOML or JSON file with the tuning of the RocksDB databases.
+    #[arg(long = "rocksdb-config", env)]
+    pub rocksdb_config: Option<PathBuf>,
+
+    /// The snapshot used at the genesis of the chain. Defaults to local testnet configuration.
+    #[arg(name = "SNAPSHOT", long = "snapshot", env)]
+    pub snapshot: Option<PathBuf>,
+
+    /// Should be set if the node produced or imported the blocks
+    /// with the full utxo stateful validation.
+    #[arg(long = "utxo-validation", env)]
+    pub utxo_validation: bool,
+
+    /// Overrides the version of the native executor.
+    #[arg(long = "native-executor-version", env)]
+    pub native_executor_version: Option<StateTransitionBytecodeVersion>,
+
+    /// If given, the da-compressed blocks are rebuilt
+    /// with the given retention time.
+    #[arg(long = "da-compression", env)]
+    pub da_compression: Option<humantime::Duration>,
+}
+
+fn get_default_max_fds() -> i32 {
+    getrlimit(Resource::NOFILE)
+        .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
+        .expect("Our supported platforms should return max FD.")
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::ShutdownListener;
+
+    let snapshot_reader = match command.snapshot.as_ref() {
+        None => crate::cli::local_testnet_reader(),
+        Some(path) => {
+            let metadata = SnapshotMetadata::read(path)?;
+            SnapshotReader::open(metadata)?
+        }
+    };
+
+    let rocksdb_config = match command.rocksdb_config.as_ref() {
+        Some(path) => RocksDbConfig::load(path)?,
+        None => RocksDbConfig::default(),
+    };
+    let config = CombinedDatabaseConfig {
+        database_path: command.database_path,
+        database_type: DbType::RocksDb,
+        max_database_cache_size: command.max_database_cache_size,
+        state_rewind_policy: StateRewindPolicy::RewindFullRange,
+        max_fds: command.rocksdb_max_fds,
+        rocksdb_config,
+    };
+    let path = config.database_path.as_path();
+    let mut db = CombinedDatabase::from_config(&config)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!("failed to open combined database at path {path:?}"))?;
+    db.check_version()?;
+
+    if reindex::is_reindex_in_progress(&config) {
+        tracing::info!("Continuing the interrupted reindexing");
+    }
+
+    let executor = ExecutorAdapter::new(
+        db.on_chain().clone(),
+        db.relayer().clone(),
+        upgradable_executor::config::Config {
+            utxo_validation_default: command.utxo_validation,
+            native_executor_version: command.native_executor_version,
+            ..Default::default()
+        },
+    );
+    let da_compression_config = match command.da_compression {
+        Some(retention) => DaCompressionConfig::Enabled(fuel_core_compression::Config {
+            temporal_registry_retention: retention.into(),
+        }),
+        None => DaCompressionConfig::Disabled,
+    };
+
+    let shutdown_listener = ShutdownListener::spawn();
+    reindex::reindex(
+        &mut db,
+        &config,
+        snapshot_reader,
+        executor,
+        da_compression_config,
+        shutdown_listener.token.clone(),
+    )
+    .await?;
+
+    Ok(())
+}
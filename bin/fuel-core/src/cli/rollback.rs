@@ -54,6 +54,7 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
         64 * 1024 * 1024,
         StateRewindPolicy::RewindFullRange,
         command.rocksdb_max_fds,
+        &Default::default(),
     )
     .map_err(Into::<anyhow::Error>::into)
     .context(format!("failed to open combined database at path {path:?}"))?;
@@ -13,7 +13,10 @@ use crate::{
     FuelService,
 };
 use anyhow::Context;
-use clap::Parser;
+use clap::{
+    Parser,
+    ValueEnum,
+};
 use fuel_core::{
     chain_config::default_consensus_dev_key,
     combined_database::{
@@ -27,7 +30,10 @@ use fuel_core::{
     },
     producer::Config as ProducerConfig,
     service::{
-        config::Trigger,
+        config::{
+            fuel_core_importer::HistoryRetention,
+            Trigger,
+        },
         genesis::NotifyCancel,
         Config,
         DbType,
@@ -37,7 +43,9 @@ use fuel_core::{
     txpool::config::{
         BlackList,
         Config as TxPoolConfig,
+        FutureQueueConfig,
         HeavyWorkConfig,
+        PersistenceConfig,
         PoolLimits,
         ServiceChannelLimits,
     },
@@ -46,6 +54,7 @@ use fuel_core::{
         fuel_vm::SecretKey,
         secrecy::Secret,
     },
+    upgradable_executor::config::ValidationMode,
 };
 use fuel_core_chain_config::{
     SnapshotMetadata,
@@ -82,8 +91,10 @@ use tracing::{
     warn,
 };
 
-#[cfg(feature = "rocksdb")]
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
 use fuel_core::state::historical_rocksdb::StateRewindPolicy;
+#[cfg(feature = "rocksdb")]
+use fuel_core::state::rocks_db::config::RocksDbConfig;
 
 use super::DEFAULT_DATABASE_CACHE_SIZE;
 
@@ -143,7 +154,15 @@ pub struct Command {
     pub rocksdb_max_fds: i32,
 
     #[cfg(feature = "rocksdb")]
-    /// Defines the state rewind policy for the database when RocksDB is enabled.
+    /// The path to the TOML or JSON file with the tuning of the RocksDB databases.
+    ///
+    /// It overrides the compression, block size, cache share, bloom filter and
+    /// compaction style per database and per column.
+    #[arg(long = "rocksdb-config", env)]
+    pub rocksdb_config: Option<PathBuf>,
+
+    #[cfg(any(feature = "rocksdb", feature = "redb"))]
+    /// Defines the state rewind policy for the database when RocksDB or redb is enabled.
     ///
     /// The duration defines how many blocks back the rewind feature works.
     /// Assuming each block requires one second to produce.
@@ -165,6 +184,30 @@ pub struct Command {
     #[arg(name = "DB_PRUNE", long = "db-prune", env, default_value = "false")]
     pub db_prune: bool,
 
+    /// Keeps the transactions and their receipts only for the specified number
+    /// of the latest blocks. The headers of the blocks are always kept.
+    /// If not set, the whole history is kept.
+    #[arg(
+        long = "history-retention-blocks",
+        env,
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with = "history_retention_duration"
+    )]
+    pub history_retention_blocks: Option<u32>,
+
+    /// Keeps the transactions and their receipts only for the blocks produced
+    /// within the specified duration before the latest block.
+    /// The headers of the blocks are always kept.
+    #[arg(long = "history-retention-duration", env)]
+    pub history_retention_duration: Option<humantime::Duration>,
+
+    /// Rebuilds the off-chain database on start-up if it is empty, lacks some
+    /// of the indexes supported by the node, or the reindexing was interrupted.
+    /// The stored blocks are re-executed, so the state history of the whole
+    /// chain is required.
+    #[arg(long = "auto-reindex", env)]
+    pub auto_reindex: bool,
+
     /// The determines whether to continue the services on internal error or not.
     #[clap(long = "continue-services-on-error", default_value = "false", env)]
     pub continue_on_error: bool,
@@ -189,6 +232,30 @@ pub struct Command {
     #[arg(long = "native-executor-version", env)]
     pub native_executor_version: Option<StateTransitionBytecodeVersion>,
 
+    /// The way the native executor validates blocks. The `compare` mode validates
+    /// each block both sequentially and in parallel and fails if the results differ.
+    /// The `parallel` and `compare` modes require the `parallel-executor` feature.
+    #[arg(
+        long = "block-validation-mode",
+        default_value = "sequential",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub block_validation_mode: BlockValidationMode,
+
+    /// The directory where the compiled WASM modules of the state transition function
+    /// are cached. It saves the compilation of the old versions after the restart.
+    #[arg(long = "wasm-module-cache-path", value_parser, env)]
+    pub wasm_module_cache_path: Option<PathBuf>,
+
+    /// Validates each block both natively and in the WASM executor and compares
+    /// the errors, storage changes, events and transaction statuses. The header
+    /// isn't compared, since both executors check it against the block.
+    /// Mismatches are reported through logs and metrics.
+    #[arg(long = "verify-executor-parity", env)]
+    pub verify_executor_parity: bool,
+
     /// The starting gas price for the network
     #[arg(long = "starting-gas-price", default_value = "0", env)]
     pub starting_gas_price: u64,
@@ -291,14 +358,22 @@ impl Command {
             #[cfg(feature = "rocksdb")]
             rocksdb_max_fds,
             #[cfg(feature = "rocksdb")]
+            rocksdb_config,
+            #[cfg(any(feature = "rocksdb", feature = "redb"))]
             state_rewind_duration,
             db_prune,
+            history_retention_blocks,
+            history_retention_duration,
+            auto_reindex,
             snapshot,
             continue_on_error,
             vm_backtrace,
             debug,
             utxo_validation,
             native_executor_version,
+            block_validation_mode,
+            wasm_module_cache_path,
+            verify_executor_parity,
             starting_gas_price,
             gas_price_change_percent,
             min_gas_price,
@@ -429,10 +504,12 @@ impl Command {
             max_wait_time: max_wait_time.into(),
         };
 
-        #[cfg(feature = "rocksdb")]
+        #[cfg(any(feature = "rocksdb", feature = "redb"))]
         let state_rewind_policy = {
-            if database_type != DbType::RocksDb {
-                tracing::warn!("State rewind policy is only supported with RocksDB");
+            if !matches!(database_type, DbType::RocksDb | DbType::Redb) {
+                tracing::warn!(
+                    "State rewind policy is only supported with RocksDB or redb"
+                );
             }
 
             let blocks = state_rewind_duration.as_secs();
@@ -453,19 +530,36 @@ impl Command {
             }
         };
 
+        #[cfg(feature = "rocksdb")]
+        let rocksdb_config = match rocksdb_config {
+            Some(path) => RocksDbConfig::load(&path)?,
+            None => RocksDbConfig::default(),
+        };
+
         let combined_db_config = CombinedDatabaseConfig {
             database_path,
             database_type,
             max_database_cache_size,
-            #[cfg(feature = "rocksdb")]
+            #[cfg(any(feature = "rocksdb", feature = "redb"))]
             state_rewind_policy,
             #[cfg(feature = "rocksdb")]
             max_fds: rocksdb_max_fds,
+            #[cfg(feature = "rocksdb")]
+            rocksdb_config,
         };
 
-        let block_importer = fuel_core::service::config::fuel_core_importer::Config::new(
-            disabled_metrics.is_enabled(Module::Importer),
-        );
+        let mut block_importer =
+            fuel_core::service::config::fuel_core_importer::Config::new(
+                disabled_metrics.is_enabled(Module::Importer),
+            );
+        block_importer.history_retention =
+            match (history_retention_blocks, history_retention_duration) {
+                (Some(blocks), _) => Some(HistoryRetention::Blocks(blocks)),
+                (None, Some(duration)) => {
+                    Some(HistoryRetention::Duration(duration.into()))
+                }
+                (None, None) => None,
+            };
 
         let da_compression = match da_compression {
             Some(retention) => {
@@ -482,18 +576,27 @@ impl Command {
             tx_max_number,
             tx_max_total_bytes,
             tx_max_total_gas,
+            tx_max_number_per_owner,
+            tx_max_total_gas_per_owner,
+            tx_max_total_bytes_per_owner,
             tx_max_chain_count,
             tx_number_active_subscriptions,
             tx_blacklist_addresses,
             tx_blacklist_coins,
             tx_blacklist_messages,
             tx_blacklist_contracts,
+            tx_blacklist_path,
             tx_number_threads_to_verify_transactions,
             tx_size_of_verification_queue,
             tx_number_threads_p2p_sync,
             tx_size_of_p2p_sync_queue,
             tx_max_pending_read_requests,
             tx_max_pending_write_requests,
+            tx_selection_algorithm,
+            tx_future_queue_size,
+            tx_future_queue_max_blocks_ahead,
+            txpool_persist,
+            txpool_persist_interval,
         } = tx_pool;
 
         let black_list = BlackList::new(
@@ -509,6 +612,15 @@ impl Command {
             max_bytes_size: tx_max_total_bytes,
         };
 
+        let owner_limits = (tx_max_number_per_owner.is_some()
+            || tx_max_total_gas_per_owner.is_some()
+            || tx_max_total_bytes_per_owner.is_some())
+        .then(|| PoolLimits {
+            max_txs: tx_max_number_per_owner.unwrap_or(usize::MAX),
+            max_gas: tx_max_total_gas_per_owner.unwrap_or(u64::MAX),
+            max_bytes_size: tx_max_total_bytes_per_owner.unwrap_or(usize::MAX),
+        });
+
         let pool_heavy_work_config = HeavyWorkConfig {
             number_threads_to_verify_transactions:
                 tx_number_threads_to_verify_transactions,
@@ -522,6 +634,16 @@ impl Command {
             max_pending_write_pool_requests: tx_max_pending_write_requests,
         };
 
+        let pool_persistence = txpool_persist.map(|path| PersistenceConfig {
+            path,
+            interval: txpool_persist_interval.into(),
+        });
+
+        let future_queue = tx_future_queue_size.map(|max_txs| FutureQueueConfig {
+            max_txs,
+            max_blocks_ahead: tx_future_queue_max_blocks_ahead,
+        });
+
         let config = Config {
             graphql_config: GraphQLConfig {
                 addr,
@@ -536,6 +658,8 @@ impl Command {
                 max_concurrent_queries: graphql.graphql_max_concurrent_queries,
                 request_body_bytes_limit: graphql.graphql_request_body_bytes_limit,
                 api_request_timeout: graphql.api_request_timeout.into(),
+                max_trace_steps: graphql.max_trace_steps,
+                max_replayed_blocks: graphql.max_replayed_blocks,
                 query_log_threshold_time: graphql.query_log_threshold_time.into(),
                 costs: Costs {
                     balance_query: graphql.costs.balance_query,
@@ -546,6 +670,9 @@ impl Command {
                     submit: graphql.costs.submit,
                     submit_and_await: graphql.costs.submit_and_await,
                     status_change: graphql.costs.status_change,
+                    new_blocks: graphql.costs.new_blocks,
+                    pending_transactions: graphql.costs.pending_transactions,
+                    trace_transaction: graphql.costs.trace_transaction,
                     storage_read: graphql.costs.storage_read,
                     tx_get: graphql.costs.tx_get,
                     tx_status_read: graphql.costs.tx_status_read,
@@ -559,12 +686,17 @@ impl Command {
                         .costs
                         .state_transition_bytecode_read,
                     da_compressed_block_read: graphql.costs.da_compressed_block_read,
+                    contract_state_proof: graphql.costs.contract_state_proof,
                 },
             },
             combined_db_config,
             snapshot_reader,
             debug,
             native_executor_version,
+            block_validation_mode: block_validation_mode.into(),
+            wasm_module_cache_path,
+            verify_executor_parity,
+            auto_reindex,
             continue_on_error,
             utxo_validation,
             block_production: trigger,
@@ -579,10 +711,15 @@ impl Command {
                 utxo_validation,
                 max_tx_update_subscriptions: tx_number_active_subscriptions,
                 black_list,
+                black_list_path: tx_blacklist_path,
                 pool_limits,
+                owner_limits,
                 heavy_work: pool_heavy_work_config,
                 service_channel_limits,
                 metrics: disabled_metrics.is_enabled(Module::TxPool),
+                persistence: pool_persistence,
+                future_queue,
+                selection_algorithm: tx_selection_algorithm.into(),
             },
             block_producer: ProducerConfig {
                 coinbase_recipient,
@@ -611,6 +748,30 @@ impl Command {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum BlockValidationMode {
+    /// Executes transactions of the block one by one.
+    Sequential,
+    /// Executes independent transactions of the block in parallel.
+    #[cfg(feature = "parallel-executor")]
+    Parallel,
+    /// Validates the block both sequentially and in parallel and compares the results.
+    #[cfg(feature = "parallel-executor")]
+    Compare,
+}
+
+impl From<BlockValidationMode> for ValidationMode {
+    fn from(value: BlockValidationMode) -> Self {
+        match value {
+            BlockValidationMode::Sequential => ValidationMode::Sequential,
+            #[cfg(feature = "parallel-executor")]
+            BlockValidationMode::Parallel => ValidationMode::Parallel,
+            #[cfg(feature = "parallel-executor")]
+            BlockValidationMode::Compare => ValidationMode::Compare,
+        }
+    }
+}
+
 #[cfg(feature = "rocksdb")]
 fn get_default_max_fds() -> i32 {
     getrlimit(Resource::NOFILE)
@@ -621,9 +782,18 @@ fn get_default_max_fds() -> i32 {
 pub async fn get_service_with_shutdown_listeners(
     command: Command,
 ) -> anyhow::Result<(FuelService, ShutdownListener)> {
-    #[cfg(feature = "rocksdb")]
     if command.db_prune && command.database_path.exists() {
-        fuel_core::combined_database::CombinedDatabase::prune(&command.database_path)?;
+        match command.database_type {
+            #[cfg(feature = "rocksdb")]
+            DbType::RocksDb => fuel_core::combined_database::CombinedDatabase::prune(
+                &command.database_path,
+            )?,
+            #[cfg(feature = "redb")]
+            DbType::Redb => fuel_core::combined_database::CombinedDatabase::prune_redb(
+                &command.database_path,
+            )?,
+            _ => {}
+        }
     }
 
     let profiling = command.profiling.clone();
@@ -636,9 +806,16 @@ pub async fn get_service_with_shutdown_listeners(
     info!("Fuel Core version v{}", env!("CARGO_PKG_VERSION"));
     trace!("Initializing in TRACE mode.");
     // initialize the server
-    let combined_database = CombinedDatabase::from_config(&config.combined_db_config)?;
+    let mut combined_database =
+        CombinedDatabase::from_config(&config.combined_db_config)?;
 
     let mut shutdown_listener = ShutdownListener::spawn();
+    FuelService::reindex_if_required(
+        &mut combined_database,
+        &config,
+        &mut shutdown_listener,
+    )
+    .await?;
 
     Ok((
         FuelService::new(combined_database, config, &mut shutdown_listener)?,
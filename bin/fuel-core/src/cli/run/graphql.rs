@@ -66,6 +66,16 @@ pub struct GraphQLArgs {
     #[clap(long = "api-request-timeout", default_value = "30s", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The max number of the instructions recorded in the trace of one transaction.
+    /// The tracing fails if the transaction executes more instructions.
+    #[clap(long = "graphql-max-trace-steps", default_value = "100000", env)]
+    pub max_trace_steps: usize,
+
+    /// The max number of the stored blocks replayed by the blocks subscription
+    /// starting from the requested height.
+    #[clap(long = "graphql-max-replayed-blocks", default_value = "10000", env)]
+    pub max_replayed_blocks: u32,
+
     #[clap(flatten)]
     pub costs: QueryCosts,
 }
@@ -136,6 +146,30 @@ pub struct QueryCosts {
     )]
     pub status_change: usize,
 
+    /// Query costs for subscribing to new blocks.
+    #[clap(
+        long = "query-cost-new-blocks",
+        default_value = DEFAULT_QUERY_COSTS.new_blocks.to_string(),
+        env
+    )]
+    pub new_blocks: usize,
+
+    /// Query costs for reading pending transactions from the transaction pool.
+    #[clap(
+        long = "query-cost-pending-transactions",
+        default_value = DEFAULT_QUERY_COSTS.pending_transactions.to_string(),
+        env
+    )]
+    pub pending_transactions: usize,
+
+    /// Query costs for re-executing the committed transaction with the tracing.
+    #[clap(
+        long = "query-cost-trace-transaction",
+        default_value = DEFAULT_QUERY_COSTS.trace_transaction.to_string(),
+        env
+    )]
+    pub trace_transaction: usize,
+
     /// Query costs for reading from storage.
     #[clap(
         long = "query-cost-storage-read",
@@ -223,4 +257,12 @@ pub struct QueryCosts {
         env
     )]
     pub da_compressed_block_read: usize,
+
+    /// Query costs for generating a sparse Merkle proof of a contract storage slot.
+    #[clap(
+        long = "query-cost-contract-state-proof",
+        default_value = DEFAULT_QUERY_COSTS.contract_state_proof.to_string(),
+        env
+    )]
+    pub contract_state_proof: usize,
 }
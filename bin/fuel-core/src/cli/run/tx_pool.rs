@@ -1,5 +1,7 @@
 //! Clap configuration related to TxPool service.
 
+use clap::ValueEnum;
+use fuel_core::txpool::config::SelectionAlgorithmType;
 use fuel_core_types::{
     fuel_tx::{
         Address,
@@ -8,6 +10,7 @@ use fuel_core_types::{
     },
     fuel_types::Nonce,
 };
+use std::path::PathBuf;
 
 #[derive(Debug, Clone, clap::Args)]
 pub struct TxPoolArgs {
@@ -31,6 +34,19 @@ pub struct TxPoolArgs {
     #[clap(long = "tx-max-total-bytes", default_value = "131072000", env)]
     pub tx_max_total_bytes: usize,
 
+    /// The max number of transactions of one owner that the `TxPool` can simultaneously store.
+    /// Transactions are attributed to the owners of their coins, predicates and messages.
+    #[clap(long = "tx-max-number-per-owner", env)]
+    pub tx_max_number_per_owner: Option<usize>,
+
+    /// The max number of gas of one owner that the `TxPool` can simultaneously store.
+    #[clap(long = "tx-max-total-gas-per-owner", env)]
+    pub tx_max_total_gas_per_owner: Option<u64>,
+
+    /// The max number of bytes of one owner that the `TxPool` can simultaneously store.
+    #[clap(long = "tx-max-total-bytes-per-owner", env)]
+    pub tx_max_total_bytes_per_owner: Option<usize>,
+
     /// The max number of tx in a chain of dependent transactions that supported by the `TxPool`.
     #[clap(long = "tx-max-depth", default_value = "32", env)]
     pub tx_max_chain_count: usize,
@@ -55,6 +71,11 @@ pub struct TxPoolArgs {
     #[clap(long = "tx-blacklist-contracts", value_delimiter = ',', env)]
     pub tx_blacklist_contracts: Vec<ContractId>,
 
+    /// The file where the blacklist is stored after changes made via the GraphQL API.
+    /// Once the file exists, it replaces the blacklist from the arguments on startup.
+    #[clap(long = "tx-blacklist-path", env)]
+    pub tx_blacklist_path: Option<PathBuf>,
+
     /// Number of threads for managing verifications/insertions.
     #[clap(
         long = "tx-number-threads-to-verify-transactions",
@@ -82,6 +103,56 @@ pub struct TxPoolArgs {
     /// Maximum number of pending read requests in the service.
     #[clap(long = "tx-max-pending-read-requests", default_value = "1000", env)]
     pub tx_max_pending_read_requests: usize,
+
+    /// The algorithm that selects transactions from the `TxPool` for the block.
+    #[clap(
+        long = "tx-selection-algorithm",
+        default_value = "ratio-tip-gas",
+        value_enum,
+        ignore_case = true,
+        env
+    )]
+    pub tx_selection_algorithm: SelectionAlgorithm,
+
+    /// The max number of transactions with the maturity above the current height
+    /// that wait for their maturity before entering the `TxPool`.
+    /// Such transactions are rejected if not set.
+    #[clap(long = "tx-future-queue-size", env)]
+    pub tx_future_queue_size: Option<usize>,
+
+    /// The max number of blocks between the current height and the maturity
+    /// of the transactions accepted into the future queue.
+    #[clap(long = "tx-future-queue-max-blocks-ahead", default_value = "1000", env)]
+    pub tx_future_queue_max_blocks_ahead: u32,
+
+    /// The file where the transactions of the `TxPool` are stored on shutdown and
+    /// periodically. The stored transactions are verified and re-inserted on startup.
+    #[clap(long = "txpool-persist", env)]
+    pub txpool_persist: Option<PathBuf>,
+
+    /// The interval for storing the transactions of the `TxPool`.
+    #[clap(long = "txpool-persist-interval", default_value = "1m", env)]
+    pub txpool_persist_interval: humantime::Duration,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SelectionAlgorithm {
+    /// Selects transactions with the highest tip/gas ratio first.
+    RatioTipGas,
+    /// Selects transactions in the order of their arrival.
+    Fifo,
+    /// Selects transactions with the highest tip per consumed share of the block first.
+    Knapsack,
+}
+
+impl From<SelectionAlgorithm> for SelectionAlgorithmType {
+    fn from(value: SelectionAlgorithm) -> Self {
+        match value {
+            SelectionAlgorithm::RatioTipGas => SelectionAlgorithmType::RatioTipGas,
+            SelectionAlgorithm::Fifo => SelectionAlgorithmType::Fifo,
+            SelectionAlgorithm::Knapsack => SelectionAlgorithmType::Knapsack,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,130 @@
+use crate::cli::default_db_path;
+use anyhow::Context;
+use clap::Parser;
+use fuel_core::{
+    combined_database::CombinedDatabase,
+    service::verification::StoredBlocksVerifier,
+    state::historical_rocksdb::StateRewindPolicy,
+    upgradable_executor,
+};
+use fuel_core_chain_config::{
+    SnapshotMetadata,
+    SnapshotReader,
+};
+use fuel_core_types::blockchain::header::StateTransitionBytecodeVersion;
+use rlimit::{
+    getrlimit,
+    Resource,
+};
+use std::path::PathBuf;
+
+/// Re-executes the stored blocks in the range and verifies that the result matches
+/// the stored state, roots of the blocks, and their consensus signatures.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = default_db_path().into_os_string()
+    )]
+    pub database_path: PathBuf,
+
+    /// Defines a specific number of file descriptors that RocksDB can use.
+    ///
+    /// If defined as -1 no limit will be applied and will use the OS limits.
+    /// If not defined the system default divided by two is used.
+    #[clap(
+        long = "rocksdb-max-fds",
+        env,
+        default_value = get_default_max_fds().to_string()
+    )]
+    pub rocksdb_max_fds: i32,
+
+    /// The snapshot used to start the node. Its chain configuration defines
+    /// the consensus keys. Defaults to local testnet configuration.
+    #[arg(name = "SNAPSHOT", long = "snapshot", env)]
+    pub snapshot: Option<PathBuf>,
+
+    /// Should be set if the node produced or imported the blocks
+    /// with the full utxo stateful validation.
+    #[arg(long = "utxo-validation", env)]
+    pub utxo_validation: bool,
+
+    /// Overrides the version of the native executor.
+    #[arg(long = "native-executor-version", env)]
+    pub native_executor_version: Option<StateTransitionBytecodeVersion>,
+
+    /// The height of the first block to verify.
+    #[clap(long = "from")]
+    pub from: u32,
+
+    /// The height of the last block to verify.
+    #[clap(long = "to")]
+    pub to: u32,
+}
+
+fn get_default_max_fds() -> i32 {
+    getrlimit(Resource::NOFILE)
+        .map(|(_, hard)| i32::try_from(hard.saturating_div(2)).unwrap_or(i32::MAX))
+        .expect("Our supported platforms should return max FD.")
+}
+
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use crate::cli::ShutdownListener;
+
+    let snapshot_reader = match command.snapshot.as_ref() {
+        None => crate::cli::local_testnet_reader(),
+        Some(path) => {
+            let metadata = SnapshotMetadata::read(path)?;
+            SnapshotReader::open(metadata)?
+        }
+    };
+    let consensus = snapshot_reader.chain_config().consensus.clone();
+
+    let path = command.database_path.as_path();
+    let db = CombinedDatabase::open(
+        path,
+        64 * 1024 * 1024,
+        StateRewindPolicy::RewindFullRange,
+        command.rocksdb_max_fds,
+        &Default::default(),
+    )
+    .map_err(Into::<anyhow::Error>::into)
+    .context(format!("failed to open combined database at path {path:?}"))?;
+
+    let executor_config = upgradable_executor::config::Config {
+        utxo_validation_default: command.utxo_validation,
+        native_executor_version: command.native_executor_version,
+        ..Default::default()
+    };
+    let verifier = StoredBlocksVerifier::new(&db, consensus, executor_config)?;
+
+    let shutdown_listener = ShutdownListener::spawn();
+    let invalid_blocks = verifier.verify_range(
+        command.from.into(),
+        command.to.into(),
+        &shutdown_listener,
+    )?;
+
+    if !invalid_blocks.is_empty() {
+        let heights = invalid_blocks
+            .iter()
+            .map(|invalid_block| invalid_block.height.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(anyhow::anyhow!(
+            "{} block(s) failed the verification: {heights}",
+            invalid_blocks.len()
+        ));
+    }
+
+    tracing::info!(
+        "All blocks from {} to {} are verified successfully",
+        command.from,
+        command.to
+    );
+
+    Ok(())
+}
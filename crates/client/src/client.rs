@@ -2,16 +2,26 @@
 use crate::client::types::StatusWithTransaction;
 use crate::client::{
     schema::{
+        blacklist::BlacklistArgs,
         block::BlockByHeightArgs,
         coins::{
             ExcludeInput,
             SpendQueryElementInput,
         },
-        contract::ContractBalanceQueryArgs,
+        contract::{
+            ContractBalanceAtQueryArgs,
+            ContractBalanceQueryArgs,
+        },
+        database::BackupDatabaseArgs,
         gas_price::EstimateGasPrice,
         message::MessageStatusArgs,
         relayed_tx::RelayedTransactionStatusArgs,
-        tx::DryRunArg,
+        tx::{
+            transparent_receipt::ReceiptType,
+            DryRunArg,
+            DryRunAtArg,
+            ReceiptsByContractConnectionArgs,
+        },
         Tai64Timestamp,
         TransactionId,
     },
@@ -78,16 +88,22 @@ use pagination::{
     PaginationRequest,
 };
 use schema::{
-    balance::BalanceArgs,
+    balance::{
+        BalanceArgs,
+        BalanceAtArgs,
+    },
     blob::BlobByIdArgs,
     block::BlockByIdArgs,
     coins::{
         CoinByIdArgs,
+        CoinByIdAtArgs,
         CoinsConnectionArgs,
     },
     contract::{
         ContractBalancesConnectionArgs,
         ContractByIdArgs,
+        ContractByIdAtArgs,
+        ContractStorageSlotsConnectionArgs,
     },
     da_compressed::DaCompressedBlockByHeightArgs,
     gas_price::BlockHorizonArgs,
@@ -140,6 +156,7 @@ use self::schema::{
     message::{
         MessageProofArgs,
         NonceArgs,
+        NonceAtArgs,
     },
 };
 
@@ -506,6 +523,85 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run with the instruction-level trace of the execution
+    pub async fn dry_run_with_trace(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_price: Option<u64>,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query: Operation<schema::tx::DryRunWithTrace, DryRunArg> =
+            schema::tx::DryRunWithTrace::build(DryRunArg {
+                txs,
+                utxo_validation,
+                gas_price: gas_price.map(|gp| gp.into()),
+            });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        tx_statuses
+            .into_iter()
+            .map(|tx_status| tx_status.try_into().map_err(Into::into))
+            .collect()
+    }
+
+    /// Dry run that reports the contract inputs and variable outputs
+    /// missing in the failed transactions
+    pub async fn dry_run_with_missing_inputs(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_price: Option<u64>,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query: Operation<schema::tx::DryRunWithMissingInputs, DryRunArg> =
+            schema::tx::DryRunWithMissingInputs::build(DryRunArg {
+                txs,
+                utxo_validation,
+                gas_price: gas_price.map(|gp| gp.into()),
+            });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        tx_statuses
+            .into_iter()
+            .map(|tx_status| tx_status.try_into().map_err(Into::into))
+            .collect()
+    }
+
+    /// Dry run against the state right before the block at the `height`,
+    /// using the consensus parameters and gas price of that block.
+    pub async fn dry_run_at(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        gas_price: Option<u64>,
+        height: BlockHeight,
+    ) -> io::Result<Vec<TransactionExecutionStatus>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query: Operation<schema::tx::DryRunAt, DryRunAtArg> =
+            schema::tx::DryRunAt::build(DryRunAtArg {
+                txs,
+                utxo_validation,
+                gas_price: gas_price.map(|gp| gp.into()),
+                block_height: U32(height.into()),
+            });
+        let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
+        tx_statuses
+            .into_iter()
+            .map(|tx_status| tx_status.try_into().map_err(Into::into))
+            .collect()
+    }
+
     /// Estimate predicates for the transaction
     pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
         let serialized_tx = tx.to_bytes();
@@ -728,6 +824,20 @@ impl FuelClient {
         Ok(transaction.map(|tx| tx.try_into()).transpose()?)
     }
 
+    /// Re-execute the committed transaction on top of the state before its block
+    /// and return the execution status with the instruction-level trace
+    pub async fn trace_transaction(
+        &self,
+        id: &TxId,
+    ) -> io::Result<Option<TransactionExecutionStatus>> {
+        let query =
+            schema::tx::TraceTransactionQuery::build(TxIdArgs { id: (*id).into() });
+
+        let tx_status = self.query(query).await?.trace_transaction;
+
+        Ok(tx_status.map(|status| status.try_into()).transpose()?)
+    }
+
     /// Get the status of a transaction
     pub async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
         let query =
@@ -774,6 +884,33 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the blocks processed by the node. If `from_height` is specified,
+    /// the stream starts from the block at this height, otherwise from the next block.
+    ///
+    /// The stream ends with an error if it is polled slower than new blocks arrive.
+    /// It can be resumed without gaps by subscribing from the next expected height.
+    pub async fn subscribe_blocks(
+        &self,
+        from_height: Option<BlockHeight>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::NewBlock>>> {
+        use cynic::SubscriptionBuilder;
+        let s =
+            schema::block::NewBlocksSubscription::build(schema::block::NewBlocksArgs {
+                from_height: from_height.map(|height| U32(height.into())),
+            });
+
+        tracing::debug!("subscribing");
+        let stream = self.subscribe(s).await?.map(|block| {
+            let block = block?;
+            let block = block.new_blocks.try_into()?;
+            Ok(block)
+        });
+
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -829,6 +966,62 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions waiting in the transaction pool.
+    pub async fn pending_transactions(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::PendingTransaction, String>> {
+        let args = schema::ConnectionArgs::from(request);
+        let query = schema::tx::PendingTransactionsQuery::build(args);
+
+        let transactions = self.query(query).await?.pending_transactions.into();
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of transactions waiting in the transaction pool
+    /// that spend or create coins of the `owner`.
+    pub async fn pending_transactions_by_owner(
+        &self,
+        owner: &Address,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::PendingTransaction, String>> {
+        let owner: schema::Address = (*owner).into();
+        let args = TransactionsByOwnerConnectionArgs::from((owner, request));
+        let query = schema::tx::PendingTransactionsByOwnerQuery::build(args);
+
+        let transactions = self
+            .query(query)
+            .await?
+            .pending_transactions_by_owner
+            .into();
+        Ok(transactions)
+    }
+
+    /// Returns a paginated set of receipts related to the contract within
+    /// the inclusive `[from_height, to_height]` range.
+    /// Requires the contract receipts indexation on the node.
+    pub async fn receipts_by_contract(
+        &self,
+        contract: &ContractId,
+        receipt_types: Option<Vec<ReceiptType>>,
+        from_height: Option<BlockHeight>,
+        to_height: Option<BlockHeight>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractReceipt, String>> {
+        let contract_id: schema::ContractId = (*contract).into();
+        let args = ReceiptsByContractConnectionArgs::from((
+            contract_id,
+            receipt_types,
+            from_height.map(Into::into),
+            to_height.map(Into::into),
+            request,
+        ));
+        let query = schema::tx::ReceiptsByContractQuery::build(args);
+
+        let receipts = self.query(query).await?.receipts_by_contract.try_into()?;
+        Ok(receipts)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query =
             schema::tx::TransactionStatusQuery::build(TxIdArgs { id: (*id).into() });
@@ -890,6 +1083,59 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Returns the blacklist of the transaction pool. Requires `debug` to be enabled.
+    pub async fn blacklist(&self) -> io::Result<types::Blacklist> {
+        let query = schema::blacklist::BlacklistQuery::build(());
+        let blacklist = self.query(query).await?.blacklist;
+        Ok(blacklist.into())
+    }
+
+    /// Adds the entries to the blacklist of the transaction pool.
+    /// Returns the updated blacklist. Requires `debug` to be enabled.
+    pub async fn add_to_blacklist(
+        &self,
+        blacklist: types::Blacklist,
+    ) -> io::Result<types::Blacklist> {
+        let query = schema::blacklist::AddToBlacklistMutation::build(BlacklistArgs {
+            blacklist: blacklist.into(),
+        });
+        let blacklist = self.query(query).await?.add_to_blacklist;
+        Ok(blacklist.into())
+    }
+
+    /// Removes the entries from the blacklist of the transaction pool.
+    /// Returns the updated blacklist. Requires `debug` to be enabled.
+    pub async fn remove_from_blacklist(
+        &self,
+        blacklist: types::Blacklist,
+    ) -> io::Result<types::Blacklist> {
+        let query =
+            schema::blacklist::RemoveFromBlacklistMutation::build(BlacklistArgs {
+                blacklist: blacklist.into(),
+            });
+        let blacklist = self.query(query).await?.remove_from_blacklist;
+        Ok(blacklist.into())
+    }
+
+    /// Creates the backup of the node's databases in the `path` on the node's machine.
+    /// Returns the height of the latest block in the backup.
+    /// Requires `debug` to be enabled.
+    pub async fn backup_database(&self, path: &str) -> io::Result<BlockHeight> {
+        let query = schema::database::BackupDatabaseMutation::build(BackupDatabaseArgs {
+            path: path.to_string(),
+        });
+        let height = self.query(query).await?.backup_database;
+        Ok(height.into())
+    }
+
+    /// Returns the statistics of the columns of the node's databases.
+    /// Requires `debug` to be enabled.
+    pub async fn database_stats(&self) -> io::Result<Vec<types::DatabaseStats>> {
+        let query = schema::database::DatabaseStatsQuery::build(());
+        let stats = self.query(query).await?.database_stats;
+        Ok(stats.into_iter().map(Into::into).collect())
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -974,6 +1220,20 @@ impl FuelClient {
         Ok(coin)
     }
 
+    /// Retrieve the coin by its id at the given block `height`.
+    pub async fn coin_at(
+        &self,
+        id: &UtxoId,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::Coin>> {
+        let query = schema::coins::CoinByIdAtQuery::build(CoinByIdAtArgs {
+            utxo_id: (*id).into(),
+            at_height: U32(height.into()),
+        });
+        let coin = self.query(query).await?.coin.map(Into::into);
+        Ok(coin)
+    }
+
     /// Retrieve a page of coins by their owner
     pub async fn coins(
         &self,
@@ -1044,6 +1304,20 @@ impl FuelClient {
         Ok(contract)
     }
 
+    /// Retrieve the contract by its id at the given block `height`.
+    pub async fn contract_at(
+        &self,
+        id: &ContractId,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::Contract>> {
+        let query = schema::contract::ContractByIdAtQuery::build(ContractByIdAtArgs {
+            id: (*id).into(),
+            at_height: U32(height.into()),
+        });
+        let contract = self.query(query).await?.contract.map(Into::into);
+        Ok(contract)
+    }
+
     pub async fn contract_balance(
         &self,
         id: &ContractId,
@@ -1065,6 +1339,30 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Retrieve the balance of the contract at the given block `height`.
+    pub async fn contract_balance_at(
+        &self,
+        id: &ContractId,
+        asset: Option<&AssetId>,
+        height: BlockHeight,
+    ) -> io::Result<u64> {
+        let asset_id: schema::AssetId = match asset {
+            Some(asset) => (*asset).into(),
+            None => schema::AssetId::default(),
+        };
+
+        let query =
+            schema::contract::ContractBalanceAtQuery::build(ContractBalanceAtQueryArgs {
+                id: (*id).into(),
+                asset: asset_id,
+                at_height: U32(height.into()),
+            });
+
+        let balance: types::ContractBalance =
+            self.query(query).await?.contract_balance.into();
+        Ok(balance.amount)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
@@ -1080,6 +1378,28 @@ impl FuelClient {
         Ok(balance.amount.try_into().unwrap_or(u64::MAX))
     }
 
+    /// Retrieve the balance of the `owner` at the given block `height`.
+    /// Requires the balances indexation to be enabled on the node.
+    pub async fn balance_at(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        height: BlockHeight,
+    ) -> io::Result<u64> {
+        let owner: schema::Address = (*owner).into();
+        let asset_id: schema::AssetId = match asset_id {
+            Some(asset_id) => (*asset_id).into(),
+            None => schema::AssetId::default(),
+        };
+        let query = schema::balance::BalanceAtQuery::build(BalanceAtArgs {
+            owner,
+            asset_id,
+            at_height: U32(height.into()),
+        });
+        let balance: types::Balance = self.query(query).await?.balance.into();
+        Ok(balance.amount.try_into().unwrap_or(u64::MAX))
+    }
+
     // Retrieve a page of balances by their owner
     pub async fn balances(
         &self,
@@ -1108,6 +1428,60 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Retrieve a page of storage slots of the `contract`. Without the `filter`,
+    /// all set slots of the contract are returned.
+    pub async fn contract_storage_slots(
+        &self,
+        contract: &ContractId,
+        filter: Option<types::ContractStorageSlotsFilter>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractStorageSlot, String>> {
+        let args = Self::contract_storage_slots_args(contract, filter, request);
+        let query = schema::contract::ContractStorageSlotsQuery::build(args);
+
+        let slots = self.query(query).await?.contract_storage_slots.into();
+
+        Ok(slots)
+    }
+
+    /// The same as [`Self::contract_storage_slots`], but each slot comes with
+    /// the sparse Merkle proof against the state root of the contract.
+    pub async fn contract_storage_slots_with_proof(
+        &self,
+        contract: &ContractId,
+        filter: Option<types::ContractStorageSlotsFilter>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::ContractStorageSlot, String>> {
+        let args = Self::contract_storage_slots_args(contract, filter, request);
+        let query = schema::contract::ContractStorageSlotsWithProofQuery::build(args);
+
+        let slots = self.query(query).await?.contract_storage_slots.into();
+
+        Ok(slots)
+    }
+
+    fn contract_storage_slots_args(
+        contract: &ContractId,
+        filter: Option<types::ContractStorageSlotsFilter>,
+        request: PaginationRequest<String>,
+    ) -> ContractStorageSlotsConnectionArgs {
+        let contract_id: schema::ContractId = (*contract).into();
+        let mut args = ContractStorageSlotsConnectionArgs::from((contract_id, request));
+        match filter {
+            Some(types::ContractStorageSlotsFilter::Keys(keys)) => {
+                args.keys = Some(keys.into_iter().map(Into::into).collect());
+            }
+            Some(types::ContractStorageSlotsFilter::Range { start, end }) => {
+                args.range = Some(schema::contract::ContractStorageSlotRangeInput {
+                    start: start.map(Into::into),
+                    end: end.map(Into::into),
+                });
+            }
+            None => {}
+        }
+        args
+    }
+
     // Retrieve a message by its nonce
     pub async fn message(&self, nonce: &Nonce) -> io::Result<Option<types::Message>> {
         let query = schema::message::MessageQuery::build(NonceArgs {
@@ -1117,6 +1491,20 @@ impl FuelClient {
         Ok(message)
     }
 
+    /// Retrieve the message by its nonce at the given block `height`.
+    pub async fn message_at(
+        &self,
+        nonce: &Nonce,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::Message>> {
+        let query = schema::message::MessageAtQuery::build(NonceAtArgs {
+            nonce: (*nonce).into(),
+            at_height: U32(height.into()),
+        });
+        let message = self.query(query).await?.message.map(Into::into);
+        Ok(message)
+    }
+
     pub async fn messages(
         &self,
         owner: Option<&Address>,
@@ -4,6 +4,7 @@ use crate::client::{
         Address,
         AssetId,
         PageInfo,
+        U32,
         U64,
     },
     PageDirection,
@@ -27,6 +28,24 @@ pub struct BalanceQuery {
     pub balance: Balance,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BalanceAtArgs {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub at_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BalanceAtArgs"
+)]
+pub struct BalanceAtQuery {
+    #[arguments(owner: $owner, assetId: $asset_id, atHeight: $at_height)]
+    pub balance: Balance,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct BalanceFilterInput {
@@ -117,6 +136,17 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn balance_at_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BalanceAtQuery::build(BalanceAtArgs {
+            owner: Address::default(),
+            asset_id: AssetId::default(),
+            at_height: U32(0),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn balances_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -0,0 +1,88 @@
+use crate::client::schema::{
+    schema,
+    Address,
+    ContractId,
+    Nonce,
+    UtxoId,
+};
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct BlacklistQuery {
+    pub blacklist: Blacklist,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BlacklistArgs {
+    pub blacklist: BlacklistInput,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "BlacklistArgs"
+)]
+pub struct AddToBlacklistMutation {
+    #[arguments(blacklist: $blacklist)]
+    pub add_to_blacklist: Blacklist,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "BlacklistArgs"
+)]
+pub struct RemoveFromBlacklistMutation {
+    #[arguments(blacklist: $blacklist)]
+    pub remove_from_blacklist: Blacklist,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Blacklist {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlacklistInput {
+    /// Owners of the coins and senders or recipients of the messages.
+    pub owners: Option<Vec<Address>>,
+    /// UTXO ids of the coins.
+    pub coins: Option<Vec<UtxoId>>,
+    /// Nonces of the messages.
+    pub messages: Option<Vec<Nonce>>,
+    /// Contracts used as inputs.
+    pub contracts: Option<Vec<ContractId>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blacklist_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlacklistQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn add_to_blacklist_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = AddToBlacklistMutation::build(BlacklistArgs {
+            blacklist: BlacklistInput {
+                owners: None,
+                coins: None,
+                messages: None,
+                contracts: None,
+            },
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -1,6 +1,10 @@
 use super::Bytes32;
 use crate::client::schema::{
     schema,
+    tx::{
+        OpaqueTransaction,
+        TransactionStatus,
+    },
     BlockId,
     ConnectionArgsFields,
     PageInfo,
@@ -173,6 +177,37 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NewBlocksArgs {
+    pub from_height: Option<U32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "NewBlocksArgs"
+)]
+pub struct NewBlocksSubscription {
+    #[arguments(fromHeight: $from_height)]
+    pub new_blocks: NewBlock,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NewBlock {
+    pub id: BlockId,
+    pub header: Header,
+    pub transactions: Vec<NewBlockTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NewBlockTransaction {
+    pub transaction: OpaqueTransaction,
+    pub status: TransactionStatus,
+}
+
 impl Block {
     /// Returns the block producer public key, if any.
     pub fn block_producer(&self) -> Option<fuel_crypto::PublicKey> {
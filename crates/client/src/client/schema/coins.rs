@@ -30,6 +30,23 @@ pub struct CoinByIdQuery {
     pub coin: Option<Coin>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct CoinByIdAtArgs {
+    pub utxo_id: UtxoId,
+    pub at_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinByIdAtArgs"
+)]
+pub struct CoinByIdAtQuery {
+    #[arguments(utxoId: $utxo_id, atHeight: $at_height)]
+    pub coin: Option<Coin>,
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct CoinFilterInput {
@@ -2,10 +2,12 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        U32,
         U64,
     },
     PageDirection,
@@ -28,6 +30,23 @@ pub struct ContractByIdQuery {
     pub contract: Option<Contract>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractByIdAtArgs {
+    pub id: ContractId,
+    pub at_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractByIdAtArgs"
+)]
+pub struct ContractByIdAtQuery {
+    #[arguments(id: $id, atHeight: $at_height)]
+    pub contract: Option<Contract>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct ContractBalance {
@@ -53,6 +72,24 @@ pub struct ContractBalanceQuery {
     pub contract_balance: ContractBalance,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractBalanceAtQueryArgs {
+    pub id: ContractId,
+    pub asset: AssetId,
+    pub at_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractBalanceAtQueryArgs"
+)]
+pub struct ContractBalanceAtQuery {
+    #[arguments(contract: $id, asset: $asset, atHeight: $at_height)]
+    pub contract_balance: ContractBalance,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Contract {
@@ -135,6 +172,143 @@ impl From<(ContractId, PaginationRequest<String>)> for ContractBalancesConnectio
     }
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotRangeInput {
+    /// The first key of the range(inclusive)
+    pub start: Option<Bytes32>,
+    /// The last key of the range(inclusive)
+    pub end: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ContractStorageSlotsConnectionArgs {
+    pub contract: ContractId,
+    /// Only the specified slots are returned if set
+    pub keys: Option<Vec<Bytes32>>,
+    /// Only the set slots within the range are returned if set
+    pub range: Option<ContractStorageSlotRangeInput>,
+    /// Skip until slot key (forward pagination)
+    pub after: Option<String>,
+    /// Skip until slot key (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n storage slots in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n storage slots in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(ContractId, PaginationRequest<String>)>
+    for ContractStorageSlotsConnectionArgs
+{
+    fn from(r: (ContractId, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => ContractStorageSlotsConnectionArgs {
+                contract: r.0,
+                keys: None,
+                range: None,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => ContractStorageSlotsConnectionArgs {
+                contract: r.0,
+                keys: None,
+                range: None,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateProof {
+    pub state_root: Bytes32,
+    pub inclusion: bool,
+    pub proof_set: Vec<Bytes32>,
+    pub leaf_key: Option<Bytes32>,
+    pub leaf_value: Option<Bytes32>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlot {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: Option<HexString>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotEdge {
+    pub cursor: String,
+    pub node: ContractStorageSlot,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStorageSlotConnection {
+    pub edges: Vec<ContractStorageSlotEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotsConnectionArgs"
+)]
+pub struct ContractStorageSlotsQuery {
+    #[arguments(contract: $contract, keys: $keys, range: $range, after: $after, before: $before, first: $first, last: $last)]
+    pub contract_storage_slots: ContractStorageSlotConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "ContractStorageSlot")]
+pub struct ContractStorageSlotWithProof {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    pub value: Option<HexString>,
+    pub proof: ContractStateProof,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "ContractStorageSlotEdge"
+)]
+pub struct ContractStorageSlotWithProofEdge {
+    pub cursor: String,
+    pub node: ContractStorageSlotWithProof,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "ContractStorageSlotConnection"
+)]
+pub struct ContractStorageSlotWithProofConnection {
+    pub edges: Vec<ContractStorageSlotWithProofEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractStorageSlotsConnectionArgs"
+)]
+pub struct ContractStorageSlotsWithProofQuery {
+    #[arguments(contract: $contract, keys: $keys, range: $range, after: $after, before: $before, first: $first, last: $last)]
+    pub contract_storage_slots: ContractStorageSlotWithProofConnection,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,4 +321,21 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_storage_slots_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractStorageSlotsWithProofQuery::build(
+            ContractStorageSlotsConnectionArgs {
+                contract: ContractId::default(),
+                keys: Some(vec![Bytes32::default()]),
+                range: None,
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            },
+        );
+        insta::assert_snapshot!(operation.query)
+    }
 }
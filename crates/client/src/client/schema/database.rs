@@ -0,0 +1,70 @@
+use crate::client::schema::{
+    schema,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct BackupDatabaseArgs {
+    pub path: String,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "BackupDatabaseArgs"
+)]
+pub struct BackupDatabaseMutation {
+    #[arguments(path: $path)]
+    pub backup_database: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct DatabaseStatsQuery {
+    pub database_stats: Vec<DatabaseStats>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DatabaseStats {
+    pub name: String,
+    pub block_cache_hit_rate: Option<f64>,
+    pub columns: Vec<ColumnStats>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ColumnStats {
+    pub name: String,
+    pub reads: U64,
+    pub writes: U64,
+    pub iterations: U64,
+    pub bytes_read: U64,
+    pub bytes_written: U64,
+    pub live_data_size: U64,
+    pub sst_files: U64,
+    pub pending_compaction_bytes: U64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backup_database_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = BackupDatabaseMutation::build(BackupDatabaseArgs {
+            path: "/tmp/backup".to_string(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn database_stats_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = DatabaseStatsQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+}
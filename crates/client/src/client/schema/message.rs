@@ -47,6 +47,23 @@ pub struct MessageQuery {
     pub message: Option<Message>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct NonceAtArgs {
+    pub nonce: Nonce,
+    pub at_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "NonceAtArgs"
+)]
+pub struct MessageAtQuery {
+    #[arguments(nonce: $nonce, atHeight: $at_height)]
+    pub message: Option<Message>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct MessageStatus {
@@ -1,9 +1,13 @@
 use crate::client::{
     schema::{
         schema,
-        tx::transparent_receipt::Receipt,
+        tx::transparent_receipt::{
+            Receipt,
+            ReceiptType,
+        },
         Address,
         ConnectionArgsFields,
+        ContractId,
         ConversionError,
         HexString,
         PageInfo,
@@ -34,6 +38,8 @@ use std::convert::{
     TryInto,
 };
 
+pub mod access_list;
+pub mod trace;
 pub mod transparent_receipt;
 pub mod transparent_tx;
 
@@ -339,6 +345,8 @@ pub struct DryRunFailureStatus {
 pub struct DryRunTransactionExecutionStatus {
     pub id: TransactionId,
     pub status: DryRunTransactionStatus,
+    pub access_list: Option<access_list::AccessList>,
+    pub missing_inputs: Option<access_list::MissingInputs>,
 }
 
 impl TryFrom<DryRunTransactionExecutionStatus> for TransactionExecutionStatus {
@@ -348,10 +356,63 @@ impl TryFrom<DryRunTransactionExecutionStatus> for TransactionExecutionStatus {
         let id = schema.id.into();
         let status = schema.status.try_into()?;
 
-        Ok(TransactionExecutionStatus { id, result: status })
+        Ok(TransactionExecutionStatus {
+            id,
+            result: status,
+            trace: None,
+            access_list: schema.access_list.map(Into::into),
+            missing_inputs: schema.missing_inputs.map(Into::into),
+        })
     }
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "DryRunTransactionExecutionStatus"
+)]
+pub struct TracedDryRunTransactionExecutionStatus {
+    pub id: TransactionId,
+    pub status: DryRunTransactionStatus,
+    pub trace: Option<Vec<trace::TraceRecord>>,
+    pub access_list: Option<access_list::AccessList>,
+    pub missing_inputs: Option<access_list::MissingInputs>,
+}
+
+impl TryFrom<TracedDryRunTransactionExecutionStatus> for TransactionExecutionStatus {
+    type Error = ConversionError;
+
+    fn try_from(
+        schema: TracedDryRunTransactionExecutionStatus,
+    ) -> Result<Self, Self::Error> {
+        let id = schema.id.into();
+        let status = schema.status.try_into()?;
+        let trace = schema
+            .trace
+            .map(|trace| trace.into_iter().map(Into::into).collect());
+
+        Ok(TransactionExecutionStatus {
+            id,
+            result: status,
+            trace,
+            access_list: schema.access_list.map(Into::into),
+            missing_inputs: schema.missing_inputs.map(Into::into),
+        })
+    }
+}
+
+/// Re-executes the committed transaction with the tracing
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxIdArgs"
+)]
+pub struct TraceTransactionQuery {
+    #[arguments(id: $id)]
+    pub trace_transaction: Option<TracedDryRunTransactionExecutionStatus>,
+}
+
 #[derive(cynic::QueryVariables, Debug)]
 pub struct TransactionsByOwnerConnectionArgs {
     /// Select transactions based on related `owner`s
@@ -399,6 +460,163 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConnectionArgs"
+)]
+pub struct PendingTransactionsQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub pending_transactions: PendingTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransactionsByOwnerConnectionArgs"
+)]
+pub struct PendingTransactionsByOwnerQuery {
+    #[arguments(owner: $owner, after: $after, before: $before, first: $first, last: $last)]
+    pub pending_transactions_by_owner: PendingTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransactionConnection {
+    pub edges: Vec<PendingTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransactionEdge {
+    pub cursor: String,
+    pub node: PendingTransaction,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct PendingTransaction {
+    pub id: TransactionId,
+    pub transaction: OpaqueTransaction,
+    pub tip: U64,
+    pub max_gas: U64,
+    pub byte_size: U64,
+    pub submitted_at: Tai64Timestamp,
+    pub dependencies: Vec<TransactionId>,
+    pub dependents: Vec<TransactionId>,
+    pub dependents_cumulative_tip: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ReceiptsByContractConnectionArgs {
+    /// Select receipts related to the contract
+    pub contract_id: ContractId,
+    /// Select only receipts of these types
+    pub receipt_types: Option<Vec<ReceiptType>>,
+    /// The lowest block height(inclusive) of the range
+    pub from_height: Option<U32>,
+    /// The highest block height(inclusive) of the range
+    pub to_height: Option<U32>,
+    /// Skip until cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n receipts in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n receipts in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl
+    From<(
+        ContractId,
+        Option<Vec<ReceiptType>>,
+        Option<U32>,
+        Option<U32>,
+        PaginationRequest<String>,
+    )> for ReceiptsByContractConnectionArgs
+{
+    fn from(
+        r: (
+            ContractId,
+            Option<Vec<ReceiptType>>,
+            Option<U32>,
+            Option<U32>,
+            PaginationRequest<String>,
+        ),
+    ) -> Self {
+        let (contract_id, receipt_types, from_height, to_height, request) = r;
+        match request.direction {
+            PageDirection::Forward => ReceiptsByContractConnectionArgs {
+                contract_id,
+                receipt_types,
+                from_height,
+                to_height,
+                after: request.cursor,
+                before: None,
+                first: Some(request.results),
+                last: None,
+            },
+            PageDirection::Backward => ReceiptsByContractConnectionArgs {
+                contract_id,
+                receipt_types,
+                from_height,
+                to_height,
+                after: None,
+                before: request.cursor,
+                first: None,
+                last: Some(request.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ReceiptsByContractConnectionArgs"
+)]
+pub struct ReceiptsByContractQuery {
+    #[arguments(
+        contractId: $contract_id,
+        receiptTypes: $receipt_types,
+        fromHeight: $from_height,
+        toHeight: $to_height,
+        after: $after,
+        before: $before,
+        first: $first,
+        last: $last
+    )]
+    pub receipts_by_contract: ContractReceiptConnection,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptConnection {
+    pub edges: Vec<ContractReceiptEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceiptEdge {
+    pub cursor: String,
+    pub node: ContractReceipt,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractReceipt {
+    pub block_height: U32,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -446,6 +664,47 @@ pub struct DryRun {
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunWithTrace {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, trace: true)]
+    pub dry_run: Vec<TracedDryRunTransactionExecutionStatus>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunWithMissingInputs {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, discoverMissingInputs: true)]
+    pub dry_run: Vec<DryRunTransactionExecutionStatus>,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct DryRunAtArg {
+    pub txs: Vec<HexString>,
+    pub utxo_validation: Option<bool>,
+    pub gas_price: Option<U64>,
+    pub block_height: U32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunAtArg"
+)]
+pub struct DryRunAt {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, gasPrice: $gas_price, blockHeight: $block_height)]
+    pub dry_run: Vec<DryRunTransactionExecutionStatus>,
+}
+
 #[derive(cynic::QueryFragment, Clone, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -552,6 +811,37 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn pending_transactions_by_owner_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            PendingTransactionsByOwnerQuery::build(TransactionsByOwnerConnectionArgs {
+                owner: Default::default(),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn receipts_by_contract_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            ReceiptsByContractQuery::build(ReceiptsByContractConnectionArgs {
+                contract_id: Default::default(),
+                receipt_types: Some(vec![ReceiptType::Log, ReceiptType::LogData]),
+                from_height: Some(U32(1)),
+                to_height: Some(U32(10)),
+                after: None,
+                before: None,
+                first: None,
+                last: None,
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -564,6 +854,52 @@ pub mod tests {
         insta::assert_snapshot!(query.query)
     }
 
+    #[test]
+    fn dry_run_at_tx_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunAt::build(DryRunAtArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: Some(true),
+            gas_price: Some(123u64.into()),
+            block_height: U32(1),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn dry_run_with_trace_tx_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunWithTrace::build(DryRunArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: Some(true),
+            gas_price: Some(123u64.into()),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn dry_run_with_missing_inputs_tx_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunWithMissingInputs::build(DryRunArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: Some(true),
+            gas_price: Some(123u64.into()),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn trace_transaction_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TraceTransactionQuery::build(TxIdArgs {
+            id: TransactionId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn submit_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -0,0 +1,53 @@
+use crate::client::schema::{
+    schema,
+    AssetId,
+    Bytes32,
+    ContractId,
+    U16,
+};
+use fuel_core_types::services::executor;
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AccessList {
+    pub contracts: Vec<ContractId>,
+    pub storage_slots: Vec<AccessedStorageSlot>,
+    pub assets: Vec<AssetId>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AccessedStorageSlot {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MissingInputs {
+    pub contracts: Vec<ContractId>,
+    pub variable_outputs: U16,
+}
+
+impl From<AccessList> for executor::AccessList {
+    fn from(value: AccessList) -> Self {
+        Self {
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+            storage_slots: value
+                .storage_slots
+                .into_iter()
+                .map(|slot| (slot.contract_id.into(), slot.key.into()))
+                .collect(),
+            assets: value.assets.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<MissingInputs> for executor::MissingInputs {
+    fn from(value: MissingInputs) -> Self {
+        Self {
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+            variable_outputs: value.variable_outputs.into(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::client::schema::{
+    schema,
+    Bytes32,
+    ContractId,
+    U32,
+    U64,
+};
+use fuel_core_types::services::executor;
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TraceRecord {
+    pub contract_id: Option<ContractId>,
+    pub pc: U64,
+    pub instruction: U32,
+    pub gas_used: U64,
+    pub storage_access: Option<StorageAccess>,
+}
+
+#[derive(cynic::QueryFragment, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StorageAccess {
+    pub kind: StorageAccessKind,
+    pub key: Bytes32,
+    pub slots: U64,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+    Clear,
+}
+
+impl From<TraceRecord> for executor::TraceRecord {
+    fn from(value: TraceRecord) -> Self {
+        Self {
+            contract_id: value.contract_id.map(Into::into),
+            pc: value.pc.into(),
+            instruction: value.instruction.into(),
+            gas_used: value.gas_used.into(),
+            storage_access: value.storage_access.map(Into::into),
+        }
+    }
+}
+
+impl From<StorageAccess> for executor::StorageAccess {
+    fn from(value: StorageAccess) -> Self {
+        Self {
+            kind: value.kind.into(),
+            key: value.key.into(),
+            slots: value.slots.into(),
+        }
+    }
+}
+
+impl From<StorageAccessKind> for executor::StorageAccessKind {
+    fn from(value: StorageAccessKind) -> Self {
+        match value {
+            StorageAccessKind::Read => Self::Read,
+            StorageAccessKind::Write => Self::Write,
+            StorageAccessKind::Clear => Self::Clear,
+        }
+    }
+}
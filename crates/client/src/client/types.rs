@@ -1,9 +1,11 @@
 pub mod balance;
+pub mod blacklist;
 pub mod blob;
 pub mod block;
 pub mod chain_info;
 pub mod coins;
 pub mod contract;
+pub mod database_stats;
 pub mod gas_costs;
 pub mod upgrades;
 
@@ -13,10 +15,12 @@ pub mod message;
 pub mod node_info;
 
 pub use balance::Balance;
+pub use blacklist::Blacklist;
 pub use blob::Blob;
 pub use block::{
     Block,
     Consensus,
+    NewBlock,
 };
 pub use chain_info::ChainInfo;
 pub use coins::{
@@ -27,6 +31,14 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractReceipt,
+    ContractStateProof,
+    ContractStorageSlot,
+    ContractStorageSlotsFilter,
+};
+pub use database_stats::{
+    ColumnStats,
+    DatabaseStats,
 };
 pub use gas_costs::{
     DependentCost,
@@ -39,19 +51,25 @@ pub use message::{
 };
 pub use node_info::NodeInfo;
 
-use crate::client::schema::{
-    relayed_tx::RelayedTransactionStatus as SchemaRelayedTransactionStatus,
-    tx::{
-        OpaqueTransactionWithStatus,
-        StatusWithTransaction as SchemaStatusWithTx,
-        TransactionStatus as SchemaTxStatus,
+use crate::client::{
+    schema::{
+        relayed_tx::RelayedTransactionStatus as SchemaRelayedTransactionStatus,
+        tx::{
+            OpaqueTransactionWithStatus,
+            PendingTransaction as SchemaPendingTransaction,
+            PendingTransactionConnection,
+            StatusWithTransaction as SchemaStatusWithTx,
+            TransactionStatus as SchemaTxStatus,
+        },
+        ConversionError,
     },
-    ConversionError,
+    PaginatedResult,
 };
 use fuel_core_types::{
     fuel_tx::{
         Receipt,
         Transaction,
+        TxId,
     },
     fuel_types::{
         canonical::Deserialize,
@@ -264,6 +282,56 @@ impl TryFrom<OpaqueTransactionWithStatus> for TransactionResponse {
     }
 }
 
+/// The transaction waiting in the transaction pool for the inclusion into a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingTransaction {
+    pub id: TxId,
+    pub transaction: TransactionType,
+    pub tip: u64,
+    pub max_gas: u64,
+    /// The metered size of the transaction in bytes.
+    pub byte_size: u64,
+    pub submitted_at: Tai64,
+    /// The ids of pending transactions that this transaction depends on.
+    pub dependencies: Vec<TxId>,
+    /// The ids of pending transactions that depend on this transaction.
+    pub dependents: Vec<TxId>,
+    /// The cumulative tip of the transaction and all pending transactions that depend on it.
+    pub dependents_cumulative_tip: u64,
+}
+
+impl From<SchemaPendingTransaction> for PendingTransaction {
+    fn from(value: SchemaPendingTransaction) -> Self {
+        let bytes = value.transaction.raw_payload.0 .0;
+        let transaction = Transaction::from_bytes(bytes.as_slice())
+            .map(Into::into)
+            .unwrap_or(TransactionType::Unknown);
+
+        Self {
+            id: value.id.into(),
+            transaction,
+            tip: value.tip.into(),
+            max_gas: value.max_gas.into(),
+            byte_size: value.byte_size.into(),
+            submitted_at: value.submitted_at.0,
+            dependencies: value.dependencies.into_iter().map(Into::into).collect(),
+            dependents: value.dependents.into_iter().map(Into::into).collect(),
+            dependents_cumulative_tip: value.dependents_cumulative_tip.into(),
+        }
+    }
+}
+
+impl From<PendingTransactionConnection> for PaginatedResult<PendingTransaction, String> {
+    fn from(conn: PendingTransactionConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RelayedTransactionStatus {
     Failed {
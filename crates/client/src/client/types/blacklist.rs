@@ -0,0 +1,41 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        Address,
+        ContractId,
+        Nonce,
+        UtxoId,
+    },
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Blacklist {
+    pub owners: Vec<Address>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+    pub contracts: Vec<ContractId>,
+}
+
+// GraphQL Translation
+
+impl From<schema::blacklist::Blacklist> for Blacklist {
+    fn from(value: schema::blacklist::Blacklist) -> Self {
+        Self {
+            owners: value.owners.into_iter().map(Into::into).collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<Blacklist> for schema::blacklist::BlacklistInput {
+    fn from(value: Blacklist) -> Self {
+        Self {
+            owners: Some(value.owners.into_iter().map(Into::into).collect()),
+            coins: Some(value.coins.into_iter().map(Into::into).collect()),
+            messages: Some(value.messages.into_iter().map(Into::into).collect()),
+            contracts: Some(value.contracts.into_iter().map(Into::into).collect()),
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use crate::client::{
     schema,
     schema::ConversionError,
-    types::primitives::{
-        BlockId,
-        Hash,
-        MerkleRoot,
-        PublicKey,
-        Signature,
-        TransactionId,
+    types::{
+        primitives::{
+            BlockId,
+            Hash,
+            MerkleRoot,
+            PublicKey,
+            Signature,
+            TransactionId,
+        },
+        TransactionResponse,
+        TransactionType,
     },
     PaginatedResult,
 };
@@ -33,6 +37,14 @@ impl Block {
     }
 }
 
+/// The block processed by the node along with its transactions and their statuses.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewBlock {
+    pub id: BlockId,
+    pub header: Header,
+    pub transactions: Vec<TransactionResponse>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Header {
     pub id: BlockId,
@@ -163,6 +175,33 @@ impl TryFrom<schema::block::Block> for Block {
     }
 }
 
+impl TryFrom<schema::block::NewBlock> for NewBlock {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::block::NewBlock) -> Result<Self, Self::Error> {
+        let transactions = value
+            .transactions
+            .into_iter()
+            .map(|tx| {
+                let transaction: TransactionType =
+                    fuel_core_types::fuel_tx::Transaction::try_from(tx.transaction)
+                        .map(Into::into)
+                        .unwrap_or(TransactionType::Unknown);
+                Ok(TransactionResponse {
+                    transaction,
+                    status: tx.status.try_into()?,
+                })
+            })
+            .collect::<Result<Vec<_>, ConversionError>>()?;
+
+        Ok(Self {
+            id: value.id.into(),
+            header: value.header.try_into()?,
+            transactions,
+        })
+    }
+}
+
 impl TryFrom<schema::block::BlockConnection> for PaginatedResult<Block, String> {
     type Error = ConversionError;
 
@@ -1,13 +1,23 @@
 use crate::client::{
-    schema,
+    schema::{
+        self,
+        ConversionError,
+    },
     types::primitives::{
         AssetId,
         Bytes,
+        Bytes32,
         ContractId,
+        MerkleRoot,
         Salt,
+        TransactionId,
     },
     PaginatedResult,
 };
+use fuel_core_types::{
+    fuel_tx::Receipt,
+    fuel_types::BlockHeight,
+};
 
 pub struct Contract {
     pub id: ContractId,
@@ -22,6 +32,49 @@ pub struct ContractBalance {
     pub asset_id: AssetId,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractStorageSlot {
+    pub contract: ContractId,
+    pub key: Bytes32,
+    /// The value of the slot, `None` if the slot is not set.
+    pub value: Option<Bytes>,
+    /// The proof of the slot, if it was requested.
+    pub proof: Option<ContractStateProof>,
+}
+
+/// The receipt related to the contract with its position on the chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractReceipt {
+    pub block_height: BlockHeight,
+    pub transaction_id: TransactionId,
+    pub receipt: Receipt,
+}
+
+/// Selects the storage slots of the contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContractStorageSlotsFilter {
+    /// The slots with these keys, including the ones that are not set.
+    Keys(Vec<Bytes32>),
+    /// The set slots with keys within the inclusive range. The range is unbounded
+    /// from the side where the bound is `None`.
+    Range {
+        start: Option<Bytes32>,
+        end: Option<Bytes32>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractStateProof {
+    /// The state root of the contract against which the proof was generated.
+    pub state_root: MerkleRoot,
+    /// `true` for the inclusion proof and `false` for the exclusion proof.
+    pub inclusion: bool,
+    pub proof_set: Vec<MerkleRoot>,
+    /// The leaf found on the path of the excluded slot(for exclusion proofs only).
+    pub leaf_key: Option<Bytes32>,
+    pub leaf_value: Option<Bytes32>,
+}
+
 // GraphQL Translation
 
 impl From<schema::contract::Contract> for Contract {
@@ -56,3 +109,98 @@ impl From<schema::contract::ContractBalanceConnection>
         }
     }
 }
+
+impl From<schema::contract::ContractStateProof> for ContractStateProof {
+    fn from(value: schema::contract::ContractStateProof) -> Self {
+        Self {
+            state_root: value.state_root.into(),
+            inclusion: value.inclusion,
+            proof_set: value.proof_set.into_iter().map(Into::into).collect(),
+            leaf_key: value.leaf_key.map(Into::into),
+            leaf_value: value.leaf_value.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlot> for ContractStorageSlot {
+    fn from(value: schema::contract::ContractStorageSlot) -> Self {
+        Self {
+            contract: value.contract.into(),
+            key: value.key.into(),
+            value: value.value.map(Into::into),
+            proof: None,
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlotWithProof> for ContractStorageSlot {
+    fn from(value: schema::contract::ContractStorageSlotWithProof) -> Self {
+        Self {
+            contract: value.contract.into(),
+            key: value.key.into(),
+            value: value.value.map(Into::into),
+            proof: Some(value.proof.into()),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlotConnection>
+    for PaginatedResult<ContractStorageSlot, String>
+{
+    fn from(conn: schema::contract::ContractStorageSlotConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+impl From<schema::contract::ContractStorageSlotWithProofConnection>
+    for PaginatedResult<ContractStorageSlot, String>
+{
+    fn from(conn: schema::contract::ContractStorageSlotWithProofConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+impl TryFrom<schema::tx::ContractReceipt> for ContractReceipt {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::tx::ContractReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            block_height: value.block_height.into(),
+            transaction_id: value.transaction_id.into(),
+            receipt: value.receipt.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<schema::tx::ContractReceiptConnection>
+    for PaginatedResult<ContractReceipt, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        conn: schema::tx::ContractReceiptConnection,
+    ) -> Result<Self, Self::Error> {
+        let results = conn
+            .edges
+            .into_iter()
+            .map(|e| e.node.try_into())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results,
+        })
+    }
+}
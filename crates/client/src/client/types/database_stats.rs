@@ -0,0 +1,49 @@
+use crate::client::schema;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatabaseStats {
+    pub name: String,
+    pub block_cache_hit_rate: Option<f64>,
+    pub columns: Vec<ColumnStats>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnStats {
+    pub name: String,
+    pub reads: u64,
+    pub writes: u64,
+    pub iterations: u64,
+    pub bytes_read: u64,
+    pub bytes_written: u64,
+    pub live_data_size: u64,
+    pub sst_files: u64,
+    pub pending_compaction_bytes: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::database::DatabaseStats> for DatabaseStats {
+    fn from(value: schema::database::DatabaseStats) -> Self {
+        Self {
+            name: value.name,
+            block_cache_hit_rate: value.block_cache_hit_rate,
+            columns: value.columns.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::database::ColumnStats> for ColumnStats {
+    fn from(value: schema::database::ColumnStats) -> Self {
+        Self {
+            name: value.name,
+            reads: value.reads.into(),
+            writes: value.writes.into(),
+            iterations: value.iterations.into(),
+            bytes_read: value.bytes_read.into(),
+            bytes_written: value.bytes_written.into(),
+            live_data_size: value.live_data_size.into(),
+            sst_files: value.sst_files.into(),
+            pending_compaction_bytes: value.pending_compaction_bytes.into(),
+        }
+    }
+}
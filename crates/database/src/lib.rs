@@ -71,6 +71,17 @@ pub enum Error {
     },
     #[display(fmt = "Reached the end of the history")]
     ReachedEndOfHistory,
+    /// The transactions of the requested height were removed by the history expiry.
+    #[display(
+        fmt = "The history for the requested height {requested_height:#x} is pruned, \
+            the oldest available height is {oldest_available_height:#x}"
+    )]
+    HistoryIsPruned {
+        /// The height of the requested data.
+        requested_height: u64,
+        /// The height of the oldest block with the available transactions.
+        oldest_available_height: u64,
+    },
 
     /// Not related to database error.
     #[from]
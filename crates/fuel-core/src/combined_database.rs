@@ -1,5 +1,7 @@
-#[cfg(feature = "rocksdb")]
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
 use crate::state::historical_rocksdb::StateRewindPolicy;
+#[cfg(feature = "rocksdb")]
+use crate::state::rocks_db::config::RocksDbConfig;
 use crate::{
     database::{
         database_description::{
@@ -7,8 +9,10 @@ use crate::{
             off_chain::OffChain,
             on_chain::OnChain,
             relayer::Relayer,
+            DatabaseDescription,
         },
         Database,
+        Error as DatabaseError,
         GenesisDatabase,
         Result as DatabaseResult,
     },
@@ -19,6 +23,7 @@ use fuel_core_chain_config::{
     StateConfig,
     StateConfigBuilder,
 };
+use fuel_core_metrics::core_metrics::DatabaseStats;
 #[cfg(feature = "test-helpers")]
 use fuel_core_storage::tables::{
     Coins,
@@ -28,19 +33,34 @@ use fuel_core_storage::tables::{
     ContractsState,
     Messages,
 };
-use fuel_core_storage::Result as StorageResult;
+use fuel_core_storage::{
+    transactional::HistoricalView,
+    Result as StorageResult,
+};
 use fuel_core_types::fuel_types::BlockHeight;
-use std::path::PathBuf;
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CombinedDatabaseConfig {
     pub database_path: PathBuf,
     pub database_type: DbType,
     pub max_database_cache_size: usize,
-    #[cfg(feature = "rocksdb")]
+    #[cfg(any(feature = "rocksdb", feature = "redb"))]
     pub state_rewind_policy: StateRewindPolicy,
     #[cfg(feature = "rocksdb")]
     pub max_fds: i32,
+    /// The tuning of the databases and their columns.
+    #[cfg(feature = "rocksdb")]
+    pub rocksdb_config: RocksDbConfig,
 }
 
 /// A database that combines the on-chain, off-chain and relayer databases into one entity.
@@ -76,27 +96,75 @@ impl CombinedDatabase {
         Ok(())
     }
 
+    #[cfg(feature = "redb")]
+    pub fn prune_redb(path: &std::path::Path) -> crate::database::Result<()> {
+        crate::state::redb::Redb::<OnChain>::prune(path)?;
+        crate::state::redb::Redb::<OffChain>::prune(path)?;
+        crate::state::redb::Redb::<Relayer>::prune(path)?;
+        crate::state::redb::Redb::<GasPriceDatabase>::prune(path)?;
+        Ok(())
+    }
+
     #[cfg(feature = "rocksdb")]
     pub fn open(
         path: &std::path::Path,
         capacity: usize,
         state_rewind_policy: StateRewindPolicy,
         max_fds: i32,
+        rocksdb_config: &RocksDbConfig,
     ) -> crate::database::Result<Self> {
         // Split the fds in equitable manner between the databases
         let max_fds = match max_fds {
             -1 => -1,
             _ => max_fds.saturating_div(4),
         };
-        // TODO: Use different cache sizes for different databases
-        let on_chain =
-            Database::open_rocksdb(path, capacity, state_rewind_policy, max_fds)?;
-        let off_chain =
-            Database::open_rocksdb(path, capacity, state_rewind_policy, max_fds)?;
-        let relayer =
-            Database::open_rocksdb(path, capacity, StateRewindPolicy::NoRewind, max_fds)?;
-        let gas_price =
-            Database::open_rocksdb(path, capacity, state_rewind_policy, max_fds)?;
+        // Each database uses the whole cache size unless the `rocksdb_config` limits it
+        let on_chain = Database::open_rocksdb(
+            path,
+            capacity,
+            state_rewind_policy,
+            max_fds,
+            rocksdb_config,
+        )?;
+        let off_chain = Database::open_rocksdb(
+            path,
+            capacity,
+            state_rewind_policy,
+            max_fds,
+            rocksdb_config,
+        )?;
+        let relayer = Database::open_rocksdb(
+            path,
+            capacity,
+            StateRewindPolicy::NoRewind,
+            max_fds,
+            rocksdb_config,
+        )?;
+        let gas_price = Database::open_rocksdb(
+            path,
+            capacity,
+            state_rewind_policy,
+            max_fds,
+            rocksdb_config,
+        )?;
+        Ok(Self {
+            on_chain,
+            off_chain,
+            relayer,
+            gas_price,
+        })
+    }
+
+    #[cfg(feature = "redb")]
+    pub fn open_redb(
+        path: &std::path::Path,
+        capacity: usize,
+        state_rewind_policy: StateRewindPolicy,
+    ) -> crate::database::Result<Self> {
+        let on_chain = Database::open_redb(path, capacity, state_rewind_policy)?;
+        let off_chain = Database::open_redb(path, capacity, state_rewind_policy)?;
+        let relayer = Database::open_redb(path, capacity, StateRewindPolicy::NoRewind)?;
+        let gas_price = Database::open_redb(path, capacity, state_rewind_policy)?;
         Ok(Self {
             on_chain,
             off_chain,
@@ -105,6 +173,19 @@ impl CombinedDatabase {
         })
     }
 
+    /// A temporary redb database with given rewind policy.
+    #[cfg(feature = "redb")]
+    pub fn temp_redb_database_with_state_rewind_policy(
+        state_rewind_policy: StateRewindPolicy,
+    ) -> DatabaseResult<Self> {
+        Ok(Self {
+            on_chain: Database::redb_temp(state_rewind_policy)?,
+            off_chain: Database::redb_temp(state_rewind_policy)?,
+            relayer: Database::redb_temp(StateRewindPolicy::NoRewind)?,
+            gas_price: Database::redb_temp(state_rewind_policy)?,
+        })
+    }
+
     /// A test-only temporary rocksdb database with given rewind policy.
     #[cfg(feature = "rocksdb")]
     pub fn temp_database_with_state_rewind_policy(
@@ -142,17 +223,162 @@ impl CombinedDatabase {
                         config.max_database_cache_size,
                         config.state_rewind_policy,
                         config.max_fds,
+                        &config.rocksdb_config,
+                    )?
+                }
+            }
+            #[cfg(feature = "redb")]
+            DbType::Redb => {
+                if config.database_path.as_os_str().is_empty() {
+                    tracing::warn!(
+                        "No redb path configured, initializing database with a tmp directory"
+                    );
+                    CombinedDatabase::temp_redb_database_with_state_rewind_policy(
+                        config.state_rewind_policy,
+                    )?
+                } else {
+                    tracing::info!(
+                        "Opening redb database {:?} with cache size \"{}\" and state rewind policy \"{:?}\"",
+                        config.database_path,
+                        config.max_database_cache_size,
+                        config.state_rewind_policy,
+                    );
+                    CombinedDatabase::open_redb(
+                        &config.database_path,
+                        config.max_database_cache_size,
+                        config.state_rewind_policy,
                     )?
                 }
             }
             DbType::InMemory => CombinedDatabase::in_memory(),
-            #[cfg(not(feature = "rocksdb"))]
+            #[cfg(not(all(feature = "rocksdb", feature = "redb")))]
             _ => CombinedDatabase::in_memory(),
         };
 
         Ok(combined_database)
     }
 
+    /// Opens the off-chain database in the `path` with the settings of the `config`.
+    /// The temporary database is created if the `path` is empty.
+    pub fn open_off_chain(
+        config: &CombinedDatabaseConfig,
+        path: &Path,
+    ) -> DatabaseResult<Database<OffChain>> {
+        let off_chain = match config.database_type {
+            #[cfg(feature = "rocksdb")]
+            DbType::RocksDb if path.as_os_str().is_empty() => {
+                Database::rocksdb_temp(config.state_rewind_policy)?
+            }
+            #[cfg(feature = "rocksdb")]
+            DbType::RocksDb => {
+                // The same share of the file descriptors as in `CombinedDatabase::open`.
+                let max_fds = match config.max_fds {
+                    -1 => -1,
+                    _ => config.max_fds.saturating_div(4),
+                };
+                Database::open_rocksdb(
+                    path,
+                    config.max_database_cache_size,
+                    config.state_rewind_policy,
+                    max_fds,
+                    &config.rocksdb_config,
+                )?
+            }
+            #[cfg(feature = "redb")]
+            DbType::Redb if path.as_os_str().is_empty() => {
+                Database::redb_temp(config.state_rewind_policy)?
+            }
+            #[cfg(feature = "redb")]
+            DbType::Redb => Database::open_redb(
+                path,
+                config.max_database_cache_size,
+                config.state_rewind_policy,
+            )?,
+            DbType::InMemory => Database::in_memory(),
+            #[cfg(not(all(feature = "rocksdb", feature = "redb")))]
+            _ => Database::in_memory(),
+        };
+
+        Ok(off_chain)
+    }
+
+    /// Replaces the off-chain database with the `new_off_chain` database
+    /// opened by [`CombinedDatabase::open_off_chain`] in the `new_path`.
+    ///
+    /// The files of the current off-chain database are moved aside, the files
+    /// of the new one are moved in their place, and only after that the old files
+    /// are removed. If any step fails, the current off-chain database is restored.
+    /// No other instance of the combined database should use any of the files.
+    pub fn replace_off_chain(
+        &mut self,
+        config: &CombinedDatabaseConfig,
+        new_path: &Path,
+        new_off_chain: Database<OffChain>,
+    ) -> DatabaseResult<()> {
+        if new_path.as_os_str().is_empty() {
+            self.off_chain = new_off_chain;
+            return Ok(())
+        }
+
+        let name = OffChain::name();
+        let current_path = config.database_path.join(&name);
+        let staged_path = new_path.join(&name);
+        let replaced_path = new_path.join(format!("{name}.replaced"));
+
+        // The databases should be closed before their files are moved.
+        drop(new_off_chain);
+        self.off_chain = Database::in_memory();
+
+        if let Err(err) =
+            Self::swap_directories(&current_path, &staged_path, &replaced_path)
+        {
+            self.off_chain = Self::open_off_chain(config, &config.database_path)?;
+            return Err(err)
+        }
+
+        match Self::open_off_chain(config, &config.database_path) {
+            Ok(off_chain) => self.off_chain = off_chain,
+            Err(err) => {
+                Self::swap_directories(&current_path, &replaced_path, &staged_path)?;
+                self.off_chain = Self::open_off_chain(config, &config.database_path)?;
+                return Err(err)
+            }
+        }
+
+        // The old files are inside of the `new_path`, so they are removed with it.
+        std::fs::remove_dir_all(new_path).map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
+    }
+
+    /// Moves the `current` directory to the `aside` path and the `incoming`
+    /// directory to the `current` path. The `current` directory is moved back
+    /// if the `incoming` one can't be moved in.
+    fn swap_directories(
+        current: &Path,
+        incoming: &Path,
+        aside: &Path,
+    ) -> DatabaseResult<()> {
+        let rename = |from: &Path, to: &Path| {
+            std::fs::rename(from, to).map_err(|e| DatabaseError::Other(e.into()))
+        };
+
+        // The leftover of the interrupted replacement.
+        if aside.exists() {
+            std::fs::remove_dir_all(aside).map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+        let current_exists = current.exists();
+        if current_exists {
+            rename(current, aside)?;
+        }
+        if let Err(err) = rename(incoming, current) {
+            if current_exists {
+                rename(aside, current)?;
+            }
+            return Err(err)
+        }
+        Ok(())
+    }
+
     pub fn in_memory() -> Self {
         Self::new(
             Database::in_memory(),
@@ -162,6 +388,113 @@ impl CombinedDatabase {
         )
     }
 
+    /// Restores the databases from the backup in the `backup_path` into the `path`.
+    /// The version metadata and the heights of the databases in the backup are
+    /// validated before the restoration. Returns the height of the restored blocks.
+    #[cfg(feature = "rocksdb")]
+    pub fn restore(
+        backup_path: &Path,
+        path: &Path,
+        capacity: usize,
+        max_fds: i32,
+    ) -> anyhow::Result<BlockHeight> {
+        ensure_empty_directory(path)?;
+
+        let backup = Self::open(
+            backup_path,
+            capacity,
+            StateRewindPolicy::NoRewind,
+            max_fds,
+            &RocksDbConfig::default(),
+        )?;
+        backup.check_version()?;
+        backup.gas_price().check_version()?;
+
+        // The backup doesn't receive new blocks, so the databases should
+        // already be at the same height.
+        backup.backup(path, Duration::ZERO)
+    }
+
+    /// Returns the statistics of the databases that collect them.
+    pub fn stats(&self) -> StorageResult<Vec<DatabaseStats>> {
+        let stats = [
+            self.on_chain.stats()?,
+            self.off_chain.stats()?,
+            self.relayer.stats()?,
+            self.gas_price.stats()?,
+        ];
+        Ok(stats.into_iter().flatten().collect())
+    }
+
+    /// Creates consistent checkpoints of all databases in the `path`.
+    ///
+    /// Waits up to the `sync_timeout` for the off-chain and gas price databases
+    /// to catch up with the on-chain database without pausing any commits.
+    /// After that, the commits to all databases are paused only while
+    /// the checkpoints are created. Returns the height of the blocks in the backup.
+    pub fn backup(
+        &self,
+        path: &Path,
+        sync_timeout: Duration,
+    ) -> anyhow::Result<BlockHeight> {
+        ensure_empty_directory(path)?;
+
+        let started = Instant::now();
+        loop {
+            if let Some(height) = self.synced_height()? {
+                let _on_chain = self.on_chain().lock_commits();
+                let _off_chain = self.off_chain().lock_commits();
+                let _gas_price = self.gas_price().lock_commits();
+                // The relayer database tracks the DA height, so it is only paused.
+                let _relayer = self.relayer().lock_commits();
+
+                // A new block could be committed before the commits were paused.
+                if self.synced_height()? == Some(height) {
+                    std::fs::create_dir_all(path)?;
+                    self.on_chain().checkpoint(path)?;
+                    self.off_chain().checkpoint(path)?;
+                    self.relayer().checkpoint(path)?;
+                    self.gas_price().checkpoint(path)?;
+
+                    tracing::info!(
+                        "Created the backup of the databases at height {height} in {path:?}"
+                    );
+                    return Ok(height)
+                }
+            }
+
+            if started.elapsed() >= sync_timeout {
+                return Err(anyhow::anyhow!(
+                    "off-chain database height({:?}) and gas-price-chain database \
+                    height({:?}) don't match on-chain database height({:?})",
+                    self.off_chain().latest_height(),
+                    self.gas_price().latest_height(),
+                    self.on_chain().latest_height(),
+                ));
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// Returns the height of the on-chain database if the off-chain
+    /// and gas price databases are at the same height.
+    fn synced_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        let height = self
+            .on_chain()
+            .latest_height()
+            .ok_or(anyhow::anyhow!("on-chain database doesn't have height"))?;
+
+        // The gas price database doesn't have height if the gas price
+        // service hasn't processed any blocks yet.
+        let gas_price_synced = self
+            .gas_price()
+            .latest_height()
+            .map_or(true, |gas_price_height| gas_price_height == height);
+        let synced = self.off_chain().latest_height() == Some(height) && gas_price_synced;
+
+        Ok(synced.then_some(height))
+    }
+
     pub fn check_version(&self) -> StorageResult<()> {
         self.on_chain.check_version()?;
         self.off_chain.check_version()?;
@@ -375,6 +708,13 @@ impl CombinedDatabase {
     }
 }
 
+fn ensure_empty_directory(path: &Path) -> anyhow::Result<()> {
+    if path.exists() && path.read_dir()?.next().is_some() {
+        return Err(anyhow::anyhow!("the directory {path:?} is not empty"));
+    }
+    Ok(())
+}
+
 /// A trait for listening to shutdown signals.
 pub trait ShutdownListener {
     /// Returns true if the shutdown signal has been received.
@@ -26,6 +26,7 @@ use crate::{
 };
 use fuel_core_chain_config::TableEntry;
 use fuel_core_gas_price_service::common::fuel_core_storage_adapter::storage::GasPriceMetadata;
+use fuel_core_metrics::core_metrics::DatabaseStats;
 use fuel_core_services::SharedMutex;
 use fuel_core_storage::{
     self,
@@ -71,16 +72,25 @@ use crate::database::database_description::{
     gas_price::GasPriceDatabase,
     indexation_availability,
 };
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
+use crate::state::historical_rocksdb::{
+    description::Historical,
+    StateRewindPolicy,
+};
+#[cfg(feature = "redb")]
+use crate::state::redb::{
+    HistoricalRedb,
+    Redb,
+};
 #[cfg(feature = "rocksdb")]
 use crate::state::{
-    historical_rocksdb::{
-        description::Historical,
-        HistoricalRocksDB,
-        StateRewindPolicy,
+    historical_rocksdb::HistoricalRocksDB,
+    rocks_db::{
+        config::RocksDbConfig,
+        RocksDb,
     },
-    rocks_db::RocksDb,
 };
-#[cfg(feature = "rocksdb")]
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
 use std::path::Path;
 
 // Storages implementation
@@ -108,6 +118,9 @@ where
 {
     /// Cached value from Metadata table, used to speed up lookups.
     height: SharedMutex<Option<Description::Height>>,
+    /// Held during the commits. Allows pausing the commits
+    /// without blocking the readers of the `height`.
+    commit_gate: SharedMutex<()>,
 }
 
 impl<Description> Default for RegularStage<Description>
@@ -117,6 +130,7 @@ where
     fn default() -> Self {
         Self {
             height: SharedMutex::new(None),
+            commit_gate: SharedMutex::new(()),
         }
     }
 }
@@ -126,6 +140,8 @@ pub type Database<Description = OnChain, Stage = RegularStage<Description>> =
 pub type OnChainIterableKeyValueView = IterableKeyValueView<ColumnType<OnChain>>;
 pub type OffChainIterableKeyValueView = IterableKeyValueView<ColumnType<OffChain>>;
 pub type RelayerIterableKeyValueView = IterableKeyValueView<ColumnType<Relayer>>;
+pub type OnChainKeyValueView = KeyValueView<ColumnType<OnChain>>;
+pub type OffChainKeyValueView = KeyValueView<ColumnType<OffChain>>;
 
 pub type GenesisDatabase<Description = OnChain> = Database<Description, GenesisStage>;
 
@@ -183,12 +199,8 @@ where
         StorageInspect<MetadataTable<Description>, Error = StorageError>,
 {
     pub fn new(data_source: DataSourceType<Description>) -> Self {
-        let mut database = Self::from_storage(DataSource::new(
-            data_source,
-            RegularStage {
-                height: SharedMutex::new(None),
-            },
-        ));
+        let mut database =
+            Self::from_storage(DataSource::new(data_source, RegularStage::default()));
         let height = database
             .latest_height_from_metadata()
             .expect("Failed to get latest height during creation of the database");
@@ -204,6 +216,7 @@ where
         capacity: impl Into<Option<usize>>,
         state_rewind_policy: StateRewindPolicy,
         max_fds: i32,
+        config: &RocksDbConfig,
     ) -> Result<Self> {
         use anyhow::Context;
         let db = HistoricalRocksDB::<Description>::default_open(
@@ -211,6 +224,7 @@ where
             capacity.into(),
             state_rewind_policy,
             max_fds,
+            config,
         )
         .map_err(Into::<anyhow::Error>::into)
         .with_context(|| {
@@ -223,6 +237,26 @@ where
         Ok(Self::new(Arc::new(db)))
     }
 
+    #[cfg(feature = "redb")]
+    pub fn open_redb(
+        path: &Path,
+        capacity: impl Into<Option<usize>>,
+        state_rewind_policy: StateRewindPolicy,
+    ) -> Result<Self> {
+        use anyhow::Context;
+        let db = Redb::<Historical<Description>>::default_open(path, capacity.into())
+            .and_then(|db| HistoricalRedb::new(db, state_rewind_policy))
+            .map_err(Into::<anyhow::Error>::into)
+            .with_context(|| {
+                format!(
+                    "Failed to open redb, you may need to wipe a \
+                    pre-existing incompatible db e.g. `rm -rf {path:?}`"
+                )
+            })?;
+
+        Ok(Self::new(Arc::new(db)))
+    }
+
     /// Converts the regular database to an unchecked database.
     ///
     /// Returns an error in the case regular database is initialized with the `GenesisDatabase`,
@@ -261,21 +295,35 @@ where
         let data = Arc::new(historical_db);
         Ok(Self::from_storage(DataSource::new(data, Stage::default())))
     }
+
+    #[cfg(feature = "redb")]
+    pub fn redb_temp(rewind_policy: StateRewindPolicy) -> Result<Self> {
+        let db = Redb::<Historical<Description>>::default_open_temp(None)?;
+        let historical_db = HistoricalRedb::new(db, rewind_policy)?;
+        let data = Arc::new(historical_db);
+        Ok(Self::from_storage(DataSource::new(data, Stage::default())))
+    }
 }
 
 /// Construct an ephemeral database
 /// uses rocksdb when rocksdb features are enabled
-/// uses in-memory when rocksdb features are disabled
+/// uses redb when only redb features are enabled
+/// uses in-memory when rocksdb and redb features are disabled
 impl<Description, Stage> Default for Database<Description, Stage>
 where
     Description: DatabaseDescription,
     Stage: Default,
 {
     fn default() -> Self {
-        #[cfg(not(feature = "rocksdb"))]
+        #[cfg(not(any(feature = "rocksdb", feature = "redb")))]
         {
             Self::in_memory()
         }
+        #[cfg(all(feature = "redb", not(feature = "rocksdb")))]
+        {
+            Self::redb_temp(StateRewindPolicy::NoRewind)
+                .expect("Failed to create a temporary database")
+        }
         #[cfg(feature = "rocksdb")]
         {
             Self::rocksdb_temp(StateRewindPolicy::NoRewind)
@@ -289,6 +337,7 @@ where
     Description: DatabaseDescription,
 {
     pub fn rollback_last_block(&self) -> StorageResult<()> {
+        let _commit_gate = self.inner_storage().stage.commit_gate.lock();
         let mut lock = self.inner_storage().stage.height.lock();
         let height = *lock;
 
@@ -308,6 +357,32 @@ where
 
         Ok(())
     }
+
+    /// Pauses the commits to the database until the returned guard is dropped.
+    /// The readers of the height are not blocked.
+    pub fn lock_commits(&self) -> impl Sized + '_ {
+        self.inner_storage().stage.commit_gate.lock()
+    }
+
+    /// Creates a consistent copy of the database in the `path`.
+    pub fn checkpoint(&self, path: &std::path::Path) -> StorageResult<()> {
+        self.inner_storage().data.checkpoint(path)
+    }
+
+    /// Returns the statistics of the columns of the database,
+    /// or `None` if the database doesn't collect them.
+    pub fn stats(&self) -> StorageResult<Option<DatabaseStats>> {
+        self.inner_storage().data.stats()
+    }
+
+    /// Commits the `changes` that don't belong to any block, like the repair of
+    /// the derived data. Unlike the regular commit, the changes don't set the new
+    /// height and don't get into the history of the modifications.
+    pub fn commit_changes_outside_of_block(&self, changes: Changes) -> StorageResult<()> {
+        let _commit_gate = self.inner_storage().stage.commit_gate.lock();
+        let _height = self.inner_storage().stage.height.lock();
+        self.inner_storage().data.commit_changes(None, changes)
+    }
 }
 
 impl<Description> AtomicView for Database<Description>
@@ -425,6 +500,10 @@ where
     for<'a> StorageTransaction<&'a &'a mut Database<Description>>:
         StorageMutate<MetadataTable<Description>, Error = StorageError>,
 {
+    // Blocks the commit while the commits are paused by `Database::lock_commits`.
+    let commit_gate = database.stage.commit_gate.clone();
+    let _commit_gate = commit_gate.lock();
+
     // Gets the all new heights from the `changes`
     let iterator = ChangesIterator::<Description::Column>::new(&changes);
     let new_heights = heights_lookup(&iterator)?;
@@ -610,6 +689,34 @@ mod tests {
             assert_eq!(database.latest_height(), Some(advanced_height));
         }
 
+        #[test]
+        fn lock_commits__pauses_commits_without_blocking_readers_of_height() {
+            // Given
+            let mut database = Database::<OnChain>::default();
+            database
+                .storage_as_mut::<FuelBlocks>()
+                .insert(&1.into(), &CompressedBlock::default())
+                .unwrap();
+            let commits_lock = database.lock_commits();
+
+            // When
+            let mut committer = database.clone();
+            let commit = std::thread::spawn(move || {
+                committer
+                    .storage_as_mut::<FuelBlocks>()
+                    .insert(&2.into(), &CompressedBlock::default())
+                    .unwrap();
+            });
+            std::thread::sleep(std::time::Duration::from_millis(100));
+
+            // Then
+            assert_eq!(database.latest_height(), Some(1.into()));
+            assert!(!commit.is_finished());
+            drop(commits_lock);
+            commit.join().unwrap();
+            assert_eq!(database.latest_height(), Some(2.into()));
+        }
+
         #[test]
         fn database_not_advances_without_block() {
             // Given
@@ -1110,6 +1217,7 @@ mod tests {
             1024 * 1024 * 1024,
             Default::default(),
             512,
+            &Default::default(),
         )
         .unwrap();
         // rocks db fails
@@ -7,18 +7,33 @@ use fuel_core_storage::{
     },
     not_found,
     tables::{
+        merkle::{
+            ContractsStateMerkleData,
+            ContractsStateMerkleMetadata,
+        },
         ContractsAssets,
         ContractsLatestUtxo,
         ContractsRawCode,
         ContractsState,
     },
     ContractsAssetKey,
+    ContractsStateKey,
+    Error as StorageError,
     Result as StorageResult,
     StorageAsRef,
 };
-use fuel_core_types::fuel_types::{
-    AssetId,
-    ContractId,
+use fuel_core_types::{
+    fuel_merkle::sparse::{
+        in_memory,
+        MerkleTree,
+        MerkleTreeKey,
+    },
+    fuel_types::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+    services::graphql_api::ContractStateProof,
 };
 use itertools::Itertools;
 
@@ -92,6 +107,50 @@ impl OnChainIterableKeyValueView {
         )
         .map_ok(|(key, value)| TableEntry { key, value })
     }
+
+    pub fn filter_contract_state(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<TableEntry<ContractsState>>> + '_ {
+        let start_key = start_key.map(|key| ContractsStateKey::new(&contract, &key));
+        self.iter_all_filtered::<ContractsState, _>(
+            Some(contract),
+            start_key.as_ref(),
+            direction,
+        )
+        .map_ok(|(key, value)| TableEntry { key, value })
+    }
+
+    /// Generates the sparse Merkle proof of the `state_key` slot against the
+    /// current state root of the contract. The proof is an exclusion proof
+    /// if the slot is not set.
+    pub fn contract_state_proof(
+        &self,
+        contract_id: &ContractId,
+        state_key: &Bytes32,
+    ) -> StorageResult<ContractStateProof> {
+        let state_root = self
+            .storage::<ContractsStateMerkleMetadata>()
+            .get(contract_id)?
+            .map(|metadata| *metadata.root())
+            .unwrap_or_else(|| in_memory::MerkleTree::new().root());
+
+        let tree: MerkleTree<ContractsStateMerkleData, _> =
+            MerkleTree::load(self, &state_root)
+                .map_err(|err| StorageError::Other(anyhow::anyhow!("{err:?}")))?;
+
+        let key = ContractsStateKey::new(contract_id, state_key);
+        let proof = tree
+            .generate_proof(&MerkleTreeKey::new(key))
+            .map_err(|err| StorageError::Other(anyhow::anyhow!("{err:?}")))?;
+
+        Ok(ContractStateProof {
+            state_root: state_root.into(),
+            proof,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -101,8 +160,14 @@ mod tests {
         database_description::on_chain::OnChain,
         Database,
     };
-    use fuel_core_storage::StorageAsMut;
-    use fuel_core_types::fuel_tx::Contract;
+    use fuel_core_storage::{
+        transactional::AtomicView,
+        StorageAsMut,
+    };
+    use fuel_core_types::{
+        fuel_merkle::sparse::proof::Proof,
+        fuel_tx::Contract,
+    };
     use rand::{
         RngCore,
         SeedableRng,
@@ -130,4 +195,65 @@ mod tests {
             .into_owned();
         assert_eq!(returned, contract);
     }
+
+    #[test]
+    fn contract_state_proof_is_inclusion_proof_for_existing_slot() {
+        let contract_id: ContractId = ContractId::from([1u8; 32]);
+        let state_key = Bytes32::from([2u8; 32]);
+        let value = vec![3u8; 32];
+
+        let database = &mut Database::<OnChain>::default();
+        for i in 0..10u8 {
+            database
+                .storage::<ContractsState>()
+                .insert(
+                    &ContractsStateKey::new(&contract_id, &[i; 32].into()),
+                    &[i; 32],
+                )
+                .unwrap();
+        }
+        let key = ContractsStateKey::new(&contract_id, &state_key);
+        database
+            .storage::<ContractsState>()
+            .insert(&key, &value)
+            .unwrap();
+        let view = database.latest_view().unwrap();
+
+        // When
+        let result = view.contract_state_proof(&contract_id, &state_key).unwrap();
+
+        // Then
+        let Proof::Inclusion(proof) = result.proof else {
+            panic!("Expected the inclusion proof")
+        };
+        assert!(proof.verify(&result.state_root, &MerkleTreeKey::new(key), &value));
+    }
+
+    #[test]
+    fn contract_state_proof_is_exclusion_proof_for_missing_slot() {
+        let contract_id: ContractId = ContractId::from([1u8; 32]);
+        let state_key = Bytes32::from([42u8; 32]);
+
+        let database = &mut Database::<OnChain>::default();
+        for i in 0..10u8 {
+            database
+                .storage::<ContractsState>()
+                .insert(
+                    &ContractsStateKey::new(&contract_id, &[i; 32].into()),
+                    &[i; 32],
+                )
+                .unwrap();
+        }
+        let view = database.latest_view().unwrap();
+
+        // When
+        let result = view.contract_state_proof(&contract_id, &state_key).unwrap();
+
+        // Then
+        let Proof::Exclusion(proof) = result.proof else {
+            panic!("Expected the exclusion proof")
+        };
+        let key = ContractsStateKey::new(&contract_id, &state_key);
+        assert!(proof.verify(&result.state_root, &MerkleTreeKey::new(key)));
+    }
 }
@@ -1,10 +1,18 @@
 use crate::{
     database::OffChainIterableKeyValueView,
-    fuel_core_graphql_api::storage::transactions::{
-        OwnedTransactionIndexCursor,
-        OwnedTransactionIndexKey,
-        OwnedTransactions,
-        TransactionStatuses,
+    fuel_core_graphql_api::storage::{
+        receipts::{
+            ContractReceipt,
+            ContractReceiptCursor,
+            ContractReceiptKey,
+            ContractReceipts,
+        },
+        transactions::{
+            OwnedTransactionIndexCursor,
+            OwnedTransactionIndexKey,
+            OwnedTransactions,
+            TransactionStatuses,
+        },
     },
 };
 use fuel_core_storage::{
@@ -18,6 +26,7 @@ use fuel_core_types::{
     self,
     fuel_tx::{
         Bytes32,
+        ContractId,
         TxPointer,
     },
     fuel_types::Address,
@@ -62,6 +71,24 @@ impl OffChainIterableKeyValueView {
         })
     }
 
+    /// Iterates over a KV mapping of `[contract id + block height + tx idx + receipt idx] => receipt`.
+    /// The cursor tracks the `[block height + tx idx + receipt idx]` for pagination purposes.
+    pub fn contract_receipts(
+        &self,
+        contract_id: ContractId,
+        start: Option<ContractReceiptCursor>,
+        direction: Option<IterDirection>,
+    ) -> impl Iterator<Item = StorageResult<(ContractReceiptCursor, ContractReceipt)>> + '_
+    {
+        let start = start.map(|cursor| ContractReceiptKey::new(&contract_id, cursor));
+        self.iter_all_filtered::<ContractReceipts, _>(
+            Some(contract_id),
+            start.as_ref(),
+            direction,
+        )
+        .map(|res| res.map(|(key, receipt)| (key.cursor, receipt)))
+    }
+
     pub fn get_tx_status(
         &self,
         id: &Bytes32,
@@ -69,6 +69,7 @@ mod tests {
                 Outputs,
                 Policies,
                 Script as ScriptField,
+                ScriptGasLimit,
                 TxPointer as TxPointerTraitTrait,
             },
             input::{
@@ -140,7 +141,10 @@ mod tests {
         },
         tai64::Tai64,
     };
-    use fuel_core_upgradable_executor::executor::Executor;
+    use fuel_core_upgradable_executor::{
+        config::ValidationMode,
+        executor::Executor,
+    };
     use itertools::Itertools;
     use rand::{
         prelude::StdRng,
@@ -197,11 +201,22 @@ mod tests {
     fn create_executor(
         database: Database,
         config: Config,
+    ) -> Executor<Database, DisabledRelayer> {
+        create_executor_with_validation_mode(database, config, Default::default())
+    }
+
+    fn create_executor_with_validation_mode(
+        database: Database,
+        config: Config,
+        validation_mode: ValidationMode,
     ) -> Executor<Database, DisabledRelayer> {
         let executor_config = fuel_core_upgradable_executor::config::Config {
             backtrace: config.backtrace,
             utxo_validation_default: config.utxo_validation_default,
             native_executor_version: None,
+            validation_mode,
+            module_cache_path: None,
+            verify_executor_parity: false,
         };
 
         let database = add_consensus_parameters(database, &config.consensus_parameters);
@@ -347,6 +362,171 @@ mod tests {
         assert!(skipped_transactions.is_empty());
     }
 
+    /// Validates the `block` sequentially, in parallel and in the compare mode,
+    /// and checks that all modes agree.
+    fn assert_parallel_validation_matches_sequential(block: &Block) {
+        let sequential = create_executor(Default::default(), Default::default());
+        let parallel = create_executor_with_validation_mode(
+            Default::default(),
+            Default::default(),
+            ValidationMode::Parallel,
+        );
+        let compare = create_executor_with_validation_mode(
+            Default::default(),
+            Default::default(),
+            ValidationMode::Compare,
+        );
+
+        let sequential_result = sequential.validate(block).unwrap();
+        let parallel_result = parallel.validate(block).unwrap();
+        let compare_result = compare.validate(block);
+
+        assert_eq!(sequential_result.changes(), parallel_result.changes());
+        let sequential_ids = sequential_result
+            .result()
+            .tx_status
+            .iter()
+            .map(|status| status.id)
+            .collect_vec();
+        let parallel_ids = parallel_result
+            .result()
+            .tx_status
+            .iter()
+            .map(|status| status.id)
+            .collect_vec();
+        assert_eq!(sequential_ids, parallel_ids);
+        assert!(compare_result.is_ok());
+    }
+
+    fn produce_block(transactions: Vec<Transaction>) -> Block {
+        let mut producer = create_executor(Default::default(), Default::default());
+        let mut block = test_block(1u32.into(), 0u64.into(), 0);
+        *block.transactions_mut() = transactions;
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = producer.produce_and_commit(block.into()).unwrap();
+        assert!(skipped_transactions.is_empty());
+        block
+    }
+
+    #[test]
+    fn executor_validates_block_in_parallel_same_as_sequentially() {
+        // Given
+        // The script calls the deployed contract, so they can't be executed in parallel.
+        let (deploy, script) = setup_executable_script();
+        let mut transactions = test_block(1u32.into(), 0u64.into(), 10)
+            .transactions()
+            .to_vec();
+        transactions.extend([deploy.into(), script.into()]);
+        let block = produce_block(transactions);
+
+        // When/Then
+        assert_parallel_validation_matches_sequential(&block);
+    }
+
+    #[test]
+    fn executor_validates_block_with_independent_partitions_in_parallel_same_as_sequentially(
+    ) {
+        // Given
+        // Each transaction spends its own coin, so each one is a separate partition.
+        let transactions = (1..=8u64)
+            .map(|seed| {
+                TxBuilder::new(seed)
+                    .script_gas_limit(10)
+                    .coin_input(AssetId::BASE, 1000)
+                    .coin_output(AssetId::BASE, 500)
+                    .change_output(AssetId::BASE)
+                    .build()
+                    .transaction()
+                    .clone()
+                    .into()
+            })
+            .collect_vec();
+        let block = produce_block(transactions);
+
+        // When/Then
+        assert_parallel_validation_matches_sequential(&block);
+    }
+
+    #[test]
+    fn executor_validates_block_with_conflicting_inputs_in_parallel_same_as_sequentially()
+    {
+        // Given
+        let chain_id = ChainId::default();
+        let build_script = |seed: u64| -> Transaction {
+            TxBuilder::new(seed)
+                .script_gas_limit(10)
+                .coin_input(AssetId::BASE, 1000)
+                .coin_output(AssetId::BASE, 500)
+                .change_output(AssetId::BASE)
+                .build()
+                .transaction()
+                .clone()
+                .into()
+        };
+        let parent = build_script(1);
+        let independent = build_script(2);
+        // The child spends the coin created by the parent, so they are in one partition
+        // and have to be executed sequentially in the order of the block.
+        let mut child = build_script(3);
+        let Input::CoinSigned(coin) = &mut child.as_script_mut().unwrap().inputs_mut()[0]
+        else {
+            panic!("The script has a coin input")
+        };
+        coin.utxo_id = UtxoId::new(parent.id(&chain_id), 0);
+        coin.amount = 500;
+        let block = produce_block(vec![parent, independent, child]);
+
+        // When/Then
+        assert_parallel_validation_matches_sequential(&block);
+    }
+
+    #[test]
+    fn executor_validates_block_with_invalid_transactions_in_parallel_same_as_sequentially(
+    ) {
+        // Given
+        // Each transaction spends its own coin, so each one is a separate partition.
+        let transactions = (1..=8u64)
+            .map(|seed| {
+                TxBuilder::new(seed)
+                    .script_gas_limit(10)
+                    .coin_input(AssetId::BASE, 1000)
+                    .coin_output(AssetId::BASE, 500)
+                    .change_output(AssetId::BASE)
+                    .build()
+                    .transaction()
+                    .clone()
+                    .into()
+            })
+            .collect_vec();
+        let mut block = produce_block(transactions);
+        // Transactions in different partitions fail with different errors.
+        let first_invalid = block.transactions_mut()[1].as_script_mut().unwrap();
+        *first_invalid.script_gas_limit_mut() = u64::MAX;
+        let second_invalid = block.transactions_mut()[5].as_script_mut().unwrap();
+        let Input::CoinSigned(coin) = &mut second_invalid.inputs_mut()[0] else {
+            panic!("The script has a coin input")
+        };
+        coin.amount = 0;
+
+        let sequential = create_executor(Default::default(), Default::default());
+        let parallel = create_executor_with_validation_mode(
+            Default::default(),
+            Default::default(),
+            ValidationMode::Parallel,
+        );
+
+        // When
+        let sequential_error = sequential.validate(&block).unwrap_err();
+        let parallel_error = parallel.validate(&block).unwrap_err();
+
+        // Then
+        assert_eq!(sequential_error, parallel_error);
+    }
+
     // Ensure transaction commitment != default after execution
     #[test]
     fn executor_commits_transactions_to_block() {
@@ -45,6 +45,11 @@ pub struct ServiceConfig {
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
     pub api_request_timeout: Duration,
+    /// The maximum number of the instructions recorded in the trace of one transaction.
+    pub max_trace_steps: usize,
+    /// The maximum number of the stored blocks replayed by the `newBlocks`
+    /// subscription starting from the `fromHeight`.
+    pub max_replayed_blocks: u32,
     /// Configurable cost parameters to limit graphql queries complexity
     pub costs: Costs,
 }
@@ -59,6 +64,9 @@ pub struct Costs {
     pub submit: usize,
     pub submit_and_await: usize,
     pub status_change: usize,
+    pub new_blocks: usize,
+    pub pending_transactions: usize,
+    pub trace_transaction: usize,
     pub storage_read: usize,
     pub tx_get: usize,
     pub tx_status_read: usize,
@@ -70,6 +78,7 @@ pub struct Costs {
     pub bytecode_read: usize,
     pub state_transition_bytecode_read: usize,
     pub da_compressed_block_read: usize,
+    pub contract_state_proof: usize,
 }
 
 #[cfg(feature = "test-helpers")]
@@ -91,6 +100,9 @@ pub const DEFAULT_QUERY_COSTS: Costs = Costs {
     submit: 40001,
     submit_and_await: 40001,
     status_change: 40001,
+    new_blocks: 40001,
+    pending_transactions: 40001,
+    trace_transaction: 40001,
     storage_read: 40,
     tx_get: 50,
     tx_status_read: 50,
@@ -102,6 +114,7 @@ pub const DEFAULT_QUERY_COSTS: Costs = Costs {
     bytecode_read: 8000,
     state_transition_bytecode_read: 76_000,
     da_compressed_block_read: 4000,
+    contract_state_proof: 2000,
 };
 
 pub fn query_costs() -> &'static Costs {
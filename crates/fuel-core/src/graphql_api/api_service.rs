@@ -5,9 +5,14 @@ use crate::{
             BlockProducerPort,
             ConsensusModulePort,
             ConsensusProvider as ConsensusProviderTrait,
+            DatabaseBackupPort,
+            DatabaseStatsPort,
             GasPriceEstimate,
+            NewBlocksPort,
             OffChainDatabase,
+            OffChainDatabaseAt,
             OnChainDatabase,
+            OnChainDatabaseAt,
             P2pPort,
             TxPoolPort,
         },
@@ -22,7 +27,7 @@ use crate::{
     },
     service::{
         adapters::SharedMemoryPool,
-        metrics::metrics,
+        metrics::encode_metrics_response,
     },
 };
 use async_graphql::{
@@ -63,7 +68,7 @@ use fuel_core_services::{
     StateWatcher,
     TaskNextAction,
 };
-use fuel_core_storage::transactional::AtomicView;
+use fuel_core_storage::transactional::HistoricalView;
 use fuel_core_types::fuel_types::BlockHeight;
 use futures::Stream;
 use hyper::rt::Executor;
@@ -94,8 +99,11 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 // In the future GraphQL should not be aware of `TxPool`. It should
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
+pub type NewBlocks = Box<dyn NewBlocksPort>;
 pub type ConsensusModule = Box<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type DatabaseBackup = Box<dyn DatabaseBackupPort>;
+pub type DatabaseStatsProvider = Arc<dyn DatabaseStatsPort>;
 
 pub type GasPriceProvider = Box<dyn GasPriceEstimate>;
 
@@ -223,18 +231,23 @@ pub fn new_service<OnChain, OffChain>(
     on_database: OnChain,
     off_database: OffChain,
     txpool: TxPool,
+    new_blocks: NewBlocks,
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
     gas_price_provider: GasPriceProvider,
     consensus_parameters_provider: ConsensusProvider,
     memory_pool: SharedMemoryPool,
+    database_backup: DatabaseBackup,
+    database_stats: DatabaseStatsProvider,
 ) -> anyhow::Result<Service>
 where
-    OnChain: AtomicView + 'static,
-    OffChain: AtomicView + worker::OffChainDatabase + 'static,
+    OnChain: HistoricalView<Height = BlockHeight> + 'static,
+    OffChain: HistoricalView<Height = BlockHeight> + worker::OffChainDatabase + 'static,
     OnChain::LatestView: OnChainDatabase,
     OffChain::LatestView: OffChainDatabase,
+    OnChain::ViewAtHeight: OnChainDatabaseAt,
+    OffChain::ViewAtHeight: OffChainDatabaseAt,
 {
     graphql_api::initialize_query_costs(config.config.costs.clone())?;
 
@@ -263,12 +276,15 @@ where
         .data(config)
         .data(combined_read_database)
         .data(txpool)
+        .data(new_blocks)
         .data(producer)
         .data(consensus_module)
         .data(p2p_service)
         .data(gas_price_provider)
         .data(consensus_parameters_provider)
         .data(memory_pool)
+        .data(database_backup)
+        .data(database_stats.clone())
         .extension(ValidationExtension::new(
             max_queries_resolver_recursive_depth,
         ))
@@ -294,10 +310,11 @@ where
             graphql_subscription_endpoint,
             post(graphql_subscription_handler).options(ok),
         )
-        .route("/v1/metrics", get(metrics))
+        .route("/v1/metrics", get(metrics_handler))
         .route("/v1/health", get(health))
         .route("/health", get(health))
         .layer(Extension(schema))
+        .layer(Extension(database_stats))
         .layer(TraceLayer::new_for_http())
         .layer(TimeoutLayer::new(request_timeout))
         .layer(SetResponseHeaderLayer::<_>::overriding(
@@ -346,6 +363,16 @@ async fn health() -> Json<serde_json::Value> {
     Json(json!({ "up": true }))
 }
 
+async fn metrics_handler(
+    database_stats: Extension<DatabaseStatsProvider>,
+) -> impl IntoResponse {
+    // The size of the columns is refreshed on scrape to keep it out of the commits.
+    if let Err(err) = database_stats.database_stats().await {
+        tracing::warn!("Failed to refresh the metrics of the databases: {err}");
+    }
+    encode_metrics_response()
+}
+
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
     req: Json<Request>,
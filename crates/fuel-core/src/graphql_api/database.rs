@@ -2,9 +2,16 @@ use crate::fuel_core_graphql_api::{
     database::arc_wrapper::ArcWrapper,
     ports::{
         OffChainDatabase,
+        OffChainDatabaseAt,
         OnChainDatabase,
+        OnChainDatabaseAt,
+    },
+    storage::receipts::{
+        ContractReceipt,
+        ContractReceiptCursor,
     },
 };
+use fuel_core_database::Error as DatabaseError;
 use fuel_core_services::yield_stream::StreamYieldExt;
 use fuel_core_storage::{
     iter::{
@@ -14,7 +21,10 @@ use fuel_core_storage::{
     },
     not_found,
     tables::Transactions,
-    transactional::AtomicView,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
     Error as StorageError,
     IsNotFound,
     Mappable,
@@ -76,6 +86,10 @@ mod arc_wrapper;
 pub type OnChainView = Arc<dyn OnChainDatabase>;
 /// The off-chain view of the database used by the [`ReadView`] to fetch off-chain data.
 pub type OffChainView = Arc<dyn OffChainDatabase>;
+/// The on-chain view of the database at some past height used by the [`ReadViewAt`].
+pub type OnChainViewAt = Arc<dyn OnChainDatabaseAt>;
+/// The off-chain view of the database at some past height used by the [`ReadViewAt`].
+pub type OffChainViewAt = Arc<dyn OffChainDatabaseAt>;
 
 /// The container of the on-chain and off-chain database view provides.
 /// It is used only by `ViewExtension` to create a [`ReadView`].
@@ -85,11 +99,25 @@ pub struct ReadDatabase {
     /// The height of the genesis block.
     genesis_height: BlockHeight,
     /// The on-chain database view provider.
-    on_chain: Box<dyn AtomicView<LatestView = OnChainView>>,
+    on_chain: Box<
+        dyn HistoricalView<
+            LatestView = OnChainView,
+            Height = BlockHeight,
+            ViewAtHeight = OnChainViewAt,
+        >,
+    >,
     /// The off-chain database view provider.
-    off_chain: Box<dyn AtomicView<LatestView = OffChainView>>,
+    off_chain: Box<
+        dyn HistoricalView<
+            LatestView = OffChainView,
+            Height = BlockHeight,
+            ViewAtHeight = OffChainViewAt,
+        >,
+    >,
     /// The flag that indicates whether the Balances cache table is enabled.
     balances_enabled: bool,
+    /// The flag that indicates whether the ContractReceipts index table is enabled.
+    contract_receipts_enabled: bool,
 }
 
 impl ReadDatabase {
@@ -101,12 +129,16 @@ impl ReadDatabase {
         off_chain: OffChain,
     ) -> Result<Self, StorageError>
     where
-        OnChain: AtomicView + 'static,
-        OffChain: AtomicView + worker::OffChainDatabase + 'static,
+        OnChain: HistoricalView<Height = BlockHeight> + 'static,
+        OffChain:
+            HistoricalView<Height = BlockHeight> + worker::OffChainDatabase + 'static,
         OnChain::LatestView: OnChainDatabase,
         OffChain::LatestView: OffChainDatabase,
+        OnChain::ViewAtHeight: OnChainDatabaseAt,
+        OffChain::ViewAtHeight: OffChainDatabaseAt,
     {
         let balances_enabled = off_chain.balances_enabled()?;
+        let contract_receipts_enabled = off_chain.contract_receipts_enabled()?;
 
         Ok(Self {
             batch_size,
@@ -114,6 +146,7 @@ impl ReadDatabase {
             on_chain: Box::new(ArcWrapper::new(on_chain)),
             off_chain: Box::new(ArcWrapper::new(off_chain)),
             balances_enabled,
+            contract_receipts_enabled,
         })
     }
 
@@ -128,6 +161,39 @@ impl ReadDatabase {
             on_chain: self.on_chain.latest_view()?,
             off_chain: self.off_chain.latest_view()?,
             balances_enabled: self.balances_enabled,
+            contract_receipts_enabled: self.contract_receipts_enabled,
+        })
+    }
+
+    /// Returns the height of the latest block processed by the off-chain worker.
+    pub fn off_chain_latest_height(&self) -> Option<BlockHeight> {
+        self.off_chain.latest_height()
+    }
+
+    /// Creates a view of the database at the given past `height`.
+    /// Fails if the height is not yet processed by both databases,
+    /// or if the history for it was already pruned.
+    pub fn view_at(&self, height: &BlockHeight) -> StorageResult<ReadViewAt> {
+        let on_chain_height = self.on_chain.latest_height();
+        let off_chain_height = self.off_chain.latest_height();
+
+        match (on_chain_height, off_chain_height) {
+            (Some(on_chain_height), Some(off_chain_height))
+                if *height <= on_chain_height && *height <= off_chain_height => {}
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "The requested height {height} is not available yet, \
+                    the latest on-chain height is {on_chain_height:?}, \
+                    the latest off-chain height is {off_chain_height:?}"
+                )
+                .into())
+            }
+        }
+
+        Ok(ReadViewAt {
+            on_chain: self.on_chain.view_at(height)?,
+            off_chain: self.off_chain.view_at(height)?,
+            balances_enabled: self.balances_enabled,
         })
     }
 
@@ -137,6 +203,15 @@ impl ReadDatabase {
     }
 }
 
+/// The read-only view of the database at some past height.
+/// Unlike the [`ReadView`], it supports only point lookups.
+#[derive(Clone)]
+pub struct ReadViewAt {
+    pub(crate) on_chain: OnChainViewAt,
+    pub(crate) off_chain: OffChainViewAt,
+    pub(crate) balances_enabled: bool,
+}
+
 #[derive(Clone)]
 pub struct ReadView {
     pub(crate) batch_size: usize,
@@ -144,6 +219,7 @@ pub struct ReadView {
     pub(crate) on_chain: OnChainView,
     pub(crate) off_chain: OffChainView,
     pub(crate) balances_enabled: bool,
+    pub(crate) contract_receipts_enabled: bool,
 }
 
 impl ReadView {
@@ -152,6 +228,8 @@ impl ReadView {
         if result.is_not_found() {
             if let Some(tx) = self.off_chain.old_transaction(tx_id)? {
                 Ok(tx)
+            } else if let Some(error) = self.pruned_transaction_error(tx_id)? {
+                Err(error)
             } else {
                 Err(not_found!(Transactions))
             }
@@ -160,6 +238,51 @@ impl ReadView {
         }
     }
 
+    /// Returns the error if the transaction is missing because it was included
+    /// into the block with the transactions pruned by the history expiry.
+    fn pruned_transaction_error(
+        &self,
+        tx_id: &TxId,
+    ) -> StorageResult<Option<StorageError>> {
+        let block_height = match self.off_chain.tx_status(tx_id) {
+            Ok(TransactionStatus::Success { block_height, .. })
+            | Ok(TransactionStatus::Failed { block_height, .. }) => block_height,
+            Ok(_) => return Ok(None),
+            Err(err) if err.is_not_found() => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        // Transactions of the previous networks are stored in the off-chain database.
+        let oldest_available_height = if block_height >= self.genesis_height {
+            self.on_chain.history_start()?
+        } else {
+            self.off_chain.history_start()?
+        };
+
+        if block_height < oldest_available_height {
+            Ok(Some(history_is_pruned(
+                block_height,
+                oldest_available_height,
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns an error if the receipts of the block at the `height`
+    /// were pruned by the history expiry.
+    pub fn ensure_receipts_are_available(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<()> {
+        let oldest_available_height = self.off_chain.history_start()?;
+        if *height < oldest_available_height {
+            Err(history_is_pruned(*height, oldest_available_height))
+        } else {
+            Ok(())
+        }
+    }
+
     pub async fn transactions(
         &self,
         tx_ids: Vec<TxId>,
@@ -316,6 +439,20 @@ impl ReadView {
         .yield_each(self.batch_size)
     }
 
+    pub fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(Bytes32, Vec<u8>)>> + '_ {
+        futures::stream::iter(self.on_chain.contract_storage_slots(
+            contract,
+            start_key,
+            direction,
+        ))
+        .yield_each(self.batch_size)
+    }
+
     pub fn da_height(&self) -> StorageResult<DaBlockHeight> {
         self.on_chain.da_height()
     }
@@ -379,6 +516,21 @@ impl ReadView {
         )
     }
 
+    pub fn contract_receipts(
+        &self,
+        contract_id: ContractId,
+        start: Option<ContractReceiptCursor>,
+        direction: IterDirection,
+    ) -> impl Stream<Item = StorageResult<(ContractReceiptCursor, ContractReceipt)>> + '_
+    {
+        futures::stream::iter(self.off_chain.contract_receipts(
+            contract_id,
+            start,
+            direction,
+        ))
+        .yield_each(self.batch_size)
+    }
+
     pub fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         self.off_chain.contract_salt(contract_id)
     }
@@ -394,3 +546,14 @@ impl ReadView {
         self.off_chain.message_is_spent(nonce)
     }
 }
+
+fn history_is_pruned(
+    requested_height: BlockHeight,
+    oldest_available_height: BlockHeight,
+) -> StorageError {
+    DatabaseError::HistoryIsPruned {
+        requested_height: u64::from(u32::from(requested_height)),
+        oldest_available_height: u64::from(u32::from(oldest_available_height)),
+    }
+    .into()
+}
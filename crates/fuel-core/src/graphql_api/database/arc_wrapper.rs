@@ -1,17 +1,25 @@
 use crate::fuel_core_graphql_api::{
     database::{
         OffChainView,
+        OffChainViewAt,
         OnChainView,
+        OnChainViewAt,
     },
     ports::{
         OffChainDatabase,
+        OffChainDatabaseAt,
         OnChainDatabase,
+        OnChainDatabaseAt,
     },
 };
 use fuel_core_storage::{
-    transactional::AtomicView,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+    },
     Result as StorageResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 use std::sync::Arc;
 
 /// The GraphQL can't work with the generics in [`async_graphql::Context::data_unchecked`] and requires a known type.
@@ -42,6 +50,25 @@ where
     }
 }
 
+impl<Provider, View, ViewAt> HistoricalView for ArcWrapper<Provider, OnChainView>
+where
+    Provider:
+        HistoricalView<LatestView = View, Height = BlockHeight, ViewAtHeight = ViewAt>,
+    View: OnChainDatabase + 'static,
+    ViewAt: OnChainDatabaseAt + 'static,
+{
+    type Height = BlockHeight;
+    type ViewAtHeight = OnChainViewAt;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        self.inner.latest_height()
+    }
+
+    fn view_at(&self, height: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        Ok(Arc::new(self.inner.view_at(height)?))
+    }
+}
+
 impl<Provider, View> AtomicView for ArcWrapper<Provider, OffChainView>
 where
     Provider: AtomicView<LatestView = View>,
@@ -53,3 +80,22 @@ where
         Ok(Arc::new(self.inner.latest_view()?))
     }
 }
+
+impl<Provider, View, ViewAt> HistoricalView for ArcWrapper<Provider, OffChainView>
+where
+    Provider:
+        HistoricalView<LatestView = View, Height = BlockHeight, ViewAtHeight = ViewAt>,
+    View: OffChainDatabase + 'static,
+    ViewAt: OffChainDatabaseAt + 'static,
+{
+    type Height = BlockHeight;
+    type ViewAtHeight = OffChainViewAt;
+
+    fn latest_height(&self) -> Option<Self::Height> {
+        self.inner.latest_height()
+    }
+
+    fn view_at(&self, height: &Self::Height) -> StorageResult<Self::ViewAtHeight> {
+        Ok(Arc::new(self.inner.view_at(height)?))
+    }
+}
@@ -352,9 +352,14 @@ mod tests {
     fn balances_enabled_flag_is_respected() {
         use tempfile::TempDir;
         let tmp_dir = TempDir::new().unwrap();
-        let mut db: Database<OffChain> =
-            Database::open_rocksdb(tmp_dir.path(), None, Default::default(), 512)
-                .unwrap();
+        let mut db: Database<OffChain> = Database::open_rocksdb(
+            tmp_dir.path(),
+            None,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let mut tx = db.write_transaction();
 
         const BALANCES_ARE_DISABLED: bool = false;
@@ -409,9 +414,14 @@ mod tests {
     fn coins() {
         use tempfile::TempDir;
         let tmp_dir = TempDir::new().unwrap();
-        let mut db: Database<OffChain> =
-            Database::open_rocksdb(tmp_dir.path(), None, Default::default(), 512)
-                .unwrap();
+        let mut db: Database<OffChain> = Database::open_rocksdb(
+            tmp_dir.path(),
+            None,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let mut tx = db.write_transaction();
 
         const BALANCES_ARE_ENABLED: bool = true;
@@ -481,9 +491,14 @@ mod tests {
     fn messages() {
         use tempfile::TempDir;
         let tmp_dir = TempDir::new().unwrap();
-        let mut db: Database<OffChain> =
-            Database::open_rocksdb(tmp_dir.path(), None, Default::default(), 512)
-                .unwrap();
+        let mut db: Database<OffChain> = Database::open_rocksdb(
+            tmp_dir.path(),
+            None,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let mut tx = db.write_transaction();
 
         const BALANCES_ARE_ENABLED: bool = true;
@@ -589,9 +604,14 @@ mod tests {
     fn coin_balance_overflow_does_not_error() {
         use tempfile::TempDir;
         let tmp_dir = TempDir::new().unwrap();
-        let mut db: Database<OffChain> =
-            Database::open_rocksdb(tmp_dir.path(), None, Default::default(), 512)
-                .unwrap();
+        let mut db: Database<OffChain> = Database::open_rocksdb(
+            tmp_dir.path(),
+            None,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let mut tx = db.write_transaction();
 
         const BALANCES_ARE_ENABLED: bool = true;
@@ -623,9 +643,14 @@ mod tests {
     fn message_balance_overflow_does_not_error() {
         use tempfile::TempDir;
         let tmp_dir = TempDir::new().unwrap();
-        let mut db: Database<OffChain> =
-            Database::open_rocksdb(tmp_dir.path(), None, Default::default(), 512)
-                .unwrap();
+        let mut db: Database<OffChain> = Database::open_rocksdb(
+            tmp_dir.path(),
+            None,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let mut tx = db.write_transaction();
 
         const BALANCES_ARE_ENABLED: bool = true;
@@ -661,9 +686,14 @@ mod tests {
     fn coin_balance_underflow_causes_error() {
         use tempfile::TempDir;
         let tmp_dir = TempDir::new().unwrap();
-        let mut db: Database<OffChain> =
-            Database::open_rocksdb(tmp_dir.path(), None, Default::default(), 512)
-                .unwrap();
+        let mut db: Database<OffChain> = Database::open_rocksdb(
+            tmp_dir.path(),
+            None,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let mut tx = db.write_transaction();
 
         const BALANCES_ARE_ENABLED: bool = true;
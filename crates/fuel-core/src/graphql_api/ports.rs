@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use fuel_core_metrics::core_metrics::DatabaseStats;
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     iter::{
@@ -10,6 +11,7 @@ use fuel_core_storage::{
         Coins,
         ContractsAssets,
         ContractsRawCode,
+        ContractsState,
         Messages,
         StateTransitionBytecodeVersions,
         UploadedBytecodes,
@@ -19,7 +21,10 @@ use fuel_core_storage::{
     StorageInspect,
     StorageRead,
 };
-use fuel_core_txpool::TxStatusMessage;
+use fuel_core_txpool::{
+    config::BlackList,
+    TxStatusMessage,
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
@@ -55,16 +60,35 @@ use fuel_core_types::{
     },
     fuel_vm::interpreter::Memory,
     services::{
-        executor::TransactionExecutionStatus,
-        graphql_api::ContractBalance,
+        block_importer::SharedImportResult,
+        executor::{
+            DryRunOptions,
+            TransactionExecutionStatus,
+        },
+        graphql_api::{
+            ContractBalance,
+            ContractStateProof,
+        },
         p2p::PeerInfo,
-        txpool::TransactionStatus,
+        txpool::{
+            PendingTransaction,
+            TransactionStatus,
+        },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+};
 
-use super::storage::balances::TotalBalanceAmount;
+use super::storage::{
+    balances::TotalBalanceAmount,
+    receipts::{
+        ContractReceipt,
+        ContractReceiptCursor,
+    },
+};
 
 pub trait OffChainDatabase: Send + Sync {
     fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
@@ -108,6 +132,13 @@ pub trait OffChainDatabase: Send + Sync {
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
 
+    fn contract_receipts(
+        &self,
+        contract_id: ContractId,
+        start: Option<ContractReceiptCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ContractReceiptCursor, ContractReceipt)>>;
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt>;
 
     fn old_block(&self, height: &BlockHeight) -> StorageResult<CompressedBlock>;
@@ -128,6 +159,32 @@ pub trait OffChainDatabase: Send + Sync {
     ) -> StorageResult<Option<RelayedTransactionStatus>>;
 
     fn message_is_spent(&self, nonce: &Nonce) -> StorageResult<bool>;
+
+    /// Returns the height of the first block with the available receipts.
+    fn history_start(&self) -> StorageResult<BlockHeight>;
+}
+
+/// The off chain database port at some past height expected by GraphQL API service.
+/// The historical view supports only point lookups.
+pub trait OffChainDatabaseAt: Send + Sync {
+    fn balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount>;
+}
+
+/// The on chain database port at some past height expected by GraphQL API service.
+/// The historical view supports only point lookups.
+pub trait OnChainDatabaseAt:
+    Send
+    + Sync
+    + StorageInspect<Coins, Error = StorageError>
+    + StorageInspect<Messages, Error = StorageError>
+    + StorageInspect<ContractsRawCode, Error = StorageError>
+    + StorageInspect<ContractsAssets, Error = StorageError>
+{
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -164,6 +221,9 @@ pub trait DatabaseBlocks {
 
     /// Get the consensus for a block.
     fn consensus(&self, id: &BlockHeight) -> StorageResult<Consensus>;
+
+    /// Returns the height of the first block with the available transactions.
+    fn history_start(&self) -> StorageResult<BlockHeight>;
 }
 
 /// Trait that specifies all the getters required for DA compressed blocks.
@@ -196,6 +256,7 @@ pub trait DatabaseRelayedTransactions {
 pub trait DatabaseContracts:
     StorageInspect<ContractsRawCode, Error = StorageError>
     + StorageInspect<ContractsAssets, Error = StorageError>
+    + StorageInspect<ContractsState, Error = StorageError>
 {
     fn contract_balances(
         &self,
@@ -203,6 +264,20 @@ pub trait DatabaseContracts:
         start_asset: Option<AssetId>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<ContractBalance>>;
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Vec<u8>)>>;
+
+    /// Gets the sparse Merkle proof of the storage slot against the state root of the contract.
+    fn contract_storage_slot_proof(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> StorageResult<ContractStateProof>;
 }
 
 /// Trait that specifies all the getters required for chain metadata.
@@ -218,10 +293,37 @@ pub trait TxPoolPort: Send + Sync {
 
     async fn insert(&self, txs: Transaction) -> anyhow::Result<()>;
 
+    /// Returns up to `limit` transactions in the pool spending coins or messages
+    /// of the `owner`(all if `None`), ordered by id in the `direction` from the `start`.
+    async fn pending_transactions(
+        &self,
+        owner: Option<Address>,
+        start: Option<TxId>,
+        direction: IterDirection,
+        limit: usize,
+    ) -> anyhow::Result<Vec<PendingTransaction>>;
+
     fn tx_update_subscribe(
         &self,
         tx_id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>>;
+
+    /// Returns the blacklist of the transaction pool.
+    async fn black_list(&self) -> anyhow::Result<BlackList>;
+
+    /// Adds `add` and removes `remove` entries from the blacklist of the transaction pool.
+    /// Returns the updated blacklist.
+    async fn update_black_list(
+        &self,
+        add: BlackList,
+        remove: BlackList,
+    ) -> anyhow::Result<BlackList>;
+}
+
+pub trait NewBlocksPort: Send + Sync {
+    /// Returns a stream of blocks processed by the off-chain worker.
+    /// The stream returns an error and ends if the subscriber lags behind.
+    fn new_blocks_subscribe(&self) -> BoxStream<anyhow::Result<SharedImportResult>>;
 }
 
 #[async_trait]
@@ -233,6 +335,7 @@ pub trait BlockProducerPort: Send + Sync {
         time: Option<Tai64>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
+        options: DryRunOptions,
     ) -> anyhow::Result<Vec<TransactionExecutionStatus>>;
 }
 
@@ -261,6 +364,20 @@ pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
 }
 
+/// Trait for creating the backup of the databases of the node.
+#[async_trait::async_trait]
+pub trait DatabaseBackupPort: Send + Sync {
+    /// Creates the backup in the `path` and returns the height of the latest block in it.
+    async fn backup(&self, path: PathBuf) -> anyhow::Result<BlockHeight>;
+}
+
+/// Trait for reading the statistics of the databases of the node.
+#[async_trait::async_trait]
+pub trait DatabaseStatsPort: Send + Sync {
+    /// Returns the statistics of the databases that collect them.
+    async fn database_stats(&self) -> anyhow::Result<Vec<DatabaseStats>>;
+}
+
 /// Trait for defining how to estimate gas price for future blocks
 #[async_trait::async_trait]
 pub trait GasPriceEstimate: Send + Sync {
@@ -299,7 +416,9 @@ pub mod worker {
                 OldFuelBlocks,
                 OldTransactions,
             },
+            receipts::ContractReceipts,
             relayed_transactions::RelayedTransactionStatuses,
+            transactions::TransactionStatuses,
         },
     };
     use derive_more::Display;
@@ -310,6 +429,7 @@ pub mod worker {
         StorageMutate,
     };
     use fuel_core_types::{
+        blockchain::block::CompressedBlock,
         fuel_tx::{
             Address,
             Bytes32,
@@ -324,6 +444,9 @@ pub mod worker {
     pub trait OnChainDatabase: Send + Sync {
         /// Returns the latest block height.
         fn latest_height(&self) -> StorageResult<Option<BlockHeight>>;
+
+        /// Returns the block at the `height` of the current network.
+        fn block(&self, height: &BlockHeight) -> StorageResult<Option<CompressedBlock>>;
     }
 
     pub trait OffChainDatabase: Send + Sync {
@@ -339,6 +462,9 @@ pub mod worker {
 
         /// Checks if Balances cache functionality is available.
         fn balances_enabled(&self) -> StorageResult<bool>;
+
+        /// Checks if the index of receipts by contract is available.
+        fn contract_receipts_enabled(&self) -> StorageResult<bool>;
     }
 
     /// Represents either the Genesis Block or a block at a specific height
@@ -362,6 +488,8 @@ pub mod worker {
         + StorageMutate<RelayedTransactionStatuses, Error = StorageError>
         + StorageMutate<CoinBalances, Error = StorageError>
         + StorageMutate<MessageBalances, Error = StorageError>
+        + StorageMutate<ContractReceipts, Error = StorageError>
+        + StorageMutate<TransactionStatuses, Error = StorageError>
         + StorageMutate<DaCompressedBlocks, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryAddress, Error = StorageError>
         + StorageMutate<DaCompressionTemporalRegistryAssetId, Error = StorageError>
@@ -393,6 +521,13 @@ pub mod worker {
         /// Gets the total number of transactions on the chain from metadata.
         fn get_tx_count(&self) -> StorageResult<u64>;
 
+        /// Returns the height of the first block with the available receipts.
+        fn history_start(&self) -> StorageResult<BlockHeight>;
+
+        /// Updates the height of the first block with the available receipts
+        /// after the history expiry pruned the blocks before it.
+        fn set_history_start(&mut self, height: &BlockHeight) -> StorageResult<()>;
+
         /// Commits the underlying changes into the database.
         fn commit(self) -> StorageResult<()>;
     }
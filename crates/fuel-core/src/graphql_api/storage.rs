@@ -43,6 +43,7 @@ pub mod contracts;
 pub mod da_compression;
 pub mod messages;
 pub mod old;
+pub mod receipts;
 pub mod statistic;
 pub mod transactions;
 
@@ -51,6 +52,11 @@ pub mod relayed_transactions;
 /// It's useful for analyzing TPS or other metrics.
 const TX_COUNT: &str = "total_tx_count";
 
+/// The height of the first block with the available receipts and transactions
+/// of the previous networks. The data of the blocks before it is pruned
+/// by the history expiry.
+pub(crate) const HISTORY_START: &str = "history_start";
+
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
 #[derive(
@@ -118,6 +124,8 @@ pub enum Column {
     CoinBalances = 23,
     /// Message balances per account.
     MessageBalances = 24,
+    /// See [`ContractReceipts`](receipts::ContractReceipts)
+    ContractReceipts = 25,
 }
 
 impl Column {
@@ -187,6 +195,23 @@ where
         Ok(tx_count)
     }
 
+    fn history_start(&self) -> StorageResult<BlockHeight> {
+        let history_start = self
+            .storage::<StatisticTable<BlockHeight>>()
+            .get(HISTORY_START)?
+            .unwrap_or_default()
+            .into_owned();
+        Ok(history_start)
+    }
+
+    fn set_history_start(&mut self, height: &BlockHeight) -> StorageResult<()> {
+        <_ as StorageMutate<StatisticTable<BlockHeight>>>::insert(
+            self,
+            HISTORY_START,
+            height,
+        )
+    }
+
     fn commit(self) -> StorageResult<()> {
         self.commit()?;
         Ok(())
@@ -0,0 +1,242 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        Decode,
+        Encode,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        ContractId,
+        Receipt,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::{
+    array::TryFromSliceError,
+    mem::size_of,
+};
+
+/// The table allows iteration over all receipts related to a contract.
+/// Only `Call`, `Log`, `LogData` and `Transfer` receipts of successful
+/// transactions are indexed.
+pub struct ContractReceipts;
+
+impl Mappable for ContractReceipts {
+    type Key = ContractReceiptKey;
+    type OwnedKey = Self::Key;
+    type Value = ContractReceipt;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractReceipts {
+    type Blueprint = Plain<Manual<ContractReceiptKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractReceipts
+    }
+}
+
+pub type TransactionIndex = u16;
+pub type ReceiptIndex = u16;
+
+const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
+const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
+const RECEIPT_INDEX_SIZE: usize = size_of::<ReceiptIndex>();
+const CURSOR_SIZE: usize = BLOCK_HEIGHT + TX_INDEX_SIZE + RECEIPT_INDEX_SIZE;
+const KEY_SIZE: usize = ContractId::LEN + CURSOR_SIZE;
+
+/// Returns `true` if the receipt is indexed by the [`ContractReceipts`] table.
+pub fn is_indexed_receipt(receipt: &Receipt) -> bool {
+    matches!(
+        receipt,
+        Receipt::Call { .. }
+            | Receipt::Log { .. }
+            | Receipt::LogData { .. }
+            | Receipt::Transfer { .. }
+    )
+}
+
+/// Returns all non-zero contract ids referenced by the indexed receipt.
+pub fn receipt_contract_ids(receipt: &Receipt) -> Vec<ContractId> {
+    let mut ids = match receipt {
+        Receipt::Call { id, to, .. } | Receipt::Transfer { id, to, .. } => {
+            vec![*id, *to]
+        }
+        Receipt::Log { id, .. } | Receipt::LogData { id, .. } => vec![*id],
+        _ => vec![],
+    };
+    // Receipts emitted by the script itself have a zeroed contract id.
+    ids.retain(|id| id != &ContractId::zeroed());
+    ids.dedup();
+    ids
+}
+
+////////////////////////////////////// Not storage part //////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ContractReceipt {
+    pub tx_id: TxId,
+    pub receipt: Receipt,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ContractReceiptKey {
+    pub contract_id: ContractId,
+    pub cursor: ContractReceiptCursor,
+}
+
+impl ContractReceiptKey {
+    pub fn new(contract_id: &ContractId, cursor: ContractReceiptCursor) -> Self {
+        Self {
+            contract_id: *contract_id,
+            cursor,
+        }
+    }
+}
+
+/// The position of the receipt on the chain: `[block height + tx idx + receipt idx]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ContractReceiptCursor {
+    pub block_height: BlockHeight,
+    pub tx_idx: TransactionIndex,
+    pub receipt_idx: ReceiptIndex,
+}
+
+impl ContractReceiptCursor {
+    pub fn new(
+        block_height: BlockHeight,
+        tx_idx: TransactionIndex,
+        receipt_idx: ReceiptIndex,
+    ) -> Self {
+        Self {
+            block_height,
+            tx_idx,
+            receipt_idx,
+        }
+    }
+
+    fn to_bytes(self) -> [u8; CURSOR_SIZE] {
+        let mut bytes = [0u8; CURSOR_SIZE];
+        bytes[..BLOCK_HEIGHT].copy_from_slice(self.block_height.to_bytes().as_ref());
+        bytes[BLOCK_HEIGHT..BLOCK_HEIGHT + TX_INDEX_SIZE]
+            .copy_from_slice(self.tx_idx.to_be_bytes().as_ref());
+        bytes[BLOCK_HEIGHT + TX_INDEX_SIZE..]
+            .copy_from_slice(self.receipt_idx.to_be_bytes().as_ref());
+        bytes
+    }
+
+    fn from_bytes(bytes: [u8; CURSOR_SIZE]) -> Self {
+        let mut block_height_bytes: [u8; 4] = Default::default();
+        block_height_bytes.copy_from_slice(&bytes[..BLOCK_HEIGHT]);
+        let mut tx_idx_bytes: [u8; 2] = Default::default();
+        tx_idx_bytes.copy_from_slice(&bytes[BLOCK_HEIGHT..BLOCK_HEIGHT + TX_INDEX_SIZE]);
+        let mut receipt_idx_bytes: [u8; 2] = Default::default();
+        receipt_idx_bytes.copy_from_slice(&bytes[BLOCK_HEIGHT + TX_INDEX_SIZE..]);
+
+        Self {
+            block_height: u32::from_be_bytes(block_height_bytes).into(),
+            tx_idx: u16::from_be_bytes(tx_idx_bytes),
+            receipt_idx: u16::from_be_bytes(receipt_idx_bytes),
+        }
+    }
+}
+
+impl From<[u8; KEY_SIZE]> for ContractReceiptKey {
+    fn from(bytes: [u8; KEY_SIZE]) -> Self {
+        let contract_id: [u8; 32] = bytes[..ContractId::LEN]
+            .try_into()
+            .expect("It's an array of 32 bytes");
+        let cursor: [u8; CURSOR_SIZE] = bytes[ContractId::LEN..]
+            .try_into()
+            .expect("It's an array of `CURSOR_SIZE` bytes");
+
+        Self {
+            contract_id: ContractId::from(contract_id),
+            cursor: ContractReceiptCursor::from_bytes(cursor),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ContractReceiptKey {
+    type Error = TryFromSliceError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; KEY_SIZE] = bytes.try_into()?;
+        Ok(Self::from(bytes))
+    }
+}
+
+impl Encode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    type Encoder<'a> = [u8; KEY_SIZE];
+
+    fn encode(t: &ContractReceiptKey) -> Self::Encoder<'_> {
+        // generate prefix to enable sorted indexing of receipts by contract
+        // contract_id + block_height + tx_idx + receipt_idx
+        let mut bytes = [0u8; KEY_SIZE];
+        bytes[..ContractId::LEN].copy_from_slice(t.contract_id.as_ref());
+        bytes[ContractId::LEN..].copy_from_slice(t.cursor.to_bytes().as_ref());
+        bytes
+    }
+}
+
+impl Decode<ContractReceiptKey> for Manual<ContractReceiptKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<ContractReceiptKey> {
+        ContractReceiptKey::try_from(bytes)
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <ContractReceipts as Mappable>::Key {
+        let mut bytes = [0u8; KEY_SIZE];
+        rng.fill(bytes.as_mut());
+        bytes.into()
+    }
+
+    fn receipt() -> ContractReceipt {
+        ContractReceipt {
+            tx_id: Default::default(),
+            receipt: Receipt::log(Default::default(), 1, 2, 3, 4, 5, 6),
+        }
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        ContractReceipts,
+        [1u8; KEY_SIZE].into(),
+        receipt(),
+        receipt(),
+        generate_key
+    );
+
+    #[test]
+    fn encoded_key_is_sorted_by_position_on_chain() {
+        let contract_id = ContractId::from([1u8; 32]);
+        let lower = ContractReceiptKey::new(
+            &contract_id,
+            ContractReceiptCursor::new(1u32.into(), u16::MAX, u16::MAX),
+        );
+        let higher = ContractReceiptKey::new(
+            &contract_id,
+            ContractReceiptCursor::new(2u32.into(), 0, 0),
+        );
+
+        let lower_bytes = Manual::<ContractReceiptKey>::encode(&lower);
+        let higher_bytes = Manual::<ContractReceiptKey>::encode(&higher);
+
+        assert!(lower_bytes < higher_bytes);
+        assert_eq!(
+            Manual::<ContractReceiptKey>::decode(&lower_bytes).unwrap(),
+            lower
+        );
+    }
+}
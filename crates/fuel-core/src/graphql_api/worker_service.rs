@@ -30,14 +30,26 @@ use crate::{
                 OwnedMessageKey,
                 SpentMessages,
             },
+            receipts::{
+                is_indexed_receipt,
+                receipt_contract_ids,
+                ContractReceipt,
+                ContractReceiptCursor,
+                ContractReceiptKey,
+                ContractReceipts,
+            },
+            transactions::TransactionStatuses,
         },
     },
     graphql_api::storage::relayed_transactions::RelayedTransactionStatuses,
 };
+use fuel_core_importer::history::{
+    HistoryRetention,
+    MAX_PRUNED_BLOCKS_PER_COMMIT,
+};
 use fuel_core_metrics::graphql_metrics::graphql_metrics;
 use fuel_core_services::{
     stream::BoxStream,
-    EmptyShared,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -48,6 +60,7 @@ use fuel_core_storage::{
     Error as StorageError,
     Result as StorageResult,
     StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_types::{
     blockchain::{
@@ -56,6 +69,7 @@ use fuel_core_types::{
             CompressedBlock,
         },
         consensus::Consensus,
+        header::BlockHeader,
     },
     entities::relayer::transaction::RelayedTransactionStatus,
     fuel_tx::{
@@ -72,6 +86,7 @@ use fuel_core_types::{
         Contract,
         Input,
         Output,
+        Receipt,
         Transaction,
         TxId,
         UniqueIdentifier,
@@ -88,9 +103,13 @@ use fuel_core_types::{
         },
         executor::{
             Event,
+            TransactionExecutionResult,
             TransactionExecutionStatus,
         },
-        txpool::from_executor_to_status,
+        txpool::{
+            from_executor_to_status,
+            TransactionStatus,
+        },
     },
 };
 use futures::{
@@ -101,6 +120,7 @@ use std::{
     borrow::Cow,
     ops::Deref,
 };
+use tokio::sync::broadcast;
 
 #[cfg(test)]
 mod tests;
@@ -111,6 +131,28 @@ pub enum DaCompressionConfig {
     Enabled(fuel_core_compression::config::Config),
 }
 
+/// The maximum number of processed blocks that a slow subscriber can lag behind.
+const NEW_BLOCKS_CHANNEL_SIZE: usize = 1024;
+
+/// The shared state of the off-chain worker.
+#[derive(Clone)]
+pub struct SharedState {
+    new_blocks: broadcast::Sender<SharedImportResult>,
+}
+
+impl SharedState {
+    fn new() -> Self {
+        let (new_blocks, _) = broadcast::channel(NEW_BLOCKS_CHANNEL_SIZE);
+        Self { new_blocks }
+    }
+
+    /// Subscribes to the blocks processed by the worker. The block is sent
+    /// only after all information about it is committed to the off-chain database.
+    pub fn subscribe_new_blocks(&self) -> broadcast::Receiver<SharedImportResult> {
+        self.new_blocks.subscribe()
+    }
+}
+
 /// The initialization task recovers the state of the GraphQL service database on startup.
 pub struct InitializeTask<TxPool, BlockImporter, OnChain, OffChain> {
     chain_id: ChainId,
@@ -121,36 +163,206 @@ pub struct InitializeTask<TxPool, BlockImporter, OnChain, OffChain> {
     block_importer: BlockImporter,
     on_chain_database: OnChain,
     off_chain_database: OffChain,
+    history_retention: Option<HistoryRetention>,
+    shared: SharedState,
 }
 
 /// The off-chain GraphQL API worker task processes the imported blocks
 /// and actualize the information used by the GraphQL service.
-pub struct Task<TxPool, D> {
+pub struct Task<TxPool, OnChain, D> {
     tx_pool: TxPool,
     block_importer: BoxStream<SharedImportResult>,
     database: D,
+    indexer: BlockIndexer<OnChain>,
+    continue_on_error: bool,
+    new_blocks: broadcast::Sender<SharedImportResult>,
+}
+
+/// Builds the off-chain indexes from the result of the block import.
+/// It is used by the worker for new blocks and by the reindexing
+/// of the off-chain database for the stored blocks.
+pub struct BlockIndexer<OnChain> {
     chain_id: ChainId,
     da_compression_config: DaCompressionConfig,
-    continue_on_error: bool,
     balances_enabled: bool,
+    contract_receipts_enabled: bool,
+    history_expiry: Option<HistoryExpiry<OnChain>>,
 }
 
-impl<TxPool, D> Task<TxPool, D>
+/// Prunes the receipts of the transactions and the transactions of the previous
+/// networks when their blocks are out of the history retention. The on-chain data
+/// is pruned by the block importer.
+pub struct HistoryExpiry<OnChain> {
+    retention: HistoryRetention,
+    on_chain_database: OnChain,
+}
+
+impl<OnChain> HistoryExpiry<OnChain>
 where
-    TxPool: ports::worker::TxPool,
-    D: ports::worker::OffChainDatabase,
+    OnChain: ports::worker::OnChainDatabase,
 {
-    fn process_block(&mut self, result: SharedImportResult) -> anyhow::Result<()> {
+    pub fn new(retention: HistoryRetention, on_chain_database: OnChain) -> Self {
+        Self {
+            retention,
+            on_chain_database,
+        }
+    }
+
+    /// Prunes the history of the blocks that are out of the retention
+    /// when the block with the `latest_header` is the latest.
+    fn prune<T>(&self, latest_header: &BlockHeader, db: &mut T) -> anyhow::Result<()>
+    where
+        T: OffChainDatabaseTransaction,
+    {
+        let first_retained_height = self
+            .retention
+            .first_retained_height(latest_header, |height| {
+                Ok(self.block(height, &*db)?.map(|block| block.header().time()))
+            })?;
+        let history_start = u32::from(db.history_start()?);
+
+        // The history is pruned gradually to avoid huge commits.
+        let end = u32::from(first_retained_height)
+            .min(history_start.saturating_add(MAX_PRUNED_BLOCKS_PER_COMMIT));
+        if end <= history_start {
+            return Ok(())
+        }
+
+        for height in history_start..end {
+            let height = BlockHeight::from(height);
+            let Some(block) = self.block(&height, &*db)? else {
+                continue
+            };
+
+            for (tx_idx, tx_id) in block.transactions().iter().enumerate() {
+                let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+                    anyhow::anyhow!(
+                        "The block has more than `u16::MAX` transactions, {}",
+                        e
+                    )
+                })?;
+                db.storage_as_mut::<OldTransactions>().remove(tx_id)?;
+                prune_receipts(height, tx_idx, tx_id, db)?;
+            }
+        }
+
+        db.set_history_start(&end.into())?;
+        Ok(())
+    }
+
+    /// Returns the block of the current or the previous networks.
+    fn block<T>(
+        &self,
+        height: &BlockHeight,
+        db: &T,
+    ) -> StorageResult<Option<CompressedBlock>>
+    where
+        T: OffChainDatabaseTransaction,
+    {
+        if let Some(block) = self.on_chain_database.block(height)? {
+            return Ok(Some(block))
+        }
+
+        Ok(db
+            .storage_as_ref::<OldFuelBlocks>()
+            .get(height)?
+            .map(Cow::into_owned))
+    }
+}
+
+/// Removes the receipts from the status of the transaction, except
+/// the `MessageOut` receipts, and from the index of the contract receipts.
+fn prune_receipts<T>(
+    block_height: BlockHeight,
+    tx_idx: u16,
+    tx_id: &TxId,
+    db: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let Some(mut status) = db
+        .storage_as_ref::<TransactionStatuses>()
+        .get(tx_id)?
+        .map(Cow::into_owned)
+    else {
+        return Ok(())
+    };
+
+    let receipts = match &mut status {
+        TransactionStatus::Success { receipts, .. }
+        | TransactionStatus::Failed { receipts, .. } => receipts,
+        TransactionStatus::Submitted { .. } | TransactionStatus::SqueezedOut { .. } => {
+            return Ok(())
+        }
+    };
+
+    for (receipt_idx, receipt) in receipts.iter().enumerate() {
+        if !is_indexed_receipt(receipt) {
+            continue;
+        }
+        let receipt_idx = u16::try_from(receipt_idx).map_err(|e| {
+            anyhow::anyhow!("The transaction has more than `u16::MAX` receipts, {}", e)
+        })?;
+        let cursor = ContractReceiptCursor::new(block_height, tx_idx, receipt_idx);
+
+        for contract_id in receipt_contract_ids(receipt) {
+            db.storage_as_mut::<ContractReceipts>()
+                .remove(&ContractReceiptKey::new(&contract_id, cursor))?;
+        }
+    }
+
+    // The `MessageOut` receipts are kept, because the `messageProof` query
+    // needs them to prove the withdrawals from the pruned blocks.
+    receipts.retain(|receipt| matches!(receipt, Receipt::MessageOut { .. }));
+
+    db.update_tx_status(tx_id, status)?;
+    Ok(())
+}
+
+impl<OnChain> BlockIndexer<OnChain>
+where
+    OnChain: ports::worker::OnChainDatabase,
+{
+    pub fn new(
+        chain_id: ChainId,
+        da_compression_config: DaCompressionConfig,
+        balances_enabled: bool,
+        contract_receipts_enabled: bool,
+        history_expiry: Option<HistoryExpiry<OnChain>>,
+    ) -> Self {
+        Self {
+            chain_id,
+            da_compression_config,
+            balances_enabled,
+            contract_receipts_enabled,
+            history_expiry,
+        }
+    }
+
+    /// Writes the indexes of the imported block into the `transaction`.
+    /// Returns the total number of transactions on the chain after the block.
+    pub fn index_block<T>(
+        &self,
+        result: &ImportResult,
+        transaction: &mut T,
+    ) -> anyhow::Result<u64>
+    where
+        T: OffChainDatabaseTransaction,
+    {
         let block = &result.sealed_block.entity;
-        let mut transaction = self.database.transaction();
         // save the status for every transaction using the finalized block id
-        persist_transaction_status(&result, &mut transaction)?;
+        persist_transaction_status(result, transaction)?;
 
         // save the associated owner for each transaction in the block
-        index_tx_owners_for_block(block, &mut transaction, &self.chain_id)?;
+        index_tx_owners_for_block(block, transaction, &self.chain_id)?;
+
+        if self.contract_receipts_enabled {
+            index_contract_receipts(result, transaction)?;
+        }
 
         // save the transaction related information
-        process_transactions(block.transactions().iter(), &mut transaction)?;
+        process_transactions(block.transactions().iter(), transaction)?;
 
         let height = block.header().height();
         let block_id = block.id();
@@ -164,19 +376,38 @@ where
 
         process_executor_events(
             result.events.iter().map(Cow::Borrowed),
-            &mut transaction,
+            transaction,
             self.balances_enabled,
         )?;
 
         match self.da_compression_config {
             DaCompressionConfig::Disabled => {}
             DaCompressionConfig::Enabled(config) => {
-                da_compress_block(config, block, &result.events, &mut transaction)?;
+                da_compress_block(config, block, &result.events, transaction)?;
             }
         }
 
+        if let Some(history_expiry) = &self.history_expiry {
+            history_expiry.prune(block.header(), transaction)?;
+        }
+
+        Ok(total_tx_count)
+    }
+}
+
+impl<TxPool, OnChain, D> Task<TxPool, OnChain, D>
+where
+    TxPool: ports::worker::TxPool,
+    OnChain: ports::worker::OnChainDatabase,
+    D: ports::worker::OffChainDatabase,
+{
+    fn process_block(&mut self, result: SharedImportResult) -> anyhow::Result<()> {
+        let block = &result.sealed_block.entity;
+        let mut transaction = self.database.transaction();
+        let total_tx_count = self.indexer.index_block(&result, &mut transaction)?;
         transaction.commit()?;
 
+        let height = block.header().height();
         for status in result.tx_status.iter() {
             let tx_id = status.id;
             let status = from_executor_to_status(block, status.result.clone());
@@ -186,6 +417,9 @@ where
         // update the importer metrics after the block is successfully committed
         graphql_metrics().total_txs_count.set(total_tx_count as i64);
 
+        // It is okay if nobody is subscribed to new blocks.
+        let _ = self.new_blocks.send(result);
+
         Ok(())
     }
 }
@@ -363,6 +597,50 @@ where
     Ok(())
 }
 
+/// Index receipts of successful transactions by the contracts they relate to.
+fn index_contract_receipts<T>(
+    import_result: &ImportResult,
+    db: &mut T,
+) -> anyhow::Result<()>
+where
+    T: OffChainDatabaseTransaction,
+{
+    let block_height = *import_result.sealed_block.entity.header().height();
+    for (tx_idx, TransactionExecutionStatus { id, result, .. }) in
+        import_result.tx_status.iter().enumerate()
+    {
+        let TransactionExecutionResult::Success { receipts, .. } = result else {
+            continue;
+        };
+        let tx_idx = u16::try_from(tx_idx).map_err(|e| {
+            anyhow::anyhow!("The block has more than `u16::MAX` transactions, {}", e)
+        })?;
+
+        for (receipt_idx, receipt) in receipts.iter().enumerate() {
+            if !is_indexed_receipt(receipt) {
+                continue;
+            }
+            let receipt_idx = u16::try_from(receipt_idx).map_err(|e| {
+                anyhow::anyhow!(
+                    "The transaction has more than `u16::MAX` receipts, {}",
+                    e
+                )
+            })?;
+            let cursor = ContractReceiptCursor::new(block_height, tx_idx, receipt_idx);
+            let value = ContractReceipt {
+                tx_id: *id,
+                receipt: receipt.clone(),
+            };
+
+            for contract_id in receipt_contract_ids(receipt) {
+                db.storage_as_mut::<ContractReceipts>()
+                    .insert(&ContractReceiptKey::new(&contract_id, cursor), &value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
 fn persist_transaction_status<T>(
     import_result: &ImportResult,
     db: &mut T,
@@ -370,7 +648,7 @@ fn persist_transaction_status<T>(
 where
     T: OffChainDatabaseTransaction,
 {
-    for TransactionExecutionStatus { id, result } in import_result.tx_status.iter() {
+    for TransactionExecutionStatus { id, result, .. } in import_result.tx_status.iter() {
         let status =
             from_executor_to_status(&import_result.sealed_block.entity, result.clone());
 
@@ -480,12 +758,12 @@ where
     OffChain: ports::worker::OffChainDatabase,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
-    type SharedData = EmptyShared;
-    type Task = Task<TxPool, OffChain>;
+    type SharedData = SharedState;
+    type Task = Task<TxPool, OnChain, OffChain>;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
-        EmptyShared
+        self.shared.clone()
     }
 
     async fn into_task(
@@ -502,6 +780,13 @@ where
         let balances_enabled = self.off_chain_database.balances_enabled()?;
         tracing::info!("Balances cache available: {}", balances_enabled);
 
+        let contract_receipts_enabled =
+            self.off_chain_database.contract_receipts_enabled()?;
+        tracing::info!(
+            "Contract receipts index available: {}",
+            contract_receipts_enabled
+        );
+
         let InitializeTask {
             chain_id,
             da_compression_config,
@@ -511,19 +796,32 @@ where
             on_chain_database,
             off_chain_database,
             continue_on_error,
+            history_retention,
+            shared,
         } = self;
 
+        let mut target_chain_height = on_chain_database.latest_height()?;
+
+        let history_expiry = history_retention
+            .map(|retention| HistoryExpiry::new(retention, on_chain_database));
+
+        let indexer = BlockIndexer::new(
+            chain_id,
+            da_compression_config,
+            balances_enabled,
+            contract_receipts_enabled,
+            history_expiry,
+        );
+
         let mut task = Task {
             tx_pool,
             block_importer: blocks_events,
             database: off_chain_database,
-            chain_id,
-            da_compression_config,
+            indexer,
             continue_on_error,
-            balances_enabled,
+            new_blocks: shared.new_blocks,
         };
 
-        let mut target_chain_height = on_chain_database.latest_height()?;
         // Process all blocks that were imported before the service started.
         // The block importer may produce some blocks on start-up during the
         // genesis stage or the recovery process. In this case, we need to
@@ -540,14 +838,15 @@ where
     }
 }
 
-fn sync_databases<TxPool, BlockImporter, OffChain>(
-    task: &mut Task<TxPool, OffChain>,
+fn sync_databases<TxPool, BlockImporter, OnChain, OffChain>(
+    task: &mut Task<TxPool, OnChain, OffChain>,
     target_chain_height: Option<BlockHeight>,
     import_result_provider: &BlockImporter,
 ) -> anyhow::Result<()>
 where
     TxPool: ports::worker::TxPool,
     BlockImporter: ports::worker::BlockImporter,
+    OnChain: ports::worker::OnChainDatabase,
     OffChain: ports::worker::OffChainDatabase,
 {
     loop {
@@ -581,9 +880,10 @@ where
 }
 
 #[async_trait::async_trait]
-impl<TxPool, D> RunnableTask for Task<TxPool, D>
+impl<TxPool, OnChain, D> RunnableTask for Task<TxPool, OnChain, D>
 where
     TxPool: ports::worker::TxPool,
+    OnChain: ports::worker::OnChainDatabase,
     D: ports::worker::OffChainDatabase,
 {
     async fn run(&mut self, watcher: &mut StateWatcher) -> TaskNextAction {
@@ -639,6 +939,7 @@ pub fn new_service<TxPool, BlockImporter, OnChain, OffChain>(
     chain_id: ChainId,
     da_compression_config: DaCompressionConfig,
     continue_on_error: bool,
+    history_retention: Option<HistoryRetention>,
 ) -> ServiceRunner<InitializeTask<TxPool, BlockImporter, OnChain, OffChain>>
 where
     TxPool: ports::worker::TxPool,
@@ -655,5 +956,7 @@ where
         chain_id,
         da_compression_config,
         continue_on_error,
+        history_retention,
+        shared: SharedState::new(),
     })
 }
@@ -2,15 +2,29 @@
 
 use super::*;
 use crate::{
-    database::Database,
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+        },
+        Database,
+    },
     graphql_api::storage::relayed_transactions::RelayedTransactionStatuses,
 };
 use fuel_core_services::stream::IntoBoxStream;
-use fuel_core_storage::StorageAsRef;
+use fuel_core_storage::{
+    transactional::WriteTransaction,
+    StorageAsRef,
+};
 use fuel_core_types::{
-    fuel_tx::Bytes32,
+    fuel_tx::{
+        Address,
+        Bytes32,
+        ContractId,
+    },
     fuel_types::BlockHeight,
     services::txpool::TransactionStatus,
+    tai64::Tai64,
 };
 use std::sync::Arc;
 
@@ -59,6 +73,52 @@ async fn run__relayed_transaction_events_are_added_to_storage() {
     assert_eq!(*actual, expected);
 }
 
+#[test]
+fn prune_receipts__keeps_message_out_receipts() {
+    let tx_id: Bytes32 = [1; 32].into();
+    let block_height = 8.into();
+    let contract_id = ContractId::from([2; 32]);
+    let message_out = Receipt::message_out(
+        &tx_id,
+        1,
+        Address::from([3; 32]),
+        Address::zeroed(),
+        4,
+        vec![5; 30],
+    );
+    let mut database = Database::<OffChain>::in_memory();
+
+    // given
+    let status = TransactionStatus::Success {
+        block_height,
+        time: Tai64::UNIX_EPOCH,
+        result: None,
+        receipts: vec![Receipt::ret(contract_id, 0, 0, 0), message_out.clone()],
+        total_gas: 0,
+        total_fee: 0,
+    };
+    let mut transaction = database.write_transaction();
+    transaction
+        .storage_as_mut::<TransactionStatuses>()
+        .insert(&tx_id, &status)
+        .unwrap();
+
+    // when
+    prune_receipts(block_height, 0, &tx_id, &mut transaction).unwrap();
+
+    // then
+    let status = transaction
+        .storage_as_ref::<TransactionStatuses>()
+        .get(&tx_id)
+        .unwrap()
+        .unwrap()
+        .into_owned();
+    let TransactionStatus::Success { receipts, .. } = status else {
+        panic!("The status should remain successful")
+    };
+    assert_eq!(receipts, vec![message_out]);
+}
+
 fn block_importer_for_event(event: Event) -> BoxStream<SharedImportResult> {
     let block = Arc::new(ImportResult {
         sealed_block: Default::default(),
@@ -73,16 +133,21 @@ fn block_importer_for_event(event: Event) -> BoxStream<SharedImportResult> {
 fn worker_task_with_block_importer_and_db<D: ports::worker::OffChainDatabase>(
     block_importer: BoxStream<SharedImportResult>,
     database: D,
-) -> Task<MockTxPool, D> {
+) -> Task<MockTxPool, Database<OnChain>, D> {
     let tx_pool = MockTxPool;
     let chain_id = Default::default();
     Task {
         tx_pool,
         block_importer,
         database,
-        chain_id,
-        da_compression_config: DaCompressionConfig::Disabled,
+        indexer: BlockIndexer::new(
+            chain_id,
+            DaCompressionConfig::Disabled,
+            true,
+            true,
+            None,
+        ),
         continue_on_error: false,
-        balances_enabled: true,
+        new_blocks: tokio::sync::broadcast::channel(1).0,
     }
 }
@@ -4,7 +4,10 @@ use std::{
 };
 
 use crate::{
-    fuel_core_graphql_api::database::ReadView,
+    fuel_core_graphql_api::database::{
+        ReadView,
+        ReadViewAt,
+    },
     graphql_api::storage::balances::TotalBalanceAmount,
 };
 use asset_query::{
@@ -154,3 +157,29 @@ impl ReadView {
             .yield_each(self.batch_size)
     }
 }
+
+impl ReadViewAt {
+    pub fn balance(
+        &self,
+        owner: Address,
+        asset_id: AssetId,
+        base_asset_id: AssetId,
+    ) -> StorageResult<AddressBalance> {
+        // The historical view doesn't support iteration over the coins,
+        // so it is possible to get the balance only from the cache.
+        if !self.balances_enabled {
+            return Err(anyhow::anyhow!(
+                "The balance at the specific height requires the balances indexation"
+            )
+            .into())
+        }
+
+        let amount = self.off_chain.balance(&owner, &asset_id, &base_asset_id)?;
+
+        Ok(AddressBalance {
+            owner,
+            amount,
+            asset_id,
+        })
+    }
+}
@@ -1,4 +1,7 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::fuel_core_graphql_api::database::{
+    ReadView,
+    ReadViewAt,
+};
 use fuel_core_storage::{
     iter::IterDirection,
     not_found,
@@ -64,3 +67,17 @@ impl ReadView {
             .try_flatten()
     }
 }
+
+impl ReadViewAt {
+    pub fn coin(&self, utxo_id: UtxoId) -> StorageResult<Coin> {
+        let coin = self
+            .on_chain
+            .as_ref()
+            .storage::<Coins>()
+            .get(&utxo_id)?
+            .ok_or(not_found!(Coins))?
+            .into_owned();
+
+        Ok(coin.uncompress(utxo_id))
+    }
+}
@@ -1,19 +1,28 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::fuel_core_graphql_api::database::{
+    ReadView,
+    ReadViewAt,
+};
 use fuel_core_storage::{
     not_found,
     tables::{
         ContractsAssets,
         ContractsRawCode,
+        ContractsState,
     },
+    ContractsStateKey,
     Result as StorageResult,
     StorageAsRef,
 };
 use fuel_core_types::{
     fuel_types::{
         AssetId,
+        Bytes32,
         ContractId,
     },
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractStateProof,
+    },
 };
 
 impl ReadView {
@@ -55,4 +64,56 @@ impl ReadView {
             asset_id,
         })
     }
+
+    pub fn contract_storage_slot(
+        &self,
+        contract_id: ContractId,
+        key: Bytes32,
+    ) -> StorageResult<Option<Vec<u8>>> {
+        let value = self
+            .on_chain
+            .as_ref()
+            .storage::<ContractsState>()
+            .get(&ContractsStateKey::new(&contract_id, &key))?
+            .map(|value| value.into_owned().into());
+
+        Ok(value)
+    }
+
+    pub fn contract_storage_slot_proof(
+        &self,
+        contract_id: &ContractId,
+        key: &Bytes32,
+    ) -> StorageResult<ContractStateProof> {
+        self.on_chain.contract_storage_slot_proof(contract_id, key)
+    }
+}
+
+impl ReadViewAt {
+    pub fn contract_exists(&self, id: ContractId) -> StorageResult<bool> {
+        self.on_chain
+            .as_ref()
+            .storage::<ContractsRawCode>()
+            .contains_key(&id)
+    }
+
+    pub fn contract_balance(
+        &self,
+        contract_id: ContractId,
+        asset_id: AssetId,
+    ) -> StorageResult<ContractBalance> {
+        let amount = self
+            .on_chain
+            .as_ref()
+            .storage::<ContractsAssets>()
+            .get(&(&contract_id, &asset_id).into())?
+            .ok_or(not_found!(ContractsAssets))?
+            .into_owned();
+
+        Ok(ContractBalance {
+            owner: contract_id,
+            amount,
+            asset_id,
+        })
+    }
 }
@@ -1,4 +1,7 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::fuel_core_graphql_api::database::{
+    ReadView,
+    ReadViewAt,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -110,6 +113,17 @@ impl ReadView {
     }
 }
 
+impl ReadViewAt {
+    pub fn message(&self, id: &Nonce) -> StorageResult<Message> {
+        self.on_chain
+            .as_ref()
+            .storage::<Messages>()
+            .get(id)?
+            .ok_or(not_found!(Messages))
+            .map(Cow::into_owned)
+    }
+}
+
 /// Trait that specifies all the data required by the output message query.
 pub trait MessageProofData {
     /// Get the block.
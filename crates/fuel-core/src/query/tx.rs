@@ -1,4 +1,10 @@
-use crate::fuel_core_graphql_api::database::ReadView;
+use crate::fuel_core_graphql_api::{
+    database::ReadView,
+    storage::receipts::{
+        ContractReceipt,
+        ContractReceiptCursor,
+    },
+};
 use fuel_core_storage::{
     iter::IterDirection,
     not_found,
@@ -8,12 +14,16 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        ContractId,
         Receipt,
         Transaction,
         TxId,
         TxPointer,
     },
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        BlockHeight,
+    },
     services::txpool::TransactionStatus,
 };
 use futures::{
@@ -59,4 +69,47 @@ impl ReadView {
             })
             .try_flatten()
     }
+
+    /// Returns receipts related to the `contract_id` within the inclusive
+    /// `[from_height, to_height]` range, ordered by position on the chain.
+    pub fn contract_receipts_in_range(
+        &self,
+        contract_id: ContractId,
+        from_height: Option<BlockHeight>,
+        to_height: Option<BlockHeight>,
+        start: Option<ContractReceiptCursor>,
+        direction: IterDirection,
+    ) -> StorageResult<
+        impl Stream<Item = StorageResult<(ContractReceiptCursor, ContractReceipt)>> + '_,
+    > {
+        if !self.contract_receipts_enabled {
+            return Err(anyhow::anyhow!(
+                "The receipts by contract query requires the contract receipts indexation"
+            )
+            .into())
+        }
+
+        let start = start.or_else(|| match direction {
+            IterDirection::Forward => {
+                from_height.map(|height| ContractReceiptCursor::new(height, 0, 0))
+            }
+            IterDirection::Reverse => to_height
+                .map(|height| ContractReceiptCursor::new(height, u16::MAX, u16::MAX)),
+        });
+
+        let stream = self
+            .contract_receipts(contract_id, start, direction)
+            .try_take_while(move |(cursor, _)| {
+                let height = cursor.block_height;
+                let in_range = match direction {
+                    IterDirection::Forward => to_height.map_or(true, |to| height <= to),
+                    IterDirection::Reverse => {
+                        from_height.map_or(true, |from| height >= from)
+                    }
+                };
+                futures::future::ready(Ok(in_range))
+            });
+
+        Ok(stream)
+    }
 }
@@ -1,6 +1,9 @@
 use crate::fuel_core_graphql_api::{
     api_service::ReadDatabase,
-    database::ReadView,
+    database::{
+        ReadView,
+        ReadViewAt,
+    },
 };
 use anyhow::anyhow;
 use async_graphql::{
@@ -23,6 +26,7 @@ use fuel_core_storage::{
     iter::IterDirection,
     Result as StorageResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 use futures::{
     Stream,
     TryStreamExt,
@@ -31,6 +35,7 @@ use std::borrow::Cow;
 use tokio_stream::StreamExt;
 
 pub mod balance;
+pub mod blacklist;
 pub mod blob;
 pub mod block;
 pub mod chain;
@@ -38,6 +43,7 @@ pub mod coins;
 pub mod contract;
 pub mod da_compressed;
 pub mod dap;
+pub mod database;
 pub mod health;
 pub mod message;
 pub mod node_info;
@@ -62,19 +68,28 @@ pub struct Query(
     da_compressed::DaCompressedBlockQuery,
     contract::ContractQuery,
     contract::ContractBalanceQuery,
+    contract::ContractStorageQuery,
     node_info::NodeQuery,
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
     message::MessageQuery,
     relayed_tx::RelayedTransactionQuery,
     upgrades::UpgradeQuery,
+    blacklist::BlacklistQuery,
+    database::DatabaseQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    blacklist::BlacklistMutation,
+    database::DatabaseMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(tx::TxStatusSubscription, block::BlockSubscription);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -216,6 +231,9 @@ where
 pub trait ReadViewProvider {
     /// Returns the read view for the current operation.
     fn read_view(&self) -> StorageResult<Cow<ReadView>>;
+
+    /// Returns the read view at the `height` for the current operation.
+    fn read_view_at(&self, height: BlockHeight) -> StorageResult<ReadViewAt>;
 }
 
 impl<'a> ReadViewProvider for Context<'a> {
@@ -232,4 +250,9 @@ impl<'a> ReadViewProvider for Context<'a> {
             Ok(Cow::Borrowed(read_view))
         }
     }
+
+    fn read_view_at(&self, height: BlockHeight) -> StorageResult<ReadViewAt> {
+        let database: &ReadDatabase = self.data_unchecked();
+        database.view_at(&height)
+    }
 }
@@ -8,6 +8,7 @@ use crate::{
             Address,
             AssetId,
             U128,
+            U32,
         },
         ReadViewProvider,
     },
@@ -66,17 +67,24 @@ impl BalanceQuery {
         ctx: &Context<'_>,
         #[graphql(desc = "address of the owner")] owner: Address,
         #[graphql(desc = "asset_id of the coin")] asset_id: AssetId,
+        #[graphql(desc = "The block height to read the state at")] at_height: Option<U32>,
     ) -> async_graphql::Result<Balance> {
-        let query = ctx.read_view()?;
         let base_asset_id = *ctx
             .data_unchecked::<ConsensusProvider>()
             .latest_consensus_params()
             .base_asset_id();
-        let balance = query
-            .balance(owner.0, asset_id.0, base_asset_id)
-            .await?
-            .into();
-        Ok(balance)
+        let balance = if let Some(height) = at_height {
+            ctx.read_view_at(height.into())?.balance(
+                owner.0,
+                asset_id.0,
+                base_asset_id,
+            )?
+        } else {
+            ctx.read_view()?
+                .balance(owner.0, asset_id.0, base_asset_id)
+                .await?
+        };
+        Ok(balance.into())
     }
 
     // TODO: This API should be migrated to the indexer for better support and
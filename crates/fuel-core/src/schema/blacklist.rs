@@ -0,0 +1,147 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::TxPool,
+        Config as GraphQLConfig,
+    },
+    schema::scalars::{
+        Address,
+        ContractId,
+        Nonce,
+        UtxoId,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    InputObject,
+    Object,
+};
+use fuel_core_txpool::config;
+
+#[derive(Default)]
+pub struct BlacklistQuery;
+
+#[Object]
+impl BlacklistQuery {
+    /// Returns the blacklist of the transaction pool.
+    /// Requires `debug` to be enabled.
+    async fn blacklist(&self, ctx: &Context<'_>) -> async_graphql::Result<Blacklist> {
+        require_debug(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        Ok(Blacklist(txpool.black_list().await?))
+    }
+}
+
+#[derive(Default)]
+pub struct BlacklistMutation;
+
+#[Object]
+impl BlacklistMutation {
+    /// Adds the entries to the blacklist of the transaction pool and removes
+    /// the pending transactions that became blacklisted. Returns the updated blacklist.
+    /// Requires `debug` to be enabled.
+    async fn add_to_blacklist(
+        &self,
+        ctx: &Context<'_>,
+        blacklist: BlacklistInput,
+    ) -> async_graphql::Result<Blacklist> {
+        require_debug(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let black_list = txpool
+            .update_black_list(blacklist.into(), Default::default())
+            .await?;
+        Ok(Blacklist(black_list))
+    }
+
+    /// Removes the entries from the blacklist of the transaction pool.
+    /// Returns the updated blacklist.
+    /// Requires `debug` to be enabled.
+    async fn remove_from_blacklist(
+        &self,
+        ctx: &Context<'_>,
+        blacklist: BlacklistInput,
+    ) -> async_graphql::Result<Blacklist> {
+        require_debug(ctx)?;
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let black_list = txpool
+            .update_black_list(Default::default(), blacklist.into())
+            .await?;
+        Ok(Blacklist(black_list))
+    }
+}
+
+fn require_debug(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+
+    if config.debug {
+        Ok(())
+    } else {
+        Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+    }
+}
+
+pub struct Blacklist(config::BlackList);
+
+#[Object]
+impl Blacklist {
+    /// Owners of the coins and senders or recipients of the messages.
+    async fn owners(&self) -> Vec<Address> {
+        let mut owners: Vec<_> = self.0.owners.iter().copied().collect();
+        owners.sort();
+        owners.into_iter().map(Into::into).collect()
+    }
+
+    /// UTXO ids of the coins.
+    async fn coins(&self) -> Vec<UtxoId> {
+        let mut coins: Vec<_> = self.0.coins.iter().copied().collect();
+        coins.sort();
+        coins.into_iter().map(Into::into).collect()
+    }
+
+    /// Nonces of the messages.
+    async fn messages(&self) -> Vec<Nonce> {
+        let mut messages: Vec<_> = self.0.messages.iter().copied().collect();
+        messages.sort();
+        messages.into_iter().map(Into::into).collect()
+    }
+
+    /// Contracts used as inputs.
+    async fn contracts(&self) -> Vec<ContractId> {
+        let mut contracts: Vec<_> = self.0.contracts.iter().copied().collect();
+        contracts.sort();
+        contracts.into_iter().map(Into::into).collect()
+    }
+}
+
+#[derive(InputObject)]
+pub struct BlacklistInput {
+    /// Owners of the coins and senders or recipients of the messages.
+    owners: Option<Vec<Address>>,
+    /// UTXO ids of the coins.
+    coins: Option<Vec<UtxoId>>,
+    /// Nonces of the messages.
+    messages: Option<Vec<Nonce>>,
+    /// Contracts used as inputs.
+    contracts: Option<Vec<ContractId>>,
+}
+
+impl From<BlacklistInput> for config::BlackList {
+    fn from(value: BlacklistInput) -> Self {
+        config::BlackList::new(
+            value.owners.into_iter().flatten().map(Into::into).collect(),
+            value.coins.into_iter().flatten().map(Into::into).collect(),
+            value
+                .messages
+                .into_iter()
+                .flatten()
+                .map(Into::into)
+                .collect(),
+            value
+                .contracts
+                .into_iter()
+                .flatten()
+                .map(Into::into)
+                .collect(),
+        )
+    }
+}
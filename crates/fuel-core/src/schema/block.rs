@@ -5,7 +5,11 @@ use super::scalars::{
 };
 use crate::{
     fuel_core_graphql_api::{
-        api_service::ConsensusModule,
+        api_service::{
+            ConsensusModule,
+            NewBlocks,
+            ReadDatabase,
+        },
         database::ReadView,
         query_costs,
         Config as GraphQLConfig,
@@ -19,7 +23,10 @@ use crate::{
             U32,
             U64,
         },
-        tx::types::Transaction,
+        tx::types::{
+            Transaction,
+            TransactionStatus,
+        },
         ReadViewProvider,
     },
 };
@@ -33,8 +40,10 @@ use async_graphql::{
     Enum,
     Object,
     SimpleObject,
+    Subscription,
     Union,
 };
+use fuel_core_services::yield_stream::StreamYieldExt;
 use fuel_core_storage::{
     iter::IterDirection,
     Result as StorageResult,
@@ -44,11 +53,21 @@ use fuel_core_types::{
         block::CompressedBlock,
         header::BlockHeader,
     },
-    fuel_tx::TxId,
+    fuel_tx::{
+        self,
+        TxId,
+    },
     fuel_types::{
         self,
         BlockHeight,
     },
+    services::{
+        block_importer::ImportResult,
+        txpool::{
+            from_executor_to_status,
+            TransactionStatus as TxStatus,
+        },
+    },
 };
 use futures::{
     Stream,
@@ -402,6 +421,205 @@ impl BlockMutation {
     }
 }
 
+#[derive(Default)]
+pub struct BlockSubscription;
+
+#[Subscription]
+impl BlockSubscription {
+    /// Returns a stream of blocks processed by the node. The block is sent
+    /// after all information about it is available through the API.
+    ///
+    /// If `from_height` is specified, the stream starts from the block at this
+    /// height, so the client can resume the subscription after a reconnect
+    /// without gaps. Otherwise, the stream starts from the next processed block.
+    /// The number of the stored blocks replayed from the `from_height` is limited
+    /// by the configuration of the node.
+    ///
+    /// If the stream is polled slower than new blocks arrive, it ends with an
+    /// error. In such a case, the stream can be restarted from the next
+    /// expected height.
+    #[graphql(complexity = "query_costs().new_blocks + child_complexity")]
+    async fn new_blocks<'a>(
+        &self,
+        ctx: &'a Context<'a>,
+        #[graphql(desc = "The height of the first block in the stream")]
+        from_height: Option<U32>,
+    ) -> async_graphql::Result<impl Stream<Item = async_graphql::Result<NewBlock>> + 'a>
+    {
+        // Subscribe before reading the database to not miss any block.
+        // All blocks up to the processed height are available in the database,
+        // the next blocks will arrive through the subscription.
+        let new_blocks = ctx.data_unchecked::<NewBlocks>().new_blocks_subscribe();
+        let database: &ReadDatabase = ctx.data_unchecked();
+        let processed_height = database.off_chain_latest_height();
+        let query = database.view()?;
+
+        let next_height = processed_height
+            .map(|height| u32::from(height).saturating_add(1))
+            .unwrap_or_default();
+        let from_height = from_height.map(|height| height.0).unwrap_or(next_height);
+        let first_new_height = from_height.max(next_height);
+        let batch_size = query.batch_size;
+
+        let max_replayed_blocks = ctx
+            .data_unchecked::<GraphQLConfig>()
+            .config
+            .max_replayed_blocks;
+        let replayed_blocks = next_height.saturating_sub(from_height);
+        if replayed_blocks > max_replayed_blocks {
+            return Err(anyhow!(
+                "The subscription can replay at most {max_replayed_blocks} stored blocks, \
+                but {replayed_blocks} blocks are requested"
+            )
+            .into())
+        }
+
+        let stored_blocks = futures::stream::iter(from_height..next_height)
+            .map(move |height| {
+                NewBlock::from_storage(&query, &height.into())
+                    .map_err(async_graphql::Error::from)
+            })
+            .yield_each(batch_size);
+
+        let new_blocks = new_blocks
+            .filter(move |result| {
+                let is_new = match result {
+                    Ok(result) => {
+                        u32::from(*result.sealed_block.entity.header().height())
+                            >= first_new_height
+                    }
+                    Err(_) => true,
+                };
+                futures::future::ready(is_new)
+            })
+            .map(|result| {
+                result
+                    .map(|result| NewBlock::from(&**result))
+                    .map_err(async_graphql::Error::from)
+            })
+            // End the stream after the first error.
+            .scan(false, |failed, result| {
+                if *failed {
+                    return futures::future::ready(None);
+                }
+                *failed = result.is_err();
+                futures::future::ready(Some(result))
+            });
+
+        Ok(stored_blocks.chain(new_blocks))
+    }
+}
+
+/// The block processed by the node along with its transactions and their statuses.
+pub struct NewBlock {
+    header: BlockHeader,
+    transactions: NewBlockTransactions,
+}
+
+enum NewBlockTransactions {
+    /// The transactions of the block received from the block importer.
+    Imported(Vec<NewBlockTransaction>),
+    /// The transactions of the stored block, which are loaded from the database
+    /// only when they are requested.
+    Stored(Vec<TxId>),
+}
+
+#[derive(Clone)]
+pub struct NewBlockTransaction {
+    id: TxId,
+    transaction: fuel_tx::Transaction,
+    status: TxStatus,
+}
+
+#[Object]
+impl NewBlock {
+    async fn id(&self) -> BlockId {
+        let bytes: fuel_types::Bytes32 = self.header.id().into();
+        bytes.into()
+    }
+
+    async fn height(&self) -> U32 {
+        let height: u32 = (*self.header.height()).into();
+        height.into()
+    }
+
+    async fn header(&self) -> Header {
+        self.header.clone().into()
+    }
+
+    #[graphql(complexity = "query_costs().block_transactions + child_complexity")]
+    async fn transactions(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<NewBlockTransaction>> {
+        match &self.transactions {
+            NewBlockTransactions::Imported(transactions) => Ok(transactions.clone()),
+            NewBlockTransactions::Stored(ids) => {
+                let query = ctx.read_view()?;
+                let transactions = ids
+                    .iter()
+                    .map(|id| {
+                        Ok(NewBlockTransaction {
+                            id: *id,
+                            transaction: query.transaction(id)?,
+                            status: query.tx_status(id)?,
+                        })
+                    })
+                    .collect::<StorageResult<Vec<_>>>()?;
+                Ok(transactions)
+            }
+        }
+    }
+}
+
+#[Object]
+impl NewBlockTransaction {
+    async fn id(&self) -> TransactionId {
+        self.id.into()
+    }
+
+    async fn transaction(&self) -> Transaction {
+        Transaction::from_tx(self.id, self.transaction.clone())
+    }
+
+    /// The status of the transaction, including its receipts.
+    async fn status(&self) -> TransactionStatus {
+        TransactionStatus::new(self.id, self.status.clone())
+    }
+}
+
+impl NewBlock {
+    fn from_storage(query: &ReadView, height: &BlockHeight) -> StorageResult<Self> {
+        let block = query.block(height)?;
+
+        Ok(Self {
+            header: block.header().clone(),
+            transactions: NewBlockTransactions::Stored(block.transactions().to_vec()),
+        })
+    }
+}
+
+impl From<&ImportResult> for NewBlock {
+    fn from(result: &ImportResult) -> Self {
+        let block = &result.sealed_block.entity;
+        let transactions = block
+            .transactions()
+            .iter()
+            .zip(result.tx_status.iter())
+            .map(|(transaction, status)| NewBlockTransaction {
+                id: status.id,
+                transaction: transaction.clone(),
+                status: from_executor_to_status(block, status.result.clone()),
+            })
+            .collect();
+
+        Self {
+            header: block.header().clone(),
+            transactions: NewBlockTransactions::Imported(transactions),
+        }
+    }
+}
+
 impl From<CompressedBlock> for Block {
     fn from(block: CompressedBlock) -> Self {
         Block(block)
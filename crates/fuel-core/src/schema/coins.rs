@@ -154,9 +154,15 @@ impl CoinQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "The ID of the coin")] utxo_id: UtxoId,
+        #[graphql(desc = "The block height to read the state at")] at_height: Option<U32>,
     ) -> async_graphql::Result<Option<Coin>> {
-        let query = ctx.read_view()?;
-        query.coin(utxo_id.0).into_api_result()
+        if let Some(height) = at_height {
+            let query = ctx.read_view_at(height.into())?;
+            query.coin(utxo_id.0).into_api_result()
+        } else {
+            let query = ctx.read_view()?;
+            query.coin(utxo_id.0).into_api_result()
+        }
     }
 
     /// Gets all unspent coins of some `owner` maybe filtered with by `asset_id` per page.
@@ -6,14 +6,17 @@ use crate::{
     schema::{
         scalars::{
             AssetId,
+            Bytes32,
             ContractId,
             HexString,
             Salt,
+            U32,
             U64,
         },
         ReadViewProvider,
     },
 };
+use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -24,14 +27,23 @@ use async_graphql::{
     Object,
 };
 use fuel_core_storage::{
+    iter::IterDirection,
     not_found,
     tables::ContractsRawCode,
+    Result as StorageResult,
 };
 use fuel_core_types::{
+    fuel_merkle::sparse::proof::{
+        ExclusionLeaf,
+        Proof,
+    },
     fuel_types,
     services::graphql_api,
 };
-use futures::StreamExt;
+use futures::{
+    future::Either,
+    StreamExt,
+};
 
 pub struct Contract(pub(crate) fuel_types::ContractId);
 
@@ -76,10 +88,14 @@ impl ContractQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "ID of the Contract")] id: ContractId,
+        #[graphql(desc = "The block height to read the state at")] at_height: Option<U32>,
     ) -> async_graphql::Result<Option<Contract>> {
-        let query = ctx.read_view()?;
-        query
-            .contract_exists(id.0)
+        let contract_exists = if let Some(height) = at_height {
+            ctx.read_view_at(height.into())?.contract_exists(id.0)
+        } else {
+            ctx.read_view()?.contract_exists(id.0)
+        };
+        contract_exists
             .and_then(|contract_exists| {
                 if contract_exists {
                     Ok(id.0)
@@ -125,23 +141,26 @@ impl ContractBalanceQuery {
         ctx: &Context<'_>,
         contract: ContractId,
         asset: AssetId,
+        #[graphql(desc = "The block height to read the state at")] at_height: Option<U32>,
     ) -> async_graphql::Result<ContractBalance> {
         let contract_id = contract.into();
         let asset_id = asset.into();
-        let query = ctx.read_view()?;
-        query
-            .contract_balance(contract_id, asset_id)
-            .into_api_result()
-            .map(|result| {
-                result.unwrap_or_else(|| {
-                    graphql_api::ContractBalance {
-                        owner: contract_id,
-                        amount: 0,
-                        asset_id,
-                    }
-                    .into()
-                })
+        let contract_balance = if let Some(height) = at_height {
+            ctx.read_view_at(height.into())?
+                .contract_balance(contract_id, asset_id)
+        } else {
+            ctx.read_view()?.contract_balance(contract_id, asset_id)
+        };
+        contract_balance.into_api_result().map(|result| {
+            result.unwrap_or_else(|| {
+                graphql_api::ContractBalance {
+                    owner: contract_id,
+                    amount: 0,
+                    asset_id,
+                }
+                .into()
             })
+        })
     }
 
     #[graphql(complexity = "{\
@@ -187,3 +206,228 @@ impl From<graphql_api::ContractBalance> for ContractBalance {
         ContractBalance(balance)
     }
 }
+
+pub struct ContractStorageSlot {
+    contract_id: fuel_types::ContractId,
+    key: fuel_types::Bytes32,
+    value: Option<Vec<u8>>,
+}
+
+#[Object]
+impl ContractStorageSlot {
+    async fn contract(&self) -> ContractId {
+        self.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.key.into()
+    }
+
+    /// The value of the storage slot. It is `null` if the slot is not set.
+    async fn value(&self) -> Option<HexString> {
+        self.value.clone().map(HexString)
+    }
+
+    /// The sparse Merkle proof of the storage slot against the state root of the contract.
+    #[graphql(complexity = "query_costs().contract_state_proof")]
+    async fn proof(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<ContractStateProof> {
+        let query = ctx.read_view()?;
+        query
+            .contract_storage_slot_proof(&self.contract_id, &self.key)
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+}
+
+pub struct ContractStateProof(graphql_api::ContractStateProof);
+
+#[Object]
+impl ContractStateProof {
+    /// The state root of the contract against which the proof was generated.
+    async fn state_root(&self) -> Bytes32 {
+        self.0.state_root.into()
+    }
+
+    /// Is `true` for the inclusion proof and `false` for the exclusion proof.
+    async fn inclusion(&self) -> bool {
+        matches!(self.0.proof, Proof::Inclusion(_))
+    }
+
+    async fn proof_set(&self) -> Vec<Bytes32> {
+        let proof_set = match &self.0.proof {
+            Proof::Inclusion(proof) => &proof.proof_set,
+            Proof::Exclusion(proof) => &proof.proof_set,
+        };
+
+        proof_set
+            .iter()
+            .cloned()
+            .map(|array| Bytes32::from(fuel_types::Bytes32::from(array)))
+            .collect()
+    }
+
+    /// The key of the leaf found on the path of the excluded slot.
+    /// It is `null` for the inclusion proof or if the path ends with the placeholder.
+    async fn leaf_key(&self) -> Option<Bytes32> {
+        match &self.0.proof {
+            Proof::Exclusion(proof) => match &proof.leaf {
+                ExclusionLeaf::Leaf(leaf) => {
+                    Some(fuel_types::Bytes32::from(leaf.leaf_key).into())
+                }
+                ExclusionLeaf::Placeholder => None,
+            },
+            Proof::Inclusion(_) => None,
+        }
+    }
+
+    /// The hash of the value of the leaf found on the path of the excluded slot.
+    /// It is `null` for the inclusion proof or if the path ends with the placeholder.
+    async fn leaf_value(&self) -> Option<Bytes32> {
+        match &self.0.proof {
+            Proof::Exclusion(proof) => match &proof.leaf {
+                ExclusionLeaf::Leaf(leaf) => {
+                    Some(fuel_types::Bytes32::from(leaf.leaf_value).into())
+                }
+                ExclusionLeaf::Placeholder => None,
+            },
+            Proof::Inclusion(_) => None,
+        }
+    }
+}
+
+impl From<graphql_api::ContractStateProof> for ContractStateProof {
+    fn from(proof: graphql_api::ContractStateProof) -> Self {
+        ContractStateProof(proof)
+    }
+}
+
+#[derive(InputObject)]
+struct ContractStorageSlotRangeInput {
+    /// The first key of the range(inclusive). The range is unbounded from below if not set.
+    start: Option<Bytes32>,
+    /// The last key of the range(inclusive). The range is unbounded from above if not set.
+    end: Option<Bytes32>,
+}
+
+#[derive(Default)]
+pub struct ContractStorageQuery;
+
+#[Object]
+impl ContractStorageQuery {
+    /// Returns the storage slots of the `contract`. If `keys` are specified, only these
+    /// slots are returned(including not set ones). If `range` is specified, only set slots
+    /// with keys within the range are returned. Otherwise all set slots of the contract.
+    /// `keys` and `range` can't be used together.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    async fn contract_storage_slots(
+        &self,
+        ctx: &Context<'_>,
+        contract: ContractId,
+        keys: Option<Vec<Bytes32>>,
+        range: Option<ContractStorageSlotRangeInput>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<Bytes32, ContractStorageSlot, EmptyFields, EmptyFields>,
+    > {
+        let query = ctx.read_view()?;
+        let query = query.as_ref();
+        let contract_id: fuel_types::ContractId = contract.into();
+        if keys.is_some() && range.is_some() {
+            return Err(anyhow!("`keys` and `range` can't be used together").into())
+        }
+        let (range_start, range_end): (
+            Option<fuel_types::Bytes32>,
+            Option<fuel_types::Bytes32>,
+        ) = range
+            .map(|range| (range.start.map(Into::into), range.end.map(Into::into)))
+            .unwrap_or_default();
+
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let start: Option<fuel_types::Bytes32> = (*start).map(Into::into);
+
+            let slots = if let Some(keys) = keys {
+                let mut keys = keys
+                    .into_iter()
+                    .map(fuel_types::Bytes32::from)
+                    .collect::<Vec<_>>();
+                keys.sort();
+                keys.dedup();
+                if direction == IterDirection::Reverse {
+                    keys.reverse();
+                }
+
+                let slots = keys
+                    .into_iter()
+                    .filter(move |key| match (start, direction) {
+                        (Some(start), IterDirection::Forward) => *key >= start,
+                        (Some(start), IterDirection::Reverse) => *key <= start,
+                        (None, _) => true,
+                    })
+                    .map(move |key| -> StorageResult<_> {
+                        let value = query.contract_storage_slot(contract_id, key)?;
+                        Ok((
+                            key.into(),
+                            ContractStorageSlot {
+                                contract_id,
+                                key,
+                                value,
+                            },
+                        ))
+                    });
+
+                Either::Left(futures::stream::iter(slots))
+            } else {
+                // The iteration starts from the bound of the range if the cursor is outside of it.
+                let start = match direction {
+                    IterDirection::Forward => start.max(range_start),
+                    IterDirection::Reverse => match (start, range_end) {
+                        (Some(start), Some(end)) => Some(start.min(end)),
+                        (start, end) => start.or(end),
+                    },
+                };
+                let slots = query
+                    .contract_storage_slots(contract_id, start, direction)
+                    .take_while(move |slot| {
+                        let within_range = match slot {
+                            Ok((key, _)) => match direction {
+                                IterDirection::Forward => {
+                                    range_end.map_or(true, |end| *key <= end)
+                                }
+                                IterDirection::Reverse => {
+                                    range_start.map_or(true, |start| *key >= start)
+                                }
+                            },
+                            Err(_) => true,
+                        };
+                        futures::future::ready(within_range)
+                    })
+                    .map(move |slot| -> StorageResult<_> {
+                        let (key, value) = slot?;
+                        Ok((
+                            key.into(),
+                            ContractStorageSlot {
+                                contract_id,
+                                key,
+                                value: Some(value),
+                            },
+                        ))
+                    });
+
+                Either::Right(slots)
+            };
+
+            Ok(slots)
+        })
+        .await
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::{
+            DatabaseBackup,
+            DatabaseStatsProvider,
+        },
+        Config as GraphQLConfig,
+    },
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Object,
+};
+use fuel_core_metrics::core_metrics;
+use std::path::PathBuf;
+
+pub struct DatabaseStats(core_metrics::DatabaseStats);
+
+#[Object]
+impl DatabaseStats {
+    /// The name of the database.
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// The part of the block reads served by the block cache. It is available
+    /// only if the statistics are enabled for the database in the RocksDB config.
+    async fn block_cache_hit_rate(&self) -> Option<f64> {
+        self.0.block_cache_hit_rate
+    }
+
+    /// The statistics of the columns of the database sorted by the name.
+    async fn columns(&self) -> Vec<ColumnStats> {
+        self.0.columns.iter().cloned().map(ColumnStats).collect()
+    }
+}
+
+pub struct ColumnStats(core_metrics::ColumnStats);
+
+#[Object]
+impl ColumnStats {
+    /// The name of the column.
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// The number of the entries read from the column since the start of the node.
+    async fn reads(&self) -> U64 {
+        self.0.reads.into()
+    }
+
+    /// The number of the entries written into the column since the start of the node.
+    async fn writes(&self) -> U64 {
+        self.0.writes.into()
+    }
+
+    /// The number of the iterators over the column since the start of the node.
+    async fn iterations(&self) -> U64 {
+        self.0.iterations.into()
+    }
+
+    async fn bytes_read(&self) -> U64 {
+        self.0.bytes_read.into()
+    }
+
+    async fn bytes_written(&self) -> U64 {
+        self.0.bytes_written.into()
+    }
+
+    /// The estimated size of the live data of the column in bytes.
+    async fn live_data_size(&self) -> U64 {
+        self.0.live_data_size.into()
+    }
+
+    /// The number of the SST files of the column. Always zero for the redb.
+    async fn sst_files(&self) -> U64 {
+        self.0.sst_files.into()
+    }
+
+    /// The estimated number of bytes the compaction of the column needs to rewrite.
+    async fn pending_compaction_bytes(&self) -> U64 {
+        self.0.pending_compaction_bytes.into()
+    }
+}
+
+#[derive(Default)]
+pub struct DatabaseQuery;
+
+#[Object]
+impl DatabaseQuery {
+    /// Returns the statistics of the columns of the node's databases.
+    /// The in-memory databases don't collect the statistics.
+    /// Requires `debug` to be enabled.
+    async fn database_stats(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DatabaseStats>> {
+        require_debug(ctx)?;
+        let database_stats = ctx.data_unchecked::<DatabaseStatsProvider>();
+        let stats = database_stats.database_stats().await?;
+        Ok(stats.into_iter().map(DatabaseStats).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct DatabaseMutation;
+
+#[Object]
+impl DatabaseMutation {
+    /// Creates a consistent backup of all databases of the node in the `path`
+    /// on the node's machine. The directory must not exist or be empty.
+    /// Returns the height of the latest block in the backup.
+    /// Requires `debug` to be enabled.
+    async fn backup_database(
+        &self,
+        ctx: &Context<'_>,
+        path: String,
+    ) -> async_graphql::Result<U32> {
+        require_debug(ctx)?;
+        let database_backup = ctx.data_unchecked::<DatabaseBackup>();
+        let height = database_backup.backup(PathBuf::from(path)).await?;
+        Ok(height.into())
+    }
+}
+
+fn require_debug(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+
+    if config.debug {
+        Ok(())
+    } else {
+        Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+    }
+}
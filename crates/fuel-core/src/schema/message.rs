@@ -71,10 +71,16 @@ impl MessageQuery {
         &self,
         ctx: &Context<'_>,
         #[graphql(desc = "The Nonce of the message")] nonce: Nonce,
+        #[graphql(desc = "The block height to read the state at")] at_height: Option<U32>,
     ) -> async_graphql::Result<Option<Message>> {
-        let query = ctx.read_view()?;
         let nonce = nonce.0;
-        query.message(&nonce).into_api_result()
+        if let Some(height) = at_height {
+            let query = ctx.read_view_at(height.into())?;
+            query.message(&nonce).into_api_result()
+        } else {
+            let query = ctx.read_view()?;
+            query.message(&nonce).into_api_result()
+        }
     }
 
     #[graphql(complexity = "{\
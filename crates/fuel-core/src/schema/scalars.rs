@@ -1,3 +1,4 @@
+use crate::graphql_api::storage::receipts;
 use async_graphql::{
     connection::CursorType,
     InputValueError,
@@ -157,6 +158,55 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+/// The position of the receipt on the chain: `block_height#tx_idx#receipt_idx`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ContractReceiptCursor {
+    pub block_height: BlockHeight,
+    pub tx_idx: u16,
+    pub receipt_idx: u16,
+}
+
+impl CursorType for ContractReceiptCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let mut parts = s.split('#');
+        let (Some(block_height), Some(tx_idx), Some(receipt_idx), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err("Incorrect format provided".to_string())
+        };
+
+        Ok(Self {
+            block_height: BlockHeight::from_str(block_height)
+                .map_err(|_| "Failed to decode block_height")?,
+            tx_idx: u16::from_str(tx_idx).map_err(|_| "Failed to decode tx_idx")?,
+            receipt_idx: u16::from_str(receipt_idx)
+                .map_err(|_| "Failed to decode receipt_idx")?,
+        })
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}#{}", self.block_height, self.tx_idx, self.receipt_idx)
+    }
+}
+
+impl From<receipts::ContractReceiptCursor> for ContractReceiptCursor {
+    fn from(cursor: receipts::ContractReceiptCursor) -> Self {
+        Self {
+            block_height: cursor.block_height,
+            tx_idx: cursor.tx_idx,
+            receipt_idx: cursor.receipt_idx,
+        }
+    }
+}
+
+impl From<ContractReceiptCursor> for receipts::ContractReceiptCursor {
+    fn from(cursor: ContractReceiptCursor) -> Self {
+        Self::new(cursor.block_height, cursor.tx_idx, cursor.receipt_idx)
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
@@ -1,4 +1,7 @@
-use super::scalars::U64;
+use super::scalars::{
+    U32,
+    U64,
+};
 use crate::{
     fuel_core_graphql_api::{
         api_service::{
@@ -7,6 +10,7 @@ use crate::{
             TxPool,
         },
         query_costs,
+        Config as GraphQLConfig,
         IntoApiResult,
     },
     graphql_api::{
@@ -20,6 +24,8 @@ use crate::{
     schema::{
         scalars::{
             Address,
+            ContractId,
+            ContractReceiptCursor,
             HexString,
             SortedTxCursor,
             TransactionId,
@@ -47,6 +53,7 @@ use fuel_core_storage::{
 };
 use fuel_core_txpool::TxStatusMessage;
 use fuel_core_types::{
+    blockchain::block::CompressedBlock,
     fuel_tx::{
         Bytes32,
         Cacheable,
@@ -61,24 +68,37 @@ use fuel_core_types::{
         CheckPredicateParams,
         EstimatePredicates,
     },
-    services::txpool,
+    services::{
+        executor::{
+            DryRunOptions,
+            TraceConfig,
+        },
+        txpool,
+    },
 };
 use futures::{
     Stream,
     TryStreamExt,
 };
+use receipt::{
+    ContractReceipt,
+    ReceiptType,
+};
 use std::{
     borrow::Cow,
     iter,
 };
 use types::{
     DryRunTransactionExecutionStatus,
+    PendingTransaction,
     Transaction,
 };
 
+pub mod access_list;
 pub mod input;
 pub mod output;
 pub mod receipt;
+pub mod trace;
 pub mod types;
 pub mod upgrade_purpose;
 
@@ -236,6 +256,185 @@ impl TxQuery {
         .await
     }
 
+    /// Returns `Call`, `Log`, `LogData` and `Transfer` receipts of successful transactions
+    /// related to the contract, ordered by position on the chain.
+    /// Requires the contract receipts indexation to be enabled.
+    #[graphql(complexity = "{\
+        query_costs().storage_iterator\
+        + (query_costs().storage_read + first.unwrap_or_default() as usize) * child_complexity \
+        + (query_costs().storage_read + last.unwrap_or_default() as usize) * child_complexity\
+    }")]
+    #[allow(clippy::too_many_arguments)]
+    async fn receipts_by_contract(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        #[graphql(
+            desc = "Only receipts of these types are returned. All if not specified."
+        )]
+        receipt_types: Option<Vec<ReceiptType>>,
+        #[graphql(desc = "The lowest block height(inclusive) of the range.")]
+        from_height: Option<U32>,
+        #[graphql(desc = "The highest block height(inclusive) of the range.")]
+        to_height: Option<U32>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<ContractReceiptCursor, ContractReceipt, EmptyFields, EmptyFields>,
+    > {
+        let query = ctx.read_view()?;
+        let contract_id = fuel_types::ContractId::from(contract_id);
+        let from_height = from_height.map(|height| height.0.into());
+        let to_height = to_height.map(|height| height.0.into());
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<ContractReceiptCursor>, direction| {
+                let start = (*start).map(Into::into);
+                let receipts = query
+                    .contract_receipts_in_range(
+                        contract_id,
+                        from_height,
+                        to_height,
+                        start,
+                        direction,
+                    )?
+                    .try_filter(move |(_, value)| {
+                        let matches = receipt_types.as_ref().map_or(true, |types| {
+                            types.contains(&ReceiptType::from(&value.receipt))
+                        });
+                        futures::future::ready(matches)
+                    })
+                    .map_ok(|(cursor, value)| {
+                        let receipt = ContractReceipt {
+                            block_height: cursor.block_height,
+                            tx_id: value.tx_id,
+                            receipt: value.receipt,
+                        };
+                        (cursor.into(), receipt)
+                    });
+                Ok(receipts)
+            },
+        )
+        .await
+    }
+
+    /// Returns transactions waiting in the transaction pool for the inclusion into a block.
+    #[graphql(complexity = "{\
+        query_costs().pending_transactions\
+        + first.unwrap_or_default() as usize * child_complexity \
+        + last.unwrap_or_default() as usize * child_complexity\
+    }")]
+    async fn pending_transactions(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransactionId, PendingTransaction, EmptyFields, EmptyFields>,
+    > {
+        pending_transactions(ctx, None, first, after, last, before).await
+    }
+
+    /// Returns transactions waiting in the transaction pool that spend coins or
+    /// messages of the `owner`.
+    #[graphql(complexity = "{\
+        query_costs().pending_transactions\
+        + first.unwrap_or_default() as usize * child_complexity \
+        + last.unwrap_or_default() as usize * child_complexity\
+    }")]
+    async fn pending_transactions_by_owner(
+        &self,
+        ctx: &Context<'_>,
+        owner: Address,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransactionId, PendingTransaction, EmptyFields, EmptyFields>,
+    > {
+        let owner = fuel_types::Address::from(owner);
+        pending_transactions(ctx, Some(owner), first, after, last, before).await
+    }
+
+    /// Re-executes the committed transaction on top of the state before its block
+    /// and returns the execution status with the instruction-level trace.
+    /// The transactions preceding it in the block are re-executed without tracing.
+    #[graphql(complexity = "query_costs().trace_transaction + child_complexity")]
+    async fn trace_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+    ) -> async_graphql::Result<Option<DryRunTransactionExecutionStatus>> {
+        let query = ctx.read_view()?;
+        let id = id.0;
+
+        let block_height = match query
+            .tx_status(&id)
+            .into_api_result::<txpool::TransactionStatus, StorageError>()?
+        {
+            Some(txpool::TransactionStatus::Success { block_height, .. })
+            | Some(txpool::TransactionStatus::Failed { block_height, .. }) => {
+                block_height
+            }
+            _ => return Ok(None),
+        };
+
+        let block = query.block(&block_height)?;
+        let position = block
+            .transactions()
+            .iter()
+            .position(|tx_id| tx_id == &id)
+            .ok_or(anyhow::anyhow!(
+                "The transaction is not a part of the block at height {block_height}"
+            ))?;
+        let mut transactions = block.transactions()[..=position]
+            .iter()
+            .map(|tx_id| query.transaction(tx_id))
+            .collect::<StorageResult<Vec<FuelTx>>>()?;
+        if transactions.last().is_some_and(FuelTx::is_mint) {
+            return Err(anyhow::anyhow!("The `Mint` transaction can't be traced").into())
+        }
+
+        let consensus_params = ctx
+            .data_unchecked::<ConsensusProvider>()
+            .consensus_params_at_version(&block.header().consensus_parameters_version)?;
+        for tx in transactions.iter_mut() {
+            tx.precompute(&consensus_params.chain_id())?;
+        }
+
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let tx_status = block_producer
+            .dry_run_txs(
+                transactions,
+                Some(block_height),
+                None,
+                None,
+                None,
+                DryRunOptions {
+                    trace: Some(TraceConfig {
+                        tx_id: Some(id),
+                        max_steps: config.config.max_trace_steps,
+                    }),
+                    discover_missing_inputs: false,
+                },
+            )
+            .await?
+            .pop()
+            .map(DryRunTransactionExecutionStatus);
+
+        Ok(tx_status)
+    }
+
     /// Estimate the predicate gas for the provided transaction
     #[graphql(complexity = "query_costs().estimate_predicates + child_complexity")]
     async fn estimate_predicates(
@@ -293,11 +492,33 @@ impl TxMutation {
         // for read-only calls.
         utxo_validation: Option<bool>,
         gas_price: Option<U64>,
+        // If set, the transactions are executed as a part of the block at this height.
+        // For the already committed block, the execution happens on top of the state
+        // before this block with the consensus parameters and the gas price of this block.
+        block_height: Option<U32>,
+        // If set to true, the statuses contain the instruction-level trace of the execution.
+        trace: Option<bool>,
+        // If set to true, the failed transactions are executed again with the contract inputs
+        // and variable outputs whose absence caused the failure, and the statuses contain them.
+        discover_missing_inputs: Option<bool>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
+        let config = ctx.data_unchecked::<GraphQLConfig>();
         let block_producer = ctx.data_unchecked::<BlockProducer>();
-        let consensus_params = ctx
-            .data_unchecked::<ConsensusProvider>()
-            .latest_consensus_params();
+        let consensus_provider = ctx.data_unchecked::<ConsensusProvider>();
+        let block_height = block_height.map(Into::into);
+        let committed_block = match &block_height {
+            Some(height) => ctx
+                .read_view()?
+                .block(height)
+                .into_api_result::<CompressedBlock, async_graphql::Error>()?,
+            None => None,
+        };
+        let consensus_params = match committed_block {
+            Some(block) => consensus_provider.consensus_params_at_version(
+                &block.header().consensus_parameters_version,
+            )?,
+            None => consensus_provider.latest_consensus_params(),
+        };
         let block_gas_limit = consensus_params.block_gas_limit();
 
         let mut transactions = txs
@@ -317,10 +538,17 @@ impl TxMutation {
         let tx_statuses = block_producer
             .dry_run_txs(
                 transactions,
-                None, // TODO(#1749): Pass parameter from API
+                block_height,
                 None, // TODO(#1749): Pass parameter from API
                 utxo_validation,
                 gas_price.map(|x| x.into()),
+                DryRunOptions {
+                    trace: trace.unwrap_or_default().then_some(TraceConfig {
+                        tx_id: None,
+                        max_steps: config.config.max_trace_steps,
+                    }),
+                    discover_missing_inputs: discover_missing_inputs.unwrap_or_default(),
+                },
             )
             .await?;
         let tx_statuses = tx_statuses
@@ -455,6 +683,48 @@ async fn submit_and_await_status<'a>(
         .take(2))
 }
 
+async fn pending_transactions(
+    ctx: &Context<'_>,
+    owner: Option<fuel_types::Address>,
+    first: Option<i32>,
+    after: Option<String>,
+    last: Option<i32>,
+    before: Option<String>,
+) -> async_graphql::Result<
+    Connection<TransactionId, PendingTransaction, EmptyFields, EmptyFields>,
+> {
+    let txpool = ctx.data_unchecked::<TxPool>();
+    // The pagination skips the `start` and requests one more entry for `has_next_page`.
+    let limit = first
+        .or(last)
+        .and_then(|count| usize::try_from(count).ok())
+        .unwrap_or_default()
+        .saturating_add(2);
+
+    crate::schema::query_pagination(
+        after,
+        before,
+        first,
+        last,
+        |start: &Option<TransactionId>, direction| {
+            let start = (*start).map(|id| id.0);
+            let txs = futures::stream::once(async move {
+                txpool
+                    .pending_transactions(owner, start, direction, limit)
+                    .await
+                    .map(|txs| {
+                        futures::stream::iter(txs.into_iter().map(Ok::<_, StorageError>))
+                    })
+                    .map_err(StorageError::from)
+            })
+            .try_flatten()
+            .map_ok(|tx| (tx.transaction.id().into(), PendingTransaction(tx)));
+            Ok(txs)
+        },
+    )
+    .await
+}
+
 struct StatusChangeState<'a> {
     query: Cow<'a, ReadView>,
     txpool: &'a TxPool,
@@ -0,0 +1,63 @@
+use crate::schema::scalars::{
+    AssetId,
+    Bytes32,
+    ContractId,
+    U16,
+};
+use async_graphql::Object;
+use fuel_core_types::{
+    fuel_types,
+    services::executor,
+};
+
+pub struct AccessList(pub(crate) executor::AccessList);
+
+#[Object]
+impl AccessList {
+    /// The contracts whose code, state or balances were accessed.
+    async fn contracts(&self) -> Vec<ContractId> {
+        self.0.contracts.iter().copied().map(Into::into).collect()
+    }
+
+    /// The storage slots of the contracts that were read or written.
+    async fn storage_slots(&self) -> Vec<AccessedStorageSlot> {
+        self.0
+            .storage_slots
+            .iter()
+            .map(|(contract_id, key)| AccessedStorageSlot(*contract_id, *key))
+            .collect()
+    }
+
+    /// The assets whose contract balances were accessed or which were transferred.
+    async fn assets(&self) -> Vec<AssetId> {
+        self.0.assets.iter().copied().map(Into::into).collect()
+    }
+}
+
+pub struct AccessedStorageSlot(fuel_types::ContractId, fuel_types::Bytes32);
+
+#[Object]
+impl AccessedStorageSlot {
+    async fn contract_id(&self) -> ContractId {
+        self.0.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.1.into()
+    }
+}
+
+pub struct MissingInputs(pub(crate) executor::MissingInputs);
+
+#[Object]
+impl MissingInputs {
+    /// The contracts called by the transaction without the contract input.
+    async fn contracts(&self) -> Vec<ContractId> {
+        self.0.contracts.iter().copied().map(Into::into).collect()
+    }
+
+    /// The number of variable outputs missing in the transaction.
+    async fn variable_outputs(&self) -> U16 {
+        self.0.variable_outputs.into()
+    }
+}
@@ -5,6 +5,8 @@ use crate::schema::scalars::{
     ContractId,
     HexString,
     Nonce,
+    TransactionId,
+    U32,
     U64,
 };
 use async_graphql::{
@@ -14,6 +16,7 @@ use async_graphql::{
 use fuel_core_types::{
     fuel_asm::Word,
     fuel_tx,
+    fuel_types::BlockHeight,
 };
 
 #[derive(
@@ -55,6 +58,29 @@ impl From<&fuel_tx::Receipt> for ReceiptType {
     }
 }
 
+pub struct ContractReceipt {
+    pub block_height: BlockHeight,
+    pub tx_id: fuel_tx::TxId,
+    pub receipt: fuel_tx::Receipt,
+}
+
+#[Object]
+impl ContractReceipt {
+    /// The height of the block that includes the transaction.
+    async fn block_height(&self) -> U32 {
+        self.block_height.into()
+    }
+
+    /// The id of the transaction that produced the receipt.
+    async fn transaction_id(&self) -> TransactionId {
+        self.tx_id.into()
+    }
+
+    async fn receipt(&self) -> Receipt {
+        Receipt(self.receipt.clone())
+    }
+}
+
 pub struct Receipt(pub fuel_tx::Receipt);
 
 #[Object]
@@ -0,0 +1,87 @@
+use crate::schema::scalars::{
+    Bytes32,
+    ContractId,
+    U32,
+    U64,
+};
+use async_graphql::{
+    Enum,
+    Object,
+};
+use fuel_core_types::{
+    fuel_asm::Instruction,
+    services::executor,
+};
+
+pub struct TraceRecord(pub(crate) executor::TraceRecord);
+
+#[Object]
+impl TraceRecord {
+    /// The contract that owns the executed code, or `null` for the script code.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.0.contract_id.map(Into::into)
+    }
+
+    /// The program counter relative to the start of the script or contract code.
+    async fn pc(&self) -> U64 {
+        self.0.pc.into()
+    }
+
+    /// The raw executed instruction.
+    async fn instruction(&self) -> U32 {
+        self.0.instruction.into()
+    }
+
+    /// The name of the executed opcode, or `null` if the instruction is invalid.
+    async fn opcode(&self) -> Option<String> {
+        Instruction::try_from(self.0.instruction)
+            .ok()
+            .map(|instruction| format!("{:?}", instruction.opcode()))
+    }
+
+    /// The gas consumed by the instruction.
+    async fn gas_used(&self) -> U64 {
+        self.0.gas_used.into()
+    }
+
+    /// The contract storage slots read or written by the instruction.
+    async fn storage_access(&self) -> Option<StorageAccess> {
+        self.0.storage_access.map(StorageAccess)
+    }
+}
+
+pub struct StorageAccess(executor::StorageAccess);
+
+#[Object]
+impl StorageAccess {
+    async fn kind(&self) -> StorageAccessKind {
+        self.0.kind.into()
+    }
+
+    /// The key of the first accessed slot.
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+
+    /// The number of sequential slots accessed starting from the `key`.
+    async fn slots(&self) -> U64 {
+        self.0.slots.into()
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+    Clear,
+}
+
+impl From<executor::StorageAccessKind> for StorageAccessKind {
+    fn from(kind: executor::StorageAccessKind) -> Self {
+        match kind {
+            executor::StorageAccessKind::Read => StorageAccessKind::Read,
+            executor::StorageAccessKind::Write => StorageAccessKind::Write,
+            executor::StorageAccessKind::Clear => StorageAccessKind::Clear,
+        }
+    }
+}
@@ -1,7 +1,12 @@
 use super::{
+    access_list::{
+        AccessList,
+        MissingInputs,
+    },
     input::Input,
     output::Output,
     receipt::Receipt,
+    trace::TraceRecord,
 };
 use crate::{
     fuel_core_graphql_api::{
@@ -199,7 +204,9 @@ impl SuccessStatus {
         self.result.map(Into::into)
     }
 
-    async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
+    async fn receipts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Receipt>> {
+        let query = ctx.read_view()?;
+        query.ensure_receipts_are_available(&self.block_height)?;
         Ok(self.receipts.iter().map(Into::into).collect())
     }
 
@@ -259,7 +266,9 @@ impl FailureStatus {
         self.state.map(Into::into)
     }
 
-    async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
+    async fn receipts(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Receipt>> {
+        let query = ctx.read_view()?;
+        query.ensure_receipts_are_available(&self.block_height)?;
         Ok(self.receipts.iter().map(Into::into).collect())
     }
 
@@ -978,6 +987,74 @@ impl DryRunTransactionExecutionStatus {
     async fn receipts(&self) -> Vec<Receipt> {
         self.0.result.receipts().iter().map(Into::into).collect()
     }
+
+    /// The instruction-level trace of the execution, if the tracing was requested.
+    async fn trace(&self) -> Option<Vec<TraceRecord>> {
+        self.0
+            .trace
+            .as_ref()
+            .map(|trace| trace.iter().cloned().map(TraceRecord).collect())
+    }
+
+    /// The contracts, storage slots and assets accessed by the transaction.
+    /// It is `null` if the transaction is executed by the WASM executor.
+    async fn access_list(&self) -> Option<AccessList> {
+        self.0.access_list.clone().map(AccessList)
+    }
+
+    /// The contract inputs and variable outputs whose absence caused the failure
+    /// of the transaction. It is `null` if nothing is missing.
+    async fn missing_inputs(&self) -> Option<MissingInputs> {
+        self.0.missing_inputs.clone().map(MissingInputs)
+    }
+}
+
+pub struct PendingTransaction(pub(crate) txpool::PendingTransaction);
+
+#[Object]
+impl PendingTransaction {
+    async fn id(&self) -> TransactionId {
+        self.0.transaction.id().into()
+    }
+
+    async fn transaction(&self) -> Transaction {
+        let tx = self.0.transaction.as_ref().into();
+        Transaction::from_tx(self.0.transaction.id(), tx)
+    }
+
+    async fn tip(&self) -> U64 {
+        self.0.transaction.tip().into()
+    }
+
+    async fn max_gas(&self) -> U64 {
+        self.0.transaction.max_gas().into()
+    }
+
+    /// The metered size of the transaction in bytes.
+    async fn byte_size(&self) -> U64 {
+        (self.0.transaction.metered_bytes_size() as u64).into()
+    }
+
+    /// The time when the transaction was added to the pool.
+    async fn submitted_at(&self) -> Tai64Timestamp {
+        Tai64Timestamp(self.0.submitted_at)
+    }
+
+    /// The ids of pending transactions that this transaction depends on.
+    async fn dependencies(&self) -> Vec<TransactionId> {
+        self.0.dependencies.iter().map(|id| (*id).into()).collect()
+    }
+
+    /// The ids of pending transactions that depend on this transaction.
+    async fn dependents(&self) -> Vec<TransactionId> {
+        self.0.dependents.iter().map(|id| (*id).into()).collect()
+    }
+
+    /// The cumulative tip of the transaction and all pending transactions that depend on it.
+    /// The pool uses it to prioritize the transaction for the inclusion into the block.
+    async fn dependents_cumulative_tip(&self) -> U64 {
+        self.0.dependents_cumulative_tip.into()
+    }
 }
 
 #[tracing::instrument(level = "debug", skip(query, txpool), ret, err)]
@@ -56,9 +56,12 @@ pub use fuel_core_services::Service as ServiceTrait;
 pub mod adapters;
 pub mod config;
 pub mod genesis;
+pub mod integrity;
 pub mod metrics;
 mod query;
+pub mod reindex;
 pub mod sub_services;
+pub mod verification;
 pub mod vm_pool;
 
 #[derive(Clone)]
@@ -151,6 +154,42 @@ impl FuelService {
         })
     }
 
+    /// Rebuilds the off-chain database if the `auto_reindex` is enabled, and it is
+    /// incomplete or the previous reindexing was interrupted. Should be called before
+    /// the [`FuelService::new`], because the sub services use the off-chain database.
+    pub async fn reindex_if_required(
+        database: &mut CombinedDatabase,
+        config: &Config,
+        shutdown_listener: &mut crate::ShutdownListener,
+    ) -> anyhow::Result<()> {
+        if !config.auto_reindex {
+            return Ok(())
+        }
+
+        database.check_version()?;
+        Self::make_database_compatible_with_config(database, config, shutdown_listener)?;
+        if !reindex::is_reindex_required(database, &config.combined_db_config)? {
+            return Ok(())
+        }
+
+        tracing::warn!("The off-chain database is incomplete and will be reindexed");
+        let executor = ExecutorAdapter::new(
+            database.on_chain().clone(),
+            database.relayer().clone(),
+            config.into(),
+        );
+        reindex::reindex(
+            database,
+            &config.combined_db_config,
+            config.snapshot_reader.clone(),
+            executor,
+            config.da_compression.clone(),
+            shutdown_listener.clone(),
+        )
+        .await?;
+        Ok(())
+    }
+
     /// Creates and starts fuel node instance from service config
     pub async fn new_node(config: Config) -> anyhow::Result<Self> {
         // initialize database
@@ -176,10 +215,11 @@ impl FuelService {
 
     /// Creates and starts fuel node instance from service config and a pre-existing combined database
     pub async fn from_combined_database(
-        combined_database: CombinedDatabase,
+        mut combined_database: CombinedDatabase,
         config: Config,
     ) -> anyhow::Result<Self> {
         let mut listener = crate::ShutdownListener::spawn();
+        Self::reindex_if_required(&mut combined_database, &config, &mut listener).await?;
         let service = Self::new(combined_database, config, &mut listener)?;
         let state = service.start_and_await().await?;
 
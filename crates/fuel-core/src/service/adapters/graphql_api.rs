@@ -6,18 +6,25 @@ use super::{
     StaticGasPrice,
 };
 use crate::{
+    combined_database::CombinedDatabase,
     database::OnChainIterableKeyValueView,
     fuel_core_graphql_api::ports::{
         worker,
         worker::BlockAt,
         BlockProducerPort,
         ConsensusProvider,
+        DatabaseBackupPort,
         DatabaseMessageProof,
+        DatabaseStatsPort,
         GasPriceEstimate,
+        NewBlocksPort,
         P2pPort,
         TxPoolPort,
     },
-    graphql_api::ports::MemoryPool,
+    graphql_api::{
+        ports::MemoryPool,
+        worker_service,
+    },
     service::{
         adapters::{
             import_result_provider::ImportResultProvider,
@@ -28,13 +35,24 @@ use crate::{
     },
 };
 use async_trait::async_trait;
-use fuel_core_services::stream::BoxStream;
-use fuel_core_storage::Result as StorageResult;
-use fuel_core_txpool::TxStatusMessage;
+use fuel_core_metrics::core_metrics::DatabaseStats;
+use fuel_core_services::stream::{
+    BoxStream,
+    IntoBoxStream,
+};
+use fuel_core_storage::{
+    iter::IterDirection,
+    Result as StorageResult,
+};
+use fuel_core_txpool::{
+    config::BlackList,
+    TxStatusMessage,
+};
 use fuel_core_types::{
     blockchain::header::ConsensusParametersVersion,
     entities::relayer::message::MerkleProof,
     fuel_tx::{
+        Address,
         Bytes32,
         ConsensusParameters,
         Transaction,
@@ -43,15 +61,23 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::{
         block_importer::SharedImportResult,
-        executor::TransactionExecutionStatus,
+        executor::{
+            DryRunOptions,
+            TransactionExecutionStatus,
+        },
         p2p::PeerInfo,
-        txpool::TransactionStatus,
+        txpool::{
+            PendingTransaction,
+            TransactionStatus,
+        },
     },
     tai64::Tai64,
 };
 use std::{
     ops::Deref,
+    path::PathBuf,
     sync::Arc,
+    time::Duration,
 };
 
 mod off_chain;
@@ -91,12 +117,54 @@ impl TxPoolPort for TxPoolAdapter {
             .map_err(|e| anyhow::anyhow!(e))
     }
 
+    async fn pending_transactions(
+        &self,
+        owner: Option<Address>,
+        start: Option<TxId>,
+        direction: IterDirection,
+        limit: usize,
+    ) -> anyhow::Result<Vec<PendingTransaction>> {
+        self.service
+            .pending_transactions(owner, start, direction, limit)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
     fn tx_update_subscribe(
         &self,
         id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
         self.service.tx_update_subscribe(id)
     }
+
+    async fn black_list(&self) -> anyhow::Result<BlackList> {
+        self.service
+            .black_list()
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+
+    async fn update_black_list(
+        &self,
+        add: BlackList,
+        remove: BlackList,
+    ) -> anyhow::Result<BlackList> {
+        self.service
+            .update_black_list(add, remove)
+            .await
+            .map_err(|e| anyhow::anyhow!(e))
+    }
+}
+
+impl NewBlocksPort for worker_service::SharedState {
+    fn new_blocks_subscribe(&self) -> BoxStream<anyhow::Result<SharedImportResult>> {
+        use futures::StreamExt;
+        tokio_stream::wrappers::BroadcastStream::new(self.subscribe_new_blocks())
+            .map(|result| {
+                result.map_err(|e| anyhow::anyhow!("Failed to receive a new block: {e}"))
+            })
+            .into_boxed()
+    }
 }
 
 impl DatabaseMessageProof for OnChainIterableKeyValueView {
@@ -118,9 +186,17 @@ impl BlockProducerPort for BlockProducerAdapter {
         time: Option<Tai64>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
+        options: DryRunOptions,
     ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
         self.block_producer
-            .dry_run(transactions, height, time, utxo_validation, gas_price)
+            .dry_run(
+                transactions,
+                height,
+                time,
+                utxo_validation,
+                gas_price,
+                options,
+            )
             .await
     }
 }
@@ -233,3 +309,25 @@ impl MemoryPool for SharedMemoryPool {
         self.memory_pool.take_raw().await
     }
 }
+
+/// The time for the off-chain and gas price databases to catch up
+/// with the on-chain database before the backup.
+const BACKUP_SYNC_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[async_trait::async_trait]
+impl DatabaseBackupPort for CombinedDatabase {
+    async fn backup(&self, path: PathBuf) -> anyhow::Result<BlockHeight> {
+        let database = self.clone();
+        tokio::task::spawn_blocking(move || database.backup(&path, BACKUP_SYNC_TIMEOUT))
+            .await?
+    }
+}
+
+#[async_trait::async_trait]
+impl DatabaseStatsPort for CombinedDatabase {
+    async fn database_stats(&self) -> anyhow::Result<Vec<DatabaseStats>> {
+        let database = self.clone();
+        let stats = tokio::task::spawn_blocking(move || database.stats()).await??;
+        Ok(stats)
+    }
+}
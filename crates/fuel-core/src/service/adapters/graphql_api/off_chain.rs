@@ -6,17 +6,25 @@ use crate::{
         },
         Database,
         OffChainIterableKeyValueView,
+        OffChainKeyValueView,
     },
     fuel_core_graphql_api::{
         ports::{
             worker,
             OffChainDatabase,
+            OffChainDatabaseAt,
         },
         storage::{
             contracts::ContractsInfo,
             da_compression::DaCompressedBlocks,
+            receipts::{
+                ContractReceipt,
+                ContractReceiptCursor,
+            },
             relayed_transactions::RelayedTransactionStatuses,
+            statistic::StatisticTable,
             transactions::OwnedTransactionIndexCursor,
+            HISTORY_START,
         },
     },
     graphql_api::storage::{
@@ -53,6 +61,7 @@ use fuel_core_storage::{
     Error as StorageError,
     Result as StorageResult,
     StorageAsRef,
+    StorageInspect,
 };
 use fuel_core_types::{
     blockchain::{
@@ -142,6 +151,16 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
             .into_boxed()
     }
 
+    fn contract_receipts(
+        &self,
+        contract_id: ContractId,
+        start: Option<ContractReceiptCursor>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(ContractReceiptCursor, ContractReceipt)>> {
+        self.contract_receipts(contract_id, start, Some(direction))
+            .into_boxed()
+    }
+
     fn contract_salt(&self, contract_id: &ContractId) -> StorageResult<Salt> {
         let salt = *self
             .storage_as_ref::<ContractsInfo>()
@@ -202,35 +221,22 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
         self.message_is_spent(nonce)
     }
 
+    fn history_start(&self) -> StorageResult<BlockHeight> {
+        let history_start = self
+            .storage_as_ref::<StatisticTable<BlockHeight>>()
+            .get(HISTORY_START)?
+            .unwrap_or_default()
+            .into_owned();
+        Ok(history_start)
+    }
+
     fn balance(
         &self,
         owner: &Address,
         asset_id: &AssetId,
         base_asset_id: &AssetId,
     ) -> StorageResult<TotalBalanceAmount> {
-        let coins = self
-            .storage_as_ref::<CoinBalances>()
-            .get(&CoinBalancesKey::new(owner, asset_id))?
-            .unwrap_or_default()
-            .into_owned() as TotalBalanceAmount;
-
-        if base_asset_id == asset_id {
-            let MessageBalance {
-                retryable: _, // TODO: https://github.com/FuelLabs/fuel-core/issues/2448
-                non_retryable,
-            } = self
-                .storage_as_ref::<MessageBalances>()
-                .get(owner)?
-                .unwrap_or_default()
-                .into_owned();
-
-            let total = coins.checked_add(non_retryable).ok_or(anyhow::anyhow!(
-                "Total balance overflow: coins: {coins}, messages: {non_retryable}"
-            ))?;
-            Ok(total)
-        } else {
-            Ok(coins)
-        }
+        balance(self, owner, asset_id, base_asset_id)
     }
 
     fn balances(
@@ -284,6 +290,52 @@ impl OffChainDatabase for OffChainIterableKeyValueView {
     }
 }
 
+impl OffChainDatabaseAt for OffChainKeyValueView {
+    fn balance(
+        &self,
+        owner: &Address,
+        asset_id: &AssetId,
+        base_asset_id: &AssetId,
+    ) -> StorageResult<TotalBalanceAmount> {
+        balance(self, owner, asset_id, base_asset_id)
+    }
+}
+
+fn balance<S>(
+    storage: &S,
+    owner: &Address,
+    asset_id: &AssetId,
+    base_asset_id: &AssetId,
+) -> StorageResult<TotalBalanceAmount>
+where
+    S: StorageInspect<CoinBalances, Error = StorageError>
+        + StorageInspect<MessageBalances, Error = StorageError>,
+{
+    let coins = storage
+        .storage_as_ref::<CoinBalances>()
+        .get(&CoinBalancesKey::new(owner, asset_id))?
+        .unwrap_or_default()
+        .into_owned() as TotalBalanceAmount;
+
+    if base_asset_id == asset_id {
+        let MessageBalance {
+            retryable: _, // TODO: https://github.com/FuelLabs/fuel-core/issues/2448
+            non_retryable,
+        } = storage
+            .storage_as_ref::<MessageBalances>()
+            .get(owner)?
+            .unwrap_or_default()
+            .into_owned();
+
+        let total = coins.checked_add(non_retryable).ok_or(anyhow::anyhow!(
+            "Total balance overflow: coins: {coins}, messages: {non_retryable}"
+        ))?;
+        Ok(total)
+    } else {
+        Ok(coins)
+    }
+}
+
 impl worker::OffChainDatabase for Database<OffChain> {
     type Transaction<'a> = StorageTransaction<&'a mut Self> where Self: 'a;
 
@@ -298,4 +350,8 @@ impl worker::OffChainDatabase for Database<OffChain> {
     fn balances_enabled(&self) -> StorageResult<bool> {
         self.indexation_available(IndexationKind::Balances)
     }
+
+    fn contract_receipts_enabled(&self) -> StorageResult<bool> {
+        self.indexation_available(IndexationKind::ContractReceipts)
+    }
 }
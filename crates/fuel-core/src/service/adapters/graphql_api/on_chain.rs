@@ -3,6 +3,7 @@ use crate::{
         database_description::on_chain::OnChain,
         Database,
         OnChainIterableKeyValueView,
+        OnChainKeyValueView,
     },
     fuel_core_graphql_api::ports::{
         DatabaseBlocks,
@@ -10,6 +11,7 @@ use crate::{
         DatabaseContracts,
         DatabaseMessages,
         OnChainDatabase,
+        OnChainDatabaseAt,
     },
     graphql_api::ports::worker,
 };
@@ -39,6 +41,7 @@ use fuel_core_types::{
     entities::relayer::message::Message,
     fuel_tx::{
         AssetId,
+        Bytes32,
         ContractId,
         Transaction,
         TxId,
@@ -47,7 +50,10 @@ use fuel_core_types::{
         BlockHeight,
         Nonce,
     },
-    services::graphql_api::ContractBalance,
+    services::graphql_api::{
+        ContractBalance,
+        ContractStateProof,
+    },
 };
 use itertools::Itertools;
 
@@ -90,6 +96,11 @@ impl DatabaseBlocks for OnChainIterableKeyValueView {
             .map(|c| c.map(|c| c.into_owned()))?
             .ok_or(not_found!(SealedBlockConsensus))
     }
+
+    fn history_start(&self) -> StorageResult<BlockHeight> {
+        let latest_height = self.latest_height()?;
+        fuel_core_importer::history::history_start(self, &latest_height)
+    }
 }
 
 impl DatabaseMessages for OnChainIterableKeyValueView {
@@ -124,6 +135,25 @@ impl DatabaseContracts for OnChainIterableKeyValueView {
             .map(|res| res.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn contract_storage_slots(
+        &self,
+        contract: ContractId,
+        start_key: Option<Bytes32>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(Bytes32, Vec<u8>)>> {
+        self.filter_contract_state(contract, start_key, Some(direction))
+            .map_ok(|entry| (*entry.key.state_key(), entry.value.into()))
+            .into_boxed()
+    }
+
+    fn contract_storage_slot_proof(
+        &self,
+        contract: &ContractId,
+        key: &Bytes32,
+    ) -> StorageResult<ContractStateProof> {
+        self.contract_state_proof(contract, key)
+    }
 }
 
 impl DatabaseChain for OnChainIterableKeyValueView {
@@ -136,8 +166,17 @@ impl DatabaseChain for OnChainIterableKeyValueView {
 
 impl OnChainDatabase for OnChainIterableKeyValueView {}
 
+impl OnChainDatabaseAt for OnChainKeyValueView {}
+
 impl worker::OnChainDatabase for Database<OnChain> {
     fn latest_height(&self) -> StorageResult<Option<BlockHeight>> {
         Ok(fuel_core_storage::transactional::HistoricalView::latest_height(self))
     }
+
+    fn block(&self, height: &BlockHeight) -> StorageResult<Option<CompressedBlock>> {
+        Ok(self
+            .storage_as_ref::<FuelBlocks>()
+            .get(height)?
+            .map(|block| block.into_owned()))
+    }
 }
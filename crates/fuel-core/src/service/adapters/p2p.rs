@@ -42,6 +42,13 @@ impl P2pDb for OnChainIterableKeyValueView {
     fn get_genesis(&self) -> StorageResult<Genesis> {
         self.get_genesis()
     }
+
+    fn history_start(&self) -> StorageResult<BlockHeight> {
+        let Some(latest_height) = self.maybe_latest_height()? else {
+            return Ok(Default::default())
+        };
+        fuel_core_importer::history::history_start(self, &latest_height)
+    }
 }
 
 impl BlockHeightImporter for BlockImporterAdapter {
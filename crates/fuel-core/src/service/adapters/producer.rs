@@ -33,6 +33,7 @@ use fuel_core_storage::{
         ConsensusParametersVersions,
         FuelBlocks,
         StateTransitionBytecodeVersions,
+        Transactions,
     },
     transactional::Changes,
     Result as StorageResult,
@@ -49,6 +50,7 @@ use fuel_core_types::{
     },
     fuel_tx,
     fuel_tx::{
+        field::MintGasPrice,
         ConsensusParameters,
         Transaction,
     },
@@ -59,6 +61,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunOptions,
             Result as ExecutorResult,
             TransactionExecutionStatus,
             UncommittedResult,
@@ -119,8 +122,9 @@ impl fuel_core_producer::ports::DryRunner for ExecutorAdapter {
         &self,
         block: Components<Vec<fuel_tx::Transaction>>,
         utxo_validation: Option<bool>,
+        options: DryRunOptions,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        self.executor.dry_run(block, utxo_validation)
+        self.executor.dry_run(block, utxo_validation, options)
     }
 }
 
@@ -225,6 +229,31 @@ impl fuel_core_producer::ports::BlockProducerDatabase for OnChainIterableKeyValu
         self.storage::<FuelBlocks>().root(height).map(Into::into)
     }
 
+    fn block_gas_price(&self, height: &BlockHeight) -> StorageResult<u64> {
+        let block = self
+            .storage::<FuelBlocks>()
+            .get(height)?
+            .ok_or(not_found!(FuelBlocks))?;
+        // The mint transaction is always the last transaction in the block.
+        let mint_id = block
+            .transactions()
+            .last()
+            .ok_or(not_found!("Mint transaction"))?;
+        let mint = self
+            .storage::<Transactions>()
+            .get(mint_id)?
+            .ok_or(not_found!(Transactions))?;
+
+        let gas_price =
+            mint.as_mint()
+                .map(|mint| *mint.gas_price())
+                .ok_or(anyhow::anyhow!(
+                    "The last transaction in the block is not a mint"
+                ))?;
+
+        Ok(gas_price)
+    }
+
     fn latest_consensus_parameters_version(
         &self,
     ) -> StorageResult<ConsensusParametersVersion> {
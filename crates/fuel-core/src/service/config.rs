@@ -29,6 +29,7 @@ pub use fuel_core_poa::Trigger;
 use fuel_core_relayer::Config as RelayerConfig;
 use fuel_core_txpool::config::Config as TxPoolConfig;
 use fuel_core_types::blockchain::header::StateTransitionBytecodeVersion;
+use fuel_core_upgradable_executor::config::ValidationMode;
 
 use crate::{
     combined_database::CombinedDatabaseConfig,
@@ -52,6 +53,16 @@ pub struct Config {
     // default to false until downstream consumers stabilize
     pub utxo_validation: bool,
     pub native_executor_version: Option<StateTransitionBytecodeVersion>,
+    pub block_validation_mode: ValidationMode,
+    /// The directory where the compiled WASM modules of the state transition
+    /// function are cached between restarts.
+    pub wasm_module_cache_path: Option<PathBuf>,
+    /// Validates each block both natively and in the WASM executor
+    /// and reports the mismatch of the results.
+    pub verify_executor_parity: bool,
+    /// Rebuilds the off-chain database on start-up if it is empty,
+    /// lacks some of the supported indexes, or the reindexing was interrupted.
+    pub auto_reindex: bool,
     pub block_production: Trigger,
     pub predefined_blocks_path: Option<PathBuf>,
     pub vm: VMConfig,
@@ -121,13 +132,17 @@ impl Config {
             database_path: Default::default(),
             #[cfg(feature = "rocksdb")]
             database_type: DbType::RocksDb,
-            #[cfg(not(feature = "rocksdb"))]
+            #[cfg(all(feature = "redb", not(feature = "rocksdb")))]
+            database_type: DbType::Redb,
+            #[cfg(not(any(feature = "rocksdb", feature = "redb")))]
             database_type: DbType::InMemory,
-            #[cfg(feature = "rocksdb")]
+            #[cfg(any(feature = "rocksdb", feature = "redb"))]
             state_rewind_policy:
                 crate::state::historical_rocksdb::StateRewindPolicy::RewindFullRange,
             #[cfg(feature = "rocksdb")]
             max_fds: 512,
+            #[cfg(feature = "rocksdb")]
+            rocksdb_config: Default::default(),
         };
         let starting_gas_price = 0;
         let gas_price_change_percent = 0;
@@ -151,6 +166,8 @@ impl Config {
                 request_body_bytes_limit: 16 * 1024 * 1024,
                 query_log_threshold_time: Duration::from_secs(2),
                 api_request_timeout: Duration::from_secs(60),
+                max_trace_steps: 100_000,
+                max_replayed_blocks: 10_000,
                 costs: Default::default(),
             },
             combined_db_config,
@@ -158,6 +175,10 @@ impl Config {
             debug: true,
             utxo_validation,
             native_executor_version: Some(native_executor_version),
+            block_validation_mode: ValidationMode::default(),
+            wasm_module_cache_path: None,
+            verify_executor_parity: false,
+            auto_reindex: false,
             snapshot_reader,
             block_production: Trigger::Instant,
             predefined_blocks_path: None,
@@ -211,6 +232,19 @@ impl Config {
     }
 }
 
+impl From<&Config> for fuel_core_upgradable_executor::config::Config {
+    fn from(config: &Config) -> Self {
+        fuel_core_upgradable_executor::config::Config {
+            backtrace: config.vm.backtrace,
+            utxo_validation_default: config.utxo_validation,
+            native_executor_version: config.native_executor_version,
+            validation_mode: config.block_validation_mode,
+            module_cache_path: config.wasm_module_cache_path.clone(),
+            verify_executor_parity: config.verify_executor_parity,
+        }
+    }
+}
+
 impl From<&Config> for fuel_core_poa::Config {
     fn from(config: &Config) -> Self {
         fuel_core_poa::Config {
@@ -240,4 +274,5 @@ pub struct VMConfig {
 pub enum DbType {
     InMemory,
     RocksDb,
+    Redb,
 }
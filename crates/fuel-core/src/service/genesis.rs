@@ -14,7 +14,10 @@ use crate::{
     },
     service::config::Config,
 };
-use fuel_core_chain_config::GenesisCommitment;
+use fuel_core_chain_config::{
+    GenesisCommitment,
+    SnapshotReader,
+};
 use fuel_core_services::StateWatcher;
 use fuel_core_storage::{
     iter::IteratorOverTable,
@@ -201,6 +204,51 @@ pub async fn recover_missing_tables_from_genesis_state_config(
     .await
 }
 
+/// Imports the off-chain tables from the snapshot into the off-chain database
+/// of the already initialized chain. The off-chain database should not have a height.
+///
+/// The progress of the import is stored in the off-chain genesis metadata,
+/// and the interrupted import continues from the last processed group.
+pub async fn import_off_chain_state(
+    watcher: impl NotifyCancel + Send + Sync + 'static,
+    snapshot_reader: &SnapshotReader,
+    db: &CombinedDatabase,
+) -> anyhow::Result<()> {
+    let off_chain = db
+        .off_chain()
+        .clone()
+        .into_genesis()
+        .map_err(|_| anyhow::anyhow!("Off chain database is already initialized"))?;
+
+    let genesis_db = CombinedGenesisDatabase {
+        on_chain: Database::default(),
+        off_chain,
+    };
+    let on_chain_view = db.on_chain().latest_view()?;
+    let genesis_block = on_chain_view
+        .genesis_block()?
+        .ok_or(not_found!("Genesis block"))?;
+
+    let chain_config_hash: Bytes32 = snapshot_reader.chain_config().root()?.into();
+    let genesis = on_chain_view.get_genesis()?;
+    if genesis.chain_config_hash != chain_config_hash {
+        tracing::warn!(
+            "The chain config hash of the snapshot({chain_config_hash}) \
+            is different from the genesis one({}). The off-chain data \
+            may not match the on-chain state.",
+            genesis.chain_config_hash
+        );
+    }
+
+    SnapshotImporter::import_off_chain(
+        genesis_db,
+        genesis_block.uncompress(vec![]),
+        snapshot_reader.clone(),
+        watcher,
+    )
+    .await
+}
+
 #[cfg(feature = "test-helpers")]
 pub async fn execute_and_commit_genesis_block(
     config: &Config,
@@ -1,6 +1,9 @@
 use super::{
     progress::MultipleProgressReporter,
-    task_manager::TaskManager,
+    task_manager::{
+        NotifyCancel,
+        TaskManager,
+    },
 };
 use crate::{
     combined_database::CombinedGenesisDatabase,
@@ -85,7 +88,7 @@ impl SnapshotImporter {
         db: CombinedGenesisDatabase,
         genesis_block: Block,
         snapshot_reader: SnapshotReader,
-        watcher: StateWatcher,
+        watcher: impl NotifyCancel + Send + Sync + 'static,
     ) -> Self {
         Self {
             db,
@@ -124,8 +127,32 @@ impl SnapshotImporter {
         Ok(())
     }
 
+    /// Imports only the off-chain tables from the snapshot. It is used to rebuild
+    /// the off-chain database of the already initialized chain.
+    pub async fn import_off_chain(
+        db: CombinedGenesisDatabase,
+        genesis_block: Block,
+        snapshot_reader: SnapshotReader,
+        watcher: impl NotifyCancel + Send + Sync + 'static,
+    ) -> anyhow::Result<()> {
+        let mut importer = Self::new(db, genesis_block, snapshot_reader, watcher);
+        tracing::info!("Running off-chain imports");
+        importer.spawn_off_chain_workers()?;
+        importer.task_manager.wait().await?;
+        Ok(())
+    }
+
     async fn run_workers(mut self) -> anyhow::Result<()> {
         tracing::info!("Running imports");
+        self.spawn_on_chain_workers()?;
+        self.spawn_off_chain_workers()?;
+
+        self.task_manager.wait().await?;
+
+        Ok(())
+    }
+
+    fn spawn_on_chain_workers(&mut self) -> anyhow::Result<()> {
         self.spawn_worker_on_chain::<Coins>()?;
         self.spawn_worker_on_chain::<Messages>()?;
         self.spawn_worker_on_chain::<BlobData>()?;
@@ -136,7 +163,10 @@ impl SnapshotImporter {
         self.spawn_worker_on_chain::<ProcessedTransactions>()?;
         self.spawn_worker_on_chain::<FuelBlockMerkleData>()?;
         self.spawn_worker_on_chain::<FuelBlockMerkleMetadata>()?;
+        Ok(())
+    }
 
+    fn spawn_off_chain_workers(&mut self) -> anyhow::Result<()> {
         self.spawn_worker_off_chain::<TransactionStatuses, TransactionStatuses>()?;
         self.spawn_worker_off_chain::<OwnedTransactions, OwnedTransactions>()?;
         self.spawn_worker_off_chain::<SpentMessages, SpentMessages>()?;
@@ -153,9 +183,6 @@ impl SnapshotImporter {
         self.spawn_worker_off_chain::<OldTransactions, OldTransactions>()?;
         self.spawn_worker_off_chain::<FuelBlocks, FuelBlockIdsToHeights>()?;
         self.spawn_worker_off_chain::<OldFuelBlocks, FuelBlockIdsToHeights>()?;
-
-        self.task_manager.wait().await?;
-
         Ok(())
     }
 
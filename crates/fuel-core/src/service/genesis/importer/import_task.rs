@@ -589,6 +589,10 @@ mod tests {
         fn rollback_block_to(&self, _: &BlockHeight) -> StorageResult<()> {
             Err(anyhow::anyhow!("I refuse to work!").into())
         }
+
+        fn checkpoint(&self, _: &std::path::Path) -> StorageResult<()> {
+            Err(anyhow::anyhow!("I refuse to work!").into())
+        }
     }
 
     #[test]
@@ -0,0 +1,843 @@
+//! The offline integrity check of the databases.
+//!
+//! The checker compares the data derived by the node with the data it is derived from:
+//! the Merkle roots of the contracts' assets and state with their leaves, and the
+//! off-chain indexes of the coins and messages with the on-chain `Coins` and `Messages`.
+//! It also checks that each block has the consensus, and that the auxiliary
+//! databases are in sync with the on-chain database.
+//!
+//! The derived data can be rebuilt by the repair. The missing consensus and the
+//! databases that are behind the on-chain database can't be repaired offline.
+
+use crate::{
+    combined_database::{
+        CombinedDatabase,
+        ShutdownListener,
+    },
+    database::{
+        balances::BalancesInitializer,
+        database_description::IndexationKind,
+        state::StateInitializer,
+        OffChainIterableKeyValueView,
+        OnChainIterableKeyValueView,
+    },
+    fuel_core_graphql_api::storage::{
+        balances::{
+            CoinBalances,
+            CoinBalancesKey,
+            MessageBalance,
+            MessageBalances,
+        },
+        coins::{
+            owner_coin_id_key,
+            OwnedCoinKey,
+            OwnedCoins,
+        },
+        messages::{
+            OwnedMessageIds,
+            OwnedMessageKey,
+        },
+    },
+};
+use fuel_core_chain_config::TableEntry;
+use fuel_core_storage::{
+    column::Column,
+    iter::{
+        IterDirection,
+        IterableStore,
+        IterableTable,
+        IteratorOverTable,
+    },
+    kv_store::{
+        Key,
+        Value,
+    },
+    tables::{
+        merkle::{
+            ContractsAssetsMerkleMetadata,
+            ContractsStateMerkleMetadata,
+            SparseMerkleMetadata,
+        },
+        Coins,
+        ContractsAssets,
+        ContractsState,
+        FuelBlocks,
+        Messages,
+        SealedBlockConsensus,
+    },
+    transactional::{
+        AtomicView,
+        IntoTransaction,
+    },
+    Mappable,
+    StorageAsMut,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_merkle::sparse::{
+        in_memory,
+        MerkleTreeKey,
+    },
+    fuel_tx::{
+        Address,
+        AssetId,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+        ContractId,
+        Nonce,
+    },
+};
+use itertools::Itertools;
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+const OFF_CHAIN: &str = "off-chain";
+const GAS_PRICE: &str = "gas-price";
+
+/// The sparse Merkle tree of the contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum ContractTree {
+    #[display(fmt = "assets")]
+    Assets,
+    #[display(fmt = "state")]
+    State,
+}
+
+/// The inconsistency found in the databases.
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+pub enum Inconsistency {
+    #[display(
+        fmt = "the Merkle root of the {tree} of the contract {contract_id} is {stored}, \
+        but the leaves produce {expected}"
+    )]
+    ContractRoot {
+        contract_id: ContractId,
+        tree: ContractTree,
+        stored: Bytes32,
+        expected: Bytes32,
+    },
+    #[display(fmt = "the coin {utxo_id} of the {owner} is not indexed")]
+    MissingOwnedCoin { owner: Address, utxo_id: UtxoId },
+    #[display(fmt = "the indexed coin {utxo_id} of the {owner} doesn't exist")]
+    UnexpectedOwnedCoin { owner: Address, utxo_id: UtxoId },
+    #[display(fmt = "the message {nonce} of the {recipient} is not indexed")]
+    MissingOwnedMessage { recipient: Address, nonce: Nonce },
+    #[display(fmt = "the indexed message {nonce} of the {recipient} doesn't exist")]
+    UnexpectedOwnedMessage { recipient: Address, nonce: Nonce },
+    #[display(
+        fmt = "the balance of the asset {asset_id} of the {owner} is {stored}, \
+        but the coins sum up to {expected}"
+    )]
+    CoinBalance {
+        owner: Address,
+        asset_id: AssetId,
+        stored: u128,
+        expected: u128,
+    },
+    #[display(fmt = "the message balance of the {owner} is {stored:?}, \
+        but the messages sum up to {expected:?}")]
+    MessageBalance {
+        owner: Address,
+        stored: MessageBalance,
+        expected: MessageBalance,
+    },
+    #[display(fmt = "the block at height {height} doesn't have the consensus")]
+    MissingConsensus { height: BlockHeight },
+    #[display(fmt = "the {database} database height({height:?}) doesn't match \
+        the on-chain database height({on_chain_height})")]
+    HeightOutOfSync {
+        database: &'static str,
+        height: Option<BlockHeight>,
+        on_chain_height: BlockHeight,
+    },
+    #[display(fmt = "the relayer database height({height:?}) is less than \
+        the DA height({da_height}) of the latest block")]
+    RelayerIsBehind {
+        height: Option<DaBlockHeight>,
+        da_height: DaBlockHeight,
+    },
+}
+
+impl Inconsistency {
+    /// Returns `true` if the inconsistency can be fixed by the repair.
+    pub fn is_repairable(&self) -> bool {
+        match self {
+            Self::MissingConsensus { .. } | Self::RelayerIsBehind { .. } => false,
+            Self::HeightOutOfSync {
+                height,
+                on_chain_height,
+                ..
+            } => height.is_some_and(|height| height > *on_chain_height),
+            _ => true,
+        }
+    }
+}
+
+/// Checks and repairs the integrity of the [`CombinedDatabase`].
+pub struct IntegrityChecker<'a> {
+    database: &'a CombinedDatabase,
+}
+
+impl<'a> IntegrityChecker<'a> {
+    pub fn new(database: &'a CombinedDatabase) -> Self {
+        Self { database }
+    }
+
+    /// Returns all inconsistencies found in the databases.
+    ///
+    /// The off-chain indexes are checked only if the off-chain database
+    /// is at the same height as the on-chain database.
+    pub fn check<S>(&self, shutdown_listener: &S) -> anyhow::Result<Vec<Inconsistency>>
+    where
+        S: ShutdownListener,
+    {
+        let on_chain = self.database.on_chain().latest_view()?;
+        let off_chain = self.database.off_chain().latest_view()?;
+
+        let mut inconsistencies = self.check_heights(&on_chain)?;
+        let off_chain_is_synced = !inconsistencies.iter().any(|inconsistency| {
+            matches!(
+                inconsistency,
+                Inconsistency::HeightOutOfSync {
+                    database: OFF_CHAIN,
+                    ..
+                }
+            )
+        });
+
+        ensure_not_cancelled(shutdown_listener, "consensus")?;
+        inconsistencies.extend(check_consensus(&on_chain)?);
+
+        ensure_not_cancelled(shutdown_listener, "contract assets roots")?;
+        inconsistencies.extend(check_contract_roots::<ContractsAssetsMerkleMetadata>(
+            &on_chain,
+            Column::ContractsAssets,
+            ContractTree::Assets,
+        )?);
+
+        ensure_not_cancelled(shutdown_listener, "contract state roots")?;
+        inconsistencies.extend(check_contract_roots::<ContractsStateMerkleMetadata>(
+            &on_chain,
+            Column::ContractsState,
+            ContractTree::State,
+        )?);
+
+        if off_chain_is_synced {
+            ensure_not_cancelled(shutdown_listener, "coins and messages indexes")?;
+            inconsistencies.extend(self.check_indexes(&on_chain, &off_chain)?);
+        } else {
+            tracing::warn!(
+                "Skipping the check of the indexes, \
+                because the off-chain database is out of sync"
+            );
+        }
+
+        Ok(inconsistencies)
+    }
+
+    /// Repairs the repairable inconsistencies and returns the ones left after the repair.
+    ///
+    /// The auxiliary databases ahead of the on-chain database are rolled back first,
+    /// so the off-chain indexes are rebuilt for the on-chain height.
+    pub fn repair<S>(
+        &self,
+        shutdown_listener: &mut S,
+    ) -> anyhow::Result<Vec<Inconsistency>>
+    where
+        S: ShutdownListener,
+    {
+        self.database.sync_aux_db_heights(shutdown_listener)?;
+
+        let inconsistencies = self.check(shutdown_listener)?;
+        let repairable = inconsistencies
+            .iter()
+            .filter(|inconsistency| inconsistency.is_repairable())
+            .collect_vec();
+        if repairable.is_empty() {
+            return Ok(inconsistencies)
+        }
+        tracing::info!("Repairing {} inconsistencies", repairable.len());
+
+        self.repair_contract_roots(&repairable)?;
+        self.repair_indexes(&repairable)?;
+
+        self.check(shutdown_listener)
+    }
+
+    fn check_heights(
+        &self,
+        on_chain: &OnChainIterableKeyValueView,
+    ) -> anyhow::Result<Vec<Inconsistency>> {
+        let Some(on_chain_height) =
+            self.database.on_chain().latest_height_from_metadata()?
+        else {
+            return Err(anyhow::anyhow!("on-chain database doesn't have height"));
+        };
+
+        let mut inconsistencies = vec![];
+        let off_chain_height = self.database.off_chain().latest_height_from_metadata()?;
+        if off_chain_height != Some(on_chain_height) {
+            inconsistencies.push(Inconsistency::HeightOutOfSync {
+                database: OFF_CHAIN,
+                height: off_chain_height,
+                on_chain_height,
+            });
+        }
+
+        // The gas price database doesn't have height if the gas price
+        // service hasn't processed any blocks yet.
+        let gas_price_height = self.database.gas_price().latest_height_from_metadata()?;
+        if gas_price_height.is_some_and(|height| height != on_chain_height) {
+            inconsistencies.push(Inconsistency::HeightOutOfSync {
+                database: GAS_PRICE,
+                height: gas_price_height,
+                on_chain_height,
+            });
+        }
+
+        // The relayer database tracks the DA height. It should contain
+        // all events included into the latest block.
+        let da_height = on_chain.latest_block()?.header().da_height;
+        let relayer_height = self.database.relayer().latest_height_from_metadata()?;
+        let relayer_is_behind = match relayer_height {
+            Some(height) => height < da_height,
+            None => da_height > DaBlockHeight(0),
+        };
+        if relayer_is_behind {
+            inconsistencies.push(Inconsistency::RelayerIsBehind {
+                height: relayer_height,
+                da_height,
+            });
+        }
+
+        Ok(inconsistencies)
+    }
+
+    fn check_indexes(
+        &self,
+        on_chain: &OnChainIterableKeyValueView,
+        off_chain: &OffChainIterableKeyValueView,
+    ) -> anyhow::Result<Vec<Inconsistency>> {
+        let balances_enabled = self
+            .database
+            .off_chain()
+            .indexation_available(IndexationKind::Balances)?;
+
+        let mut owned_coins = BTreeSet::new();
+        let mut coin_balances = BTreeMap::<CoinBalancesKey, u128>::new();
+        for entry in on_chain.iter_all::<Coins>(None) {
+            let (utxo_id, coin) = entry?;
+            owned_coins.insert(owner_coin_id_key(coin.owner(), &utxo_id));
+            let balance = coin_balances
+                .entry(CoinBalancesKey::new(coin.owner(), coin.asset_id()))
+                .or_default();
+            *balance = balance.saturating_add(u128::from(*coin.amount()));
+        }
+
+        let mut owned_messages = BTreeSet::new();
+        let mut message_balances = BTreeMap::<Address, MessageBalance>::new();
+        for entry in on_chain.iter_all::<Messages>(None) {
+            let (_, message) = entry?;
+            owned_messages
+                .insert(OwnedMessageKey::new(message.recipient(), message.nonce()));
+            let balance = message_balances.entry(*message.recipient()).or_default();
+            let amount = u128::from(message.amount());
+            if message.is_retryable_message() {
+                balance.retryable = balance.retryable.saturating_add(amount);
+            } else {
+                balance.non_retryable = balance.non_retryable.saturating_add(amount);
+            }
+        }
+
+        let mut inconsistencies = vec![];
+        for key in off_chain.iter_all_keys::<OwnedCoins>(None) {
+            let key = key?;
+            if !owned_coins.remove(&key) {
+                let (owner, utxo_id) = decode_owned_coin_key(&key);
+                inconsistencies
+                    .push(Inconsistency::UnexpectedOwnedCoin { owner, utxo_id });
+            }
+        }
+        inconsistencies.extend(owned_coins.iter().map(|key| {
+            let (owner, utxo_id) = decode_owned_coin_key(key);
+            Inconsistency::MissingOwnedCoin { owner, utxo_id }
+        }));
+
+        for key in off_chain.iter_all_keys::<OwnedMessageIds>(None) {
+            let key = key?;
+            if !owned_messages.remove(&key) {
+                inconsistencies.push(Inconsistency::UnexpectedOwnedMessage {
+                    recipient: *key.address(),
+                    nonce: *key.nonce(),
+                });
+            }
+        }
+        inconsistencies.extend(owned_messages.iter().map(|key| {
+            Inconsistency::MissingOwnedMessage {
+                recipient: *key.address(),
+                nonce: *key.nonce(),
+            }
+        }));
+
+        if !balances_enabled {
+            return Ok(inconsistencies)
+        }
+
+        // The balances that dropped to zero are kept in the table,
+        // so the absent balance is the same as the zero balance.
+        for entry in off_chain.iter_all::<CoinBalances>(None) {
+            let (key, stored) = entry?;
+            let expected = coin_balances.remove(&key).unwrap_or_default();
+            if stored != expected {
+                inconsistencies.push(Inconsistency::CoinBalance {
+                    owner: *key.address(),
+                    asset_id: *key.asset_id(),
+                    stored,
+                    expected,
+                });
+            }
+        }
+        inconsistencies.extend(
+            coin_balances
+                .into_iter()
+                .filter(|(_, expected)| *expected != 0)
+                .map(|(key, expected)| Inconsistency::CoinBalance {
+                    owner: *key.address(),
+                    asset_id: *key.asset_id(),
+                    stored: 0,
+                    expected,
+                }),
+        );
+
+        for entry in off_chain.iter_all::<MessageBalances>(None) {
+            let (owner, stored) = entry?;
+            let expected = message_balances.remove(&owner).unwrap_or_default();
+            if stored != expected {
+                inconsistencies.push(Inconsistency::MessageBalance {
+                    owner,
+                    stored,
+                    expected,
+                });
+            }
+        }
+        inconsistencies.extend(
+            message_balances
+                .into_iter()
+                .filter(|(_, expected)| *expected != MessageBalance::default())
+                .map(|(owner, expected)| Inconsistency::MessageBalance {
+                    owner,
+                    stored: MessageBalance::default(),
+                    expected,
+                }),
+        );
+
+        Ok(inconsistencies)
+    }
+
+    fn repair_contract_roots(
+        &self,
+        inconsistencies: &[&Inconsistency],
+    ) -> anyhow::Result<()> {
+        let view = self.database.on_chain().latest_view()?;
+        let mut transaction = self.database.on_chain().clone().into_transaction();
+
+        for inconsistency in inconsistencies {
+            let Inconsistency::ContractRoot {
+                contract_id, tree, ..
+            } = inconsistency
+            else {
+                continue
+            };
+
+            // The Merkle tree is built from scratch, if the metadata doesn't exist.
+            match tree {
+                ContractTree::Assets => {
+                    transaction
+                        .storage_as_mut::<ContractsAssetsMerkleMetadata>()
+                        .remove(contract_id)?;
+                    let balances: Vec<_> = view
+                        .iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id))
+                        .map_ok(|(key, value)| TableEntry { key, value })
+                        .try_collect()?;
+                    transaction.update_contract_balances(balances)?;
+                }
+                ContractTree::State => {
+                    transaction
+                        .storage_as_mut::<ContractsStateMerkleMetadata>()
+                        .remove(contract_id)?;
+                    let slots: Vec<_> = view
+                        .iter_all_by_prefix::<ContractsState, _>(Some(contract_id))
+                        .map_ok(|(key, value)| TableEntry { key, value })
+                        .try_collect()?;
+                    transaction.update_contract_states(slots)?;
+                }
+            }
+        }
+
+        self.database
+            .on_chain()
+            .commit_changes_outside_of_block(transaction.into_changes())?;
+        Ok(())
+    }
+
+    fn repair_indexes(&self, inconsistencies: &[&Inconsistency]) -> anyhow::Result<()> {
+        let mut transaction = self.database.off_chain().clone().into_transaction();
+
+        for inconsistency in inconsistencies {
+            match inconsistency {
+                Inconsistency::MissingOwnedCoin { owner, utxo_id } => {
+                    transaction
+                        .storage_as_mut::<OwnedCoins>()
+                        .insert(&owner_coin_id_key(owner, utxo_id), &())?;
+                }
+                Inconsistency::UnexpectedOwnedCoin { owner, utxo_id } => {
+                    transaction
+                        .storage_as_mut::<OwnedCoins>()
+                        .remove(&owner_coin_id_key(owner, utxo_id))?;
+                }
+                Inconsistency::MissingOwnedMessage { recipient, nonce } => {
+                    transaction
+                        .storage_as_mut::<OwnedMessageIds>()
+                        .insert(&OwnedMessageKey::new(recipient, nonce), &())?;
+                }
+                Inconsistency::UnexpectedOwnedMessage { recipient, nonce } => {
+                    transaction
+                        .storage_as_mut::<OwnedMessageIds>()
+                        .remove(&OwnedMessageKey::new(recipient, nonce))?;
+                }
+                Inconsistency::CoinBalance {
+                    owner,
+                    asset_id,
+                    expected,
+                    ..
+                } => {
+                    transaction
+                        .storage_as_mut::<CoinBalances>()
+                        .insert(&CoinBalancesKey::new(owner, asset_id), expected)?;
+                }
+                Inconsistency::MessageBalance {
+                    owner, expected, ..
+                } => {
+                    transaction
+                        .storage_as_mut::<MessageBalances>()
+                        .insert(owner, expected)?;
+                }
+                _ => {}
+            }
+        }
+
+        self.database
+            .off_chain()
+            .commit_changes_outside_of_block(transaction.into_changes())?;
+        Ok(())
+    }
+}
+
+fn ensure_not_cancelled<S>(shutdown_listener: &S, next_check: &str) -> anyhow::Result<()>
+where
+    S: ShutdownListener,
+{
+    if shutdown_listener.is_cancelled() {
+        return Err(anyhow::anyhow!(
+            "the check was interrupted before the {next_check}"
+        ));
+    }
+    tracing::info!("Checking the {next_check}");
+    Ok(())
+}
+
+fn check_consensus(
+    on_chain: &OnChainIterableKeyValueView,
+) -> anyhow::Result<Vec<Inconsistency>> {
+    let mut inconsistencies = vec![];
+    for height in on_chain.iter_all_keys::<FuelBlocks>(None) {
+        let height = height?;
+        if !on_chain
+            .storage::<SealedBlockConsensus>()
+            .contains_key(&height)?
+        {
+            inconsistencies.push(Inconsistency::MissingConsensus { height });
+        }
+    }
+    Ok(inconsistencies)
+}
+
+/// Compares the roots stored in the `Metadata` table with the roots
+/// of the leaves stored in the `column`.
+fn check_contract_roots<Metadata>(
+    on_chain: &OnChainIterableKeyValueView,
+    column: Column,
+    tree: ContractTree,
+) -> anyhow::Result<Vec<Inconsistency>>
+where
+    Metadata: Mappable<
+        Key = ContractId,
+        OwnedKey = ContractId,
+        Value = SparseMerkleMetadata,
+        OwnedValue = SparseMerkleMetadata,
+    >,
+    OnChainIterableKeyValueView: IterableTable<Metadata>,
+{
+    // The keys of the leaves start with the contract id,
+    // so the leaves of each contract are adjacent.
+    let mut expected_roots = BTreeMap::new();
+    let mut contract_leaves: Option<(ContractId, Vec<(Key, Value)>)> = None;
+    for entry in on_chain.iter_store(column, None, None, IterDirection::Forward) {
+        let (key, value) = entry?;
+        let contract_id = key
+            .get(..ContractId::LEN)
+            .and_then(|bytes| ContractId::try_from(bytes).ok())
+            .ok_or(anyhow::anyhow!("the key of the {column:?} is too short"))?;
+
+        match contract_leaves.as_mut() {
+            Some((id, leaves)) if *id == contract_id => leaves.push((key, value)),
+            _ => {
+                if let Some((id, leaves)) = contract_leaves.take() {
+                    expected_roots.insert(id, sparse_root(&leaves));
+                }
+                contract_leaves = Some((contract_id, vec![(key, value)]));
+            }
+        }
+    }
+    if let Some((id, leaves)) = contract_leaves {
+        expected_roots.insert(id, sparse_root(&leaves));
+    }
+
+    let mut inconsistencies = vec![];
+    for entry in on_chain.iter_all::<Metadata>(None) {
+        let (contract_id, metadata) = entry?;
+        let stored = Bytes32::from(*metadata.root());
+        let expected = expected_roots
+            .remove(&contract_id)
+            .unwrap_or_else(empty_sparse_root);
+        if stored != expected {
+            inconsistencies.push(Inconsistency::ContractRoot {
+                contract_id,
+                tree,
+                stored,
+                expected,
+            });
+        }
+    }
+
+    // The metadata of the tree without leaves is removed.
+    inconsistencies.extend(expected_roots.into_iter().map(|(contract_id, expected)| {
+        Inconsistency::ContractRoot {
+            contract_id,
+            tree,
+            stored: empty_sparse_root(),
+            expected,
+        }
+    }));
+
+    Ok(inconsistencies)
+}
+
+fn sparse_root(leaves: &[(Key, Value)]) -> Bytes32 {
+    let root = in_memory::MerkleTree::root_from_set(
+        leaves
+            .iter()
+            .map(|(key, value)| (MerkleTreeKey::new(key), value.as_ref())),
+    );
+    Bytes32::from(root)
+}
+
+fn empty_sparse_root() -> Bytes32 {
+    Bytes32::from(in_memory::MerkleTree::new().root())
+}
+
+fn decode_owned_coin_key(key: &OwnedCoinKey) -> (Address, UtxoId) {
+    let (owner, utxo_id) = key.split_at(Address::LEN);
+    let (tx_id, output_index) = utxo_id.split_at(TxId::LEN);
+    let owner = Address::try_from(owner).expect("The slice has size 32");
+    let tx_id = TxId::try_from(tx_id).expect("The slice has size 32");
+    let output_index =
+        u16::from_be_bytes(output_index.try_into().expect("The slice has size 2"));
+    (owner, UtxoId::new(tx_id, output_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fuel_core_graphql_api::storage::blocks::FuelBlockIdsToHeights;
+    use fuel_core_storage::{
+        transactional::WriteTransaction,
+        ContractsAssetKey,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::CompressedBlock,
+            consensus::Consensus,
+        },
+        entities::coins::coin::CompressedCoin,
+    };
+
+    struct NotCancelled;
+
+    impl ShutdownListener for NotCancelled {
+        fn is_cancelled(&self) -> bool {
+            false
+        }
+    }
+
+    const OWNER: Address = Address::new([1; 32]);
+    const CONTRACT_ID: ContractId = ContractId::new([2; 32]);
+
+    /// Creates the database with the genesis block, one coin and one contract asset.
+    fn consistent_database() -> CombinedDatabase {
+        let mut database = CombinedDatabase::default();
+        let block = CompressedBlock::default();
+        let utxo_id = UtxoId::new([3; 32].into(), 0);
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(OWNER);
+        coin.set_amount(100);
+
+        let mut transaction = database.on_chain_mut().write_transaction();
+        transaction
+            .storage_as_mut::<FuelBlocks>()
+            .insert(block.header().height(), &block)
+            .unwrap();
+        transaction
+            .storage_as_mut::<SealedBlockConsensus>()
+            .insert(
+                block.header().height(),
+                &Consensus::Genesis(Default::default()),
+            )
+            .unwrap();
+        transaction
+            .storage_as_mut::<Coins>()
+            .insert(&utxo_id, &coin)
+            .unwrap();
+        transaction
+            .storage_as_mut::<ContractsAssets>()
+            .insert(&ContractsAssetKey::new(&CONTRACT_ID, &AssetId::BASE), &10)
+            .unwrap();
+        transaction.commit().unwrap();
+
+        let mut transaction = database.off_chain_mut().write_transaction();
+        transaction
+            .storage_as_mut::<FuelBlockIdsToHeights>()
+            .insert(&block.header().id(), block.header().height())
+            .unwrap();
+        transaction
+            .storage_as_mut::<OwnedCoins>()
+            .insert(&owner_coin_id_key(&OWNER, &utxo_id), &())
+            .unwrap();
+        transaction
+            .storage_as_mut::<CoinBalances>()
+            .insert(&CoinBalancesKey::new(&OWNER, coin.asset_id()), &100)
+            .unwrap();
+        transaction.commit().unwrap();
+
+        database
+    }
+
+    #[test]
+    fn check__returns_nothing_for_consistent_database() {
+        // Given
+        let database = consistent_database();
+        let checker = IntegrityChecker::new(&database);
+
+        // When
+        let inconsistencies = checker.check(&NotCancelled).unwrap();
+
+        // Then
+        assert_eq!(inconsistencies, vec![]);
+    }
+
+    #[test]
+    fn repair__rebuilds_corrupted_contract_root() {
+        // Given
+        let database = consistent_database();
+        let mut transaction = database.on_chain().clone().into_transaction();
+        transaction
+            .storage_as_mut::<ContractsAssetsMerkleMetadata>()
+            .insert(&CONTRACT_ID, &SparseMerkleMetadata::new([4; 32]))
+            .unwrap();
+        database
+            .on_chain()
+            .commit_changes_outside_of_block(transaction.into_changes())
+            .unwrap();
+        let checker = IntegrityChecker::new(&database);
+        let inconsistencies = checker.check(&NotCancelled).unwrap();
+        assert!(matches!(
+            inconsistencies.as_slice(),
+            [Inconsistency::ContractRoot {
+                contract_id: CONTRACT_ID,
+                tree: ContractTree::Assets,
+                ..
+            }]
+        ));
+
+        // When
+        let inconsistencies = checker.repair(&mut NotCancelled).unwrap();
+
+        // Then
+        assert_eq!(inconsistencies, vec![]);
+    }
+
+    #[test]
+    fn repair__restores_index_of_the_coin() {
+        // Given
+        let database = consistent_database();
+        let mut transaction = database.off_chain().clone().into_transaction();
+        transaction
+            .storage_as_mut::<OwnedCoins>()
+            .remove(&owner_coin_id_key(&OWNER, &UtxoId::new([3; 32].into(), 0)))
+            .unwrap();
+        database
+            .off_chain()
+            .commit_changes_outside_of_block(transaction.into_changes())
+            .unwrap();
+        let checker = IntegrityChecker::new(&database);
+        let inconsistencies = checker.check(&NotCancelled).unwrap();
+        assert_eq!(
+            inconsistencies,
+            vec![Inconsistency::MissingOwnedCoin {
+                owner: OWNER,
+                utxo_id: UtxoId::new([3; 32].into(), 0),
+            }]
+        );
+
+        // When
+        let inconsistencies = checker.repair(&mut NotCancelled).unwrap();
+
+        // Then
+        assert_eq!(inconsistencies, vec![]);
+    }
+
+    #[test]
+    fn check__detects_missing_consensus() {
+        // Given
+        let database = consistent_database();
+        let mut transaction = database.on_chain().clone().into_transaction();
+        transaction
+            .storage_as_mut::<SealedBlockConsensus>()
+            .remove(&BlockHeight::new(0))
+            .unwrap();
+        database
+            .on_chain()
+            .commit_changes_outside_of_block(transaction.into_changes())
+            .unwrap();
+        let checker = IntegrityChecker::new(&database);
+
+        // When
+        let inconsistencies = checker.repair(&mut NotCancelled).unwrap();
+
+        // Then
+        assert_eq!(
+            inconsistencies,
+            vec![Inconsistency::MissingConsensus {
+                height: BlockHeight::new(0)
+            }]
+        );
+        assert!(!inconsistencies[0].is_repairable());
+    }
+}
@@ -0,0 +1,408 @@
+//! The rebuilding of the off-chain database from the on-chain blocks.
+//!
+//! The off-chain database contains only the data derived from the genesis snapshot
+//! and the blocks stored in the on-chain database. The reindexing builds a new
+//! off-chain database next to the current one, imports the off-chain tables of the
+//! snapshot, and replays the stored blocks through the same indexation as the
+//! off-chain worker. The current off-chain database is replaced only after
+//! the successful replay. The events of the blocks are not stored, so each block is
+//! re-executed on top of the state at the previous height. It requires all blocks
+//! and the state history of the whole chain in the on-chain database.
+//!
+//! The stage of the reindexing is stored in the genesis metadata of the new database.
+//! The interrupted reindexing continues from the last imported group of the snapshot
+//! or from the last replayed block instead of starting from scratch.
+
+use crate::{
+    combined_database::{
+        CombinedDatabase,
+        CombinedDatabaseConfig,
+    },
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            IndexationKind,
+        },
+        genesis_progress::{
+            GenesisMetadata,
+            GenesisProgressInspect,
+            GenesisProgressMutate,
+        },
+        Database,
+    },
+    fuel_core_graphql_api::{
+        ports::worker::BlockAt,
+        worker_service::{
+            BlockIndexer,
+            DaCompressionConfig,
+        },
+    },
+    service::{
+        adapters::{
+            import_result_provider::ImportResultProvider,
+            ExecutorAdapter,
+        },
+        genesis::{
+            self,
+            NotifyCancel,
+        },
+        DbType,
+    },
+};
+use anyhow::Context;
+use fuel_core_chain_config::SnapshotReader;
+use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IteratorOverTable,
+    },
+    tables::FuelBlocks,
+    transactional::{
+        AtomicView,
+        HistoricalView,
+        IntoTransaction,
+    },
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+use itertools::Itertools;
+use std::{
+    path::PathBuf,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The key of the off-chain genesis metadata that marks the unfinished reindexing.
+/// The value is the current stage of the reindexing.
+pub const REINDEX_PROGRESS_KEY: &str = "off_chain_reindex";
+
+/// The directory inside of the database path where the new off-chain database is built.
+pub const STAGING_DIRECTORY: &str = "reindex";
+
+/// The off-chain tables of the snapshot are being imported.
+const SNAPSHOT_STAGE: usize = 0;
+/// The stored blocks are being replayed.
+const BLOCKS_STAGE: usize = 1;
+
+/// The interval between the reports about the replayed blocks.
+const PROGRESS_REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Returns the path where the new off-chain database is built.
+/// The path is empty if the database isn't stored on the disk.
+pub fn staging_path(config: &CombinedDatabaseConfig) -> PathBuf {
+    if config.database_type == DbType::InMemory
+        || config.database_path.as_os_str().is_empty()
+    {
+        PathBuf::new()
+    } else {
+        config.database_path.join(STAGING_DIRECTORY)
+    }
+}
+
+/// Returns `true` if the reindexing of the off-chain database was started and not finished.
+pub fn is_reindex_in_progress(config: &CombinedDatabaseConfig) -> bool {
+    let path = staging_path(config);
+    !path.as_os_str().is_empty() && path.exists()
+}
+
+/// Returns `true` if the off-chain database of the initialized chain should be rebuilt:
+/// the reindexing is unfinished, the off-chain database is empty,
+/// or it lacks some of the indexes supported by the node.
+pub fn is_reindex_required(
+    database: &CombinedDatabase,
+    config: &CombinedDatabaseConfig,
+) -> StorageResult<bool> {
+    if is_reindex_in_progress(config) {
+        return Ok(true)
+    }
+
+    if database.on_chain().latest_height_from_metadata()?.is_none() {
+        return Ok(false)
+    }
+
+    let off_chain = database.off_chain();
+    if off_chain.latest_height_from_metadata()?.is_none() {
+        return Ok(true)
+    }
+
+    for kind in IndexationKind::all() {
+        if !off_chain.indexation_available(kind)? {
+            return Ok(true)
+        }
+    }
+
+    Ok(false)
+}
+
+/// Checks that the on-chain database can be replayed: it has the blocks at all
+/// heights from the genesis block up to the latest height, and the state history
+/// to re-execute them. Returns the latest height.
+pub fn check_blocks_available(
+    on_chain: &Database<OnChain>,
+) -> anyhow::Result<BlockHeight> {
+    let latest_height = on_chain
+        .latest_height_from_metadata()?
+        .ok_or(anyhow::anyhow!("The on-chain database doesn't have height"))?;
+    let view = on_chain.latest_view()?;
+    let genesis_height = view
+        .genesis_height()?
+        .ok_or(anyhow::anyhow!("The on-chain database doesn't have blocks"))?;
+
+    let mut expected_height = genesis_height;
+    for height in view.iter_all_keys::<FuelBlocks>(Some(IterDirection::Forward)) {
+        let height = height?;
+        if height != expected_height {
+            return Err(anyhow::anyhow!(
+                "The block at height {expected_height} is missing in the on-chain database"
+            ))
+        }
+        if height >= latest_height {
+            break
+        }
+        expected_height = height
+            .succ()
+            .ok_or(anyhow::anyhow!("The block height({height}) overflows"))?;
+    }
+    if expected_height != latest_height {
+        return Err(anyhow::anyhow!(
+            "The blocks after height {expected_height} are missing in the on-chain database"
+        ))
+    }
+
+    // Each block is re-executed on top of the state at the previous height.
+    if genesis_height < latest_height {
+        on_chain.view_at(&genesis_height).with_context(|| {
+            format!(
+                "The state at the genesis height({genesis_height}) is not available, \
+                the state history of the whole chain is required"
+            )
+        })?;
+    }
+
+    Ok(latest_height)
+}
+
+/// Rebuilds the off-chain database in the staging location and replaces the
+/// current off-chain database with it. The current database stays untouched
+/// if the on-chain database can't be replayed or the reindexing fails.
+/// The interrupted reindexing continues on the next call.
+/// Returns the height of the last replayed block.
+pub async fn reindex<C>(
+    database: &mut CombinedDatabase,
+    config: &CombinedDatabaseConfig,
+    snapshot_reader: SnapshotReader,
+    executor: ExecutorAdapter,
+    da_compression_config: DaCompressionConfig,
+    cancel: C,
+) -> anyhow::Result<BlockHeight>
+where
+    C: NotifyCancel + Clone + Send + Sync + 'static,
+{
+    check_blocks_available(database.on_chain())?;
+
+    let staging_path = staging_path(config);
+    let staging_off_chain = CombinedDatabase::open_off_chain(config, &staging_path)?;
+    if !is_reindex_started(&staging_off_chain)? {
+        let mut transaction = staging_off_chain.clone().into_transaction();
+        GenesisProgressMutate::<OffChain>::update_genesis_progress(
+            &mut transaction,
+            REINDEX_PROGRESS_KEY,
+            SNAPSHOT_STAGE,
+        )?;
+        transaction.commit()?;
+    }
+
+    let staging = CombinedDatabase::new(
+        database.on_chain().clone(),
+        staging_off_chain,
+        database.relayer().clone(),
+        database.gas_price().clone(),
+    );
+    let reindexer = OffChainReindexer::new(
+        &staging,
+        snapshot_reader,
+        executor,
+        da_compression_config,
+    )?;
+    let height = reindexer.reindex(cancel).await?;
+    drop(reindexer);
+
+    tracing::info!("Replacing the off-chain database with the reindexed one");
+    let staging_off_chain = staging.off_chain().clone();
+    drop(staging);
+    database.replace_off_chain(config, &staging_path, staging_off_chain)?;
+    Ok(height)
+}
+
+/// Returns `true` if the reindexing into the `off_chain` database is started:
+/// it is in progress or already finished.
+fn is_reindex_started(off_chain: &Database<OffChain>) -> StorageResult<bool> {
+    let in_progress = GenesisProgressInspect::<OffChain>::genesis_progress(
+        off_chain,
+        REINDEX_PROGRESS_KEY,
+    )
+    .is_some();
+    Ok(in_progress || off_chain.latest_height_from_metadata()?.is_some())
+}
+
+/// Rebuilds the empty off-chain database from the snapshot and the on-chain blocks.
+struct OffChainReindexer {
+    database: CombinedDatabase,
+    snapshot_reader: SnapshotReader,
+    import_result_provider: ImportResultProvider,
+    indexer: BlockIndexer<Database<OnChain>>,
+}
+
+impl OffChainReindexer {
+    fn new(
+        database: &CombinedDatabase,
+        snapshot_reader: SnapshotReader,
+        executor: ExecutorAdapter,
+        da_compression_config: DaCompressionConfig,
+    ) -> StorageResult<Self> {
+        let chain_id = snapshot_reader
+            .chain_config()
+            .consensus_parameters
+            .chain_id();
+        let off_chain = database.off_chain();
+        // The history of the replayed blocks is pruned by the off-chain worker
+        // together with the next imported block.
+        let indexer = BlockIndexer::new(
+            chain_id,
+            da_compression_config,
+            off_chain.indexation_available(IndexationKind::Balances)?,
+            off_chain.indexation_available(IndexationKind::ContractReceipts)?,
+            None,
+        );
+        let import_result_provider =
+            ImportResultProvider::new(database.on_chain().clone(), executor);
+
+        Ok(Self {
+            database: database.clone(),
+            snapshot_reader,
+            import_result_provider,
+            indexer,
+        })
+    }
+
+    /// Continues the reindexing until the off-chain database reaches
+    /// the height of the on-chain database.
+    /// Returns the height of the last replayed block.
+    async fn reindex<C>(&self, cancel: C) -> anyhow::Result<BlockHeight>
+    where
+        C: NotifyCancel + Clone + Send + Sync + 'static,
+    {
+        let off_chain = self.database.off_chain();
+        let Some(stage) = GenesisProgressInspect::<OffChain>::genesis_progress(
+            off_chain,
+            REINDEX_PROGRESS_KEY,
+        ) else {
+            // The reindexing was finished, but the database wasn't replaced yet.
+            return off_chain
+                .latest_height_from_metadata()?
+                .ok_or(anyhow::anyhow!(
+                    "The reindexed database doesn't have height"
+                ))
+        };
+
+        if stage == SNAPSHOT_STAGE {
+            tracing::info!("Importing the off-chain tables of the snapshot");
+            genesis::import_off_chain_state(
+                cancel.clone(),
+                &self.snapshot_reader,
+                &self.database,
+            )
+            .await?;
+            self.finish_snapshot_stage()?;
+        }
+
+        let height = self.replay_blocks(&cancel)?;
+
+        let mut transaction = off_chain.clone().into_transaction();
+        transaction
+            .storage_as_mut::<GenesisMetadata<OffChain>>()
+            .remove(REINDEX_PROGRESS_KEY)?;
+        self.database
+            .off_chain()
+            .commit_changes_outside_of_block(transaction.into_changes())?;
+
+        tracing::info!("The off-chain database is reindexed up to height {height}");
+        Ok(height)
+    }
+
+    /// Removes the progress of the import of the snapshot tables
+    /// and moves the reindexing to the next stage in one commit.
+    fn finish_snapshot_stage(&self) -> anyhow::Result<()> {
+        let off_chain = self.database.off_chain();
+        let import_progress: Vec<String> = off_chain
+            .iter_all_keys::<GenesisMetadata<OffChain>>(None)
+            .try_collect()?;
+
+        let mut transaction = off_chain.clone().into_transaction();
+        for key in import_progress {
+            transaction
+                .storage_as_mut::<GenesisMetadata<OffChain>>()
+                .remove(&key)?;
+        }
+        GenesisProgressMutate::<OffChain>::update_genesis_progress(
+            &mut transaction,
+            REINDEX_PROGRESS_KEY,
+            BLOCKS_STAGE,
+        )?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Replays the blocks after the latest height of the off-chain database.
+    /// Each block is committed separately, so the height of the off-chain
+    /// database is the progress of the replay.
+    fn replay_blocks<C>(&self, cancel: &C) -> anyhow::Result<BlockHeight>
+    where
+        C: NotifyCancel,
+    {
+        let target_height = self
+            .database
+            .on_chain()
+            .latest_height_from_metadata()?
+            .ok_or(anyhow::anyhow!("on-chain database doesn't have height"))?;
+        let off_chain = self.database.off_chain();
+        let mut last_report = Instant::now();
+
+        loop {
+            let off_chain_height = off_chain.latest_height_from_metadata()?;
+            if let Some(height) = off_chain_height {
+                if height >= target_height {
+                    return Ok(height)
+                }
+            }
+
+            if cancel.is_cancelled() {
+                return Err(anyhow::anyhow!(
+                    "The reindexing was interrupted at height {off_chain_height:?}"
+                ));
+            }
+
+            let next_block = match off_chain_height {
+                Some(height) => BlockAt::Specific(height.succ().ok_or(
+                    anyhow::anyhow!("The off-chain height({height}) overflows"),
+                )?),
+                None => BlockAt::Genesis,
+            };
+            let result = self.import_result_provider.result_at_height(next_block)?;
+            let height = *result.sealed_block.entity.header().height();
+
+            let mut transaction = off_chain.clone().into_transaction();
+            self.indexer.index_block(&result, &mut transaction)?;
+            transaction.commit()?;
+
+            if last_report.elapsed() >= PROGRESS_REPORT_INTERVAL {
+                tracing::info!("Reindexed blocks up to height {height}/{target_height}");
+                last_report = Instant::now();
+            }
+        }
+    }
+}
@@ -99,11 +99,7 @@ pub fn init_sub_services(
     let executor = ExecutorAdapter::new(
         database.on_chain().clone(),
         database.relayer().clone(),
-        fuel_core_upgradable_executor::config::Config {
-            backtrace: config.vm.backtrace,
-            utxo_validation_default: config.utxo_validation,
-            native_executor_version: config.native_executor_version,
-        },
+        config.into(),
     );
     let import_result_provider =
         ImportResultProvider::new(database.on_chain().clone(), executor.clone());
@@ -288,6 +284,7 @@ pub fn init_sub_services(
         chain_id,
         config.da_compression.clone(),
         config.continue_on_error,
+        config.block_importer.history_retention,
     );
 
     let graphql_config = GraphQLConfig {
@@ -307,12 +304,15 @@ pub fn init_sub_services(
         database.on_chain().clone(),
         database.off_chain().clone(),
         Box::new(tx_pool_adapter),
+        Box::new(graphql_worker.shared.clone()),
         Box::new(producer_adapter),
         Box::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
         Box::new(gas_price_provider),
         Box::new(consensus_parameters_provider),
         SharedMemoryPool::new(config.memory_pool_size),
+        Box::new(database.clone()),
+        Arc::new(database.clone()),
     )?;
 
     let shared = SharedState {
@@ -0,0 +1,202 @@
+//! The offline verification of the blocks stored in the database.
+//!
+//! Each block is checked the same way as it was checked by the block importer:
+//! the consensus signature, the fields of the header, and the re-execution on top
+//! of the state at the previous height. The execution verifies that the roots of
+//! the transactions, the outbox messages and the events match the header.
+//! After the execution, the produced changes are compared with the state stored
+//! at the height of the block.
+
+use crate::{
+    combined_database::{
+        CombinedDatabase,
+        ShutdownListener,
+    },
+    database::Database,
+    service::adapters::{
+        ExecutorAdapter,
+        VerifierAdapter,
+    },
+};
+use fuel_core_chain_config::ConsensusConfig;
+use fuel_core_importer::ports::{
+    BlockVerifier,
+    Validator,
+};
+use fuel_core_storage::{
+    column::Column,
+    kv_store::{
+        KeyValueInspect,
+        WriteOperation,
+    },
+    not_found,
+    transactional::{
+        AtomicView,
+        Changes,
+        HistoricalView,
+    },
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::Consensus,
+        SealedBlockHeader,
+    },
+    fuel_types::BlockHeight,
+};
+
+/// The block that failed the verification.
+#[derive(Debug)]
+pub struct InvalidBlock {
+    /// The height of the block.
+    pub height: BlockHeight,
+    /// The reason of the failure.
+    pub reason: anyhow::Error,
+}
+
+/// Verifies the blocks stored in the on-chain database.
+///
+/// The database should be opened with the state rewind policy that keeps
+/// the history of the verified heights.
+pub struct StoredBlocksVerifier {
+    database: Database,
+    executor: ExecutorAdapter,
+    verifier: VerifierAdapter,
+}
+
+impl StoredBlocksVerifier {
+    pub fn new(
+        database: &CombinedDatabase,
+        consensus: ConsensusConfig,
+        executor_config: fuel_core_upgradable_executor::config::Config,
+    ) -> anyhow::Result<Self> {
+        let genesis_block = database
+            .on_chain()
+            .latest_view()?
+            .genesis_block()?
+            .ok_or(not_found!("Genesis block"))?;
+        let executor = ExecutorAdapter::new(
+            database.on_chain().clone(),
+            database.relayer().clone(),
+            executor_config,
+        );
+        let verifier =
+            VerifierAdapter::new(&genesis_block, consensus, database.on_chain().clone());
+
+        Ok(Self {
+            database: database.on_chain().clone(),
+            executor,
+            verifier,
+        })
+    }
+
+    /// Verifies the blocks in the `[from; to]` range.
+    ///
+    /// The verification continues after the invalid block, and all of them
+    /// are returned. The error is returned only if the range can't be verified.
+    pub fn verify_range<S>(
+        &self,
+        from: BlockHeight,
+        to: BlockHeight,
+        shutdown_listener: &S,
+    ) -> anyhow::Result<Vec<InvalidBlock>>
+    where
+        S: ShutdownListener,
+    {
+        if from > to {
+            return Err(anyhow::anyhow!(
+                "the start of the range({from}) is greater than the end({to})"
+            ));
+        }
+
+        let latest_height = self
+            .database
+            .latest_height()
+            .ok_or(anyhow::anyhow!("on-chain database doesn't have height"))?;
+        if to > latest_height {
+            return Err(anyhow::anyhow!(
+                "on-chain database height({latest_height}) \
+                is less than the end of the range({to})"
+            ));
+        }
+
+        let mut invalid_blocks = vec![];
+        for height in u32::from(from)..=u32::from(to) {
+            if shutdown_listener.is_cancelled() {
+                return Err(anyhow::anyhow!(
+                    "the verification was interrupted at height {height}"
+                ));
+            }
+
+            let height = BlockHeight::from(height);
+            match self.verify_block(height) {
+                Ok(()) => {
+                    tracing::info!("Block at height {height} is valid");
+                }
+                Err(reason) => {
+                    tracing::error!("Block at height {height} is invalid: {reason:?}");
+                    invalid_blocks.push(InvalidBlock { height, reason });
+                }
+            }
+        }
+
+        Ok(invalid_blocks)
+    }
+
+    /// Verifies the block at the `height`.
+    pub fn verify_block(&self, height: BlockHeight) -> anyhow::Result<()> {
+        let sealed_block = self
+            .database
+            .latest_view()?
+            .get_sealed_block_by_height(&height)?
+            .ok_or(not_found!("SealedBlock"))?;
+        let block = sealed_block.entity;
+        let consensus = sealed_block.consensus;
+
+        let header = SealedBlockHeader {
+            entity: block.header().clone(),
+            consensus: consensus.clone(),
+        };
+        if !self.verifier.block_verifier.verify_consensus(&header) {
+            return Err(anyhow::anyhow!("the consensus signature is invalid"));
+        }
+
+        self.verifier.verify_block_fields(&consensus, &block)?;
+
+        // The genesis block is created from the snapshot and not by the executor.
+        if let Consensus::Genesis(_) = consensus {
+            return Ok(())
+        }
+
+        let changes = self.executor.validate(&block)?.into_changes();
+        let stored_state = self.database.view_at(&height)?;
+        verify_changes_are_stored(&changes, &stored_state)
+    }
+}
+
+fn verify_changes_are_stored<S>(changes: &Changes, stored_state: &S) -> anyhow::Result<()>
+where
+    S: KeyValueInspect<Column = Column>,
+{
+    for (column, column_changes) in changes {
+        let column = Column::try_from(*column)
+            .map_err(|_| anyhow::anyhow!("unknown column {column}"))?;
+
+        for (key, operation) in column_changes {
+            let stored_value = stored_state.get(key.as_ref(), column)?;
+            let expected_value = match operation {
+                WriteOperation::Insert(value) => Some(value),
+                WriteOperation::Remove => None,
+            };
+
+            if stored_value.as_ref() != expected_value {
+                return Err(anyhow::anyhow!(
+                    "the stored value of the key {} in the column {column:?} \
+                    doesn't match the result of the execution",
+                    hex::encode(key)
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
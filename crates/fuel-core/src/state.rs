@@ -6,6 +6,7 @@ use crate::{
         key_value_view::KeyValueViewWrapper,
     },
 };
+use fuel_core_metrics::core_metrics::DatabaseStats;
 use fuel_core_storage::{
     iter::{
         IterDirection,
@@ -15,20 +16,37 @@ use fuel_core_storage::{
     transactional::Changes,
     Result as StorageResult,
 };
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    path::Path,
+};
 
 pub mod data_source;
 pub mod generic_database;
-#[cfg(feature = "rocksdb")]
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
 pub mod historical_rocksdb;
 pub mod in_memory;
 pub mod iterable_key_value_view;
 pub mod key_value_view;
+#[cfg(feature = "redb")]
+pub mod redb;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db_key_iterator;
 
+/// The `None` means overflow, so there is not following prefix.
+#[cfg(any(feature = "rocksdb", feature = "redb"))]
+pub(crate) fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
+    for byte in prefix.iter_mut().rev() {
+        if let Some(new_byte) = byte.checked_add(1) {
+            *byte = new_byte;
+            return Some(prefix);
+        }
+    }
+    None
+}
+
 pub type ColumnType<Description> = <Description as DatabaseDescription>::Column;
 
 /// A type extends the `KeyValueView`, allowing iteration over the storage.
@@ -66,6 +84,15 @@ pub trait TransactableStorage<Height>: IterableStore + Debug + Send + Sync {
     fn latest_view(&self) -> StorageResult<IterableKeyValueView<Self::Column>>;
 
     fn rollback_block_to(&self, height: &Height) -> StorageResult<()>;
+
+    /// Creates a consistent copy of the storage in the `path`.
+    fn checkpoint(&self, path: &Path) -> StorageResult<()>;
+
+    /// Returns the statistics of the columns of the storage.
+    /// The storages that don't collect the statistics return `None`.
+    fn stats(&self) -> StorageResult<Option<DatabaseStats>> {
+        Ok(None)
+    }
 }
 
 // It is used only to allow conversion of the `StorageTransaction` into the `DataSource`.
@@ -90,4 +117,8 @@ where
     fn rollback_block_to(&self, _: &Height) -> StorageResult<()> {
         unimplemented!()
     }
+
+    fn checkpoint(&self, _: &Path) -> StorageResult<()> {
+        unimplemented!()
+    }
 }
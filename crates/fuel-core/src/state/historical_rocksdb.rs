@@ -1,3 +1,11 @@
+#[cfg(feature = "rocksdb")]
+use crate::state::{
+    historical_rocksdb::description::Historical,
+    rocks_db::{
+        config::RocksDbConfig,
+        RocksDb,
+    },
+};
 use crate::{
     database::{
         database_description::{
@@ -12,19 +20,21 @@ use crate::{
             description::{
                 historical_duplicate_column_id,
                 Column,
-                Historical,
             },
             view_at_height::ViewAtHeight,
         },
         iterable_key_value_view::IterableKeyValueViewWrapper,
         key_value_view::KeyValueViewWrapper,
-        rocks_db::RocksDb,
         ColumnType,
         IterableKeyValueView,
         KeyValueView,
         TransactableStorage,
     },
 };
+use fuel_core_metrics::core_metrics::{
+    DatabaseMetrics,
+    DatabaseStats,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -60,8 +70,10 @@ use serde::{
     Serialize,
 };
 use std::{
+    fmt::Debug,
     num::NonZeroU64,
     path::Path,
+    sync::Arc,
 };
 
 pub mod description;
@@ -81,40 +93,130 @@ pub enum StateRewindPolicy {
     RewindRange { size: NonZeroU64 },
 }
 
+/// The key-value storage engine used by the [`HistoricalDatabase`]. It stores the latest
+/// state in the original columns and the history of the modifications in the historical
+/// columns of the same storage, so the changes of both are committed atomically.
+pub trait HistoricalBackend<Description>:
+    IterableStore<Column = Column<Description>> + Debug + Send + Sync + Sized + 'static
+where
+    Description: DatabaseDescription,
+{
+    /// The view of the original columns at the moment of its creation.
+    type LatestView: IterableStore<Column = Description::Column> + Send + Sync + 'static;
+
+    /// Creates the view of all columns that is not affected by the following commits.
+    fn snapshot(&self) -> Self;
+
+    /// Creates the view of the latest state that is not affected by the following commits.
+    fn latest_view(&self) -> Self::LatestView;
+
+    /// Returns the values of the `keys` from the `column` in the same order.
+    fn multi_get<K, I>(
+        &self,
+        column: u32,
+        keys: I,
+    ) -> DatabaseResult<Vec<Option<Vec<u8>>>>
+    where
+        I: Iterator<Item = K>,
+        K: AsRef<[u8]>;
+
+    /// Atomically commits the `changes` into the storage.
+    fn commit_changes(&self, changes: &Changes) -> StorageResult<()>;
+
+    /// Creates a consistent copy of the storage in the `path`.
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()>;
+
+    /// Returns the metrics of the storage with the up-to-date size of the columns.
+    fn metrics(&self) -> DatabaseResult<Arc<DatabaseMetrics>>;
+}
+
+#[cfg(feature = "rocksdb")]
+impl<Description> HistoricalBackend<Description> for RocksDb<Historical<Description>>
+where
+    Description: DatabaseDescription,
+{
+    type LatestView = RocksDb<Description>;
+
+    fn snapshot(&self) -> Self {
+        self.create_snapshot()
+    }
+
+    fn latest_view(&self) -> Self::LatestView {
+        self.create_snapshot_generic()
+    }
+
+    fn multi_get<K, I>(
+        &self,
+        column: u32,
+        keys: I,
+    ) -> DatabaseResult<Vec<Option<Vec<u8>>>>
+    where
+        I: Iterator<Item = K>,
+        K: AsRef<[u8]>,
+    {
+        RocksDb::multi_get(self, column, keys)
+    }
+
+    fn commit_changes(&self, changes: &Changes) -> StorageResult<()> {
+        RocksDb::commit_changes(self, changes)
+    }
+
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        RocksDb::checkpoint(self, path)
+    }
+
+    fn metrics(&self) -> DatabaseResult<Arc<DatabaseMetrics>> {
+        RocksDb::metrics(self)
+    }
+}
+
+/// The historical database on top of the RocksDB.
+#[cfg(feature = "rocksdb")]
+pub type HistoricalRocksDB<Description> =
+    HistoricalDatabase<Description, RocksDb<Historical<Description>>>;
+
 /// Implementation of a database
 #[derive(Debug)]
-pub struct HistoricalRocksDB<Description> {
-    /// The [`StateRewindPolicy`] used by the historical rocksdb
+pub struct HistoricalDatabase<Description, Backend> {
+    /// The [`StateRewindPolicy`] used by the historical database
     state_rewind_policy: StateRewindPolicy,
-    /// The Description of the database.
-    db: RocksDb<Historical<Description>>,
+    /// The storage of the latest state and the history.
+    db: Backend,
+    _marker: core::marker::PhantomData<Description>,
 }
 
+#[cfg(feature = "rocksdb")]
 impl<Description> HistoricalRocksDB<Description>
 where
     Description: DatabaseDescription,
 {
-    pub fn new(
-        db: RocksDb<Historical<Description>>,
-        state_rewind_policy: StateRewindPolicy,
-    ) -> DatabaseResult<Self> {
-        Ok(Self {
-            state_rewind_policy,
-            db,
-        })
-    }
-
     pub fn default_open<P: AsRef<Path>>(
         path: P,
         capacity: Option<usize>,
         state_rewind_policy: StateRewindPolicy,
         max_fds: i32,
+        config: &RocksDbConfig,
+    ) -> DatabaseResult<Self> {
+        let db = RocksDb::<Historical<Description>>::default_open(
+            path, capacity, max_fds, config,
+        )?;
+        Self::new(db, state_rewind_policy)
+    }
+}
+
+impl<Description, Backend> HistoricalDatabase<Description, Backend>
+where
+    Description: DatabaseDescription,
+    Backend: HistoricalBackend<Description>,
+{
+    pub fn new(
+        db: Backend,
+        state_rewind_policy: StateRewindPolicy,
     ) -> DatabaseResult<Self> {
-        let db =
-            RocksDb::<Historical<Description>>::default_open(path, capacity, max_fds)?;
         Ok(Self {
             state_rewind_policy,
             db,
+            _marker: Default::default(),
         })
     }
 
@@ -158,15 +260,15 @@ where
         Ok(reverse_changes)
     }
 
-    pub fn latest_view(&self) -> RocksDb<Description> {
-        self.db.create_snapshot_generic()
+    pub fn latest_view(&self) -> Backend::LatestView {
+        self.db.latest_view()
     }
 
     pub fn create_view_at(
         &self,
         height: &Description::Height,
-    ) -> StorageResult<ViewAtHeight<Description>> {
-        let latest_view = self.db.create_snapshot_generic::<Historical<Description>>();
+    ) -> StorageResult<ViewAtHeight<Description, Backend>> {
+        let latest_view = self.db.snapshot();
 
         // Each height stores reverse modification caused by the corresponding
         // block at the same height. Applying reverse changes at height `X`
@@ -508,9 +610,10 @@ where
     Ok(())
 }
 
-impl<Description> KeyValueInspect for HistoricalRocksDB<Description>
+impl<Description, Backend> KeyValueInspect for HistoricalDatabase<Description, Backend>
 where
     Description: DatabaseDescription,
+    Backend: HistoricalBackend<Description>,
 {
     type Column = Description::Column;
 
@@ -542,9 +645,10 @@ where
     }
 }
 
-impl<Description> IterableStore for HistoricalRocksDB<Description>
+impl<Description, Backend> IterableStore for HistoricalDatabase<Description, Backend>
 where
     Description: DatabaseDescription,
+    Backend: HistoricalBackend<Description>,
 {
     fn iter_store(
         &self,
@@ -569,10 +673,11 @@ where
     }
 }
 
-impl<Description> TransactableStorage<Description::Height>
-    for HistoricalRocksDB<Description>
+impl<Description, Backend> TransactableStorage<Description::Height>
+    for HistoricalDatabase<Description, Backend>
 where
     Description: DatabaseDescription,
+    Backend: HistoricalBackend<Description>,
 {
     fn commit_changes(
         &self,
@@ -612,6 +717,16 @@ where
     fn rollback_block_to(&self, height: &Description::Height) -> StorageResult<()> {
         self.rollback_block_to(height.as_u64())
     }
+
+    fn checkpoint(&self, path: &Path) -> StorageResult<()> {
+        self.db.checkpoint(path)?;
+        Ok(())
+    }
+
+    fn stats(&self) -> StorageResult<Option<DatabaseStats>> {
+        let metrics = self.db.metrics()?;
+        Ok(Some(metrics.stats()))
+    }
 }
 
 pub fn height_key(key: &[u8], height: &u64) -> Vec<u8> {
@@ -638,7 +753,7 @@ where
     postcard::from_bytes(bytes).map_err(|err| StorageError::Codec(err.into()))
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rocksdb"))]
 #[allow(non_snake_case)]
 #[allow(clippy::cast_possible_truncation)]
 mod tests {
@@ -1,21 +1,16 @@
 use crate::{
     database::database_description::DatabaseDescription,
-    state::{
-        historical_rocksdb::{
-            description::{
-                Column,
-                Historical,
-            },
-            deserialize,
-            height_key,
-        },
-        rocks_db::{
-            KeyAndValue,
-            RocksDb,
-        },
+    state::historical_rocksdb::{
+        description::Column,
+        deserialize,
+        height_key,
     },
 };
 use fuel_core_storage::{
+    iter::{
+        IterDirection,
+        IterableStore,
+    },
     kv_store::{
         KeyValueInspect,
         Value,
@@ -23,46 +18,44 @@ use fuel_core_storage::{
     },
     Result as StorageResult,
 };
-use rocksdb::{
-    IteratorMode,
-    ReadOptions,
-};
 
-pub struct ViewAtHeight<Description> {
+pub struct ViewAtHeight<Description, Backend> {
     height: u64,
-    read_db: RocksDb<Historical<Description>>,
+    read_db: Backend,
+    _marker: core::marker::PhantomData<Description>,
 }
 
-impl<Description> ViewAtHeight<Description>
+impl<Description, Backend> ViewAtHeight<Description, Backend>
 where
     Description: DatabaseDescription,
 {
-    pub fn new(height: u64, read_db: RocksDb<Historical<Description>>) -> Self {
-        Self { height, read_db }
+    pub fn new(height: u64, read_db: Backend) -> Self {
+        Self {
+            height,
+            read_db,
+            _marker: Default::default(),
+        }
     }
 }
 
-impl<Description> KeyValueInspect for ViewAtHeight<Description>
+impl<Description, Backend> KeyValueInspect for ViewAtHeight<Description, Backend>
 where
     Description: DatabaseDescription,
+    Backend: IterableStore<Column = Column<Description>>,
 {
     type Column = Description::Column;
 
     fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
         let read_history = &self.read_db;
         let height_key = height_key(key, &self.height);
-        let mut options = ReadOptions::default();
-        // We need this option because our iterator will try to start in the `height_key` prefix section
-        // but if there is no data in this section, we expect the iterator to fetch data in an other prefix section.
-        // Without this option it's not guarantee that we fetch the correct next prefix section.
-        // Source : https://github.com/facebook/rocksdb/wiki/Prefix-Seek#how-to-ignore-prefix-bloom-filters-in-read
-        // and https://github.com/facebook/rocksdb/wiki/Prefix-Seek#general-prefix-seek-api
-        options.set_total_order_seek(true);
+        // The iteration from the `height_key` without the prefix continues into the
+        // next prefix sections if there is no data in the `height_key` prefix section.
         let nearest_modification = read_history
-            .iterator::<KeyAndValue>(
+            .iter_store(
                 Column::HistoricalDuplicateColumn(column),
-                options,
-                IteratorMode::From(&height_key, rocksdb::Direction::Forward),
+                None,
+                Some(&height_key),
+                IterDirection::Forward,
             )
             .next();
 
@@ -84,16 +77,18 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rocksdb"))]
 mod tests {
     use super::*;
     use crate::{
         database::database_description::on_chain::OnChain,
         state::{
             historical_rocksdb::{
+                description::Historical,
                 HistoricalRocksDB,
                 StateRewindPolicy,
             },
+            rocks_db::RocksDb,
             TransactableStorage,
         },
     };
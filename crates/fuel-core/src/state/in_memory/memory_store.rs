@@ -38,6 +38,7 @@ use std::{
     collections::BTreeMap,
     fmt::Debug,
     ops::Deref,
+    path::Path,
     sync::Mutex,
 };
 
@@ -213,6 +214,10 @@ where
                 .into(),
         )
     }
+
+    fn checkpoint(&self, _: &Path) -> StorageResult<()> {
+        Err(anyhow::anyhow!("The checkpoints are not supported by `MemoryStore`").into())
+    }
 }
 
 #[cfg(test)]
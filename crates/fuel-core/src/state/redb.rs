@@ -0,0 +1,976 @@
+//! The storage on top of the [redb](https://docs.rs/redb) embedded database.
+//!
+//! The redb is written in pure Rust, so it is an alternative to the RocksDB
+//! for the environments where the build of the C++ library is a problem.
+//! Each database is stored in the `<path>/<database name>/data.redb` file,
+//! and each column is a separate table of the file.
+
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        Error as DatabaseError,
+        Result as DatabaseResult,
+    },
+    state::{
+        historical_rocksdb::{
+            description::Historical,
+            HistoricalBackend,
+            HistoricalDatabase,
+        },
+        next_prefix,
+    },
+};
+use fuel_core_metrics::core_metrics::DatabaseMetrics;
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IntoBoxedIter,
+        IterDirection,
+        IterableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyItem,
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+        WriteOperation,
+    },
+    transactional::Changes,
+    Result as StorageResult,
+};
+use redb::{
+    ReadOnlyTable,
+    ReadTransaction,
+    ReadableTable,
+    ReadableTableMetadata,
+    TableDefinition,
+    TableError,
+    TableHandle,
+};
+use std::{
+    collections::VecDeque,
+    ops::Bound,
+    path::Path,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Instant,
+};
+use tempfile::TempDir;
+
+/// The name of the file of the database inside of its directory.
+const DATA_FILE: &str = "data.redb";
+
+/// The number of entries read from the table at once during the iteration.
+const ITERATION_BATCH_SIZE: usize = 1024;
+
+type Table<'a> = TableDefinition<'a, &'static [u8], &'static [u8]>;
+
+/// The read transaction shared by the snapshot and its iterators.
+/// The error of the creation of the transaction is returned on the first read.
+type SharedReadTransaction = Arc<Mutex<Result<ReadTransaction, String>>>;
+
+/// The historical database on top of the redb.
+pub type HistoricalRedb<Description> =
+    HistoricalDatabase<Description, Redb<Historical<Description>>>;
+
+pub struct Redb<Description> {
+    db: Arc<redb::Database>,
+    /// The read transaction of the snapshot. The storage without the snapshot
+    /// reads the latest committed state.
+    snapshot: Option<SharedReadTransaction>,
+    metrics: Arc<DatabaseMetrics>,
+    // used for RAII
+    _temp_dir: Option<Arc<TempDir>>,
+    _marker: core::marker::PhantomData<Description>,
+}
+
+impl<Description> std::fmt::Debug for Redb<Description> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Redb").field("db", &self.db).finish()
+    }
+}
+
+impl<Description> Redb<Description>
+where
+    Description: DatabaseDescription,
+{
+    pub fn default_open_temp(capacity: Option<usize>) -> DatabaseResult<Self> {
+        let tmp_dir = TempDir::new().map_err(|e| DatabaseError::Other(e.into()))?;
+        let mut db = Self::default_open(tmp_dir.path(), capacity)?;
+        db._temp_dir = Some(Arc::new(tmp_dir));
+        Ok(db)
+    }
+
+    pub fn default_open<P: AsRef<Path>>(
+        path: P,
+        capacity: Option<usize>,
+    ) -> DatabaseResult<Self> {
+        let path = path.as_ref().join(Description::name());
+        std::fs::create_dir_all(&path).map_err(|e| DatabaseError::Other(e.into()))?;
+
+        let mut builder = redb::Builder::new();
+        if let Some(capacity) = capacity {
+            builder.set_cache_size(capacity);
+        }
+        let db = builder.create(path.join(DATA_FILE)).map_err(into_error)?;
+
+        let metric_columns = enum_iterator::all::<Description::Column>()
+            .map(|column| (column.id(), column.name()))
+            .collect::<Vec<_>>();
+        let metrics = Arc::new(DatabaseMetrics::new(
+            Description::name().as_str(),
+            &metric_columns,
+        ));
+
+        Ok(Self {
+            db: Arc::new(db),
+            snapshot: None,
+            metrics,
+            _temp_dir: None,
+            _marker: Default::default(),
+        })
+    }
+
+    pub fn prune(path: &Path) -> DatabaseResult<()> {
+        let path = path.join(Description::name());
+        if path.exists() {
+            std::fs::remove_dir_all(path).map_err(|e| DatabaseError::Other(e.into()))?;
+        }
+        Ok(())
+    }
+
+    /// Copies all tables of the database into the new database in the `path`.
+    /// The copy uses the same layout as the [`Redb::default_open`].
+    pub fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        let path = path.join(Description::name());
+        std::fs::create_dir_all(&path).map_err(|e| DatabaseError::Other(e.into()))?;
+        let target = redb::Database::create(path.join(DATA_FILE)).map_err(into_error)?;
+
+        let transaction = self.db.begin_read().map_err(into_error)?;
+        let write_transaction = target.begin_write().map_err(into_error)?;
+        for handle in transaction.list_tables().map_err(into_error)? {
+            let definition = Table::new(handle.name());
+            let source = transaction.open_table(definition).map_err(into_error)?;
+            let mut target_table = write_transaction
+                .open_table(definition)
+                .map_err(into_error)?;
+            for entry in source.iter().map_err(into_error)? {
+                let (key, value) = entry.map_err(into_error)?;
+                target_table
+                    .insert(key.value(), value.value())
+                    .map_err(into_error)?;
+            }
+        }
+        write_transaction.commit().map_err(into_error)?;
+        Ok(())
+    }
+
+    pub fn create_snapshot(&self) -> Self {
+        self.create_snapshot_generic()
+    }
+
+    pub fn create_snapshot_generic<TargetDescription>(&self) -> Redb<TargetDescription> {
+        Redb {
+            db: self.db.clone(),
+            snapshot: Some(self.shared_read_transaction()),
+            metrics: self.metrics.clone(),
+            _temp_dir: self._temp_dir.clone(),
+            _marker: Default::default(),
+        }
+    }
+
+    fn begin_read(&self) -> SharedReadTransaction {
+        let transaction = self
+            .db
+            .begin_read()
+            .map_err(|e| redb::Error::from(e).to_string());
+        Arc::new(Mutex::new(transaction))
+    }
+
+    /// Returns the transaction of the snapshot or a new transaction
+    /// with the latest committed state.
+    fn shared_read_transaction(&self) -> SharedReadTransaction {
+        match &self.snapshot {
+            Some(snapshot) => snapshot.clone(),
+            None => self.begin_read(),
+        }
+    }
+
+    fn get_u32(&self, key: &[u8], column: u32) -> DatabaseResult<Option<Vec<u8>>> {
+        let instant = Instant::now();
+        let transaction = self.shared_read_transaction();
+        let value = with_table(&transaction, column, |table| {
+            let Some(table) = table else { return Ok(None) };
+            let value = table.get(key).map_err(into_error)?;
+            Ok(value.map(|value| value.value().to_vec()))
+        })?;
+
+        let bytes = value.as_ref().map_or(0, |value| value.len());
+        self.record_read(column, bytes as u64);
+        if let Some(metrics) = self.metrics.columns.get(&column) {
+            let elapsed = u64::try_from(instant.elapsed().as_nanos()).unwrap_or(u64::MAX);
+            metrics.read_time.inc_by(elapsed);
+        }
+        Ok(value)
+    }
+
+    fn record_read(&self, column: u32, bytes: u64) {
+        self.metrics.read_meter.inc();
+        self.metrics.bytes_read.inc_by(bytes);
+        if let Some(metrics) = self.metrics.columns.get(&column) {
+            metrics.reads.inc();
+            metrics.bytes_read.inc_by(bytes);
+        }
+    }
+
+    /// Returns the metrics of the database with the up-to-date size of the columns.
+    /// The redb doesn't have SST files and compactions, so only the size is reported.
+    pub fn metrics(&self) -> DatabaseResult<Arc<DatabaseMetrics>> {
+        let transaction = self.shared_read_transaction();
+        for (column, metrics) in &self.metrics.columns {
+            let live_data_size = with_table(&transaction, *column, |table| {
+                let Some(table) = table else { return Ok(0) };
+                let stats = table.stats().map_err(into_error)?;
+                Ok(stats.stored_bytes())
+            })?;
+            metrics.set_size(live_data_size, 0, 0);
+        }
+        Ok(self.metrics.clone())
+    }
+
+    pub fn multi_get<K, I>(
+        &self,
+        column: u32,
+        iterator: I,
+    ) -> DatabaseResult<Vec<Option<Vec<u8>>>>
+    where
+        I: Iterator<Item = K>,
+        K: AsRef<[u8]>,
+    {
+        iterator
+            .map(|key| self.get_u32(key.as_ref(), column))
+            .collect()
+    }
+
+    fn _iter_store(
+        &self,
+        column: Description::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+        with_values: bool,
+    ) -> BoxedIter<StorageResult<(Vec<u8>, Vec<u8>)>> {
+        let prefix_upper_bound = |prefix: &[u8]| match next_prefix(prefix.to_vec()) {
+            Some(next_prefix) => Bound::Excluded(next_prefix),
+            None => Bound::Unbounded,
+        };
+        let (lower, upper) = match (prefix, start) {
+            (None, None) => (Bound::Unbounded, Bound::Unbounded),
+            (Some(prefix), None) => {
+                (Bound::Included(prefix.to_vec()), prefix_upper_bound(prefix))
+            }
+            (None, Some(start)) => match direction {
+                IterDirection::Forward => {
+                    (Bound::Included(start.to_vec()), Bound::Unbounded)
+                }
+                IterDirection::Reverse => {
+                    (Bound::Unbounded, Bound::Included(start.to_vec()))
+                }
+            },
+            (Some(prefix), Some(start)) => {
+                // If the `start` doesn't have the same `prefix`, return nothing.
+                if !start.starts_with(prefix) {
+                    return core::iter::empty().into_boxed();
+                }
+
+                match direction {
+                    IterDirection::Forward => {
+                        (Bound::Included(start.to_vec()), prefix_upper_bound(prefix))
+                    }
+                    IterDirection::Reverse => (
+                        Bound::Included(prefix.to_vec()),
+                        Bound::Included(start.to_vec()),
+                    ),
+                }
+            }
+        };
+
+        let column_metrics = self.metrics.columns.get(&column.id());
+        column_metrics.map(|metrics| metrics.iterations.inc());
+        BatchedIter {
+            transaction: self.shared_read_transaction(),
+            column: column.id(),
+            lower,
+            upper,
+            direction,
+            with_values,
+            batch: VecDeque::new(),
+            finished: false,
+        }
+        .map(move |item| {
+            item.inspect(|(key, value)| {
+                let bytes = key.len().saturating_add(value.len());
+                self.record_read(column.id(), bytes as u64);
+            })
+            .map_err(Into::into)
+        })
+        .into_boxed()
+    }
+
+    pub fn commit_changes(&self, changes: &Changes) -> StorageResult<()> {
+        let instant = Instant::now();
+        let transaction = self.db.begin_write().map_err(into_error)?;
+
+        for (column, ops) in changes {
+            let name = table_name(*column);
+            let mut table = transaction
+                .open_table(Table::new(&name))
+                .map_err(into_error)?;
+            let column_metrics = self.metrics.columns.get(column);
+            for (key, op) in ops {
+                self.metrics.write_meter.inc();
+                column_metrics.map(|metrics| metrics.writes.inc());
+                match op {
+                    WriteOperation::Insert(value) => {
+                        let bytes = value.len() as u64;
+                        self.metrics.bytes_written.inc_by(bytes);
+                        column_metrics.map(|metrics| metrics.bytes_written.inc_by(bytes));
+                        table
+                            .insert(key.as_slice(), value.as_ref())
+                            .map_err(into_error)?;
+                    }
+                    WriteOperation::Remove => {
+                        table.remove(key.as_slice()).map_err(into_error)?;
+                    }
+                }
+            }
+        }
+
+        transaction.commit().map_err(into_error)?;
+        let elapsed = instant.elapsed();
+        // TODO: Use `u128` when `AtomicU128` is stable.
+        self.metrics.database_commit_time.inc_by(
+            u64::try_from(elapsed.as_nanos())
+                .expect("The commit shouldn't take longer than `u64`"),
+        );
+        for column in changes.keys() {
+            if let Some(metrics) = self.metrics.columns.get(column) {
+                metrics.commit_time.observe(elapsed.as_secs_f64());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<Description> KeyValueInspect for Redb<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        let value = self.get_u32(key, column.id())?;
+        Ok(value.map(Arc::from))
+    }
+}
+
+impl<Description> IterableStore for Redb<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_store(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self._iter_store(column, prefix, start, direction, true)
+            .map(|item| item.map(|(key, value)| (key, Arc::from(value))))
+            .into_boxed()
+    }
+
+    fn iter_store_keys(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KeyItem> {
+        self._iter_store(column, prefix, start, direction, false)
+            .map(|item| item.map(|(key, _)| key))
+            .into_boxed()
+    }
+}
+
+impl<Description> HistoricalBackend<Description> for Redb<Historical<Description>>
+where
+    Description: DatabaseDescription,
+{
+    type LatestView = Redb<Description>;
+
+    fn snapshot(&self) -> Self {
+        self.create_snapshot()
+    }
+
+    fn latest_view(&self) -> Self::LatestView {
+        self.create_snapshot_generic()
+    }
+
+    fn multi_get<K, I>(
+        &self,
+        column: u32,
+        keys: I,
+    ) -> DatabaseResult<Vec<Option<Vec<u8>>>>
+    where
+        I: Iterator<Item = K>,
+        K: AsRef<[u8]>,
+    {
+        Redb::multi_get(self, column, keys)
+    }
+
+    fn commit_changes(&self, changes: &Changes) -> StorageResult<()> {
+        Redb::commit_changes(self, changes)
+    }
+
+    fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        Redb::checkpoint(self, path)
+    }
+
+    fn metrics(&self) -> DatabaseResult<Arc<DatabaseMetrics>> {
+        Redb::metrics(self)
+    }
+}
+
+/// Iterates over the range of the table by the batches of the [`ITERATION_BATCH_SIZE`].
+/// The table is opened only for the time of the reading of the batch,
+/// while the read transaction keeps the iteration consistent.
+struct BatchedIter {
+    transaction: SharedReadTransaction,
+    column: u32,
+    lower: Bound<Vec<u8>>,
+    upper: Bound<Vec<u8>>,
+    direction: IterDirection,
+    with_values: bool,
+    batch: VecDeque<(Vec<u8>, Vec<u8>)>,
+    finished: bool,
+}
+
+impl BatchedIter {
+    fn is_empty_range(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Bound::Included(lower), Bound::Included(upper)) => lower > upper,
+            (Bound::Included(lower), Bound::Excluded(upper))
+            | (Bound::Excluded(lower), Bound::Included(upper))
+            | (Bound::Excluded(lower), Bound::Excluded(upper)) => lower >= upper,
+            _ => false,
+        }
+    }
+
+    fn read_batch(&mut self) -> DatabaseResult<()> {
+        if self.is_empty_range() {
+            self.finished = true;
+            return Ok(())
+        }
+
+        let lower = self.lower.as_ref().map(Vec::as_slice);
+        let upper = self.upper.as_ref().map(Vec::as_slice);
+        let (direction, with_values) = (self.direction, self.with_values);
+        let batch = with_table(&self.transaction, self.column, |table| {
+            let Some(table) = table else {
+                return Ok(VecDeque::new())
+            };
+            let range = table.range::<&[u8]>((lower, upper)).map_err(into_error)?;
+            let entries: Box<dyn Iterator<Item = _>> = match direction {
+                IterDirection::Forward => Box::new(range),
+                IterDirection::Reverse => Box::new(range.rev()),
+            };
+            entries
+                .take(ITERATION_BATCH_SIZE)
+                .map(|entry| {
+                    let (key, value) = entry.map_err(into_error)?;
+                    let value = if with_values {
+                        value.value().to_vec()
+                    } else {
+                        Vec::new()
+                    };
+                    Ok((key.value().to_vec(), value))
+                })
+                .collect()
+        })?;
+
+        self.finished = batch.len() < ITERATION_BATCH_SIZE;
+        if let Some((last_key, _)) = batch.back() {
+            match self.direction {
+                IterDirection::Forward => self.lower = Bound::Excluded(last_key.clone()),
+                IterDirection::Reverse => self.upper = Bound::Excluded(last_key.clone()),
+            }
+        }
+        self.batch = batch;
+        Ok(())
+    }
+}
+
+impl Iterator for BatchedIter {
+    type Item = DatabaseResult<(Vec<u8>, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.batch.is_empty() && !self.finished {
+            if let Err(err) = self.read_batch() {
+                self.finished = true;
+                return Some(Err(err))
+            }
+        }
+        self.batch.pop_front().map(Ok)
+    }
+}
+
+/// Calls the `f` with the table of the `column`,
+/// or with `None` if nothing was written into the column yet.
+fn with_table<R>(
+    transaction: &SharedReadTransaction,
+    column: u32,
+    f: impl FnOnce(Option<ReadOnlyTable<&'static [u8], &'static [u8]>>) -> DatabaseResult<R>,
+) -> DatabaseResult<R> {
+    let name = table_name(column);
+    let guard = transaction
+        .lock()
+        .expect("The read transaction lock should be available");
+    let transaction = guard
+        .as_ref()
+        .map_err(|e| DatabaseError::Other(anyhow::anyhow!(e.clone())))?;
+
+    match transaction.open_table(Table::new(&name)) {
+        Ok(table) => f(Some(table)),
+        Err(TableError::TableDoesNotExist(_)) => f(None),
+        Err(err) => Err(into_error(err)),
+    }
+}
+
+fn table_name(column: u32) -> String {
+    format!("col-{}", column)
+}
+
+fn into_error<E>(error: E) -> DatabaseError
+where
+    E: Into<redb::Error>,
+{
+    let error: redb::Error = error.into();
+    DatabaseError::Other(error.into())
+}
+
+#[cfg(feature = "test-helpers")]
+pub mod test_helpers {
+    use super::*;
+    use fuel_core_storage::{
+        kv_store::KeyValueMutate,
+        transactional::ReadTransaction,
+    };
+
+    impl<Description> KeyValueMutate for Redb<Description>
+    where
+        Description: DatabaseDescription,
+    {
+        fn write(
+            &mut self,
+            key: &[u8],
+            column: Self::Column,
+            buf: &[u8],
+        ) -> StorageResult<usize> {
+            let mut transaction = self.read_transaction();
+            let len = transaction.write(key, column, buf)?;
+            let changes = transaction.into_changes();
+            self.commit_changes(&changes)?;
+
+            Ok(len)
+        }
+
+        fn delete(&mut self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+            let mut transaction = self.read_transaction();
+            transaction.delete(key, column)?;
+            let changes = transaction.into_changes();
+            self.commit_changes(&changes)?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::database_description::on_chain::OnChain,
+        state::{
+            historical_rocksdb::StateRewindPolicy,
+            TransactableStorage,
+        },
+    };
+    use fuel_core_storage::{
+        column::Column,
+        kv_store::KeyValueMutate,
+        tables::ContractsAssets,
+        transactional::{
+            IntoTransaction,
+            ReadTransaction,
+        },
+        ContractsAssetKey,
+        StorageAsMut,
+        StorageAsRef,
+    };
+    use std::collections::{
+        BTreeMap,
+        HashMap,
+    };
+
+    fn create_db() -> Redb<OnChain> {
+        Redb::default_open_temp(None).unwrap()
+    }
+
+    fn create_historical_db() -> HistoricalRedb<OnChain> {
+        let db = Redb::<Historical<OnChain>>::default_open_temp(None).unwrap();
+        HistoricalRedb::new(db, StateRewindPolicy::RewindFullRange).unwrap()
+    }
+
+    fn key() -> ContractsAssetKey {
+        ContractsAssetKey::new(&[123; 32].into(), &[213; 32].into())
+    }
+
+    fn keys(
+        db: &Redb<OnChain>,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> Vec<Vec<u8>> {
+        db.iter_store_keys(Column::Metadata, prefix, start, direction)
+            .map(|item| item.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn can_put_and_read() {
+        let key = vec![0xA, 0xB, 0xC];
+
+        let mut db = create_db();
+        let expected = Value::from([1, 2, 3]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected)
+    }
+
+    #[test]
+    fn delete_and_get() {
+        let key = vec![0xA, 0xB, 0xC];
+
+        let mut db = create_db();
+        let expected = Value::from([1, 2, 3]);
+        db.put(&key, Column::Metadata, expected.clone()).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), expected);
+
+        db.delete(&key, Column::Metadata).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn get__returns_none_for_column_that_was_never_written() {
+        let db = create_db();
+
+        let result = db.get(&[1, 2, 3], Column::Metadata).unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn commit_changes_inserts() {
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Value::from([1, 2, 3]);
+
+        let db = create_db();
+        let ops = vec![(
+            Column::Metadata.id(),
+            BTreeMap::from_iter(vec![(
+                key.clone().into(),
+                WriteOperation::Insert(value.clone()),
+            )]),
+        )];
+
+        db.commit_changes(&HashMap::from_iter(ops)).unwrap();
+        assert_eq!(db.get(&key, Column::Metadata).unwrap().unwrap(), value)
+    }
+
+    #[test]
+    fn commit_changes_removes() {
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Arc::new([1, 2, 3]);
+
+        let mut db = create_db();
+        db.put(&key, Column::Metadata, value).unwrap();
+
+        let ops = vec![(
+            Column::Metadata.id(),
+            BTreeMap::from_iter(vec![(key.clone().into(), WriteOperation::Remove)]),
+        )];
+        db.commit_changes(&HashMap::from_iter(ops)).unwrap();
+
+        assert_eq!(db.get(&key, Column::Metadata).unwrap(), None);
+    }
+
+    #[test]
+    fn multi_get__returns_values_in_the_order_of_keys() {
+        // Given
+        let mut db = create_db();
+        db.put(&[1], Column::Metadata, Value::from([1])).unwrap();
+        db.put(&[3], Column::Metadata, Value::from([3])).unwrap();
+
+        // When
+        let values = db
+            .multi_get(
+                Column::Metadata.id(),
+                [vec![3], vec![2], vec![1]].into_iter(),
+            )
+            .unwrap();
+
+        // Then
+        assert_eq!(values, vec![Some(vec![3]), None, Some(vec![1])]);
+    }
+
+    #[test]
+    fn snapshot_allows_get_entry_after_it_was_removed() {
+        let mut db = create_db();
+        let value = Value::from([1, 2, 3]);
+
+        // Given
+        let key_1 = [1; 32];
+        db.put(&key_1, Column::Metadata, value.clone()).unwrap();
+        let snapshot = db.create_snapshot();
+
+        // When
+        db.delete(&key_1, Column::Metadata).unwrap();
+
+        // Then
+        let db_get = db.get(&key_1, Column::Metadata).unwrap();
+        assert!(db_get.is_none());
+
+        let snapshot_get = snapshot.get(&key_1, Column::Metadata).unwrap();
+        assert_eq!(snapshot_get, Some(value));
+    }
+
+    #[test]
+    fn snapshot_allows_correct_iteration_even_after_all_elements_where_removed() {
+        let mut db = create_db();
+        let value = Value::from([1, 2, 3]);
+
+        // Given
+        let key_1 = vec![1; 32];
+        let key_2 = vec![2; 32];
+        db.put(&key_1, Column::Metadata, value.clone()).unwrap();
+        db.put(&key_2, Column::Metadata, value.clone()).unwrap();
+        let snapshot = db.create_snapshot();
+
+        // When
+        db.delete(&key_1, Column::Metadata).unwrap();
+        db.delete(&key_2, Column::Metadata).unwrap();
+
+        // Then
+        let db_keys = keys(&db, None, None, IterDirection::Forward);
+        assert!(db_keys.is_empty());
+
+        let snapshot_keys = snapshot
+            .iter_store_keys(Column::Metadata, None, None, IterDirection::Forward)
+            .map(|item| item.unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(snapshot_keys, vec![key_1, key_2]);
+    }
+
+    #[test]
+    fn iter_store__forward_iterator__respects_prefix_and_start() {
+        // Given
+        let mut db = create_db();
+        let value = Value::from([]);
+        for key in [[1, 1], [2, 1], [2, 2], [2, 3], [3, 1]] {
+            db.put(&key, Column::Metadata, value.clone()).unwrap();
+        }
+
+        // When
+        let result = keys(
+            &db,
+            Some([2].as_slice()),
+            Some([2, 2].as_slice()),
+            IterDirection::Forward,
+        );
+
+        // Then
+        assert_eq!(result, vec![vec![2, 2], vec![2, 3]]);
+    }
+
+    #[test]
+    fn iter_store__reverse_iterator__target_prefix_at_the_middle() {
+        // Given
+        let mut db = create_db();
+        let value = Value::from([]);
+        for key in [[1, 1], [2, 1], [2, 2], [2, 3], [3, 1]] {
+            db.put(&key, Column::Metadata, value.clone()).unwrap();
+        }
+
+        // When
+        let result = keys(&db, Some([2].as_slice()), None, IterDirection::Reverse);
+
+        // Then
+        assert_eq!(result, vec![vec![2, 3], vec![2, 2], vec![2, 1]]);
+    }
+
+    #[test]
+    fn iter_store__reverse_iterator__start_is_inclusive() {
+        // Given
+        let mut db = create_db();
+        let value = Value::from([]);
+        for key in [[1, 1], [2, 1], [2, 2], [2, 3], [3, 1]] {
+            db.put(&key, Column::Metadata, value.clone()).unwrap();
+        }
+
+        // When
+        let result = keys(
+            &db,
+            Some([2].as_slice()),
+            Some([2, 2].as_slice()),
+            IterDirection::Reverse,
+        );
+
+        // Then
+        assert_eq!(result, vec![vec![2, 2], vec![2, 1]]);
+    }
+
+    #[test]
+    fn iter_store__iterates_over_more_entries_than_one_batch() {
+        // Given
+        let mut db = create_db();
+        let value = Value::from([]);
+        let count = ITERATION_BATCH_SIZE * 2 + 1;
+        let mut expected = Vec::with_capacity(count);
+        for i in 0..count as u32 {
+            let key = i.to_be_bytes().to_vec();
+            db.put(&key, Column::Metadata, value.clone()).unwrap();
+            expected.push(key);
+        }
+
+        // When
+        let forward = keys(&db, None, None, IterDirection::Forward);
+        let reverse = keys(&db, None, None, IterDirection::Reverse);
+
+        // Then
+        assert_eq!(forward, expected);
+        expected.reverse();
+        assert_eq!(reverse, expected);
+    }
+
+    #[test]
+    fn reopen__reads_data_written_before() {
+        // Given
+        let tmp_dir = TempDir::new().unwrap();
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Value::from([1, 2, 3]);
+        let mut db = Redb::<OnChain>::default_open(tmp_dir.path(), None).unwrap();
+        db.put(&key, Column::Metadata, value.clone()).unwrap();
+        drop(db);
+
+        // When
+        let db = Redb::<OnChain>::default_open(tmp_dir.path(), None).unwrap();
+
+        // Then
+        assert_eq!(db.get(&key, Column::Metadata).unwrap(), Some(value));
+    }
+
+    #[test]
+    fn checkpoint__copies_all_tables() {
+        // Given
+        let mut db = create_db();
+        let checkpoint_dir = TempDir::new().unwrap();
+        let value = Value::from([1, 2, 3]);
+        db.put(&[1], Column::Metadata, value.clone()).unwrap();
+        db.put(&[2], Column::Coins, value.clone()).unwrap();
+
+        // When
+        db.checkpoint(checkpoint_dir.path()).unwrap();
+
+        // Then
+        let restored =
+            Redb::<OnChain>::default_open(checkpoint_dir.path(), None).unwrap();
+        assert_eq!(
+            restored.get(&[1], Column::Metadata).unwrap(),
+            Some(value.clone())
+        );
+        assert_eq!(restored.get(&[2], Column::Coins).unwrap(), Some(value));
+    }
+
+    #[test]
+    fn historical_redb__view_at_height_returns_old_value() {
+        // Given
+        let historical_db = create_historical_db();
+        for (height, balance) in [(1u32, 123u64), (2, 321)] {
+            let mut transaction = historical_db.read_transaction();
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&key(), &balance)
+                .unwrap();
+            historical_db
+                .commit_changes(Some(height.into()), transaction.into_changes())
+                .unwrap();
+        }
+
+        // When
+        let view = historical_db
+            .view_at_height(&1u32.into())
+            .unwrap()
+            .into_transaction();
+        let latest_view = historical_db.latest_view().into_transaction();
+
+        // Then
+        let old_balance = view
+            .storage_as_ref::<ContractsAssets>()
+            .get(&key())
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        let latest_balance = latest_view
+            .storage_as_ref::<ContractsAssets>()
+            .get(&key())
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(old_balance, 123);
+        assert_eq!(latest_balance, 321);
+    }
+
+    #[test]
+    fn historical_redb__rollback_block_to_restores_old_value() {
+        // Given
+        let historical_db = create_historical_db();
+        for (height, balance) in [(1u32, 123u64), (2, 321), (3, 456)] {
+            let mut transaction = historical_db.read_transaction();
+            transaction
+                .storage_as_mut::<ContractsAssets>()
+                .insert(&key(), &balance)
+                .unwrap();
+            historical_db
+                .commit_changes(Some(height.into()), transaction.into_changes())
+                .unwrap();
+        }
+
+        // When
+        TransactableStorage::rollback_block_to(&historical_db, &1u32.into()).unwrap();
+
+        // Then
+        let balance = historical_db
+            .read_transaction()
+            .storage_as_ref::<ContractsAssets>()
+            .get(&key())
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        assert_eq!(balance, 123);
+    }
+}
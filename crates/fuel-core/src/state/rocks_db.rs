@@ -5,14 +5,24 @@ use crate::{
         Error as DatabaseError,
         Result as DatabaseResult,
     },
-    state::IterDirection,
+    state::{
+        next_prefix,
+        IterDirection,
+    },
 };
 
 use super::rocks_db_key_iterator::{
     ExtractItem,
     RocksDBKeyIterator,
 };
-use fuel_core_metrics::core_metrics::DatabaseMetrics;
+use config::{
+    ColumnConfig,
+    RocksDbConfig,
+};
+use fuel_core_metrics::core_metrics::{
+    ColumnMetrics,
+    DatabaseMetrics,
+};
 use fuel_core_storage::{
     iter::{
         BoxedIter,
@@ -33,6 +43,7 @@ use fuel_core_storage::{
 };
 use itertools::Itertools;
 use rocksdb::{
+    checkpoint::Checkpoint,
     BlockBasedOptions,
     BoundColumnFamily,
     Cache,
@@ -50,7 +61,10 @@ use rocksdb::{
 };
 use std::{
     cmp,
-    collections::BTreeMap,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
     fmt,
     fmt::Formatter,
     iter,
@@ -62,9 +76,15 @@ use std::{
         Arc,
         Mutex,
     },
+    time::{
+        Duration,
+        Instant,
+    },
 };
 use tempfile::TempDir;
 
+pub mod config;
+
 type DB = DBWithThreadMode<MultiThreaded>;
 
 type DropFn = Box<dyn FnOnce() + Send + Sync>;
@@ -133,6 +153,7 @@ where
             enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
             capacity,
             512,
+            &RocksDbConfig::default(),
         );
         let mut db = result?;
 
@@ -153,15 +174,28 @@ where
         path: P,
         capacity: Option<usize>,
         max_fds: i32,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self> {
         Self::open(
             path,
             enum_iterator::all::<Description::Column>().collect::<Vec<_>>(),
             capacity,
             max_fds,
+            config,
         )
     }
 
+    /// Creates the checkpoint of the database in the `path`. The checkpoint uses
+    /// the same layout as the [`RocksDb::open`], so it can be opened as a regular
+    /// database. The files are hard-linked when the `path` is on the same filesystem.
+    pub fn checkpoint(&self, path: &Path) -> DatabaseResult<()> {
+        let path = path.join(Description::name());
+        Checkpoint::new(&self.db)
+            .and_then(|checkpoint| checkpoint.create_checkpoint(path))
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        Ok(())
+    }
+
     pub fn prune(path: &Path) -> DatabaseResult<()> {
         let path = path.join(Description::name());
         DB::destroy(&Options::default(), path)
@@ -174,8 +208,16 @@ where
         columns: Vec<Description::Column>,
         capacity: Option<usize>,
         max_fds: i32,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self> {
-        Self::open_with(DB::open_cf_descriptors, path, columns, capacity, max_fds)
+        Self::open_with(
+            DB::open_cf_descriptors,
+            path,
+            columns,
+            capacity,
+            max_fds,
+            config,
+        )
     }
 
     pub fn open_read_only<P: AsRef<Path>>(
@@ -184,6 +226,7 @@ where
         capacity: Option<usize>,
         error_if_log_file_exist: bool,
         max_fds: i32,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self> {
         Self::open_with(
             |options, primary_path, cfs| {
@@ -198,6 +241,7 @@ where
             columns,
             capacity,
             max_fds,
+            config,
         )
     }
 
@@ -207,6 +251,7 @@ where
         columns: Vec<Description::Column>,
         capacity: Option<usize>,
         max_fds: i32,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self>
     where
         PrimaryPath: AsRef<Path>,
@@ -225,6 +270,7 @@ where
             columns,
             capacity,
             max_fds,
+            config,
         )
     }
 
@@ -234,6 +280,7 @@ where
         columns: Vec<Description::Column>,
         capacity: Option<usize>,
         max_fds: i32,
+        config: &RocksDbConfig,
     ) -> DatabaseResult<Self>
     where
         F: Fn(
@@ -253,26 +300,51 @@ where
             Description::name().as_str(),
             &metric_columns,
         ));
-        let mut block_opts = BlockBasedOptions::default();
-        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
-        block_opts.set_format_version(5);
+        let database_config = config.database(&Description::name());
+        let capacity = capacity.map(|capacity| database_config.cache_capacity(capacity));
+        // Set cache size 1/3 of the capacity as recommended by
+        // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
+        let block_cache_size = capacity.map(|capacity| capacity / 3);
+        let column_configs = columns
+            .iter()
+            .map(|column| {
+                let column_config = config.column(&Description::name(), &column.name());
+                (column.id(), column_config)
+            })
+            .collect::<BTreeMap<_, _>>();
+        // The columns with the reserved part of the block cache don't use the shared cache.
+        let shared_block_cache = block_cache_size.map(|block_cache_size| {
+            let reserved = column_configs
+                .values()
+                .filter_map(|config| config.block_cache_capacity(block_cache_size))
+                .fold(0usize, |sum, size| sum.saturating_add(size));
+            Cache::new_lru_cache(block_cache_size.saturating_sub(reserved))
+        });
+        let block_opts = |column_config: &ColumnConfig| {
+            let mut block_opts = BlockBasedOptions::default();
+            // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
+            block_opts.set_format_version(5);
 
-        if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity as recommended by
-            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let block_cache_size = capacity / 3;
-            let cache = Cache::new_lru_cache(block_cache_size);
-            block_opts.set_block_cache(&cache);
-            // "index and filter blocks will be stored in block cache, together with all other data blocks."
-            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
-            block_opts.set_cache_index_and_filter_blocks(true);
-            // Don't evict L0 filter/index blocks from the cache
-            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        } else {
-            block_opts.disable_cache();
-        }
-        block_opts.set_bloom_filter(10.0, true);
-        block_opts.set_block_size(16 * 1024);
+            if let (Some(block_cache_size), Some(shared_block_cache)) =
+                (block_cache_size, &shared_block_cache)
+            {
+                match column_config.block_cache_capacity(block_cache_size) {
+                    Some(reserved) => {
+                        block_opts.set_block_cache(&Cache::new_lru_cache(reserved))
+                    }
+                    None => block_opts.set_block_cache(shared_block_cache),
+                }
+                // "index and filter blocks will be stored in block cache, together with all other data blocks."
+                // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
+                block_opts.set_cache_index_and_filter_blocks(true);
+                // Don't evict L0 filter/index blocks from the cache
+                block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
+            } else {
+                block_opts.disable_cache();
+            }
+            column_config.apply_to_block(&mut block_opts);
+            block_opts
+        };
 
         let mut opts = Options::default();
         opts.set_compression_type(DBCompressionType::Lz4);
@@ -290,7 +362,7 @@ where
             let cache = Cache::new_lru_cache(row_cache_size);
             opts.set_row_cache(&cache);
         }
-        opts.set_max_background_jobs(6);
+        database_config.apply(&mut opts);
         opts.set_bytes_per_sync(1048576);
         opts.set_max_open_files(max_fds);
 
@@ -300,7 +372,8 @@ where
         let mut cf_descriptors_to_create = BTreeMap::new();
         for column in columns.clone() {
             let column_name = Self::col_name(column.id());
-            let opts = Self::cf_opts(column, &block_opts);
+            let column_config = &column_configs[&column.id()];
+            let opts = Self::cf_opts(column, column_config, &block_opts(column_config));
             if existing_column_families.contains(&column_name) {
                 cf_descriptors_to_open.insert(column_name, opts);
             } else {
@@ -319,7 +392,10 @@ where
                     && !cf_descriptors_to_create.contains_key(*column_name)
             })
             .map(|unknown_column_name| {
-                let unknown_column_options = Self::default_opts(&block_opts);
+                let column_config =
+                    config.column(&Description::name(), unknown_column_name);
+                let unknown_column_options =
+                    Self::default_opts(&column_config, &block_opts(&column_config));
                 (unknown_column_name.clone(), unknown_column_options)
             })
             .collect();
@@ -453,17 +529,24 @@ where
         format!("col-{}", column)
     }
 
-    fn default_opts(block_opts: &BlockBasedOptions) -> Options {
+    fn default_opts(
+        column_config: &ColumnConfig,
+        block_opts: &BlockBasedOptions,
+    ) -> Options {
         let mut opts = Options::default();
         opts.create_if_missing(true);
-        opts.set_compression_type(DBCompressionType::Lz4);
+        column_config.apply(&mut opts);
         opts.set_block_based_table_factory(block_opts);
 
         opts
     }
 
-    fn cf_opts(column: Description::Column, block_opts: &BlockBasedOptions) -> Options {
-        let mut opts = Self::default_opts(block_opts);
+    fn cf_opts(
+        column: Description::Column,
+        column_config: &ColumnConfig,
+        block_opts: &BlockBasedOptions,
+    ) -> Options {
+        let mut opts = Self::default_opts(column_config, block_opts);
 
         // All double-keys should be configured here
         if let Some(size) = Description::prefix(&column) {
@@ -535,20 +618,20 @@ where
     where
         T: ExtractItem,
     {
-        let column_metrics = self.metrics.columns_read_statistic.get(&column.id());
+        let column_metrics = self.metrics.columns.get(&column.id());
+        column_metrics.map(|metrics| metrics.iterations.inc());
 
-        RocksDBKeyIterator::<_, T>::new(
+        let iterator = RocksDBKeyIterator::<_, T>::new(
             self.db.raw_iterator_cf_opt(&self.cf(column), opts),
             iter_mode,
         )
         .map(move |item| {
             item.inspect(|item| {
-                self.metrics.read_meter.inc();
-                column_metrics.map(|metric| metric.inc());
-                self.metrics.bytes_read.inc_by(T::size(item));
+                self.record_read(column.id(), T::size(item));
             })
             .map_err(|e| DatabaseError::Other(e.into()).into())
-        })
+        });
+        TimedIterator::new(iterator, column_metrics)
     }
 
     pub fn multi_get<K, I>(
@@ -560,19 +643,15 @@ where
         I: Iterator<Item = K>,
         K: AsRef<[u8]>,
     {
-        let column_metrics = self.metrics.columns_read_statistic.get(&column);
         let cl = self.cf_u32(column);
         let results = self
             .db
             .multi_get_cf_opt(iterator.map(|k| (&cl, k)), &self.read_options)
             .into_iter()
             .map(|el| {
-                self.metrics.read_meter.inc();
-                column_metrics.map(|metric| metric.inc());
-                el.map(|value| {
-                    value.inspect(|vec| {
-                        self.metrics.bytes_read.inc_by(vec.len() as u64);
-                    })
+                el.inspect(|value| {
+                    let bytes = value.as_ref().map_or(0, |vec| vec.len());
+                    self.record_read(column, bytes as u64);
                 })
                 .map_err(|err| DatabaseError::Other(err.into()))
             })
@@ -727,30 +806,27 @@ where
         key: &[u8],
         column: Self::Column,
     ) -> StorageResult<Option<usize>> {
-        self.metrics.read_meter.inc();
-        let column_metrics = self.metrics.columns_read_statistic.get(&column.id());
-        column_metrics.map(|metric| metric.inc());
-
-        Ok(self
+        let instant = Instant::now();
+        let size = self
             .db
             .get_pinned_cf_opt(&self.cf(column), key, &self.read_options)
             .map_err(|e| DatabaseError::Other(e.into()))?
-            .map(|value| value.len()))
+            .map(|value| value.len());
+        self.record_read(column.id(), 0);
+        self.record_read_time(column.id(), instant);
+
+        Ok(size)
     }
 
     fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
-        self.metrics.read_meter.inc();
-        let column_metrics = self.metrics.columns_read_statistic.get(&column.id());
-        column_metrics.map(|metric| metric.inc());
-
+        let instant = Instant::now();
         let value = self
             .db
             .get_cf_opt(&self.cf(column), key, &self.read_options)
             .map_err(|e| DatabaseError::Other(e.into()))?;
-
-        if let Some(value) = &value {
-            self.metrics.bytes_read.inc_by(value.len() as u64);
-        }
+        let bytes = value.as_ref().map_or(0, |value| value.len());
+        self.record_read(column.id(), bytes as u64);
+        self.record_read_time(column.id(), instant);
 
         Ok(value.map(Arc::from))
     }
@@ -762,10 +838,7 @@ where
         offset: usize,
         buf: &mut [u8],
     ) -> StorageResult<Option<usize>> {
-        self.metrics.read_meter.inc();
-        let column_metrics = self.metrics.columns_read_statistic.get(&column.id());
-        column_metrics.map(|metric| metric.inc());
-
+        let instant = Instant::now();
         let r = self
             .db
             .get_pinned_cf_opt(&self.cf(column), key, &self.read_options)
@@ -789,9 +862,8 @@ where
             })
             .transpose()?;
 
-        if let Some(r) = &r {
-            self.metrics.bytes_read.inc_by(*r as u64);
-        }
+        self.record_read(column.id(), r.unwrap_or_default() as u64);
+        self.record_read_time(column.id(), instant);
 
         Ok(r)
     }
@@ -827,18 +899,20 @@ where
     Description: DatabaseDescription,
 {
     pub fn commit_changes(&self, changes: &Changes) -> StorageResult<()> {
-        let instant = std::time::Instant::now();
+        let instant = Instant::now();
         let mut batch = WriteBatch::default();
 
         for (column, ops) in changes {
             let cf = self.cf_u32(*column);
-            let column_metrics = self.metrics.columns_write_statistic.get(column);
+            let column_metrics = self.metrics.columns.get(column);
             for (key, op) in ops {
                 self.metrics.write_meter.inc();
-                column_metrics.map(|metric| metric.inc());
+                column_metrics.map(|metrics| metrics.writes.inc());
                 match op {
                     WriteOperation::Insert(value) => {
-                        self.metrics.bytes_written.inc_by(value.len() as u64);
+                        let bytes = value.len() as u64;
+                        self.metrics.bytes_written.inc_by(bytes);
+                        column_metrics.map(|metrics| metrics.bytes_written.inc_by(bytes));
                         batch.put_cf(&cf, key, value.as_ref());
                     }
                     WriteOperation::Remove => {
@@ -851,25 +925,142 @@ where
         self.db
             .write(batch)
             .map_err(|e| DatabaseError::Other(e.into()))?;
+        let elapsed = instant.elapsed();
         // TODO: Use `u128` when `AtomicU128` is stable.
         self.metrics.database_commit_time.inc_by(
-            u64::try_from(instant.elapsed().as_nanos())
+            u64::try_from(elapsed.as_nanos())
                 .expect("The commit shouldn't take longer than `u64`"),
         );
+        for column in changes.keys() {
+            if let Some(metrics) = self.metrics.columns.get(column) {
+                metrics.commit_time.observe(elapsed.as_secs_f64());
+            }
+        }
 
         Ok(())
     }
+
+    fn record_read(&self, column: u32, bytes: u64) {
+        self.metrics.read_meter.inc();
+        self.metrics.bytes_read.inc_by(bytes);
+        if let Some(metrics) = self.metrics.columns.get(&column) {
+            metrics.reads.inc();
+            metrics.bytes_read.inc_by(bytes);
+        }
+    }
+
+    fn record_read_time(&self, column: u32, started: Instant) {
+        if let Some(metrics) = self.metrics.columns.get(&column) {
+            let elapsed = u64::try_from(started.elapsed().as_nanos()).unwrap_or(u64::MAX);
+            metrics.read_time.inc_by(elapsed);
+        }
+    }
+
+    /// Returns the metrics of the database with the up-to-date size of the columns.
+    pub fn metrics(&self) -> DatabaseResult<Arc<DatabaseMetrics>> {
+        self.refresh_metrics()?;
+        Ok(self.metrics.clone())
+    }
+
+    /// Updates the metrics of the size of the columns and of the block cache
+    /// from the properties and the statistics of the RocksDB.
+    fn refresh_metrics(&self) -> DatabaseResult<()> {
+        let mut sst_files = HashMap::<String, u64>::new();
+        for file in self
+            .db
+            .live_files()
+            .map_err(|e| DatabaseError::Other(e.into()))?
+        {
+            let count = sst_files.entry(file.column_family_name).or_default();
+            *count = count.saturating_add(1);
+        }
+
+        for (column, metrics) in &self.metrics.columns {
+            let name = Self::col_name(*column);
+            // The column family is created on the first write.
+            let Some(cf) = self.db.cf_handle(&name) else {
+                continue
+            };
+            let property = |property| {
+                self.db
+                    .property_int_value_cf(&cf, property)
+                    .map(Option::unwrap_or_default)
+                    .map_err(|e| DatabaseError::Other(e.into()))
+            };
+            metrics.set_size(
+                property(rocksdb::properties::ESTIMATE_LIVE_DATA_SIZE)?,
+                sst_files.get(&name).copied().unwrap_or_default(),
+                property(rocksdb::properties::ESTIMATE_PENDING_COMPACTION_BYTES)?,
+            );
+        }
+
+        // The statistics are available only if they are enabled in the `RocksDbConfig`.
+        let statistics = self
+            .db
+            .property_value(rocksdb::properties::OPTIONS_STATISTICS)
+            .map_err(|e| DatabaseError::Other(e.into()))?;
+        if let Some(statistics) = statistics {
+            let hits = ticker_count(&statistics, BLOCK_CACHE_HIT_TICKER);
+            let misses = ticker_count(&statistics, BLOCK_CACHE_MISS_TICKER);
+            if let (Some(hits), Some(misses)) = (hits, misses) {
+                self.metrics.set_block_cache_usage(hits, misses);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const BLOCK_CACHE_HIT_TICKER: &str = "rocksdb.block.cache.hit";
+const BLOCK_CACHE_MISS_TICKER: &str = "rocksdb.block.cache.miss";
+
+/// Returns the value of the `ticker` from the statistics of the RocksDB.
+/// Each ticker is a line of the statistics in the form `<ticker> COUNT : <value>`.
+fn ticker_count(statistics: &str, ticker: &str) -> Option<u64> {
+    statistics.lines().find_map(|line| {
+        let value = line.strip_prefix(ticker)?.strip_prefix(" COUNT : ")?;
+        value.trim().parse().ok()
+    })
+}
+
+/// The iterator that reports the time spent in all calls of the `next`
+/// into the metrics of the column when it is dropped.
+struct TimedIterator<'a, I> {
+    iterator: I,
+    metrics: Option<&'a ColumnMetrics>,
+    elapsed: Duration,
+}
+
+impl<'a, I> TimedIterator<'a, I> {
+    fn new(iterator: I, metrics: Option<&'a ColumnMetrics>) -> Self {
+        Self {
+            iterator,
+            metrics,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+impl<I> Iterator for TimedIterator<'_, I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let instant = Instant::now();
+        let item = self.iterator.next();
+        self.elapsed = self.elapsed.saturating_add(instant.elapsed());
+        item
+    }
 }
 
-/// The `None` means overflow, so there is not following prefix.
-fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
-    for byte in prefix.iter_mut().rev() {
-        if let Some(new_byte) = byte.checked_add(1) {
-            *byte = new_byte;
-            return Some(prefix);
+impl<I> Drop for TimedIterator<'_, I> {
+    fn drop(&mut self) {
+        if let Some(metrics) = self.metrics {
+            metrics.iteration_time.observe(self.elapsed.as_secs_f64());
         }
     }
-    None
 }
 
 #[cfg(feature = "test-helpers")]
@@ -926,7 +1117,8 @@ mod tests {
     fn create_db() -> (RocksDb<OnChain>, TempDir) {
         let tmp_dir = TempDir::new().unwrap();
         (
-            RocksDb::default_open(tmp_dir.path(), None, 512).unwrap(),
+            RocksDb::default_open(tmp_dir.path(), None, 512, &Default::default())
+                .unwrap(),
             tmp_dir,
         )
     }
@@ -938,17 +1130,28 @@ mod tests {
         // Given
         let old_columns =
             vec![Column::Coins, Column::Messages, Column::UploadedBytecodes];
-        let database_with_old_columns =
-            RocksDb::<OnChain>::open(tmp_dir.path(), old_columns.clone(), None, 512)
-                .expect("Failed to open database with old columns");
+        let database_with_old_columns = RocksDb::<OnChain>::open(
+            tmp_dir.path(),
+            old_columns.clone(),
+            None,
+            512,
+            &Default::default(),
+        )
+        .expect("Failed to open database with old columns");
         drop(database_with_old_columns);
 
         // When
         let mut new_columns = old_columns;
         new_columns.push(Column::ContractsAssets);
         new_columns.push(Column::Metadata);
-        let database_with_new_columns =
-            RocksDb::<OnChain>::open(tmp_dir.path(), new_columns, None, 512).map(|_| ());
+        let database_with_new_columns = RocksDb::<OnChain>::open(
+            tmp_dir.path(),
+            new_columns,
+            None,
+            512,
+            &Default::default(),
+        )
+        .map(|_| ());
 
         // Then
         assert_eq!(Ok(()), database_with_new_columns);
@@ -1117,7 +1320,13 @@ mod tests {
         // When
         let columns = enum_iterator::all::<<OnChain as DatabaseDescription>::Column>()
             .collect::<Vec<_>>();
-        let result = RocksDb::<OnChain>::open(tmp_dir.path(), columns, None, 512);
+        let result = RocksDb::<OnChain>::open(
+            tmp_dir.path(),
+            columns,
+            None,
+            512,
+            &Default::default(),
+        );
 
         // Then
         assert!(result.is_err());
@@ -1137,6 +1346,7 @@ mod tests {
             None,
             false,
             512,
+            &Default::default(),
         )
         .map(|_| ());
 
@@ -1159,6 +1369,7 @@ mod tests {
             old_columns.clone(),
             None,
             512,
+            &Default::default(),
         )
         .map(|_| ());
 
@@ -1250,14 +1461,80 @@ mod tests {
             enum_iterator::all::<<OnChain as DatabaseDescription>::Column>()
                 .skip(1)
                 .collect::<Vec<_>>();
-        let open_with_part_of_columns =
-            RocksDb::<OnChain>::open(tmp_dir.path(), part_of_columns, None, 512);
+        let open_with_part_of_columns = RocksDb::<OnChain>::open(
+            tmp_dir.path(),
+            part_of_columns,
+            None,
+            512,
+            &Default::default(),
+        );
 
         // Then
         let _ = open_with_part_of_columns
             .expect("Should open the database with shorter number of columns");
     }
 
+    #[test]
+    fn open__with_tuned_columns_reads_data_written_before_reopening() {
+        let tmp_dir = TempDir::new().unwrap();
+        let config: RocksDbConfig = toml::from_str(
+            r#"
+            [default]
+            max_background_jobs = 2
+
+            [default.columns."*"]
+            compression = { type = "none" }
+
+            [databases.on_chain]
+            cache_percent = 50
+
+            [databases.on_chain.columns.ContractsState]
+            compression = { type = "zstd", level = 1, max_dictionary_bytes = 4096 }
+            block_size = 4096
+            bloom_filter_bits = 0
+            block_cache_percent = 20
+
+            [databases.on_chain.columns.Coins]
+            compaction_style = "universal"
+            "#,
+        )
+        .unwrap();
+        let columns = enum_iterator::all::<<OnChain as DatabaseDescription>::Column>()
+            .collect::<Vec<_>>();
+        let key = vec![0xA, 0xB, 0xC];
+        let value = Value::from([1, 2, 3]);
+
+        // Given
+        let mut db = RocksDb::<OnChain>::open(
+            tmp_dir.path(),
+            columns.clone(),
+            Some(1024 * 1024),
+            512,
+            &config,
+        )
+        .unwrap();
+        db.put(&key, Column::ContractsState, value.clone()).unwrap();
+        db.put(&key, Column::Coins, value.clone()).unwrap();
+        drop(db);
+
+        // When
+        let db = RocksDb::<OnChain>::open(
+            tmp_dir.path(),
+            columns,
+            Some(1024 * 1024),
+            512,
+            &config,
+        )
+        .unwrap();
+
+        // Then
+        assert_eq!(
+            db.get(&key, Column::ContractsState).unwrap().unwrap(),
+            value
+        );
+        assert_eq!(db.get(&key, Column::Coins).unwrap().unwrap(), value);
+    }
+
     #[test]
     fn iter_store__reverse_iterator__no_target_prefix() {
         // Given
@@ -1369,4 +1646,48 @@ mod tests {
         // Then
         assert_eq!(db_iter, vec![Ok(key_3.to_vec()), Ok(key_2.to_vec())]);
     }
+
+    #[test]
+    fn metrics__reports_reads_and_writes_of_the_column() {
+        // Given
+        let (mut db, _tmp) = create_db();
+        let value = Value::from([1, 2, 3]);
+        db.put(&[1], Column::Metadata, value.clone()).unwrap();
+
+        // When
+        db.get(&[1], Column::Metadata).unwrap();
+        let _ = db
+            .iter_store(Column::Metadata, None, None, IterDirection::Forward)
+            .collect::<Vec<_>>();
+
+        // Then
+        let stats = db.metrics().unwrap().stats();
+        let metadata = stats
+            .columns
+            .iter()
+            .find(|column| column.name == Column::Metadata.name())
+            .unwrap();
+        assert_eq!(metadata.writes, 1);
+        assert_eq!(metadata.bytes_written, 3);
+        assert_eq!(metadata.reads, 2);
+        assert_eq!(metadata.iterations, 1);
+    }
+
+    #[test]
+    fn ticker_count__parses_the_count_of_the_ticker() {
+        // Given
+        let statistics = "rocksdb.block.cache.miss COUNT : 12\n\
+                          rocksdb.block.cache.hit COUNT : 34\n\
+                          rocksdb.block.cache.hit.rate COUNT : 1\n";
+
+        // When
+        let hits = ticker_count(statistics, "rocksdb.block.cache.hit");
+        let misses = ticker_count(statistics, "rocksdb.block.cache.miss");
+        let absent = ticker_count(statistics, "rocksdb.block.cache.add");
+
+        // Then
+        assert_eq!(hits, Some(34));
+        assert_eq!(misses, Some(12));
+        assert_eq!(absent, None);
+    }
 }
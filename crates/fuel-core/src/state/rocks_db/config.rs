@@ -0,0 +1,505 @@
+//! The tuning of the RocksDB databases and their columns.
+//!
+//! The settings are loaded from a TOML or JSON file. The `default` section applies
+//! to all databases, while the `databases` section overrides it for the database
+//! with the given name: `on_chain`, `off_chain`, `relayer` or `gas_price`.
+//! The settings of the columns are selected by the name of the column. The name
+//! ending with `*` is a pattern that matches all columns with the same prefix.
+//! The duplicates of the columns used by the state history are named
+//! `history_<column>`, and the history itself is `modifications_history_v2`.
+//!
+//! ```toml
+//! [default]
+//! max_background_jobs = 4
+//!
+//! [default.columns."*"]
+//! compression = { type = "lz4" }
+//!
+//! [databases.on_chain]
+//! cache_percent = 50
+//! statistics = true
+//!
+//! [databases.on_chain.columns.ContractsState]
+//! compression = { type = "zstd", level = 3, max_dictionary_bytes = 16384 }
+//! block_size = 4096
+//! bloom_filter_bits = 16
+//!
+//! [databases.on_chain.columns."history_*"]
+//! compaction_style = "universal"
+//! block_cache_percent = 10
+//! ```
+
+use anyhow::Context;
+use rocksdb::{
+    BlockBasedOptions,
+    DBCompactionStyle,
+    DBCompressionType,
+    Options,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::BTreeMap,
+    path::Path,
+};
+
+/// The default size of the data block in bytes.
+pub const DEFAULT_BLOCK_SIZE: usize = 16 * 1024;
+/// The default number of bits per key of the bloom filter.
+pub const DEFAULT_BLOOM_FILTER_BITS: u32 = 10;
+/// The default number of the background jobs of the database.
+pub const DEFAULT_MAX_BACKGROUND_JOBS: i32 = 6;
+
+/// The window bits of the zstd compression used by RocksDB by default.
+const ZSTD_WINDOW_BITS: i32 = -14;
+/// The level of the zstd compression used when the level is not specified.
+const DEFAULT_ZSTD_LEVEL: i32 = 3;
+/// RocksDB recommends to train the dictionary on 100 times more data than its size.
+const ZSTD_TRAINING_RATIO: i32 = 100;
+
+/// The tuning of all RocksDB databases.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RocksDbConfig {
+    /// The settings applied to all databases.
+    pub default: DatabaseConfig,
+    /// The settings of the specific databases by the name of the database.
+    pub databases: BTreeMap<String, DatabaseConfig>,
+}
+
+/// The tuning of one RocksDB database.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseConfig {
+    /// The percent of the `max_database_cache_size` used by the database.
+    pub cache_percent: Option<u8>,
+    /// The maximum number of the concurrent flushes and compactions.
+    pub max_background_jobs: Option<i32>,
+    /// Collects the statistics of the database, like the block cache hit rate.
+    /// The statistics slow down the database by a few percent, so they are disabled by default.
+    pub statistics: Option<bool>,
+    /// The settings of the columns by the name of the column or by the pattern.
+    pub columns: BTreeMap<String, ColumnConfig>,
+}
+
+/// The tuning of one column of the database.
+/// The settings that are not specified are inherited from the less specific sections.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ColumnConfig {
+    /// The compression of the data blocks.
+    pub compression: Option<Compression>,
+    /// The size of the data block in bytes.
+    pub block_size: Option<usize>,
+    /// The percent of the block cache of the database reserved for the column.
+    /// The column without it uses the block cache shared with other columns.
+    pub block_cache_percent: Option<u8>,
+    /// The number of bits per key of the bloom filter. Zero disables the filter.
+    pub bloom_filter_bits: Option<u32>,
+    /// The style of the compaction.
+    pub compaction_style: Option<CompactionStyle>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Compression {
+    None,
+    Lz4,
+    Zstd {
+        /// The level of the compression.
+        level: Option<i32>,
+        /// The maximum size of the dictionary trained on the data of the column.
+        /// The dictionary is not used if it is not specified.
+        max_dictionary_bytes: Option<i32>,
+    },
+}
+
+/// The FIFO compaction is not supported because it deletes the oldest data
+/// of the column, while all databases of the node should keep the whole state.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompactionStyle {
+    Level,
+    Universal,
+}
+
+impl RocksDbConfig {
+    /// Loads the config from the file. The file with the `json` extension
+    /// is parsed as JSON, any other file is parsed as TOML.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path).with_context(|| {
+            format!("Failed to read the RocksDB config from {path:?}")
+        })?;
+        let config: Self = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_str(&content)?,
+            _ => toml::from_str(&content)?,
+        };
+        config
+            .validate()
+            .with_context(|| format!("Invalid RocksDB config in {path:?}"))?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let databases = core::iter::once(("default", &self.default)).chain(
+            self.databases
+                .iter()
+                .map(|(name, config)| (name.as_str(), config)),
+        );
+        for (name, database) in databases {
+            validate_percent(database.cache_percent).with_context(|| {
+                format!("The `cache_percent` of the `{name}` database")
+            })?;
+            for (column, config) in &database.columns {
+                config.validate().with_context(|| {
+                    format!("The `{column}` column of the `{name}` database")
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the settings of the database with the `name`.
+    pub fn database(&self, name: &str) -> DatabaseConfig {
+        let specific = self.databases.get(name);
+        DatabaseConfig {
+            cache_percent: specific
+                .and_then(|config| config.cache_percent)
+                .or(self.default.cache_percent),
+            max_background_jobs: specific
+                .and_then(|config| config.max_background_jobs)
+                .or(self.default.max_background_jobs),
+            statistics: specific
+                .and_then(|config| config.statistics)
+                .or(self.default.statistics),
+            columns: Default::default(),
+        }
+    }
+
+    /// Returns the settings of the `column` of the database with the `name`.
+    /// The database settings override the default settings. Within each of them,
+    /// the exact name of the column overrides the patterns,
+    /// and the longer patterns override the shorter ones.
+    pub fn column(&self, name: &str, column: &str) -> ColumnConfig {
+        let specific = self.databases.get(name);
+        core::iter::once(&self.default)
+            .chain(specific)
+            .flat_map(|database| database.matching_columns(column))
+            .fold(ColumnConfig::default(), |config, other| config.merge(other))
+    }
+}
+
+impl DatabaseConfig {
+    /// Returns the settings matching the `column` from the least to the most specific.
+    fn matching_columns<'a>(
+        &'a self,
+        column: &str,
+    ) -> impl Iterator<Item = &'a ColumnConfig> + 'a {
+        let mut matching = self
+            .columns
+            .iter()
+            .filter_map(|(pattern, config)| {
+                if pattern == column {
+                    Some(((true, pattern.len()), config))
+                } else {
+                    let prefix = pattern.strip_suffix('*')?;
+                    column
+                        .starts_with(prefix)
+                        .then_some(((false, prefix.len()), config))
+                }
+            })
+            .collect::<Vec<_>>();
+        matching.sort_by_key(|(specificity, _)| *specificity);
+        matching.into_iter().map(|(_, config)| config)
+    }
+
+    /// Returns the size of the cache of the database from the total `capacity`.
+    pub fn cache_capacity(&self, capacity: usize) -> usize {
+        match self.cache_percent {
+            Some(percent) => percent_of(capacity, percent),
+            None => capacity,
+        }
+    }
+
+    pub fn apply(&self, opts: &mut Options) {
+        opts.set_max_background_jobs(
+            self.max_background_jobs
+                .unwrap_or(DEFAULT_MAX_BACKGROUND_JOBS),
+        );
+        if self.statistics == Some(true) {
+            opts.enable_statistics();
+        }
+    }
+}
+
+impl ColumnConfig {
+    fn validate(&self) -> anyhow::Result<()> {
+        validate_percent(self.block_cache_percent)
+            .context("The `block_cache_percent`")?;
+        if self.block_size == Some(0) {
+            anyhow::bail!("The `block_size` should be positive");
+        }
+        if let Some(Compression::Zstd {
+            max_dictionary_bytes: Some(bytes),
+            ..
+        }) = &self.compression
+        {
+            if *bytes < 0 {
+                anyhow::bail!("The `max_dictionary_bytes` should not be negative");
+            }
+        }
+        Ok(())
+    }
+
+    /// Overrides the settings of `self` with the settings specified in the `other`.
+    fn merge(self, other: &Self) -> Self {
+        Self {
+            compression: other.compression.clone().or(self.compression),
+            block_size: other.block_size.or(self.block_size),
+            block_cache_percent: other.block_cache_percent.or(self.block_cache_percent),
+            bloom_filter_bits: other.bloom_filter_bits.or(self.bloom_filter_bits),
+            compaction_style: other.compaction_style.or(self.compaction_style),
+        }
+    }
+
+    /// Returns the size of the block cache reserved for the column
+    /// from the block cache of the database.
+    pub fn block_cache_capacity(&self, block_cache_size: usize) -> Option<usize> {
+        self.block_cache_percent
+            .map(|percent| percent_of(block_cache_size, percent))
+    }
+
+    pub fn apply_to_block(&self, block_opts: &mut BlockBasedOptions) {
+        match self.bloom_filter_bits.unwrap_or(DEFAULT_BLOOM_FILTER_BITS) {
+            0 => {}
+            bits => block_opts.set_bloom_filter(f64::from(bits), true),
+        }
+        block_opts.set_block_size(self.block_size.unwrap_or(DEFAULT_BLOCK_SIZE));
+    }
+
+    pub fn apply(&self, opts: &mut Options) {
+        match self.compression.as_ref().unwrap_or(&Compression::Lz4) {
+            Compression::None => opts.set_compression_type(DBCompressionType::None),
+            Compression::Lz4 => opts.set_compression_type(DBCompressionType::Lz4),
+            Compression::Zstd {
+                level,
+                max_dictionary_bytes,
+            } => {
+                opts.set_compression_type(DBCompressionType::Zstd);
+                let max_dictionary_bytes = max_dictionary_bytes.unwrap_or(0);
+                opts.set_compression_options(
+                    ZSTD_WINDOW_BITS,
+                    level.unwrap_or(DEFAULT_ZSTD_LEVEL),
+                    0,
+                    max_dictionary_bytes,
+                );
+                if max_dictionary_bytes > 0 {
+                    opts.set_zstd_max_train_bytes(
+                        max_dictionary_bytes.saturating_mul(ZSTD_TRAINING_RATIO),
+                    );
+                }
+            }
+        }
+
+        if let Some(style) = self.compaction_style {
+            opts.set_compaction_style(match style {
+                CompactionStyle::Level => DBCompactionStyle::Level,
+                CompactionStyle::Universal => DBCompactionStyle::Universal,
+            });
+        }
+    }
+}
+
+fn validate_percent(percent: Option<u8>) -> anyhow::Result<()> {
+    match percent {
+        Some(percent) if percent > 100 => {
+            anyhow::bail!("The percent({percent}) should not exceed 100")
+        }
+        _ => Ok(()),
+    }
+}
+
+fn percent_of(value: usize, percent: u8) -> usize {
+    value.saturating_mul(usize::from(percent)) / 100
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        [default]
+        max_background_jobs = 4
+
+        [default.columns."*"]
+        compression = { type = "none" }
+        block_size = 8192
+
+        [databases.on_chain]
+        cache_percent = 50
+        statistics = true
+
+        [databases.on_chain.columns.ContractsState]
+        compression = { type = "zstd", level = 5, max_dictionary_bytes = 16384 }
+
+        [databases.on_chain.columns."history_*"]
+        compaction_style = "universal"
+        bloom_filter_bits = 0
+
+        [databases.on_chain.columns."history_Contracts*"]
+        block_cache_percent = 10
+    "#;
+
+    #[test]
+    fn column__exact_name_overrides_patterns_and_defaults() {
+        // Given
+        let config: RocksDbConfig = toml::from_str(CONFIG).unwrap();
+
+        // When
+        let column = config.column("on_chain", "ContractsState");
+
+        // Then
+        assert_eq!(
+            column,
+            ColumnConfig {
+                compression: Some(Compression::Zstd {
+                    level: Some(5),
+                    max_dictionary_bytes: Some(16384),
+                }),
+                block_size: Some(8192),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn column__longer_pattern_overrides_shorter_one() {
+        // Given
+        let config: RocksDbConfig = toml::from_str(CONFIG).unwrap();
+
+        // When
+        let column = config.column("on_chain", "history_ContractsState");
+
+        // Then
+        assert_eq!(
+            column,
+            ColumnConfig {
+                compression: Some(Compression::None),
+                block_size: Some(8192),
+                block_cache_percent: Some(10),
+                bloom_filter_bits: Some(0),
+                compaction_style: Some(CompactionStyle::Universal),
+            }
+        );
+    }
+
+    #[test]
+    fn column__other_database_uses_only_defaults() {
+        // Given
+        let config: RocksDbConfig = toml::from_str(CONFIG).unwrap();
+
+        // When
+        let column = config.column("off_chain", "ContractsState");
+
+        // Then
+        assert_eq!(
+            column,
+            ColumnConfig {
+                compression: Some(Compression::None),
+                block_size: Some(8192),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn database__specific_database_overrides_defaults() {
+        // Given
+        let config: RocksDbConfig = toml::from_str(CONFIG).unwrap();
+
+        // When
+        let on_chain = config.database("on_chain");
+        let off_chain = config.database("off_chain");
+
+        // Then
+        assert_eq!(on_chain.cache_percent, Some(50));
+        assert_eq!(on_chain.max_background_jobs, Some(4));
+        assert_eq!(on_chain.statistics, Some(true));
+        assert_eq!(off_chain.cache_percent, None);
+        assert_eq!(off_chain.statistics, None);
+        assert_eq!(off_chain.cache_capacity(1000), 1000);
+        assert_eq!(on_chain.cache_capacity(1000), 500);
+    }
+
+    #[test]
+    fn load__parses_json_and_toml_into_same_config() {
+        // Given
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let config: RocksDbConfig = toml::from_str(CONFIG).unwrap();
+        let toml_path = tmp_dir.path().join("rocksdb.toml");
+        let json_path = tmp_dir.path().join("rocksdb.json");
+        std::fs::write(&toml_path, CONFIG).unwrap();
+        std::fs::write(&json_path, serde_json::to_string(&config).unwrap()).unwrap();
+
+        // When
+        let from_toml = RocksDbConfig::load(&toml_path).unwrap();
+        let from_json = RocksDbConfig::load(&json_path).unwrap();
+
+        // Then
+        assert_eq!(from_toml, config);
+        assert_eq!(from_json, config);
+    }
+
+    #[test]
+    fn load__fails_on_invalid_percent() {
+        // Given
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let path = tmp_dir.path().join("rocksdb.toml");
+        std::fs::write(&path, "[databases.on_chain]\ncache_percent = 150\n").unwrap();
+
+        // When
+        let result = RocksDbConfig::load(&path);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn load__fails_on_unknown_field() {
+        // Given
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let path = tmp_dir.path().join("rocksdb.toml");
+        std::fs::write(&path, "[default.columns.Coins]\nblock_sise = 4096\n").unwrap();
+
+        // When
+        let result = RocksDbConfig::load(&path);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn load__fails_on_fifo_compaction_style() {
+        // Given
+        let tmp_dir = tempfile::TempDir::new().unwrap();
+        let path = tmp_dir.path().join("rocksdb.toml");
+        for database in ["on_chain", "off_chain", "relayer"] {
+            std::fs::write(
+                &path,
+                format!(
+                    "[databases.{database}.columns.\"*\"]\ncompaction_style = \"fifo\"\n"
+                ),
+            )
+            .unwrap();
+
+            // When
+            let result = RocksDbConfig::load(&path);
+
+            // Then
+            assert!(result.is_err(), "FIFO is accepted for {database}");
+        }
+    }
+}
@@ -13,6 +13,7 @@ pub(crate) enum Buckets {
     TransactionInsertionTimeInThreadPool,
     SelectTransactionsTime,
     TransactionTimeInTxpool,
+    DatabaseOperationTime,
 }
 static BUCKETS: OnceLock<HashMap<Buckets, Vec<f64>>> = OnceLock::new();
 pub(crate) fn buckets(b: Buckets) -> impl Iterator<Item = f64> {
@@ -100,6 +101,26 @@ fn initialize_buckets() -> HashMap<Buckets, Vec<f64>> {
                     600.0
             ]
         ),
+        (
+            // The time of the database operations in seconds,
+            // from the reads served by the cache up to the large commits.
+            Buckets::DatabaseOperationTime,
+            vec![
+                0.000_001,
+                0.000_005,
+                0.000_010,
+                0.000_050,
+                0.000_100,
+                0.000_500,
+                0.001,
+                0.005,
+                0.010,
+                0.050,
+                0.100,
+                0.500,
+                1.000,
+            ]
+        ),
     ]
     .into_iter()
     .collect()
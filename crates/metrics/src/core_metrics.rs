@@ -1,52 +1,90 @@
-use crate::global_registry;
-use prometheus_client::metrics::counter::Counter;
-use std::collections::HashMap;
+use crate::{
+    buckets::{
+        buckets,
+        Buckets,
+    },
+    global_registry,
+};
+use prometheus_client::{
+    metrics::{
+        counter::Counter,
+        gauge::Gauge,
+        histogram::Histogram,
+    },
+    registry::Registry,
+};
+use std::{
+    collections::HashMap,
+    sync::atomic::AtomicU64,
+};
 
 #[derive(Debug)]
 pub struct DatabaseMetrics {
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
+    pub name: String,
     pub write_meter: Counter,
     pub read_meter: Counter,
     pub bytes_written: Counter,
     pub bytes_read: Counter,
     pub database_commit_time: Counter,
-    pub columns_read_statistic: HashMap<u32, Counter>,
-    pub columns_write_statistic: HashMap<u32, Counter>,
+    pub block_cache_hits: Gauge,
+    pub block_cache_misses: Gauge,
+    pub block_cache_hit_rate: Gauge<f64, AtomicU64>,
+    pub columns: HashMap<u32, ColumnMetrics>,
+}
+
+/// The metrics of one column of the database.
+#[derive(Debug)]
+pub struct ColumnMetrics {
+    // For descriptions of each metric, see the `new` function where each metric is initialized
+    pub name: String,
+    pub reads: Counter,
+    pub writes: Counter,
+    pub iterations: Counter,
+    pub bytes_read: Counter,
+    pub bytes_written: Counter,
+    pub read_time: Counter,
+    pub commit_time: Histogram,
+    pub iteration_time: Histogram,
+    pub live_data_size: Gauge,
+    pub sst_files: Gauge,
+    pub pending_compaction_bytes: Gauge,
+}
+
+/// The snapshot of the statistics of the database.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DatabaseStats {
+    pub name: String,
+    /// The part of the block reads served by the block cache.
+    /// `None` if the database doesn't collect statistics of the block cache
+    /// or no blocks were read yet.
+    pub block_cache_hit_rate: Option<f64>,
+    pub columns: Vec<ColumnStats>,
+}
+
+/// The snapshot of the statistics of the column.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnStats {
+    pub name: String,
+    pub reads: u64,
+    pub writes: u64,
+    pub iterations: u64,
+    pub bytes_read: u64,
+    pub bytes_written: u64,
+    pub live_data_size: u64,
+    pub sst_files: u64,
+    pub pending_compaction_bytes: u64,
 }
 
 impl DatabaseMetrics {
     pub fn new(name: &str, columns: &[(u32, String)]) -> Self {
         let mut registry = global_registry().registry.lock();
 
-        let columns_read_statistic = columns
-            .iter()
-            .map(|(column_id, column_name)| {
-                let counter: Counter = Counter::default();
-                registry.register(
-                    format!("{}_Column_{}_Reads", name, column_name),
-                    format!(
-                        "Number of {} read operations on column {}",
-                        name, column_name
-                    ),
-                    counter.clone(),
-                );
-                (*column_id, counter)
-            })
-            .collect();
-
-        let columns_write_statistic = columns
+        let columns = columns
             .iter()
             .map(|(column_id, column_name)| {
-                let counter: Counter = Counter::default();
-                registry.register(
-                    format!("{}_Column_{}_Writes", name, column_name),
-                    format!(
-                        "Number of {} write operations on column {}",
-                        name, column_name
-                    ),
-                    counter.clone(),
-                );
-                (*column_id, counter)
+                let metrics = ColumnMetrics::new(&mut registry, name, column_name);
+                (*column_id, metrics)
             })
             .collect();
 
@@ -55,6 +93,9 @@ impl DatabaseMetrics {
         let bytes_written = Counter::default();
         let bytes_read = Counter::default();
         let database_commit_time: Counter = Counter::default();
+        let block_cache_hits = Gauge::default();
+        let block_cache_misses = Gauge::default();
+        let block_cache_hit_rate = Gauge::<f64, AtomicU64>::default();
 
         registry.register(
             format!("{}_Database_Writes", name),
@@ -84,15 +125,295 @@ impl DatabaseMetrics {
             ),
             database_commit_time.clone(),
         );
+        registry.register(
+            format!("{}_Block_Cache_Hits", name),
+            format!("Number of {} block reads served by the block cache", name),
+            block_cache_hits.clone(),
+        );
+        registry.register(
+            format!("{}_Block_Cache_Misses", name),
+            format!("Number of {} block reads that missed the block cache", name),
+            block_cache_misses.clone(),
+        );
+        registry.register(
+            format!("{}_Block_Cache_Hit_Rate", name),
+            format!("The part of {} block reads served by the block cache", name),
+            block_cache_hit_rate.clone(),
+        );
 
         DatabaseMetrics {
+            name: name.to_string(),
             write_meter,
             read_meter,
             bytes_read,
             bytes_written,
             database_commit_time,
-            columns_read_statistic,
-            columns_write_statistic,
+            block_cache_hits,
+            block_cache_misses,
+            block_cache_hit_rate,
+            columns,
+        }
+    }
+
+    /// Updates the block cache metrics with the total number of hits and misses.
+    pub fn set_block_cache_usage(&self, hits: u64, misses: u64) {
+        self.block_cache_hits.set(to_gauge_value(hits));
+        self.block_cache_misses.set(to_gauge_value(misses));
+        if let Some(rate) = hit_rate(hits, misses) {
+            self.block_cache_hit_rate.set(rate);
+        }
+    }
+
+    /// Returns the snapshot of the statistics of the database.
+    /// The columns are sorted by the name.
+    pub fn stats(&self) -> DatabaseStats {
+        let hits = self.block_cache_hits.get();
+        let misses = self.block_cache_misses.get();
+        let block_cache_hit_rate =
+            hit_rate(from_gauge_value(hits), from_gauge_value(misses));
+
+        let mut columns = self
+            .columns
+            .values()
+            .map(ColumnMetrics::stats)
+            .collect::<Vec<_>>();
+        columns.sort_by(|a, b| a.name.cmp(&b.name));
+
+        DatabaseStats {
+            name: self.name.clone(),
+            block_cache_hit_rate,
+            columns,
         }
     }
 }
+
+impl ColumnMetrics {
+    fn new(registry: &mut Registry, database: &str, column: &str) -> Self {
+        let reads = Counter::default();
+        let writes = Counter::default();
+        let iterations = Counter::default();
+        let bytes_read = Counter::default();
+        let bytes_written = Counter::default();
+        let read_time = Counter::default();
+        let commit_time = Histogram::new(buckets(Buckets::DatabaseOperationTime));
+        let iteration_time = Histogram::new(buckets(Buckets::DatabaseOperationTime));
+        let live_data_size = Gauge::default();
+        let sst_files = Gauge::default();
+        let pending_compaction_bytes = Gauge::default();
+
+        registry.register(
+            format!("{}_Column_{}_Reads", database, column),
+            format!(
+                "Number of {} read operations on column {}",
+                database, column
+            ),
+            reads.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Writes", database, column),
+            format!(
+                "Number of {} write operations on column {}",
+                database, column
+            ),
+            writes.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Iterations", database, column),
+            format!("Number of {} iterators over column {}", database, column),
+            iterations.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Bytes_Read", database, column),
+            format!(
+                "The total amount of read bytes from column {} of {}",
+                column, database
+            ),
+            bytes_read.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Bytes_Written", database, column),
+            format!(
+                "The total amount of written bytes into column {} of {}",
+                column, database
+            ),
+            bytes_written.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Read_Time", database, column),
+            format!(
+                "The total time in nanoseconds of {} point reads from column {}",
+                database, column
+            ),
+            read_time.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Commit_Time", database, column),
+            format!(
+                "The time in seconds of the whole {} commits that wrote into column {}",
+                database, column
+            ),
+            commit_time.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Iteration_Time", database, column),
+            format!(
+                "The time in seconds spent by one {} iterator over column {}",
+                database, column
+            ),
+            iteration_time.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Live_Data_Size", database, column),
+            format!(
+                "The estimated size in bytes of the live data of column {} of {}",
+                column, database
+            ),
+            live_data_size.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Sst_Files", database, column),
+            format!(
+                "Number of the SST files of column {} of {}",
+                column, database
+            ),
+            sst_files.clone(),
+        );
+        registry.register(
+            format!("{}_Column_{}_Pending_Compaction_Bytes", database, column),
+            format!(
+                "The estimated number of bytes the compaction of column {} of {} needs to rewrite",
+                column, database
+            ),
+            pending_compaction_bytes.clone(),
+        );
+
+        Self {
+            name: column.to_string(),
+            reads,
+            writes,
+            iterations,
+            bytes_read,
+            bytes_written,
+            read_time,
+            commit_time,
+            iteration_time,
+            live_data_size,
+            sst_files,
+            pending_compaction_bytes,
+        }
+    }
+
+    /// Updates the metrics of the size of the column.
+    pub fn set_size(
+        &self,
+        live_data_size: u64,
+        sst_files: u64,
+        pending_compaction_bytes: u64,
+    ) {
+        self.live_data_size.set(to_gauge_value(live_data_size));
+        self.sst_files.set(to_gauge_value(sst_files));
+        self.pending_compaction_bytes
+            .set(to_gauge_value(pending_compaction_bytes));
+    }
+
+    fn stats(&self) -> ColumnStats {
+        ColumnStats {
+            name: self.name.clone(),
+            reads: self.reads.get(),
+            writes: self.writes.get(),
+            iterations: self.iterations.get(),
+            bytes_read: self.bytes_read.get(),
+            bytes_written: self.bytes_written.get(),
+            live_data_size: from_gauge_value(self.live_data_size.get()),
+            sst_files: from_gauge_value(self.sst_files.get()),
+            pending_compaction_bytes: from_gauge_value(
+                self.pending_compaction_bytes.get(),
+            ),
+        }
+    }
+}
+
+fn hit_rate(hits: u64, misses: u64) -> Option<f64> {
+    let total = hits.saturating_add(misses);
+    if total == 0 {
+        None
+    } else {
+        Some(hits as f64 / total as f64)
+    }
+}
+
+fn to_gauge_value(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+fn from_gauge_value(value: i64) -> u64 {
+    u64::try_from(value).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats__returns_counters_of_columns_sorted_by_name() {
+        // Given
+        let metrics = DatabaseMetrics::new(
+            "core_metrics_test",
+            &[(1, "Coins".to_string()), (0, "Blocks".to_string())],
+        );
+        let coins = &metrics.columns[&1];
+        coins.reads.inc_by(3);
+        coins.bytes_written.inc_by(100);
+        coins.set_size(1024, 2, 512);
+
+        // When
+        let stats = metrics.stats();
+
+        // Then
+        assert_eq!(stats.name, "core_metrics_test");
+        assert_eq!(
+            stats.columns,
+            vec![
+                ColumnStats {
+                    name: "Blocks".to_string(),
+                    ..Default::default()
+                },
+                ColumnStats {
+                    name: "Coins".to_string(),
+                    reads: 3,
+                    bytes_written: 100,
+                    live_data_size: 1024,
+                    sst_files: 2,
+                    pending_compaction_bytes: 512,
+                    ..Default::default()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn stats__block_cache_hit_rate_is_none_without_lookups() {
+        // Given
+        let metrics = DatabaseMetrics::new("core_metrics_empty_cache_test", &[]);
+
+        // When
+        let stats = metrics.stats();
+
+        // Then
+        assert_eq!(stats.block_cache_hit_rate, None);
+    }
+
+    #[test]
+    fn set_block_cache_usage__updates_hit_rate() {
+        // Given
+        let metrics = DatabaseMetrics::new("core_metrics_cache_test", &[]);
+
+        // When
+        metrics.set_block_cache_usage(3, 1);
+
+        // Then
+        assert_eq!(metrics.stats().block_cache_hit_rate, Some(0.75));
+        assert_eq!(metrics.block_cache_hit_rate.get(), 0.75);
+    }
+}
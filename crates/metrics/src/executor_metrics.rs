@@ -0,0 +1,39 @@
+use crate::global_registry;
+use prometheus_client::metrics::counter::Counter;
+use std::sync::OnceLock;
+
+pub struct ExecutorMetrics {
+    pub parity_checks: Counter,
+    pub parity_mismatches: Counter,
+}
+
+impl Default for ExecutorMetrics {
+    fn default() -> Self {
+        let parity_checks = Counter::default();
+        let parity_mismatches = Counter::default();
+
+        let mut registry = global_registry().registry.lock();
+        registry.register(
+            "executor_parity_checks",
+            "The number of blocks executed both natively and in the WASM executor",
+            parity_checks.clone(),
+        );
+
+        registry.register(
+            "executor_parity_mismatches",
+            "The number of blocks where the native and the WASM executors disagree",
+            parity_mismatches.clone(),
+        );
+
+        Self {
+            parity_checks,
+            parity_mismatches,
+        }
+    }
+}
+
+static EXECUTOR_METRICS: OnceLock<ExecutorMetrics> = OnceLock::new();
+
+pub fn executor_metrics() -> &'static ExecutorMetrics {
+    EXECUTOR_METRICS.get_or_init(ExecutorMetrics::default)
+}
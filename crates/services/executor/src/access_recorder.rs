@@ -0,0 +1,157 @@
+//! The module contains the wrapper around the storage that records
+//! the contracts, storage slots and assets accessed by the VM.
+
+use fuel_core_storage::{
+    column::Column,
+    kv_store::{
+        KeyValueInspect,
+        StorageColumn,
+        Value,
+    },
+    transactional::Changes,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        Receipt,
+    },
+    services::executor::AccessList,
+};
+use parking_lot::Mutex as ParkingMutex;
+
+#[cfg(feature = "std")]
+use std::collections::BTreeSet;
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeSet;
+
+#[derive(Default)]
+struct Accesses {
+    contracts: BTreeSet<ContractId>,
+    storage_slots: BTreeSet<(ContractId, Bytes32)>,
+    assets: BTreeSet<AssetId>,
+}
+
+/// The storage wrapper that records the keys of the contract tables
+/// read from the underlying storage.
+pub struct AccessRecorder<S> {
+    storage: S,
+    accesses: Option<ParkingMutex<Accesses>>,
+}
+
+impl<S> AccessRecorder<S> {
+    /// Creates the recorder. If `enabled` is `false`, nothing is recorded.
+    pub fn new(storage: S, enabled: bool) -> Self {
+        Self {
+            storage,
+            accesses: enabled.then(Default::default),
+        }
+    }
+
+    /// Records the keys modified by the `changes`. Keys written without
+    /// reading don't reach the underlying storage, so they are taken from the changes.
+    pub fn record_changes(&self, changes: &Changes) {
+        for column in [
+            Column::ContractsRawCode,
+            Column::ContractsState,
+            Column::ContractsAssets,
+        ] {
+            for key in changes.get(&column.id()).into_iter().flat_map(|c| c.keys()) {
+                self.record(key, column);
+            }
+        }
+    }
+
+    /// Records the assets transferred by the `receipts`.
+    pub fn record_receipts(&self, receipts: &[Receipt]) {
+        let Some(accesses) = &self.accesses else {
+            return
+        };
+        let mut accesses = accesses.lock();
+        for receipt in receipts {
+            if let Receipt::Transfer { asset_id, .. }
+            | Receipt::TransferOut { asset_id, .. } = receipt
+            {
+                accesses.assets.insert(*asset_id);
+            }
+        }
+    }
+
+    /// Returns the recorded access list, or `None` if the recording is disabled.
+    pub fn into_access_list(self) -> Option<AccessList> {
+        let accesses = self.accesses?.into_inner();
+        Some(AccessList {
+            contracts: accesses.contracts.into_iter().collect(),
+            storage_slots: accesses.storage_slots.into_iter().collect(),
+            assets: accesses.assets.into_iter().collect(),
+        })
+    }
+
+    fn record(&self, key: &[u8], column: Column) {
+        let Some(accesses) = &self.accesses else {
+            return
+        };
+        let contract_id = key
+            .get(..ContractId::LEN)
+            .and_then(|bytes| ContractId::try_from(bytes).ok());
+        let sub_key = key
+            .get(ContractId::LEN..)
+            .and_then(|bytes| Bytes32::try_from(bytes).ok());
+
+        let mut accesses = accesses.lock();
+        match (column, contract_id, sub_key) {
+            (Column::ContractsRawCode, Some(contract_id), _) => {
+                accesses.contracts.insert(contract_id);
+            }
+            (Column::ContractsState, Some(contract_id), Some(slot)) => {
+                accesses.contracts.insert(contract_id);
+                accesses.storage_slots.insert((contract_id, slot));
+            }
+            (Column::ContractsAssets, Some(contract_id), Some(asset_id)) => {
+                accesses.contracts.insert(contract_id);
+                accesses.assets.insert(AssetId::new(*asset_id));
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<S> KeyValueInspect for AccessRecorder<S>
+where
+    S: KeyValueInspect<Column = Column>,
+{
+    type Column = Column;
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        self.record(key, column);
+        self.storage.exists(key, column)
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        self.record(key, column);
+        self.storage.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.record(key, column);
+        self.storage.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        offset: usize,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        self.record(key, column);
+        self.storage.read(key, column, offset, buf)
+    }
+}
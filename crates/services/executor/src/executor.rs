@@ -1,4 +1,5 @@
 use crate::{
+    access_recorder::AccessRecorder,
     ports::{
         MaybeCheckedTransaction,
         RelayerPort,
@@ -22,11 +23,11 @@ use fuel_core_storage::{
         ConflictPolicy,
         IntoTransaction,
         Modifiable,
-        ReadTransaction,
         StorageTransaction,
         WriteTransaction,
     },
     vm_storage::VmStorage,
+    InterpreterStorage,
     StorageAsMut,
     StorageAsRef,
 };
@@ -51,6 +52,9 @@ use fuel_core_types::{
         RelayedTransaction,
     },
     fuel_asm::{
+        Instruction,
+        Opcode,
+        RawInstruction,
         RegId,
         Word,
     },
@@ -110,6 +114,7 @@ use fuel_core_types::{
             CheckedTransaction,
             Checks,
             IntoChecked,
+            Ready,
         },
         interpreter::{
             CheckedMetadata as CheckedMetadataTrait,
@@ -118,19 +123,29 @@ use fuel_core_types::{
             Memory,
             MemoryInstance,
         },
-        state::StateTransition,
+        state::{
+            DebugEval,
+            StateTransition,
+        },
         Backtrace as FuelBacktrace,
+        Breakpoint,
         Interpreter,
+        InterpreterError,
         ProgramState,
     },
     services::{
         block_producer::Components,
         executor::{
+            AccessList,
             Error as ExecutorError,
             Event as ExecutorEvent,
             ExecutionResult,
             ForcedTransactionFailure,
             Result as ExecutorResult,
+            StorageAccess,
+            StorageAccessKind,
+            TraceConfig,
+            TraceRecord,
             TransactionExecutionResult,
             TransactionExecutionStatus,
             TransactionValidityError,
@@ -162,6 +177,9 @@ use alloc::{
     vec::Vec,
 };
 
+#[cfg(feature = "parallel")]
+mod parallel;
+
 /// The maximum amount of transactions that can be included in a block,
 /// excluding the mint transaction.
 #[cfg(not(feature = "test-helpers"))]
@@ -253,6 +271,15 @@ pub struct ExecutionOptions {
     pub extra_tx_checks: bool,
     /// Print execution backtraces if transaction execution reverts.
     pub backtrace: bool,
+    /// Record the instruction-level trace of the executed transactions.
+    /// Only the native executor supports tracing, so the settings are never
+    /// passed to the WASM executor.
+    #[serde(skip)]
+    pub trace: Option<TraceConfig>,
+    /// Record the contracts, storage slots and assets accessed by the transactions.
+    /// Like the tracing, it is supported only by the native executor.
+    #[serde(skip)]
+    pub record_access_list: bool,
 }
 
 /// The executor instance performs block production and validation. Given a block, it will execute all
@@ -1252,14 +1279,15 @@ where
             checked_tx = self.extra_tx_checks(checked_tx, header, storage_tx, memory)?;
         }
 
-        let (reverted, state, tx, receipts) = self.attempt_tx_execution_with_vm(
-            checked_tx,
-            header,
-            coinbase_contract_id,
-            gas_price,
-            storage_tx,
-            memory,
-        )?;
+        let (reverted, state, tx, receipts, trace, access_list) = self
+            .attempt_tx_execution_with_vm(
+                checked_tx,
+                header,
+                coinbase_contract_id,
+                gas_price,
+                storage_tx,
+                memory,
+            )?;
 
         self.spend_input_utxos(tx.inputs(), storage_tx, reverted, execution_data)?;
 
@@ -1284,6 +1312,8 @@ where
             reverted,
             state,
             tx_id,
+            trace,
+            access_list,
         )?;
 
         Ok(tx.into())
@@ -1355,6 +1385,9 @@ where
                 total_gas: 0,
                 total_fee: 0,
             },
+            trace: None,
+            access_list: None,
+            missing_inputs: None,
         });
 
         if storage_tx
@@ -1463,6 +1496,8 @@ where
         reverted: bool,
         state: ProgramState,
         tx_id: TxId,
+        trace: Option<Vec<TraceRecord>>,
+        access_list: Option<AccessList>,
     ) -> ExecutorResult<()> {
         let (used_gas, tx_fee) = self.total_fee_paid(tx, &receipts, gas_price)?;
         let used_size = tx.metered_bytes_size().try_into().unwrap_or(u32::MAX);
@@ -1510,6 +1545,9 @@ where
         execution_data.tx_status.push(TransactionExecutionStatus {
             id: tx_id,
             result: status,
+            trace,
+            access_list,
+            missing_inputs: None,
         });
         Ok(())
     }
@@ -1598,7 +1636,14 @@ where
         gas_price: Word,
         storage_tx: &mut TxStorageTransaction<T>,
         memory: &mut MemoryInstance,
-    ) -> ExecutorResult<(bool, ProgramState, Tx, Vec<Receipt>)>
+    ) -> ExecutorResult<(
+        bool,
+        ProgramState,
+        Tx,
+        Vec<Receipt>,
+        Option<Vec<TraceRecord>>,
+        Option<AccessList>,
+    )>
     where
         Tx: ExecutableTransaction + Cacheable,
         <Tx as IntoChecked>::Metadata: CheckedMetadataTrait + Send + Sync,
@@ -1606,9 +1651,10 @@ where
     {
         let tx_id = checked_tx.id();
 
-        let mut sub_block_db_commit = storage_tx
-            .read_transaction()
-            .with_policy(ConflictPolicy::Overwrite);
+        let mut sub_block_db_commit =
+            AccessRecorder::new(&*storage_tx, self.options.record_access_list)
+                .into_transaction()
+                .with_policy(ConflictPolicy::Overwrite);
 
         let vm_db = VmStorage::new(
             &mut sub_block_db_commit,
@@ -1639,13 +1685,30 @@ where
             InterpreterParams::new(gas_price, &self.consensus_params),
         );
 
-        let vm_result: StateTransition<_> = vm
-            .transact(ready_tx)
-            .map_err(|error| ExecutorError::VmExecution {
-                error: error.to_string(),
-                transaction_id: tx_id,
-            })?
-            .into();
+        let trace_config = self
+            .options
+            .trace
+            .filter(|config| config.tx_id.map_or(true, |id| id == tx_id));
+        let (vm_result, trace): (StateTransition<_>, _) = match trace_config {
+            Some(config) => {
+                let (vm_result, trace) = Self::transact_with_trace(
+                    &mut vm,
+                    ready_tx,
+                    tx_id,
+                    config.max_steps,
+                )?;
+                (vm_result, Some(trace))
+            }
+            None => {
+                let vm_result = vm.transact(ready_tx).map_err(|error| {
+                    ExecutorError::VmExecution {
+                        error: error.to_string(),
+                        transaction_id: tx_id,
+                    }
+                })?;
+                (vm_result.into(), None)
+            }
+        };
         let reverted = vm_result.should_revert();
 
         let (state, mut tx, receipts): (_, Tx, _) = vm_result.into_inner();
@@ -1661,15 +1724,131 @@ where
         // because VM zeroes malleable fields during the execution.
         self.compute_inputs(tx.inputs_mut(), storage_tx)?;
 
-        // only commit state changes if execution was a success
         if !reverted {
             self.log_backtrace(&vm, &receipts);
-            let changes = sub_block_db_commit.into_changes();
+        }
+        let (access_recorder, changes) = sub_block_db_commit.into_inner();
+        access_recorder.record_changes(&changes);
+        access_recorder.record_receipts(&receipts);
+        let access_list = access_recorder.into_access_list();
+
+        // only commit state changes if execution was a success
+        if !reverted {
             storage_tx.commit_changes(changes)?;
         }
 
         self.update_tx_outputs(storage_tx, tx_id, &mut tx)?;
-        Ok((reverted, state, tx, receipts))
+        Ok((reverted, state, tx, receipts, trace, access_list))
+    }
+
+    /// Executes the transaction in the single-stepping mode
+    /// and records every executed instruction.
+    /// Fails if the transaction executes more than `max_steps` instructions.
+    fn transact_with_trace<M, S, Tx>(
+        vm: &mut Interpreter<M, S, Tx>,
+        ready_tx: Ready<Tx>,
+        tx_id: TxId,
+        max_steps: usize,
+    ) -> ExecutorResult<(StateTransition<Tx>, Vec<TraceRecord>)>
+    where
+        M: Memory,
+        S: InterpreterStorage,
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadataTrait,
+    {
+        let vm_error =
+            |error: InterpreterError<S::DataError>| ExecutorError::VmExecution {
+                error: error.to_string(),
+                transaction_id: tx_id,
+            };
+        vm.set_single_stepping(true);
+
+        let mut trace: Vec<TraceRecord> = Vec::new();
+        let mut state = *vm.transact(ready_tx).map_err(vm_error)?.state();
+        let mut remaining_gas = vm.registers()[RegId::GGAS];
+
+        loop {
+            // The gas used by the instruction is known only after its execution,
+            // so it is assigned to the previous record on the next step.
+            let current_remaining_gas = vm.registers()[RegId::GGAS];
+            if let Some(record) = trace.last_mut() {
+                record.gas_used = remaining_gas.saturating_sub(current_remaining_gas);
+            }
+            remaining_gas = current_remaining_gas;
+
+            let Some(DebugEval::Breakpoint(breakpoint)) = state.debug_ref() else {
+                break
+            };
+            if trace.len() >= max_steps {
+                return Err(ExecutorError::TraceLimitExceeded {
+                    transaction_id: tx_id,
+                    max_steps,
+                })
+            }
+            trace.push(Self::trace_record(vm, breakpoint));
+            state = vm.resume().map_err(vm_error)?;
+        }
+
+        let vm_result =
+            StateTransition::new(state, vm.transaction().clone(), vm.receipts().to_vec());
+        Ok((vm_result, trace))
+    }
+
+    /// Creates the record for the instruction at which the VM stopped.
+    fn trace_record<M, S, Tx>(
+        vm: &Interpreter<M, S, Tx>,
+        breakpoint: &Breakpoint,
+    ) -> TraceRecord
+    where
+        M: Memory,
+    {
+        let registers = vm.registers();
+        let memory = vm.memory();
+        let instruction = memory
+            .read(registers[RegId::PC], Instruction::SIZE)
+            .ok()
+            .and_then(|bytes| <[u8; Instruction::SIZE]>::try_from(bytes).ok())
+            .map(RawInstruction::from_be_bytes)
+            .unwrap_or_default();
+        let storage_access =
+            Instruction::try_from(instruction)
+                .ok()
+                .and_then(|instruction| {
+                    Self::storage_access(&instruction, registers, memory)
+                });
+        // The breakpoints inside of the script use the zeroed contract id.
+        let contract_id =
+            Some(*breakpoint.contract()).filter(|id| id != &ContractId::zeroed());
+
+        TraceRecord {
+            contract_id,
+            pc: breakpoint.pc(),
+            instruction,
+            gas_used: 0,
+            storage_access,
+        }
+    }
+
+    /// Returns the storage slots accessed by the instruction before its execution.
+    fn storage_access(
+        instruction: &Instruction,
+        registers: &[Word],
+        memory: &MemoryInstance,
+    ) -> Option<StorageAccess> {
+        let [ra, _, rc, rd] = instruction.reg_ids();
+        let (kind, key_ptr, slots) = match instruction.opcode() {
+            Opcode::SRW => (StorageAccessKind::Read, rc, None),
+            Opcode::SRWQ => (StorageAccessKind::Read, rc, rd),
+            Opcode::SWW => (StorageAccessKind::Write, ra, None),
+            Opcode::SWWQ => (StorageAccessKind::Write, ra, rd),
+            Opcode::SCWQ => (StorageAccessKind::Clear, ra, rc),
+            _ => return None,
+        };
+        let key = memory.read(registers[key_ptr?], Bytes32::LEN).ok()?;
+        let key = Bytes32::try_from(key).ok()?;
+        let slots = slots.map(|slots| registers[slots]).unwrap_or(1);
+
+        Some(StorageAccess { kind, key, slots })
     }
 
     fn verify_inputs_exist_and_values_match<T>(
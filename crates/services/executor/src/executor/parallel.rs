@@ -0,0 +1,674 @@
+//! The parallel validation of the block.
+//!
+//! Transactions of the block are partitioned into groups that don't share any coin,
+//! message or contract. Each group is executed on its own storage transaction
+//! across the thread pool, and the changes of all groups are merged in the order
+//! of the transactions in the block. The result is the same as with the
+//! sequential validation.
+
+use super::{
+    BlockExecutor,
+    BlockStorageTransaction,
+    ExecutionData,
+    ExecutionInstance,
+};
+use crate::ports::{
+    MaybeCheckedTransaction,
+    RelayerPort,
+};
+use fuel_core_storage::{
+    column::Column,
+    kv_store::KeyValueInspect,
+    transactional::{
+        Changes,
+        ConflictPolicy,
+        Modifiable,
+        ReadTransaction,
+        StorageTransaction,
+        WriteTransaction,
+    },
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::{
+            Block,
+            PartialFuelBlock,
+        },
+        header::PartialBlockHeader,
+    },
+    fuel_asm::Word,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        ContractId,
+        Input,
+        Output,
+        Transaction,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        ChainId,
+        Nonce,
+    },
+    fuel_vm::interpreter::MemoryInstance,
+    services::executor::{
+        Error as ExecutorError,
+        Result as ExecutorResult,
+        UncommittedValidationResult,
+        ValidationResult,
+    },
+};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use tracing::{
+    debug,
+    warn,
+};
+
+/// The resource that can't be shared by transactions from different partitions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ConflictKey {
+    Coin(UtxoId),
+    Message(Nonce),
+    Contract(ContractId),
+}
+
+/// The transaction executed as a part of the partition.
+struct ExecutedTransaction {
+    /// The position of the transaction in the block.
+    position: usize,
+    transaction: Transaction,
+    data: ExecutionData,
+}
+
+impl<R, D> ExecutionInstance<R, D>
+where
+    R: RelayerPort + Sync,
+    D: KeyValueInspect<Column = Column> + Sync,
+{
+    /// Validates the block like `validate_without_commit`, but executes
+    /// independent transactions of the block in parallel.
+    pub fn validate_in_parallel_without_commit(
+        self,
+        block: &Block,
+    ) -> ExecutorResult<UncommittedValidationResult<Changes>> {
+        let consensus_params_version = block.header().consensus_parameters_version;
+        let (block_executor, storage_tx) =
+            self.into_executor(consensus_params_version)?;
+
+        let ExecutionData {
+            coinbase,
+            used_gas,
+            used_size,
+            tx_status,
+            events,
+            changes,
+            ..
+        } = block_executor.validate_block_in_parallel(block, storage_tx)?;
+
+        let finalized_block_id = block.id();
+
+        debug!(
+            "Block {:#x} fees: {} gas: {} tx_size: {}",
+            finalized_block_id, coinbase, used_gas, used_size
+        );
+
+        let result = ValidationResult { tx_status, events };
+
+        Ok(UncommittedValidationResult::new(result, changes))
+    }
+}
+
+impl<R> BlockExecutor<R>
+where
+    R: RelayerPort + Sync,
+{
+    #[tracing::instrument(skip_all)]
+    fn validate_block_in_parallel<D>(
+        mut self,
+        block: &Block,
+        mut block_storage_tx: StorageTransaction<D>,
+    ) -> ExecutorResult<ExecutionData>
+    where
+        D: KeyValueInspect<Column = Column> + Sync,
+    {
+        let mut data = ExecutionData::new();
+
+        let partial_header = PartialBlockHeader::from(block.header());
+        let mut partial_block = PartialFuelBlock::new(partial_header, vec![]);
+        let transactions = block.transactions();
+        let mut memory = MemoryInstance::new();
+
+        let (gas_price, coinbase_contract_id) =
+            Self::get_coinbase_info_from_mint_tx(transactions)?;
+
+        self.process_l1_txs(
+            &mut partial_block,
+            coinbase_contract_id,
+            &mut block_storage_tx,
+            &mut data,
+            &mut memory,
+        )?;
+        let processed_l1_tx_count = partial_block.transactions.len();
+
+        let l2_transactions = transactions.get(processed_l1_tx_count..).unwrap_or(&[]);
+        let (regular_transactions, mint) = match l2_transactions.split_last() {
+            Some((mint @ Transaction::Mint(_), regular_transactions))
+                if regular_transactions.iter().all(Self::is_parallelizable) =>
+            {
+                (regular_transactions, core::slice::from_ref(mint))
+            }
+            // The block is invalid or contains transactions that modify the global state,
+            // so the sequential execution is used to report the same outcome.
+            _ => (&[][..], l2_transactions),
+        };
+
+        if !regular_transactions.is_empty() {
+            let executed = self
+                .execute_partitions(
+                    regular_transactions,
+                    &partial_block.header,
+                    data.tx_count,
+                    gas_price,
+                    coinbase_contract_id,
+                    &block_storage_tx,
+                )
+                .and_then(|(changes, executed)| {
+                    merge_partitions(&mut block_storage_tx, changes)?;
+                    Ok(executed)
+                });
+
+            match executed {
+                Ok(executed) => {
+                    for ExecutedTransaction {
+                        transaction,
+                        data: tx_data,
+                        ..
+                    } in executed
+                    {
+                        partial_block.transactions.push(transaction);
+                        data.append(tx_data)?;
+                    }
+                }
+                Err(err) => {
+                    // Partitions modified the same storage slot, so the outcome
+                    // depends on the order, or some transaction is invalid, and
+                    // any partition could be the first to fail. Fallback to
+                    // the sequential execution to report the same outcome.
+                    warn!("Failed to execute transactions in parallel: {err:?}");
+                    for transaction in regular_transactions {
+                        self.execute_transaction_and_commit(
+                            &mut partial_block,
+                            &mut block_storage_tx,
+                            &mut data,
+                            MaybeCheckedTransaction::Transaction(transaction.clone()),
+                            gas_price,
+                            coinbase_contract_id,
+                            &mut memory,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        for transaction in mint {
+            let maybe_checked_tx =
+                MaybeCheckedTransaction::Transaction(transaction.clone());
+            self.execute_transaction_and_commit(
+                &mut partial_block,
+                &mut block_storage_tx,
+                &mut data,
+                maybe_checked_tx,
+                gas_price,
+                coinbase_contract_id,
+                &mut memory,
+            )?;
+        }
+
+        self.check_block_matches(partial_block, block, &data)?;
+
+        data.changes = block_storage_tx.into_changes();
+        Ok(data)
+    }
+
+    /// Only transactions that affect the state of their own inputs and outputs
+    /// can be executed in parallel.
+    fn is_parallelizable(transaction: &Transaction) -> bool {
+        matches!(transaction, Transaction::Script(_) | Transaction::Create(_))
+    }
+
+    /// Executes partitions of transactions in parallel on top of the `block_storage_tx`.
+    /// Returns the changes of each partition and executed transactions
+    /// in the order of the block. If several partitions fail, any of their
+    /// errors can be returned.
+    fn execute_partitions<D>(
+        &self,
+        transactions: &[Transaction],
+        header: &PartialBlockHeader,
+        first_tx_index: u16,
+        gas_price: Word,
+        coinbase_contract_id: ContractId,
+        block_storage_tx: &BlockStorageTransaction<D>,
+    ) -> ExecutorResult<(Vec<Changes>, Vec<ExecutedTransaction>)>
+    where
+        D: KeyValueInspect<Column = Column> + Sync,
+    {
+        let partitions = partition(transactions, &self.consensus_params.chain_id());
+        debug!(
+            "Executing {} transactions in {} partitions",
+            transactions.len(),
+            partitions.len()
+        );
+
+        let results = partitions
+            .into_par_iter()
+            .map(|partition| {
+                self.execute_partition(
+                    transactions,
+                    partition,
+                    header,
+                    first_tx_index,
+                    gas_price,
+                    coinbase_contract_id,
+                    block_storage_tx,
+                )
+            })
+            .collect::<ExecutorResult<Vec<_>>>()?;
+
+        let mut changes = Vec::with_capacity(results.len());
+        let mut executed = Vec::with_capacity(transactions.len());
+        for (partition_changes, executed_transactions) in results {
+            changes.push(partition_changes);
+            executed.extend(executed_transactions);
+        }
+        executed.sort_by_key(|executed| executed.position);
+
+        Ok((changes, executed))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn execute_partition<D>(
+        &self,
+        transactions: &[Transaction],
+        partition: Vec<usize>,
+        header: &PartialBlockHeader,
+        first_tx_index: u16,
+        gas_price: Word,
+        coinbase_contract_id: ContractId,
+        block_storage_tx: &BlockStorageTransaction<D>,
+    ) -> ExecutorResult<(Changes, Vec<ExecutedTransaction>)>
+    where
+        D: KeyValueInspect<Column = Column>,
+    {
+        let mut partition_storage_tx = block_storage_tx.read_transaction();
+        let mut memory = MemoryInstance::new();
+        let mut executed = Vec::with_capacity(partition.len());
+
+        for position in partition {
+            let Some(transaction) = transactions.get(position) else {
+                continue
+            };
+            let mut tx_data = ExecutionData::new();
+            tx_data.tx_count = u16::try_from(position)
+                .ok()
+                .and_then(|position| first_tx_index.checked_add(position))
+                .ok_or(ExecutorError::TooManyTransactions)?;
+
+            let mut tx_st_transaction = partition_storage_tx
+                .write_transaction()
+                .with_policy(ConflictPolicy::Overwrite);
+            let tx_id = transaction.id(&self.consensus_params.chain_id());
+            let transaction = self.execute_transaction(
+                MaybeCheckedTransaction::Transaction(transaction.clone()),
+                &tx_id,
+                header,
+                coinbase_contract_id,
+                gas_price,
+                &mut tx_data,
+                &mut tx_st_transaction,
+                &mut memory,
+            )?;
+            tx_st_transaction.commit()?;
+
+            executed.push(ExecutedTransaction {
+                position,
+                transaction,
+                data: tx_data,
+            });
+        }
+
+        Ok((partition_storage_tx.into_changes(), executed))
+    }
+}
+
+impl ExecutionData {
+    /// Appends the data of the transaction executed separately from the block.
+    fn append(&mut self, other: ExecutionData) -> ExecutorResult<()> {
+        self.coinbase = self
+            .coinbase
+            .checked_add(other.coinbase)
+            .ok_or(ExecutorError::FeeOverflow)?;
+        self.used_gas = self.used_gas.checked_add(other.used_gas).ok_or(
+            ExecutorError::GasOverflow(
+                "Execution used gas overflowed.".into(),
+                self.used_gas,
+                other.used_gas,
+            ),
+        )?;
+        self.used_size = self
+            .used_size
+            .checked_add(other.used_size)
+            .ok_or(ExecutorError::TxSizeOverflow)?;
+        self.tx_count = self
+            .tx_count
+            .checked_add(1)
+            .ok_or(ExecutorError::TooManyTransactions)?;
+        self.message_ids.extend(other.message_ids);
+        self.tx_status.extend(other.tx_status);
+        self.events.extend(other.events);
+        Ok(())
+    }
+}
+
+/// Commits the changes of all partitions into the `block_storage_tx`.
+///
+/// Partitions don't share any state, so the changes of one partition can't
+/// overwrite the changes of another. If they do, the `block_storage_tx`
+/// is left untouched and the error is returned.
+fn merge_partitions<D>(
+    block_storage_tx: &mut BlockStorageTransaction<D>,
+    changes: Vec<Changes>,
+) -> StorageResult<()>
+where
+    D: KeyValueInspect<Column = Column>,
+{
+    let mut merged_tx = block_storage_tx
+        .write_transaction()
+        .with_policy(ConflictPolicy::Fail);
+    for partition_changes in changes {
+        merged_tx.commit_changes(partition_changes)?;
+    }
+    merged_tx.commit()?;
+    Ok(())
+}
+
+/// Splits transactions into partitions without shared coins, messages and contracts.
+/// Each partition contains positions of transactions in ascending order,
+/// and partitions are sorted by the position of their first transaction.
+fn partition(transactions: &[Transaction], chain_id: &ChainId) -> Vec<Vec<usize>> {
+    let mut parents: Vec<usize> = (0..transactions.len()).collect();
+    let mut owners = HashMap::<ConflictKey, usize>::new();
+
+    for (position, transaction) in transactions.iter().enumerate() {
+        let tx_id = transaction.id(chain_id);
+        let (inputs, outputs) = match transaction {
+            Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+            Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+            _ => (&[][..], &[][..]),
+        };
+
+        let input_keys = inputs.iter().filter_map(|input| match input {
+            Input::CoinSigned(coin) => Some(ConflictKey::Coin(coin.utxo_id)),
+            Input::CoinPredicate(coin) => Some(ConflictKey::Coin(coin.utxo_id)),
+            Input::Contract(contract) => {
+                Some(ConflictKey::Contract(contract.contract_id))
+            }
+            Input::MessageCoinSigned(_)
+            | Input::MessageCoinPredicate(_)
+            | Input::MessageDataSigned(_)
+            | Input::MessageDataPredicate(_) => {
+                input.nonce().copied().map(ConflictKey::Message)
+            }
+        });
+        let output_keys =
+            outputs
+                .iter()
+                .enumerate()
+                .filter_map(|(index, output)| match output {
+                    Output::Coin { .. }
+                    | Output::Change { .. }
+                    | Output::Variable { .. } => u16::try_from(index)
+                        .ok()
+                        .map(|index| ConflictKey::Coin(UtxoId::new(tx_id, index))),
+                    Output::ContractCreated { contract_id, .. } => {
+                        Some(ConflictKey::Contract(*contract_id))
+                    }
+                    Output::Contract(_) => None,
+                });
+
+        for key in input_keys.chain(output_keys) {
+            let owner = *owners.entry(key).or_insert(position);
+            union(&mut parents, owner, position);
+        }
+    }
+
+    let mut partitions = HashMap::<usize, Vec<usize>>::new();
+    for position in 0..transactions.len() {
+        let root = find(&mut parents, position);
+        partitions.entry(root).or_default().push(position);
+    }
+
+    let mut partitions: Vec<_> = partitions.into_values().collect();
+    partitions.sort_by_key(|partition| partition.first().copied());
+    partitions
+}
+
+fn find(parents: &mut [usize], mut position: usize) -> usize {
+    while let Some(&parent) = parents.get(position) {
+        if parent == position {
+            break
+        }
+        // Path halving keeps the trees flat.
+        let grandparent = parents.get(parent).copied().unwrap_or(parent);
+        if let Some(slot) = parents.get_mut(position) {
+            *slot = grandparent;
+        }
+        position = grandparent;
+    }
+    position
+}
+
+fn union(parents: &mut [usize], a: usize, b: usize) {
+    let a = find(parents, a);
+    let b = find(parents, b);
+    // The smallest position is the root, so the root of a partition
+    // is always its first transaction.
+    let (root, child) = if a <= b { (a, b) } else { (b, a) };
+    if let Some(slot) = parents.get_mut(child) {
+        *slot = root;
+    }
+}
+
+#[allow(non_snake_case)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        kv_store::KeyValueMutate,
+        structured_storage::test::InMemoryStorage,
+        transactional::IntoTransaction,
+    };
+    use fuel_core_types::fuel_tx::{
+        AssetId,
+        TransactionBuilder,
+    };
+
+    fn coin(seed: u8) -> Input {
+        Input::coin_signed(
+            UtxoId::new([seed; 32].into(), 0),
+            Default::default(),
+            100,
+            AssetId::BASE,
+            Default::default(),
+            0,
+        )
+    }
+
+    fn contract(seed: u8) -> Input {
+        Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            ContractId::from([seed; 32]),
+        )
+    }
+
+    fn script(inputs: Vec<Input>) -> Transaction {
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        for (index, input) in inputs.into_iter().enumerate() {
+            let is_contract = matches!(input, Input::Contract(_));
+            builder.add_input(input);
+            if is_contract {
+                builder.add_output(Output::contract(
+                    u16::try_from(index).unwrap(),
+                    Default::default(),
+                    Default::default(),
+                ));
+            }
+        }
+        builder.finalize_as_transaction()
+    }
+
+    fn changes_of(key: u8) -> Changes {
+        let storage = InMemoryStorage::<Column>::default();
+        let mut transaction = storage.read_transaction();
+        transaction.write(&[key], Column::Metadata, &[key]).unwrap();
+        transaction.into_changes()
+    }
+
+    #[test]
+    fn partition__independent_transactions_are_in_separate_partitions() {
+        // Given
+        let transactions = vec![
+            script(vec![coin(1)]),
+            script(vec![coin(2), contract(1)]),
+            script(vec![coin(3), contract(2)]),
+            script(vec![coin(4)]),
+        ];
+
+        // When
+        let partitions = partition(&transactions, &ChainId::default());
+
+        // Then
+        assert_eq!(partitions, vec![vec![0], vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    fn partition__transactions_with_the_same_coin_are_in_one_partition() {
+        // Given
+        let transactions = vec![
+            script(vec![coin(1)]),
+            script(vec![coin(2)]),
+            script(vec![coin(1)]),
+        ];
+
+        // When
+        let partitions = partition(&transactions, &ChainId::default());
+
+        // Then
+        assert_eq!(partitions, vec![vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn partition__transactions_with_the_same_contract_are_in_one_partition() {
+        // Given
+        let transactions = vec![
+            script(vec![coin(1), contract(1)]),
+            script(vec![coin(2), contract(2)]),
+            script(vec![coin(3), contract(1)]),
+        ];
+
+        // When
+        let partitions = partition(&transactions, &ChainId::default());
+
+        // Then
+        assert_eq!(partitions, vec![vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn partition__transaction_spending_the_output_of_another_is_in_its_partition() {
+        // Given
+        let chain_id = ChainId::default();
+        let mut builder = TransactionBuilder::script(vec![], vec![]);
+        builder.add_input(coin(1)).add_output(Output::coin(
+            Default::default(),
+            100,
+            AssetId::BASE,
+        ));
+        let parent = builder.finalize_as_transaction();
+        let child_coin = Input::coin_signed(
+            UtxoId::new(parent.id(&chain_id), 0),
+            Default::default(),
+            100,
+            AssetId::BASE,
+            Default::default(),
+            0,
+        );
+        let transactions = vec![parent, script(vec![coin(2)]), script(vec![child_coin])];
+
+        // When
+        let partitions = partition(&transactions, &chain_id);
+
+        // Then
+        assert_eq!(partitions, vec![vec![0, 2], vec![1]]);
+    }
+
+    #[test]
+    fn partition__conflicts_are_transitive() {
+        // Given
+        let transactions = vec![
+            script(vec![coin(1)]),
+            script(vec![coin(2)]),
+            script(vec![coin(3)]),
+            script(vec![coin(1), coin(2)]),
+        ];
+
+        // When
+        let partitions = partition(&transactions, &ChainId::default());
+
+        // Then
+        assert_eq!(partitions, vec![vec![0, 1, 3], vec![2]]);
+    }
+
+    #[test]
+    fn merge_partitions__merges_changes_of_independent_partitions() {
+        // Given
+        let mut block_storage_tx =
+            InMemoryStorage::<Column>::default().into_transaction();
+        let changes = vec![changes_of(1), changes_of(2)];
+
+        // When
+        let result = merge_partitions(&mut block_storage_tx, changes);
+
+        // Then
+        assert!(result.is_ok());
+        assert_eq!(
+            block_storage_tx.get(&[1], Column::Metadata).unwrap(),
+            Some(vec![1].into())
+        );
+        assert_eq!(
+            block_storage_tx.get(&[2], Column::Metadata).unwrap(),
+            Some(vec![2].into())
+        );
+    }
+
+    #[test]
+    fn merge_partitions__fails_without_changes_when_partitions_conflict() {
+        // Given
+        let mut block_storage_tx =
+            InMemoryStorage::<Column>::default().into_transaction();
+        let changes = vec![changes_of(1), changes_of(2), changes_of(1)];
+
+        // When
+        let result = merge_partitions(&mut block_storage_tx, changes);
+
+        // Then
+        assert!(result.is_err());
+        assert!(block_storage_tx.changes().is_empty());
+    }
+}
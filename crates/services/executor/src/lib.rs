@@ -7,6 +7,7 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod access_recorder;
 pub mod executor;
 pub mod ports;
 pub mod refs;
@@ -1,7 +1,11 @@
+use crate::history::HistoryRetention;
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
+    /// Defines for how long the transactions are kept. All history is kept if `None`.
+    pub history_retention: Option<HistoryRetention>,
 }
 
 impl Config {
@@ -9,6 +13,7 @@ impl Config {
         Self {
             max_block_notify_buffer: 1 << 10,
             metrics,
+            history_retention: None,
         }
     }
 }
@@ -0,0 +1,192 @@
+//! The history expiry of the blocks.
+//!
+//! The node can be configured to keep the transactions only for the recent blocks.
+//! The headers of the blocks, stored in the `FuelBlocks` table, and the block
+//! Merkle data are always kept, because they are required to prove the inclusion
+//! of the messages and to verify the next blocks.
+//!
+//! The history is always pruned from the oldest block, so the blocks with pruned
+//! transactions form a prefix of the chain. It allows finding the start of the
+//! available history with the binary search, without tracking it separately.
+
+use fuel_core_storage::{
+    tables::{
+        FuelBlocks,
+        Transactions,
+    },
+    Error as StorageError,
+    Result as StorageResult,
+    StorageAsRef,
+    StorageInspect,
+};
+use fuel_core_types::{
+    blockchain::header::BlockHeader,
+    fuel_types::BlockHeight,
+    tai64::Tai64,
+};
+use std::time::Duration;
+
+/// The maximum number of blocks pruned together with the import of one block.
+/// It limits the size of the commit when the history is pruned for the first time
+/// or after the increase of the retention.
+pub const MAX_PRUNED_BLOCKS_PER_COMMIT: u32 = 128;
+
+/// Defines for how long the transactions of the blocks are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryRetention {
+    /// Keeps the transactions of the specified number of the latest blocks.
+    Blocks(u32),
+    /// Keeps the transactions of the blocks produced within the specified duration
+    /// before the latest block.
+    Duration(Duration),
+}
+
+impl HistoryRetention {
+    /// Returns the height of the first block that should be retained
+    /// when the block with the `latest_header` is the latest.
+    ///
+    /// The `block_time` returns the time of the block at the height,
+    /// or `None` if the block is unknown.
+    pub fn first_retained_height<F>(
+        &self,
+        latest_header: &BlockHeader,
+        block_time: F,
+    ) -> StorageResult<BlockHeight>
+    where
+        F: Fn(&BlockHeight) -> StorageResult<Option<Tai64>>,
+    {
+        let latest_height = u32::from(*latest_header.height());
+        match self {
+            HistoryRetention::Blocks(blocks) => Ok(latest_height
+                .saturating_add(1)
+                .saturating_sub(*blocks)
+                .into()),
+            HistoryRetention::Duration(duration) => {
+                let oldest_time =
+                    Tai64(latest_header.time().0.saturating_sub(duration.as_secs()));
+                partition_point(latest_height, |height| {
+                    Ok(block_time(&height)?.is_some_and(|time| time >= oldest_time))
+                })
+            }
+        }
+    }
+}
+
+/// Returns `true` if the transactions of the block at the `height` are pruned.
+pub fn is_pruned<S>(storage: &S, height: &BlockHeight) -> StorageResult<bool>
+where
+    S: StorageInspect<FuelBlocks, Error = StorageError>
+        + StorageInspect<Transactions, Error = StorageError>,
+{
+    let Some(block) = storage.storage::<FuelBlocks>().get(height)? else {
+        return Ok(false)
+    };
+
+    // All transactions of the block are pruned together,
+    // so it is enough to check the last one.
+    match block.transactions().last() {
+        Some(tx_id) => Ok(!storage.storage::<Transactions>().contains_key(tx_id)?),
+        None => Ok(false),
+    }
+}
+
+/// Returns the height of the first block with the available transactions.
+/// The blocks before it are either pruned or belong to the previous network.
+pub fn history_start<S>(
+    storage: &S,
+    latest_height: &BlockHeight,
+) -> StorageResult<BlockHeight>
+where
+    S: StorageInspect<FuelBlocks, Error = StorageError>
+        + StorageInspect<Transactions, Error = StorageError>,
+{
+    partition_point(u32::from(*latest_height), |height| {
+        let Some(block) = storage.storage::<FuelBlocks>().get(&height)? else {
+            return Ok(false)
+        };
+        // The genesis block doesn't have transactions and can't be pruned.
+        // It is treated as pruned to keep the predicate monotonic.
+        match block.transactions().last() {
+            Some(tx_id) => storage.storage::<Transactions>().contains_key(tx_id),
+            None => Ok(false),
+        }
+    })
+}
+
+/// Returns the first height in the `[0; latest_height]` range that satisfies
+/// the `predicate`, or `latest_height` if none of them do. The `predicate`
+/// should be `false` for all heights before some point and `true` after it.
+fn partition_point<F>(latest_height: u32, predicate: F) -> StorageResult<BlockHeight>
+where
+    F: Fn(BlockHeight) -> StorageResult<bool>,
+{
+    let mut low = 0u32;
+    let mut high = latest_height;
+    while low < high {
+        let middle = low.saturating_add(high.saturating_sub(low) / 2);
+        if predicate(middle.into())? {
+            high = middle;
+        } else {
+            low = middle.saturating_add(1);
+        }
+    }
+    Ok(low.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_retained_height__keeps_the_requested_number_of_blocks() {
+        // Given
+        let retention = HistoryRetention::Blocks(10);
+        let mut header = BlockHeader::default();
+        header.set_block_height(100.into());
+
+        // When
+        let first_retained_height = retention
+            .first_retained_height(&header, |_| unreachable!())
+            .unwrap();
+
+        // Then
+        assert_eq!(first_retained_height, 91.into());
+    }
+
+    #[test]
+    fn first_retained_height__keeps_all_blocks_of_the_short_chain() {
+        // Given
+        let retention = HistoryRetention::Blocks(10);
+        let mut header = BlockHeader::default();
+        header.set_block_height(5.into());
+
+        // When
+        let first_retained_height = retention
+            .first_retained_height(&header, |_| unreachable!())
+            .unwrap();
+
+        // Then
+        assert_eq!(first_retained_height, 0.into());
+    }
+
+    #[test]
+    fn first_retained_height__keeps_blocks_within_duration() {
+        // Given
+        let retention = HistoryRetention::Duration(Duration::from_secs(25));
+        let mut header = BlockHeader::default();
+        header.set_block_height(100.into());
+        header.set_time(Tai64(1000));
+
+        // When
+        // Each block is produced 10 seconds after the previous one.
+        let first_retained_height = retention
+            .first_retained_height(&header, |height| {
+                let time = u64::from(u32::from(*height)).saturating_mul(10);
+                Ok(Some(Tai64(time)))
+            })
+            .unwrap();
+
+        // Then
+        assert_eq!(first_retained_height, 98.into());
+    }
+}
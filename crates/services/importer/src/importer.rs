@@ -1,4 +1,5 @@
 use crate::{
+    history::HistoryRetention,
     ports::{
         BlockVerifier,
         DatabaseTransaction,
@@ -135,6 +136,7 @@ pub struct Importer<D, E, V> {
     process_thread: rayon::ThreadPool,
     /// Enables prometheus metrics for this fuel-service
     metrics: bool,
+    history_retention: Option<HistoryRetention>,
 }
 
 impl<D, E, V> Importer<D, E, V> {
@@ -165,6 +167,7 @@ impl<D, E, V> Importer<D, E, V> {
             guard: Semaphore::new(1),
             process_thread,
             metrics: config.metrics,
+            history_retention: config.history_retention,
         }
     }
 
@@ -349,6 +352,10 @@ where
             return Err(Error::NotUnique(expected_next_height))
         }
 
+        if let Some(retention) = &self.history_retention {
+            db_after_execution.prune_history(retention, block.header())?;
+        }
+
         db_after_execution.commit()?;
 
         if self.metrics {
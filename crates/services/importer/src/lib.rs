@@ -6,10 +6,12 @@
 use fuel_core_types::services::block_importer::SharedImportResult;
 
 pub mod config;
+pub mod history;
 pub mod importer;
 pub mod ports;
 
 pub use config::Config;
+pub use history::HistoryRetention;
 pub use importer::Importer;
 
 #[derive(Clone)]
@@ -1,3 +1,8 @@
+use crate::history::{
+    self,
+    HistoryRetention,
+    MAX_PRUNED_BLOCKS_PER_COMMIT,
+};
 use fuel_core_storage::{
     column::Column,
     kv_store::KeyValueInspect,
@@ -26,6 +31,7 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::Consensus,
+        header::BlockHeader,
         SealedBlock,
     },
     fuel_tx::UniqueIdentifier,
@@ -87,6 +93,14 @@ pub trait DatabaseTransaction {
         block: &SealedBlock,
     ) -> StorageResult<bool>;
 
+    /// Removes the transactions of the blocks that are out of the `retention`
+    /// when the block with the `latest_header` is the latest.
+    fn prune_history(
+        &mut self,
+        retention: &HistoryRetention,
+        latest_header: &BlockHeader,
+    ) -> StorageResult<()>;
+
     /// Commits the changes to the underlying storage.
     fn commit(self) -> StorageResult<()>;
 }
@@ -157,6 +171,36 @@ where
         Ok(!found)
     }
 
+    fn prune_history(
+        &mut self,
+        retention: &HistoryRetention,
+        latest_header: &BlockHeader,
+    ) -> StorageResult<()> {
+        let first_retained_height =
+            retention.first_retained_height(latest_header, |height| {
+                Ok(self
+                    .storage_as_ref::<FuelBlocks>()
+                    .get(height)?
+                    .map(|block| block.header().time()))
+            })?;
+        let history_start = history::history_start(&*self, latest_header.height())?;
+
+        // The history is pruned gradually to avoid huge commits.
+        let end = u32::from(first_retained_height)
+            .min(u32::from(history_start).saturating_add(MAX_PRUNED_BLOCKS_PER_COMMIT));
+        for height in u32::from(history_start)..end {
+            let Some(block) = self.storage_as_ref::<FuelBlocks>().get(&height.into())?
+            else {
+                continue
+            };
+            let block = block.into_owned();
+            for tx_id in block.transactions() {
+                self.storage_as_mut::<Transactions>().remove(tx_id)?;
+            }
+        }
+        Ok(())
+    }
+
     fn commit(self) -> StorageResult<()> {
         self.commit()?;
         Ok(())
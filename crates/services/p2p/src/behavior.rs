@@ -204,6 +204,10 @@ impl FuelBehaviour {
         self.heartbeat.update_block_height(block_height);
     }
 
+    pub fn update_history_start(&mut self, history_start: BlockHeight) {
+        self.heartbeat.update_history_start(history_start);
+    }
+
     #[cfg(test)]
     pub fn get_peer_score(&self, peer_id: &PeerId) -> Option<f64> {
         self.gossipsub.peer_score(peer_id)
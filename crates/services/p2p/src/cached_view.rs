@@ -166,6 +166,12 @@ mod tests {
             self.sender.notify_waiters();
             Ok(Genesis::default())
         }
+
+        fn history_start(
+            &self,
+        ) -> StorageResult<fuel_core_types::fuel_types::BlockHeight> {
+            Ok(Default::default())
+        }
     }
 
     #[tokio::test]
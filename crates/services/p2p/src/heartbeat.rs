@@ -29,6 +29,9 @@ use std::{
 mod handler;
 
 pub const HEARTBEAT_PROTOCOL: &str = "/fuel/heartbeat/0.0.1";
+/// In addition to the latest block height, advertises the height of the
+/// first block with the available transactions.
+pub const V2_HEARTBEAT_PROTOCOL: &str = "/fuel/heartbeat/0.0.2";
 
 #[derive(Debug, Clone)]
 enum HeartbeatAction {
@@ -61,6 +64,9 @@ impl HeartbeatAction {
 pub struct Event {
     pub peer_id: PeerId,
     pub latest_block_height: BlockHeight,
+    /// The height of the first block with the available transactions.
+    /// `None` if the peer doesn't advertise it.
+    pub history_start: Option<BlockHeight>,
 }
 
 #[derive(Debug, Clone)]
@@ -68,6 +74,7 @@ pub struct Behaviour {
     config: Config,
     pending_events: VecDeque<HeartbeatAction>,
     current_block_height: BlockHeight,
+    current_history_start: BlockHeight,
 }
 
 impl Behaviour {
@@ -76,12 +83,17 @@ impl Behaviour {
             config,
             pending_events: VecDeque::default(),
             current_block_height: block_height,
+            current_history_start: BlockHeight::default(),
         }
     }
 
     pub fn update_block_height(&mut self, block_height: BlockHeight) {
         self.current_block_height = block_height;
     }
+
+    pub fn update_history_start(&mut self, history_start: BlockHeight) {
+        self.current_history_start = history_start;
+    }
 }
 
 impl NetworkBehaviour for Behaviour {
@@ -117,20 +129,25 @@ impl NetworkBehaviour for Behaviour {
         event: THandlerOutEvent<Self>,
     ) {
         match event {
-            HeartbeatOutEvent::BlockHeight(latest_block_height) => self
+            HeartbeatOutEvent::BlockHeight {
+                block_height,
+                history_start,
+            } => self
                 .pending_events
                 .push_back(HeartbeatAction::HeartbeatEvent(Event {
                     peer_id,
-                    latest_block_height,
+                    latest_block_height: block_height,
+                    history_start,
                 })),
             HeartbeatOutEvent::RequestBlockHeight => {
                 self.pending_events
                     .push_back(HeartbeatAction::BlockHeightRequest {
                         peer_id,
                         connection_id,
-                        in_event: HeartbeatInEvent::LatestBlock(
-                            self.current_block_height,
-                        ),
+                        in_event: HeartbeatInEvent::LatestBlock {
+                            block_height: self.current_block_height,
+                            history_start: self.current_history_start,
+                        },
                     })
             }
         }
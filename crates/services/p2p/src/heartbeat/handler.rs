@@ -1,7 +1,13 @@
-use super::HEARTBEAT_PROTOCOL;
+use super::{
+    HEARTBEAT_PROTOCOL,
+    V2_HEARTBEAT_PROTOCOL,
+};
 use fuel_core_types::fuel_types::BlockHeight;
 use futures::{
-    future::BoxFuture,
+    future::{
+        self,
+        BoxFuture,
+    },
     AsyncRead,
     AsyncReadExt,
     AsyncWrite,
@@ -9,7 +15,11 @@ use futures::{
     FutureExt,
 };
 use libp2p::{
-    core::upgrade::ReadyUpgrade,
+    core::upgrade::{
+        InboundUpgrade,
+        OutboundUpgrade,
+        UpgradeInfo,
+    },
     swarm::{
         handler::{
             ConnectionEvent,
@@ -23,6 +33,7 @@ use libp2p::{
     },
 };
 use std::{
+    convert::Infallible,
     num::NonZeroU32,
     pin::Pin,
     task::Poll,
@@ -36,15 +47,76 @@ use tracing::debug;
 
 #[derive(Debug, Clone)]
 pub enum HeartbeatInEvent {
-    LatestBlock(BlockHeight),
+    LatestBlock {
+        block_height: BlockHeight,
+        history_start: BlockHeight,
+    },
 }
 
 #[derive(Debug, Clone)]
 pub enum HeartbeatOutEvent {
-    BlockHeight(BlockHeight),
+    BlockHeight {
+        block_height: BlockHeight,
+        /// `None` if the peer uses `/fuel/heartbeat/0.0.1`,
+        /// which doesn't advertise the history start.
+        history_start: Option<BlockHeight>,
+    },
     RequestBlockHeight,
 }
 
+/// The version of the heartbeat protocol negotiated for the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeartbeatVersion {
+    /// Sends only the latest `BlockHeight`.
+    V1,
+    /// Sends the latest `BlockHeight` and the height of the first block
+    /// with the available transactions.
+    V2,
+}
+
+impl HeartbeatVersion {
+    fn from_protocol(protocol: &str) -> Self {
+        if protocol == V2_HEARTBEAT_PROTOCOL {
+            Self::V2
+        } else {
+            Self::V1
+        }
+    }
+}
+
+/// Negotiates the newest version of the heartbeat protocol supported by both peers.
+#[derive(Debug, Clone, Copy)]
+pub struct HeartbeatUpgrade;
+
+impl UpgradeInfo for HeartbeatUpgrade {
+    type Info = &'static str;
+    type InfoIter = [&'static str; 2];
+
+    fn protocol_info(&self) -> Self::InfoIter {
+        [V2_HEARTBEAT_PROTOCOL, HEARTBEAT_PROTOCOL]
+    }
+}
+
+impl<C> InboundUpgrade<C> for HeartbeatUpgrade {
+    type Output = (C, HeartbeatVersion);
+    type Error = Infallible;
+    type Future = future::Ready<Result<Self::Output, Self::Error>>;
+
+    fn upgrade_inbound(self, stream: C, protocol: Self::Info) -> Self::Future {
+        future::ready(Ok((stream, HeartbeatVersion::from_protocol(protocol))))
+    }
+}
+
+impl<C> OutboundUpgrade<C> for HeartbeatUpgrade {
+    type Output = (C, HeartbeatVersion);
+    type Error = Infallible;
+    type Future = future::Ready<Result<Self::Output, Self::Error>>;
+
+    fn upgrade_outbound(self, stream: C, protocol: Self::Info) -> Self::Future {
+        future::ready(Ok((stream, HeartbeatVersion::from_protocol(protocol))))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Config {
     /// Sending of `BlockHeight` should not take longer than this
@@ -80,13 +152,17 @@ impl Default for Config {
     }
 }
 
-type InboundData = BoxFuture<'static, Result<(Stream, BlockHeight), std::io::Error>>;
+type InboundData = BoxFuture<
+    'static,
+    Result<(Stream, HeartbeatVersion, ReceivedHeartbeat), std::io::Error>,
+>;
 type OutboundData = BoxFuture<'static, Result<Stream, std::io::Error>>;
 
 pub struct HeartbeatHandler {
     config: Config,
     inbound: Option<InboundData>,
     outbound: Option<OutboundState>,
+    outbound_version: HeartbeatVersion,
     timer: Pin<Box<Sleep>>,
     failure_count: u32,
 }
@@ -97,6 +173,7 @@ impl HeartbeatHandler {
             config,
             inbound: None,
             outbound: None,
+            outbound_version: HeartbeatVersion::V1,
             timer: Box::pin(sleep(Duration::new(0, 0))),
             failure_count: 0,
         }
@@ -106,13 +183,13 @@ impl HeartbeatHandler {
 impl ConnectionHandler for HeartbeatHandler {
     type FromBehaviour = HeartbeatInEvent;
     type ToBehaviour = HeartbeatOutEvent;
-    type InboundProtocol = ReadyUpgrade<&'static str>;
-    type OutboundProtocol = ReadyUpgrade<&'static str>;
+    type InboundProtocol = HeartbeatUpgrade;
+    type OutboundProtocol = HeartbeatUpgrade;
     type InboundOpenInfo = ();
     type OutboundOpenInfo = ();
 
-    fn listen_protocol(&self) -> SubstreamProtocol<ReadyUpgrade<&'static str>, ()> {
-        SubstreamProtocol::new(ReadyUpgrade::new(HEARTBEAT_PROTOCOL), ())
+    fn listen_protocol(&self) -> SubstreamProtocol<HeartbeatUpgrade, ()> {
+        SubstreamProtocol::new(HeartbeatUpgrade, ())
     }
 
     fn connection_keep_alive(&self) -> bool {
@@ -136,13 +213,16 @@ impl ConnectionHandler for HeartbeatHandler {
                     debug!(target: "fuel-libp2p", "Incoming heartbeat errored");
                     self.inbound = None;
                 }
-                Poll::Ready(Ok((stream, block_height))) => {
+                Poll::Ready(Ok((stream, version, received))) => {
                     // start waiting for the next `BlockHeight`
-                    self.inbound = Some(receive_block_height(stream).boxed());
+                    self.inbound = Some(receive_block_height(stream, version).boxed());
 
                     // report newly received `BlockHeight` to the Behaviour
                     return Poll::Ready(ConnectionHandlerEvent::NotifyBehaviour(
-                        HeartbeatOutEvent::BlockHeight(block_height),
+                        HeartbeatOutEvent::BlockHeight {
+                            block_height: received.block_height,
+                            history_start: received.history_start,
+                        },
                     ))
                 }
                 _ => {}
@@ -219,9 +299,8 @@ impl ConnectionHandler for HeartbeatHandler {
                 None => {
                     // Request new stream
                     self.outbound = Some(OutboundState::NegotiatingStream);
-                    let protocol =
-                        SubstreamProtocol::new(ReadyUpgrade::new(HEARTBEAT_PROTOCOL), ())
-                            .with_timeout(self.config.send_timeout);
+                    let protocol = SubstreamProtocol::new(HeartbeatUpgrade, ())
+                        .with_timeout(self.config.send_timeout);
                     return Poll::Ready(ConnectionHandlerEvent::OutboundSubstreamRequest {
                         protocol,
                     })
@@ -232,7 +311,10 @@ impl ConnectionHandler for HeartbeatHandler {
     }
 
     fn on_behaviour_event(&mut self, event: Self::FromBehaviour) {
-        let HeartbeatInEvent::LatestBlock(block_height) = event;
+        let HeartbeatInEvent::LatestBlock {
+            block_height,
+            history_start,
+        } = event;
 
         match self.outbound.take() {
             Some(OutboundState::RequestingBlockHeight {
@@ -243,7 +325,13 @@ impl ConnectionHandler for HeartbeatHandler {
                 self.timer = Box::pin(sleep(self.config.send_timeout));
                 // send latest `BlockHeight`
                 self.outbound = Some(OutboundState::SendingBlockHeight(
-                    send_block_height(stream, block_height).boxed(),
+                    send_block_height(
+                        stream,
+                        self.outbound_version,
+                        block_height,
+                        history_start,
+                    )
+                    .boxed(),
                 ))
             }
             other_state => self.outbound = other_state,
@@ -261,15 +349,16 @@ impl ConnectionHandler for HeartbeatHandler {
     ) {
         match event {
             ConnectionEvent::FullyNegotiatedInbound(FullyNegotiatedInbound {
-                protocol: stream,
+                protocol: (stream, version),
                 ..
             }) => {
-                self.inbound = Some(receive_block_height(stream).boxed());
+                self.inbound = Some(receive_block_height(stream, version).boxed());
             }
             ConnectionEvent::FullyNegotiatedOutbound(FullyNegotiatedOutbound {
-                protocol: stream,
+                protocol: (stream, version),
                 ..
             }) => {
+                self.outbound_version = version;
                 self.outbound = Some(OutboundState::RequestingBlockHeight {
                     stream,
                     requested: false,
@@ -299,30 +388,59 @@ enum OutboundState {
 
 const BLOCK_HEIGHT_SIZE: usize = 4;
 
+/// The data received from the peer with the heartbeat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ReceivedHeartbeat {
+    block_height: BlockHeight,
+    history_start: Option<BlockHeight>,
+}
+
 /// Takes in a stream
-/// Waits to receive next `BlockHeight`
-/// Returns the flushed stream and the received `BlockHeight`
-async fn receive_block_height<S>(mut stream: S) -> std::io::Result<(S, BlockHeight)>
+/// Waits to receive next `BlockHeight`(and the history start for `V2`)
+/// Returns the flushed stream and the received data
+async fn receive_block_height<S>(
+    mut stream: S,
+    version: HeartbeatVersion,
+) -> std::io::Result<(S, HeartbeatVersion, ReceivedHeartbeat)>
 where
     S: AsyncRead + AsyncWrite + Unpin,
 {
     let mut payload = [0u8; BLOCK_HEIGHT_SIZE];
     stream.read_exact(&mut payload).await?;
-    stream.flush().await?;
     let block_height = u32::from_be_bytes(payload).into();
-    Ok((stream, block_height))
+
+    let history_start = match version {
+        HeartbeatVersion::V1 => None,
+        HeartbeatVersion::V2 => {
+            stream.read_exact(&mut payload).await?;
+            Some(u32::from_be_bytes(payload).into())
+        }
+    };
+    stream.flush().await?;
+
+    let received = ReceivedHeartbeat {
+        block_height,
+        history_start,
+    };
+    Ok((stream, version, received))
 }
 
 /// Takes in a stream and latest `BlockHeight`
-/// Sends the `BlockHeight` and returns back the stream after flushing it
+/// Sends the `BlockHeight`(and the history start for `V2`)
+/// and returns back the stream after flushing it
 async fn send_block_height<S>(
     mut stream: S,
+    version: HeartbeatVersion,
     block_height: BlockHeight,
+    history_start: BlockHeight,
 ) -> std::io::Result<S>
 where
     S: AsyncRead + AsyncWrite + Unpin,
 {
     stream.write_all(&block_height.to_bytes()).await?;
+    if version == HeartbeatVersion::V2 {
+        stream.write_all(&history_start.to_bytes()).await?;
+    }
     stream.flush().await?;
 
     Ok(stream)
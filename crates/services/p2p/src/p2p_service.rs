@@ -452,6 +452,12 @@ impl FuelP2PService {
         self.swarm.behaviour_mut().update_block_height(block_height)
     }
 
+    pub fn update_history_start(&mut self, history_start: BlockHeight) {
+        self.swarm
+            .behaviour_mut()
+            .update_history_start(history_start)
+    }
+
     /// The report is forwarded to gossipsub behaviour
     /// If acceptance is "Rejected" the gossipsub peer score is calculated
     /// And if it's below allowed threshold the peer is banned
@@ -828,9 +834,13 @@ impl FuelP2PService {
         let heartbeat::Event {
             peer_id,
             latest_block_height,
+            history_start,
         } = event;
-        self.peer_manager
-            .handle_peer_info_updated(&peer_id, latest_block_height);
+        self.peer_manager.handle_peer_info_updated(
+            &peer_id,
+            latest_block_height,
+            history_start,
+        );
 
         Some(FuelP2PEvent::PeerInfoUpdated {
             peer_id,
@@ -1390,6 +1400,7 @@ mod tests {
         let mut node_b = build_service_from_config(p2p_config).await;
 
         let latest_block_height = 40_u32.into();
+        let history_start = 10_u32.into();
 
         loop {
             tokio::select! {
@@ -1400,7 +1411,10 @@ mod tests {
                             // 1. Peer Addresses are known
                             // 2. Client Version is known
                             // 3. Node has responded with their latest BlockHeight
-                            if client_version.is_some() && heartbeat_data.block_height == Some(latest_block_height) {
+                            // 4. Node has advertised their history start
+                            if client_version.is_some()
+                                && heartbeat_data.block_height == Some(latest_block_height)
+                                && heartbeat_data.history_start == Some(history_start) {
                                 break;
                             }
                         }
@@ -1413,6 +1427,7 @@ mod tests {
                         // we've connected to Peer A
                         // let's update our BlockHeight
                         node_b.update_block_height(latest_block_height);
+                        node_b.update_history_start(history_start);
                     }
 
                     tracing::info!("Node B Event: {:?}", node_b_event);
@@ -113,6 +113,7 @@ impl PeerManager {
         &mut self,
         peer_id: &PeerId,
         block_height: BlockHeight,
+        history_start: Option<BlockHeight>,
     ) {
         if let Some(time_elapsed) = self
             .get_peer_info(peer_id)
@@ -122,7 +123,7 @@ impl PeerManager {
         }
 
         let peers = self.get_assigned_peer_table_mut(peer_id);
-        update_heartbeat(peers, peer_id, block_height);
+        update_heartbeat(peers, peer_id, block_height, history_start);
     }
 
     /// Returns `true` signaling that the peer should be disconnected
@@ -240,6 +241,30 @@ impl PeerManager {
             .choose(&mut range)
     }
 
+    /// Find a peer that is holding the transactions of the blocks
+    /// from `start` up to `end` inclusive.
+    /// Peers that don't advertise the history start are treated as
+    /// holding the whole history.
+    pub fn get_peer_id_with_history(
+        &self,
+        start: &BlockHeight,
+        end: &BlockHeight,
+    ) -> Option<PeerId> {
+        let mut range = rand::thread_rng();
+        self.non_reserved_connected_peers
+            .iter()
+            .chain(self.reserved_connected_peers.iter())
+            .filter(|(_, peer_info)| {
+                let heartbeat_data = &peer_info.heartbeat_data;
+                heartbeat_data.block_height >= Some(*end)
+                    && heartbeat_data
+                        .history_start
+                        .map_or(true, |history_start| history_start <= *start)
+            })
+            .map(|(peer_id, _)| *peer_id)
+            .choose(&mut range)
+    }
+
     /// Handles the first connection established with a Peer
     fn handle_initial_connection(&mut self, peer_id: &PeerId) -> bool {
         const HEARTBEAT_AVG_WINDOW: u32 = 10;
@@ -336,9 +361,10 @@ fn update_heartbeat(
     peers: &mut HashMap<PeerId, PeerInfo>,
     peer_id: &PeerId,
     block_height: BlockHeight,
+    history_start: Option<BlockHeight>,
 ) {
     if let Some(peer) = peers.get_mut(peer_id) {
-        peer.heartbeat_data.update(block_height);
+        peer.heartbeat_data.update(block_height, history_start);
     } else {
         log_missing_peer(peer_id);
     }
@@ -486,4 +512,34 @@ mod tests {
             reserved_peers.len() + max_non_reserved_peers
         );
     }
+
+    #[test]
+    fn get_peer_id_with_history__skips_peers_that_pruned_the_range() {
+        // Given
+        let peers = get_random_peers(3);
+        let mut peer_manager = initialize_peer_manager(vec![], peers.len());
+        for peer_id in &peers {
+            peer_manager.handle_initial_connection(peer_id);
+        }
+        let pruned = peers[0];
+        let full_history = peers[1];
+        let legacy = peers[2];
+        peer_manager.handle_peer_info_updated(&pruned, 100.into(), Some(50.into()));
+        peer_manager.handle_peer_info_updated(&full_history, 100.into(), Some(0.into()));
+        peer_manager.handle_peer_info_updated(&legacy, 100.into(), None);
+
+        for _ in 0..20 {
+            // When
+            let peer = peer_manager
+                .get_peer_id_with_history(&10.into(), &20.into())
+                .expect("Should find a peer with the history");
+
+            // Then
+            assert_ne!(peer, pruned);
+        }
+        assert_eq!(
+            peer_manager.get_peer_id_with_history(&10.into(), &200.into()),
+            None
+        );
+    }
 }
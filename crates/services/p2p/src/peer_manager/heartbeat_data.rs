@@ -11,6 +11,9 @@ use tokio::time::Instant;
 #[derive(Debug, Clone)]
 pub struct HeartbeatData {
     pub block_height: Option<BlockHeight>,
+    /// The height of the first block with the available transactions.
+    /// `None` if the peer doesn't advertise it.
+    pub history_start: Option<BlockHeight>,
     pub last_heartbeat: Instant,
     pub last_heartbeat_sys: SystemTime,
     // Size of moving average window
@@ -22,6 +25,7 @@ impl HeartbeatData {
     pub fn new(window: u32) -> Self {
         Self {
             block_height: None,
+            history_start: None,
             last_heartbeat: Instant::now(),
             last_heartbeat_sys: SystemTime::now(),
             window,
@@ -54,8 +58,13 @@ impl HeartbeatData {
         self.durations.push_front(new_duration);
     }
 
-    pub fn update(&mut self, block_height: BlockHeight) {
+    pub fn update(
+        &mut self,
+        block_height: BlockHeight,
+        history_start: Option<BlockHeight>,
+    ) {
         self.block_height = Some(block_height);
+        self.history_start = history_start;
         let old_heartbeat = self.last_heartbeat;
         self.last_heartbeat = Instant::now();
         self.last_heartbeat_sys = SystemTime::now();
@@ -87,7 +96,7 @@ mod tests {
         let mut heartbeat_data = HeartbeatData::new(10);
         for (i, interval) in intervals.clone().into_iter().enumerate() {
             tokio::time::advance(Duration::from_secs(interval)).await;
-            heartbeat_data.update(1.into());
+            heartbeat_data.update(1.into(), None);
             let bottom = if i < 10 { 0 } else { i - 9 };
             let range = &intervals[bottom..=i];
             let expected = range
@@ -26,6 +26,10 @@ pub trait P2pDb: Send + Sync {
     ) -> StorageResult<Option<Vec<Transactions>>>;
 
     fn get_genesis(&self) -> StorageResult<Genesis>;
+
+    /// Returns the height of the first block with the available transactions.
+    /// The transactions of the blocks before it were removed by the history expiry.
+    fn history_start(&self) -> StorageResult<BlockHeight>;
 }
 
 pub trait BlockHeightImporter: Send + Sync {
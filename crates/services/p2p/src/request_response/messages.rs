@@ -46,6 +46,10 @@ pub enum ResponseMessageErrorCode {
     Timeout = 2,
     #[error("Sync processor is out of capacity")]
     SyncProcessorOutOfCapacity = 3,
+    /// The peer pruned the transactions of the requested range.
+    /// Peers that don't know this code decode it as `Unknown`.
+    #[error("The requested range is pruned by the peer")]
+    RequestedRangePruned = 4,
     #[error("The peer sent an unknown error code")]
     #[serde(skip_serializing, other)]
     Unknown,
@@ -230,6 +230,11 @@ pub enum HeartBeatPeerReportReason {
 pub trait TaskP2PService: Send {
     fn get_all_peer_info(&self) -> Vec<(&PeerId, &PeerInfo)>;
     fn get_peer_id_with_height(&self, height: &BlockHeight) -> Option<PeerId>;
+    fn get_peer_id_with_history(
+        &self,
+        start: &BlockHeight,
+        end: &BlockHeight,
+    ) -> Option<PeerId>;
 
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>>;
 
@@ -266,6 +271,8 @@ pub trait TaskP2PService: Send {
 
     fn update_block_height(&mut self, height: BlockHeight) -> anyhow::Result<()>;
 
+    fn update_history_start(&mut self, history_start: BlockHeight) -> anyhow::Result<()>;
+
     fn update_metrics<T>(&self, update_fn: T)
     where
         T: FnOnce();
@@ -287,6 +294,14 @@ impl TaskP2PService for FuelP2PService {
         self.peer_manager().get_peer_id_with_height(height)
     }
 
+    fn get_peer_id_with_history(
+        &self,
+        start: &BlockHeight,
+        end: &BlockHeight,
+    ) -> Option<PeerId> {
+        self.peer_manager().get_peer_id_with_history(start, end)
+    }
+
     fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
         Box::pin(self.next_event())
     }
@@ -351,6 +366,11 @@ impl TaskP2PService for FuelP2PService {
         self.update_block_height(height);
         Ok(())
     }
+
+    fn update_history_start(&mut self, history_start: BlockHeight) -> anyhow::Result<()> {
+        self.update_history_start(history_start);
+        Ok(())
+    }
 }
 
 pub trait Broadcast: Send {
@@ -567,7 +587,11 @@ where
         max_len: usize,
     ) -> anyhow::Result<()>
     where
-        DbLookUpFn: Fn(&V::LatestView, &Arc<CachedView>, Range<u32>) -> anyhow::Result<Option<R>>
+        DbLookUpFn: Fn(
+                &V::LatestView,
+                &Arc<CachedView>,
+                Range<u32>,
+            ) -> Result<R, ResponseMessageErrorCode>
             + Send
             + 'static,
         ResponseSenderFn:
@@ -606,10 +630,7 @@ where
                     return;
                 }
 
-                let response = db_lookup(&view, &cached_view, range.clone())
-                    .ok()
-                    .flatten()
-                    .ok_or(ResponseMessageErrorCode::Timeout);
+                let response = db_lookup(&view, &cached_view, range.clone());
 
                 let _ = response_channel
                     .try_send(task_request(response, request_id))
@@ -637,9 +658,20 @@ where
             request_id,
             V2ResponseMessage::Transactions,
             |view, cached_view, range| {
+                // The transactions of the blocks before the history start
+                // were removed by the history expiry.
+                let history_start = view
+                    .history_start()
+                    .map_err(|_| ResponseMessageErrorCode::Timeout)?;
+                if range.start < u32::from(history_start) {
+                    return Err(ResponseMessageErrorCode::RequestedRangePruned)
+                }
+
                 cached_view
                     .get_transactions(view, range)
-                    .map_err(anyhow::Error::from)
+                    .ok()
+                    .flatten()
+                    .ok_or(ResponseMessageErrorCode::Timeout)
             },
             |response, request_id| TaskRequest::DatabaseTransactionsLookUp {
                 response,
@@ -661,7 +693,9 @@ where
             |view, cached_view, range| {
                 cached_view
                     .get_sealed_headers(view, range)
-                    .map_err(anyhow::Error::from)
+                    .ok()
+                    .flatten()
+                    .ok_or(ResponseMessageErrorCode::Timeout)
             },
             |response, request_id| TaskRequest::DatabaseHeaderLookUp {
                 response,
@@ -828,6 +862,7 @@ where
         )
         .await?;
         p2p_service.update_block_height(last_height);
+        p2p_service.update_history_start(view.history_start()?);
         p2p_service.start().await?;
 
         let next_check_time =
@@ -888,6 +923,19 @@ where
             latest_block_height = self.next_block_height.next() => {
                 if let Some(latest_block_height) = latest_block_height {
                     let _ = self.p2p_service.update_block_height(latest_block_height);
+                    // The history expiry moves the history start with new blocks.
+                    let history_start = self
+                        .view_provider
+                        .latest_view()
+                        .and_then(|view| view.history_start());
+                    match history_start {
+                        Ok(history_start) => {
+                            let _ = self.p2p_service.update_history_start(history_start);
+                        }
+                        Err(err) => {
+                            tracing::error!("Failed to get the history start: {err}");
+                        }
+                    }
                     TaskNextAction::Continue
                 } else {
                     TaskNextAction::Stop
@@ -916,8 +964,9 @@ where
                         self.p2p_service.send_request_msg(Some(peer), request_msg, channel).expect("We always have a peer here, so send has a target");
                     }
                     Some(TaskRequest::GetTransactions {block_height_range, channel }) => {
-                        let height = BlockHeight::from(block_height_range.end.saturating_sub(1));
-                        let Some(peer) = self.p2p_service.get_peer_id_with_height(&height) else {
+                        let start = BlockHeight::from(block_height_range.start);
+                        let end = BlockHeight::from(block_height_range.end.saturating_sub(1));
+                        let Some(peer) = self.p2p_service.get_peer_id_with_history(&start, &end) else {
                             let _ = channel.send(Err(TaskError::NoPeerFound));
                             return TaskNextAction::Continue
                         };
@@ -1451,6 +1500,10 @@ pub mod tests {
         fn get_genesis(&self) -> StorageResult<Genesis> {
             Ok(Default::default())
         }
+
+        fn history_start(&self) -> StorageResult<BlockHeight> {
+            Ok(Default::default())
+        }
     }
 
     #[derive(Clone, Debug)]
@@ -1523,6 +1576,14 @@ pub mod tests {
             todo!()
         }
 
+        fn get_peer_id_with_history(
+            &self,
+            _start: &BlockHeight,
+            _end: &BlockHeight,
+        ) -> Option<PeerId> {
+            todo!()
+        }
+
         fn next_event(&mut self) -> BoxFuture<'_, Option<FuelP2PEvent>> {
             self.next_event_stream.next().boxed()
         }
@@ -1571,6 +1632,13 @@ pub mod tests {
         fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
             Ok(())
         }
+
+        fn update_history_start(
+            &mut self,
+            _history_start: BlockHeight,
+        ) -> anyhow::Result<()> {
+            Ok(())
+        }
     }
 
     #[derive(Clone)]
@@ -1602,6 +1670,10 @@ pub mod tests {
         fn get_genesis(&self) -> StorageResult<Genesis> {
             todo!()
         }
+
+        fn history_start(&self) -> StorageResult<BlockHeight> {
+            Ok(Default::default())
+        }
     }
 
     struct FakeBroadcast {
@@ -1656,6 +1728,7 @@ pub mod tests {
 
         let heartbeat_data = HeartbeatData {
             block_height: None,
+            history_start: None,
             last_heartbeat: Instant::now(),
             last_heartbeat_sys: SystemTime::now(),
             window: 0,
@@ -1749,6 +1822,7 @@ pub mod tests {
 
         let heartbeat_data = HeartbeatData {
             block_height: None,
+            history_start: None,
             last_heartbeat,
             last_heartbeat_sys,
             window: 0,
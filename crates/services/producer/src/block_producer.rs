@@ -42,6 +42,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunOptions,
             TransactionExecutionStatus,
             UncommittedResult,
         },
@@ -317,28 +318,54 @@ where
         time: Option<Tai64>,
         utxo_validation: Option<bool>,
         gas_price: Option<u64>,
+        options: DryRunOptions,
     ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
         let view = self.view_provider.latest_view()?;
         let latest_height = view.latest_height().unwrap_or_default();
 
-        let simulated_height = height.unwrap_or_else(|| {
-            latest_height
-                .succ()
-                .expect("It is impossible to overflow the current block height")
-        });
-
-        let simulated_time = time.unwrap_or_else(|| {
-            view.get_block(&latest_height)
-                .map(|block| block.header().time())
-                .unwrap_or(Tai64::UNIX_EPOCH)
-        });
+        let historical_height =
+            height.filter(|height| height <= &latest_height && height.pred().is_some());
+
+        let (header, gas_price) = if let Some(historical_height) = historical_height {
+            // The dry run at the height of the already committed block is executed
+            // on top of the state before this block. It uses the same header and
+            // the gas price as the committed block, so the consensus parameters
+            // are the same as at the moment of the block production.
+            let block = view.get_block(&historical_height)?;
+            let mut header = PartialBlockHeader::from(block.header());
+            if let Some(time) = time {
+                header.consensus.time = time;
+            }
 
-        let header = self.new_header(simulated_height, simulated_time, &view)?;
+            let gas_price = if let Some(inner) = gas_price {
+                inner
+            } else {
+                view.block_gas_price(&historical_height)?
+            };
 
-        let gas_price = if let Some(inner) = gas_price {
-            inner
+            (header, gas_price)
         } else {
-            self.calculate_gas_price().await?
+            let simulated_height = height.unwrap_or_else(|| {
+                latest_height
+                    .succ()
+                    .expect("It is impossible to overflow the current block height")
+            });
+
+            let simulated_time = time.unwrap_or_else(|| {
+                view.get_block(&latest_height)
+                    .map(|block| block.header().time())
+                    .unwrap_or(Tai64::UNIX_EPOCH)
+            });
+
+            let header = self.new_header(simulated_height, simulated_time, &view)?;
+
+            let gas_price = if let Some(inner) = gas_price {
+                inner
+            } else {
+                self.calculate_gas_price().await?
+            };
+
+            (header, gas_price)
         };
 
         // The dry run execution should use the state of the blockchain based on the
@@ -358,7 +385,7 @@ where
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
         let tx_statuses = tokio_rayon::spawn_fifo(
             move || -> anyhow::Result<Vec<TransactionExecutionStatus>> {
-                Ok(executor.dry_run(component, utxo_validation)?)
+                Ok(executor.dry_run(component, utxo_validation, options)?)
             },
         )
         .await?;
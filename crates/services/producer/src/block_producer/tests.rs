@@ -141,6 +141,7 @@ mod produce_and_execute_block_txpool {
             blocks: Arc::new(Mutex::new(
                 vec![(prev_height, previous_block)].into_iter().collect(),
             )),
+            gas_prices: Default::default(),
             consensus_parameters_version,
             state_transition_bytecode_version,
         };
@@ -185,6 +186,7 @@ mod produce_and_execute_block_txpool {
             blocks: Arc::new(Mutex::new(
                 vec![(prev_height, previous_block)].into_iter().collect(),
             )),
+            gas_prices: Default::default(),
             consensus_parameters_version,
             state_transition_bytecode_version: 0,
         };
@@ -238,6 +240,7 @@ mod produce_and_execute_block_txpool {
             blocks: Arc::new(Mutex::new(
                 vec![(prev_height, previous_block)].into_iter().collect(),
             )),
+            gas_prices: Default::default(),
             consensus_parameters_version: 0,
             state_transition_bytecode_version,
         };
@@ -603,7 +606,14 @@ mod dry_run {
         // When
         let _ = ctx
             .producer()
-            .dry_run(vec![], None, Some(simulated_block_time), None, None)
+            .dry_run(
+                vec![],
+                None,
+                Some(simulated_block_time),
+                None,
+                None,
+                Default::default(),
+            )
             .await;
 
         // Then
@@ -624,7 +634,14 @@ mod dry_run {
         // When
         let _ = ctx
             .producer()
-            .dry_run(vec![], None, Some(simulated_block_time), None, None)
+            .dry_run(
+                vec![],
+                None,
+                Some(simulated_block_time),
+                None,
+                None,
+                Default::default(),
+            )
             .await;
 
         // Then
@@ -642,7 +659,10 @@ mod dry_run {
             .build_with_executor(executor.clone());
 
         // When
-        let _ = ctx.producer().dry_run(vec![], None, None, None, None).await;
+        let _ = ctx
+            .producer()
+            .dry_run(vec![], None, None, None, None, Default::default())
+            .await;
 
         // Then
         assert_eq!(executor.captured_block_timestamp(), last_block_time);
@@ -665,16 +685,79 @@ mod dry_run {
             SAME_HEIGHT.into(),
             block.result().block.clone().compress(&Default::default()),
         );
+        producer
+            .view_provider
+            .gas_prices
+            .lock()
+            .unwrap()
+            .insert(SAME_HEIGHT.into(), 0);
 
         // When
         let result = producer
-            .dry_run(vec![], Some(SAME_HEIGHT.into()), None, None, None)
+            .dry_run(
+                vec![],
+                Some(SAME_HEIGHT.into()),
+                None,
+                None,
+                None,
+                Default::default(),
+            )
             .await;
 
         // Then
         assert!(result.is_ok(), "{:?}", result);
     }
 
+    #[tokio::test]
+    async fn dry_run__uses_header_and_gas_price_of_committed_block_at_historical_height()
+    {
+        let executor = MockExecutorWithCapture::default();
+        let ctx = TestContext::default_from_executor(executor.clone());
+        let producer = ctx.producer();
+
+        const HISTORICAL_HEIGHT: u32 = 1;
+        const HISTORICAL_GAS_PRICE: u64 = 123;
+
+        // Given
+        let block = producer
+            .produce_and_execute_block_txpool(HISTORICAL_HEIGHT.into(), Tai64::now())
+            .await
+            .unwrap();
+        let block = block.result().block.clone();
+        producer.view_provider.blocks.lock().unwrap().insert(
+            HISTORICAL_HEIGHT.into(),
+            block.compress(&Default::default()),
+        );
+        producer
+            .view_provider
+            .gas_prices
+            .lock()
+            .unwrap()
+            .insert(HISTORICAL_HEIGHT.into(), HISTORICAL_GAS_PRICE);
+
+        // When
+        let result = producer
+            .dry_run(
+                vec![],
+                Some(HISTORICAL_HEIGHT.into()),
+                None,
+                None,
+                None,
+                Default::default(),
+            )
+            .await;
+
+        // Then
+        assert!(result.is_ok(), "{:?}", result);
+        let captured = executor.captured.lock().unwrap();
+        let captured = captured.as_ref().expect("should have captured a block");
+        assert_eq!(
+            captured.header_to_produce,
+            PartialBlockHeader::from(block.header())
+        );
+        assert_eq!(captured.gas_price, HISTORICAL_GAS_PRICE);
+    }
+
     impl MockExecutorWithCapture {
         fn captured_block_timestamp(&self) -> Tai64 {
             *self
@@ -870,6 +953,7 @@ impl<Executor> TestContext<Executor> {
             blocks: Arc::new(Mutex::new(
                 vec![(genesis_height, genesis_block)].into_iter().collect(),
             )),
+            gas_prices: Default::default(),
             consensus_parameters_version: 0,
             state_transition_bytecode_version: 0,
         }
@@ -1076,6 +1160,7 @@ impl TestContextBuilder {
 
         let db = MockDb {
             blocks: self.pre_existing_blocks(),
+            gas_prices: Default::default(),
             consensus_parameters_version: 0,
             state_transition_bytecode_version: 0,
         };
@@ -1100,6 +1185,7 @@ impl TestContextBuilder {
 
         let db = MockDb {
             blocks: self.pre_existing_blocks(),
+            gas_prices: Default::default(),
             consensus_parameters_version: 0,
             state_transition_bytecode_version: 0,
         };
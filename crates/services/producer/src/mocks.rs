@@ -36,6 +36,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunOptions,
             Error as ExecutorError,
             ExecutionResult,
             Result as ExecutorResult,
@@ -202,6 +203,7 @@ impl DryRunner for MockExecutorWithCapture {
         &self,
         block: Components<Vec<Transaction>>,
         _utxo_validation: Option<bool>,
+        _options: DryRunOptions,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
         *self.captured.lock().unwrap() = Some(block);
 
@@ -220,6 +222,7 @@ impl Default for MockExecutorWithCapture {
 #[derive(Clone, Default, Debug)]
 pub struct MockDb {
     pub blocks: Arc<Mutex<HashMap<BlockHeight, CompressedBlock>>>,
+    pub gas_prices: Arc<Mutex<HashMap<BlockHeight, u64>>>,
     pub consensus_parameters_version: ConsensusParametersVersion,
     pub state_transition_bytecode_version: StateTransitionBytecodeVersion,
 }
@@ -254,6 +257,14 @@ impl BlockProducerDatabase for MockDb {
         ))
     }
 
+    fn block_gas_price(&self, height: &BlockHeight) -> StorageResult<u64> {
+        let gas_prices = self.gas_prices.lock().unwrap();
+        gas_prices
+            .get(height)
+            .cloned()
+            .ok_or(not_found!("Didn't find gas price for test"))
+    }
+
     fn latest_consensus_parameters_version(
         &self,
     ) -> StorageResult<ConsensusParametersVersion> {
@@ -19,6 +19,7 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunOptions,
             Result as ExecutorResult,
             TransactionExecutionStatus,
             UncommittedResult,
@@ -37,6 +38,9 @@ pub trait BlockProducerDatabase: Send + Sync {
     /// Gets the block header BMT MMR root at `height`.
     fn block_header_merkle_root(&self, height: &BlockHeight) -> StorageResult<Bytes32>;
 
+    /// Gets the gas price used by the committed block at the `height`.
+    fn block_gas_price(&self, height: &BlockHeight) -> StorageResult<u64>;
+
     /// Returns the latest consensus parameters version.
     fn latest_consensus_parameters_version(
         &self,
@@ -94,10 +98,11 @@ pub trait BlockProducer<TxSource>: Send + Sync {
 pub trait DryRunner: Send + Sync {
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `utxo_validation` field can be used to disable the validation
-    /// of utxos during execution.
+    /// of utxos during execution. The `options` enable the optional features of the dry run.
     fn dry_run(
         &self,
         block: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
+        options: DryRunOptions,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>>;
 }
@@ -1,5 +1,6 @@
 use std::{
     collections::HashSet,
+    path::PathBuf,
     time::Duration,
 };
 
@@ -28,7 +29,7 @@ use fuel_core_types::{
 
 use crate::error::BlacklistedError;
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct BlackList {
     /// Blacklisted addresses.
     pub owners: HashSet<Address>,
@@ -56,12 +57,35 @@ impl BlackList {
         }
     }
 
+    /// Adds the entries of the `other` blacklist.
+    pub fn extend(&mut self, other: BlackList) {
+        self.owners.extend(other.owners);
+        self.coins.extend(other.coins);
+        self.messages.extend(other.messages);
+        self.contracts.extend(other.contracts);
+    }
+
+    /// Removes the entries of the `other` blacklist.
+    pub fn remove(&mut self, other: &BlackList) {
+        self.owners.retain(|owner| !other.owners.contains(owner));
+        self.coins.retain(|coin| !other.coins.contains(coin));
+        self.messages
+            .retain(|nonce| !other.messages.contains(nonce));
+        self.contracts
+            .retain(|contract| !other.contracts.contains(contract));
+    }
+
     /// Check if the transaction has blacklisted inputs.
     pub fn check_blacklisting(
         &self,
         tx: &PoolTransaction,
     ) -> Result<(), BlacklistedError> {
-        for input in tx.inputs() {
+        self.check_inputs(tx.inputs())
+    }
+
+    /// Check if any of the `inputs` is blacklisted.
+    pub fn check_inputs(&self, inputs: &[Input]) -> Result<(), BlacklistedError> {
+        for input in inputs {
             match input {
                 Input::CoinSigned(CoinSigned { utxo_id, owner, .. })
                 | Input::CoinPredicate(CoinPredicate { utxo_id, owner, .. }) => {
@@ -130,6 +154,9 @@ pub struct Config {
     pub max_txs_chain_count: usize,
     /// Pool limits
     pub pool_limits: PoolLimits,
+    /// Limits applied to the transactions of each owner of the inputs
+    /// (including predicates). Disabled if `None`.
+    pub owner_limits: Option<PoolLimits>,
     /// Service channel limits
     pub service_channel_limits: ServiceChannelLimits,
     /// Interval for checking the time to live of transactions.
@@ -140,8 +167,49 @@ pub struct Config {
     pub heavy_work: HeavyWorkConfig,
     /// Blacklist. Transactions with blacklisted inputs will not be accepted.
     pub black_list: BlackList,
+    /// The file where the blacklist is stored after each runtime update.
+    /// Once the file exists, it replaces the `black_list` on startup.
+    /// Runtime updates are not persisted if `None`.
+    pub black_list_path: Option<PathBuf>,
     /// Enable metrics when set to true
     pub metrics: bool,
+    /// Persistence of the transactions across restarts. Disabled if `None`.
+    pub persistence: Option<PersistenceConfig>,
+    /// The queue for transactions that are not mature yet. If `None`, such
+    /// transactions are rejected.
+    pub future_queue: Option<FutureQueueConfig>,
+    /// The algorithm that selects transactions for the block.
+    pub selection_algorithm: SelectionAlgorithmType,
+}
+
+/// The algorithms that can be used to select transactions for the block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionAlgorithmType {
+    /// Selects transactions with the highest tip/gas ratio first.
+    #[default]
+    RatioTipGas,
+    /// Selects transactions in the order of their arrival into the pool.
+    Fifo,
+    /// Selects transactions with the highest tip per consumed share of the block first,
+    /// considering both the gas and the block size limits.
+    Knapsack,
+}
+
+#[derive(Clone, Debug)]
+pub struct PersistenceConfig {
+    /// The file where the transactions are stored.
+    pub path: PathBuf,
+    /// Interval for storing the transactions. They are also stored on shutdown.
+    pub interval: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub struct FutureQueueConfig {
+    /// Maximum number of transactions waiting for their maturity.
+    pub max_txs: usize,
+    /// Maximum number of blocks between the current height and the maturity
+    /// of the transaction accepted into the queue.
+    pub max_blocks_ahead: u32,
 }
 
 #[derive(Clone, Debug)]
@@ -184,11 +252,13 @@ impl Default for Config {
             ttl_check_interval: Duration::from_secs(60),
             max_txs_ttl: Duration::from_secs(60 * 10),
             black_list: BlackList::default(),
+            black_list_path: None,
             pool_limits: PoolLimits {
                 max_txs: 10000,
                 max_gas: 100_000_000_000,
                 max_bytes_size: 1_000_000_000,
             },
+            owner_limits: None,
             heavy_work: HeavyWorkConfig {
                 // It is important for tests to have only one thread for verification
                 // because some of them rely on the ordering of insertion.
@@ -202,6 +272,9 @@ impl Default for Config {
                 max_pending_read_pool_requests: 1000,
             },
             metrics: false,
+            persistence: None,
+            future_queue: None,
+            selection_algorithm: SelectionAlgorithmType::default(),
         }
     }
 }
@@ -37,6 +37,10 @@ pub enum Error {
     MintIsDisallowed,
     #[display(fmt = "Pool limit is hit, try to increase gas_price")]
     NotInsertedLimitHit,
+    #[display(
+        fmt = "Limit of transactions for the owner `{_0}` is hit, try to increase gas_price"
+    )]
+    NotInsertedOwnerLimitHit(Address),
     #[display(fmt = "Transaction is removed: {_0}")]
     Removed(RemovedReason),
     #[display(fmt = "Transaction has been skipped during block insertion: {_0}")]
@@ -47,6 +51,8 @@ pub enum Error {
     ServiceCommunicationFailed,
     #[display(fmt = "Request failed to be sent because service queue is full")]
     ServiceQueueFull,
+    #[display(fmt = "The queue of not mature transactions is full")]
+    FutureQueueFull,
     #[display(fmt = "The provided max fee can't cover the transaction cost. \
         The minimal gas price should be {minimal_gas_price:?}, \
         while it is {max_gas_price_from_fee:?}")]
@@ -64,6 +70,14 @@ pub enum RemovedReason {
         fmt = "Transaction was removed because it was less worth than a new one (id: {_0}) that has been inserted"
     )]
     LessWorth(TxId),
+    #[display(
+        fmt = "Transaction was removed because its inputs were blacklisted at runtime"
+    )]
+    Blacklisted,
+    #[display(
+        fmt = "Transaction was removed because the max fee of it or of its dependency can't cover the increased gas price"
+    )]
+    InsufficientMaxFee,
     #[display(
         fmt = "Transaction expired because it exceeded the configured time to live `tx-pool-ttl`."
     )]
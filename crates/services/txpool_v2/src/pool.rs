@@ -1,8 +1,14 @@
 mod collisions;
 
 use std::{
-    collections::HashMap,
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        HashMap,
+        HashSet,
+    },
     iter,
+    ops::Bound,
     time::{
         Instant,
         SystemTime,
@@ -11,15 +17,32 @@ use std::{
 
 use collisions::CollisionsExt;
 use fuel_core_metrics::txpool_metrics::txpool_metrics;
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_types::{
     fuel_tx::{
         field::BlobId,
+        input::{
+            coin::{
+                CoinPredicate,
+                CoinSigned,
+            },
+            message::{
+                MessageCoinPredicate,
+                MessageCoinSigned,
+                MessageDataPredicate,
+                MessageDataSigned,
+            },
+        },
+        Address,
+        Input,
         TxId,
     },
     services::txpool::{
         ArcPoolTx,
+        PendingTransaction,
         PoolTransaction,
     },
+    tai64::Tai64,
 };
 use num_rational::Ratio;
 
@@ -28,7 +51,10 @@ use crate::{
         CollisionManager,
         Collisions,
     },
-    config::Config,
+    config::{
+        BlackList,
+        Config,
+    },
     error::{
         DependencyError,
         Error,
@@ -46,9 +72,6 @@ use crate::{
     },
 };
 
-#[cfg(test)]
-use std::collections::HashSet;
-
 /// The pool is the main component of the txpool service. It is responsible for storing transactions
 /// and allowing the selection of transactions for inclusion in a block.
 pub struct Pool<S, SI, CM, SA> {
@@ -60,8 +83,12 @@ pub struct Pool<S, SI, CM, SA> {
     pub(crate) collision_manager: CM,
     /// The selection algorithm of the pool.
     pub(crate) selection_algorithm: SA,
-    /// Mapping from tx_id to storage_id.
-    pub(crate) tx_id_to_storage_id: HashMap<TxId, SI>,
+    /// Mapping from tx_id to storage_id, ordered by tx_id.
+    pub(crate) tx_id_to_storage_id: BTreeMap<TxId, SI>,
+    /// Mapping from the owner of the inputs to its transactions in the pool.
+    pub(crate) owners_transactions: HashMap<Address, BTreeSet<TxId>>,
+    /// Transactions in the pool ordered by their max gas price.
+    pub(crate) max_gas_price_transactions: BTreeSet<(u64, TxId)>,
     /// Current pool gas stored.
     pub(crate) current_gas: u64,
     /// Current pool size in bytes.
@@ -81,7 +108,9 @@ impl<S, SI, CM, SA> Pool<S, SI, CM, SA> {
             collision_manager,
             selection_algorithm,
             config,
-            tx_id_to_storage_id: HashMap::new(),
+            tx_id_to_storage_id: BTreeMap::new(),
+            owners_transactions: HashMap::new(),
+            max_gas_price_transactions: BTreeSet::new(),
             current_gas: 0,
             current_bytes_size: 0,
         }
@@ -155,6 +184,8 @@ where
         let gas = tx.max_gas();
         let creation_instant = SystemTime::now();
         let bytes_size = tx.metered_bytes_size();
+        let owners = transaction_owners(tx);
+        let max_gas_price = tx.max_gas_price();
 
         let storage_id = self
             .storage
@@ -164,6 +195,14 @@ where
         self.current_bytes_size = self.current_bytes_size.saturating_add(bytes_size);
         debug_assert!(!self.tx_id_to_storage_id.contains_key(&tx_id));
         self.tx_id_to_storage_id.insert(tx_id, storage_id);
+        for owner in owners {
+            self.owners_transactions
+                .entry(owner)
+                .or_default()
+                .insert(tx_id);
+        }
+        self.max_gas_price_transactions
+            .insert((max_gas_price, tx_id));
 
         if self.config.metrics {
             txpool_metrics().tx_size.observe(bytes_size as f64);
@@ -237,11 +276,18 @@ where
             )
             .map_err(Error::Collided)?;
 
-        let can_fit_into_pool = self.can_fit_into_pool(&checked_transaction)?;
+        let mut transactions_to_remove = self.check_owner_limits(&checked_transaction)?;
+
+        let can_fit_into_pool =
+            self.can_fit_into_pool(&checked_transaction, &transactions_to_remove)?;
 
-        let mut transactions_to_remove = vec![];
         if let SpaceCheckResult::NotEnoughSpace(left) = can_fit_into_pool {
-            transactions_to_remove = self.find_free_space(left, &checked_transaction)?;
+            let free_space = self.find_free_space(
+                left,
+                &checked_transaction,
+                &transactions_to_remove,
+            )?;
+            transactions_to_remove.extend(free_space);
         }
 
         let can_store_transaction = CanStoreTransaction {
@@ -329,6 +375,123 @@ where
         self.tx_id_to_storage_id.keys()
     }
 
+    /// Returns up to `limit` transactions in the pool spending the coins or messages
+    /// of the `owner`(all if `None`) along with their direct dependencies and
+    /// dependents in the pool. The transactions are ordered by id in the `direction`
+    /// starting from the `start` id(inclusive).
+    pub fn pending_transactions(
+        &self,
+        owner: Option<&Address>,
+        start: Option<TxId>,
+        direction: IterDirection,
+        limit: usize,
+    ) -> Vec<PendingTransaction> {
+        let range = match (start, direction) {
+            (Some(start), IterDirection::Forward) => {
+                (Bound::Included(start), Bound::Unbounded)
+            }
+            (Some(start), IterDirection::Reverse) => {
+                (Bound::Unbounded, Bound::Included(start))
+            }
+            (None, _) => (Bound::Unbounded, Bound::Unbounded),
+        };
+        let tx_ids = match owner {
+            Some(owner) => take_in_direction(
+                self.owners_transactions
+                    .get(owner)
+                    .into_iter()
+                    .flat_map(|tx_ids| tx_ids.range(range)),
+                direction,
+                limit,
+            ),
+            None => take_in_direction(
+                self.tx_id_to_storage_id
+                    .range(range)
+                    .map(|(tx_id, _)| tx_id),
+                direction,
+                limit,
+            ),
+        };
+
+        tx_ids
+            .into_iter()
+            .filter_map(|tx_id| {
+                let storage_id = self.tx_id_to_storage_id.get(&tx_id)?;
+                self.pending_transaction(*storage_id)
+            })
+            .collect()
+    }
+
+    fn pending_transaction(
+        &self,
+        storage_id: S::StorageIndex,
+    ) -> Option<PendingTransaction> {
+        let storage_data = Storage::get(&self.storage, &storage_id)?;
+        let dependencies = self
+            .storage
+            .get_direct_dependencies(storage_id)
+            .filter_map(|index| Storage::get(&self.storage, &index))
+            .map(|data| data.transaction.id())
+            .collect();
+        let dependents = self
+            .storage
+            .get_direct_dependents(storage_id)
+            .filter_map(|index| Storage::get(&self.storage, &index))
+            .map(|data| data.transaction.id())
+            .collect();
+        let submitted_at = storage_data
+            .creation_instant
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+
+        Some(PendingTransaction {
+            transaction: storage_data.transaction.clone(),
+            submitted_at: Tai64::from_unix(submitted_at),
+            dependents_cumulative_tip: storage_data.dependents_cumulative_tip,
+            dependencies,
+            dependents,
+        })
+    }
+
+    /// Returns the blacklist used by the pool.
+    pub fn black_list(&self) -> &BlackList {
+        &self.config.black_list
+    }
+
+    /// Replace the blacklist of the pool.
+    /// Returns the removed transactions with blacklisted inputs and their dependents.
+    pub fn set_black_list(&mut self, black_list: BlackList) -> Vec<ArcPoolTx> {
+        self.config.black_list = black_list;
+        let blacklisted_txs = self
+            .tx_id_to_storage_id
+            .iter()
+            .filter_map(|(tx_id, storage_id)| {
+                let storage_data = self.storage.get(storage_id)?;
+                self.config
+                    .black_list
+                    .check_blacklisting(&storage_data.transaction)
+                    .is_err()
+                    .then_some(*tx_id)
+            })
+            .collect();
+        self.remove_transaction_and_dependents(blacklisted_txs)
+    }
+
+    /// Removes the transactions that can't pay the `minimal_gas_price` anymore.
+    /// Returns the removed transactions and their dependents.
+    pub fn remove_transactions_with_insufficient_max_fee(
+        &mut self,
+        minimal_gas_price: u64,
+    ) -> Vec<ArcPoolTx> {
+        let underpriced_txs = self
+            .max_gas_price_transactions
+            .range(..(minimal_gas_price, TxId::zeroed()))
+            .map(|(_, tx_id)| *tx_id)
+            .collect();
+        self.remove_transaction_and_dependents(underpriced_txs)
+    }
+
     /// Remove transaction but keep its dependents.
     /// The dependents become executables.
     pub fn remove_transaction(&mut self, tx_ids: Vec<TxId>) {
@@ -369,6 +532,7 @@ where
     /// It returns `true` if:
     /// - Pool is not full
     /// - Removing colliding subtree is enough to make space
+    /// - Removing the already marked `transactions_to_remove` is enough to make space
     ///
     /// It returns an error if the pool is full and transactions has dependencies.
     ///
@@ -376,13 +540,24 @@ where
     fn can_fit_into_pool(
         &self,
         checked_transaction: &S::CheckedTransaction,
+        transactions_to_remove: &[S::StorageIndex],
     ) -> Result<SpaceCheckResult, Error> {
         let tx = checked_transaction.tx();
         let tx_gas = tx.max_gas();
         let bytes_size = tx.metered_bytes_size();
-        let gas_left = self.current_gas.saturating_add(tx_gas);
-        let bytes_left = self.current_bytes_size.saturating_add(bytes_size);
-        let txs_left = self.tx_id_to_storage_id.len().saturating_add(1);
+        let mut gas_left = self.current_gas.saturating_add(tx_gas);
+        let mut bytes_left = self.current_bytes_size.saturating_add(bytes_size);
+        let mut txs_left = self.tx_id_to_storage_id.len().saturating_add(1);
+        for storage_id in transactions_to_remove {
+            if let Some(storage_data) = self.storage.get(storage_id) {
+                gas_left =
+                    gas_left.saturating_sub(storage_data.dependents_cumulative_gas);
+                bytes_left = bytes_left
+                    .saturating_sub(storage_data.dependents_cumulative_bytes_size);
+                txs_left =
+                    txs_left.saturating_sub(storage_data.number_dependents_in_chain);
+            }
+        }
         if gas_left <= self.config.pool_limits.max_gas
             && bytes_left <= self.config.pool_limits.max_bytes_size
             && txs_left <= self.config.pool_limits.max_txs
@@ -411,11 +586,14 @@ where
     /// Return the list of transactions that must be removed from the pool along all of
     /// their dependent subtree.
     ///
+    /// The `already_removed` transactions are not counted twice.
+    ///
     /// Returns an error impossible to find enough space.
     fn find_free_space(
         &self,
         left: NotEnoughSpace,
         checked_transaction: &S::CheckedTransaction,
+        already_removed: &[S::StorageIndex],
     ) -> Result<Vec<S::StorageIndex>, Error> {
         let tx = checked_transaction.tx();
         let NotEnoughSpace {
@@ -441,7 +619,9 @@ where
         {
             let storage_id = sorted_txs.next().ok_or(Error::NotInsertedLimitHit)?;
 
-            if checked_transaction.all_dependencies().contains(storage_id) {
+            if checked_transaction.all_dependencies().contains(storage_id)
+                || already_removed.contains(storage_id)
+            {
                 continue
             }
 
@@ -497,6 +677,188 @@ where
         Ok(transactions_to_remove)
     }
 
+    /// Check that the transaction fits into the limits of each owner of its inputs.
+    ///
+    /// If the limits of the owner are hit, the owner's transactions less profitable
+    /// than the new one are marked for removal along with their dependent subtree.
+    /// Transactions with dependents of other owners are never evicted on behalf
+    /// of the owner.
+    ///
+    /// Returns an error if it is impossible to free enough space for the owner.
+    fn check_owner_limits(
+        &self,
+        checked_transaction: &S::CheckedTransaction,
+    ) -> Result<Vec<S::StorageIndex>, Error> {
+        let Some(limits) = &self.config.owner_limits else {
+            return Ok(vec![])
+        };
+
+        let tx = checked_transaction.tx();
+        let new_tx_ratio = Ratio::new(tx.tip(), tx.max_gas());
+        let mut transactions_to_remove = vec![];
+
+        for owner in transaction_owners(tx) {
+            let mut owner_txs = self
+                .owners_transactions
+                .get(&owner)
+                .into_iter()
+                .flatten()
+                .filter_map(|tx_id| {
+                    let storage_id = self.tx_id_to_storage_id.get(tx_id)?;
+                    let storage_data = self.storage.get(storage_id)?;
+                    Some((*storage_id, storage_data))
+                })
+                // The transactions already selected for removal on behalf of other
+                // owners don't occupy the space of the owner.
+                .filter(|(storage_id, _)| {
+                    !transactions_to_remove.contains(storage_id)
+                        && !self.has_dependency_in(*storage_id, &transactions_to_remove)
+                })
+                .collect::<Vec<_>>();
+
+            let mut gas_left = tx.max_gas();
+            let mut bytes_left = tx.metered_bytes_size();
+            let mut txs_left = owner_txs.len().saturating_add(1);
+            for (_, storage_data) in &owner_txs {
+                let owner_tx = &storage_data.transaction;
+                gas_left = gas_left.saturating_add(owner_tx.max_gas());
+                bytes_left = bytes_left.saturating_add(owner_tx.metered_bytes_size());
+            }
+
+            // The less profitable transactions of the owner are removed first.
+            owner_txs.sort_by_key(|(_, storage_data)| {
+                Ratio::new(
+                    storage_data.dependents_cumulative_tip,
+                    storage_data.dependents_cumulative_gas,
+                )
+            });
+            let mut sorted_txs = owner_txs.into_iter();
+
+            while gas_left > limits.max_gas
+                || bytes_left > limits.max_bytes_size
+                || txs_left > limits.max_txs
+            {
+                let (storage_id, storage_data) = sorted_txs
+                    .next()
+                    .ok_or(Error::NotInsertedOwnerLimitHit(owner))?;
+
+                // The transaction may be a dependent of the transaction selected
+                // before, in which case it is already counted as removed.
+                if checked_transaction.all_dependencies().contains(&storage_id)
+                    || self.has_dependency_in(storage_id, &transactions_to_remove)
+                    || !self.is_subtree_of_owner(storage_id, &owner)
+                {
+                    continue
+                }
+
+                let ratio = Ratio::new(
+                    storage_data.dependents_cumulative_tip,
+                    storage_data.dependents_cumulative_gas,
+                );
+
+                if ratio > new_tx_ratio {
+                    return Err(Error::NotInsertedOwnerLimitHit(owner));
+                }
+
+                // The whole subtree belongs to the owner and is removed together
+                // with the transaction. The dependents selected before are a part
+                // of the subtree now, so they are not counted twice.
+                let mut gas = storage_data.dependents_cumulative_gas;
+                let mut bytes = storage_data.dependents_cumulative_bytes_size;
+                let mut txs = storage_data.number_dependents_in_chain;
+                let selected_dependents =
+                    self.selected_dependents(storage_id, &transactions_to_remove);
+                for dependent in &selected_dependents {
+                    if let Some(dependent) = self.storage.get(dependent) {
+                        gas = gas.saturating_sub(dependent.dependents_cumulative_gas);
+                        bytes = bytes
+                            .saturating_sub(dependent.dependents_cumulative_bytes_size);
+                        txs = txs.saturating_sub(dependent.number_dependents_in_chain);
+                    }
+                }
+                transactions_to_remove.retain(|id| !selected_dependents.contains(id));
+
+                gas_left = gas_left.saturating_sub(gas);
+                bytes_left = bytes_left.saturating_sub(bytes);
+                txs_left = txs_left.saturating_sub(txs);
+
+                transactions_to_remove.push(storage_id);
+            }
+        }
+
+        Ok(transactions_to_remove)
+    }
+
+    /// Returns `true` if all transactions of the dependent subtree of the `storage_id`
+    /// spend coins or messages of the `owner`.
+    fn is_subtree_of_owner(&self, storage_id: S::StorageIndex, owner: &Address) -> bool {
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![storage_id];
+        while let Some(storage_id) = to_visit.pop() {
+            if !visited.insert(storage_id) {
+                continue
+            }
+            let Some(storage_data) = self.storage.get(&storage_id) else {
+                continue
+            };
+            if !transaction_owners(&storage_data.transaction).contains(owner) {
+                return false
+            }
+            to_visit.extend(self.storage.get_direct_dependents(storage_id));
+        }
+        true
+    }
+
+    /// Returns `true` if any of the direct or indirect dependencies of
+    /// the `storage_id` is one of the `selected` transactions.
+    fn has_dependency_in(
+        &self,
+        storage_id: S::StorageIndex,
+        selected: &[S::StorageIndex],
+    ) -> bool {
+        let mut visited = HashSet::new();
+        let mut to_visit = self
+            .storage
+            .get_direct_dependencies(storage_id)
+            .collect::<Vec<_>>();
+        while let Some(storage_id) = to_visit.pop() {
+            if !visited.insert(storage_id) {
+                continue
+            }
+            if selected.contains(&storage_id) {
+                return true
+            }
+            to_visit.extend(self.storage.get_direct_dependencies(storage_id));
+        }
+        false
+    }
+
+    /// Returns the `selected` transactions from the dependent subtree of
+    /// the `storage_id`. The subtrees of the found transactions are not visited.
+    fn selected_dependents(
+        &self,
+        storage_id: S::StorageIndex,
+        selected: &[S::StorageIndex],
+    ) -> Vec<S::StorageIndex> {
+        let mut found = vec![];
+        let mut visited = HashSet::new();
+        let mut to_visit = self
+            .storage
+            .get_direct_dependents(storage_id)
+            .collect::<Vec<_>>();
+        while let Some(storage_id) = to_visit.pop() {
+            if !visited.insert(storage_id) {
+                continue
+            }
+            if selected.contains(&storage_id) {
+                found.push(storage_id);
+                continue
+            }
+            to_visit.extend(self.storage.get_direct_dependents(storage_id));
+        }
+        found
+    }
+
     /// Remove transaction and its dependents.
     pub fn remove_transaction_and_dependents(
         &mut self,
@@ -557,7 +919,18 @@ where
             self.current_bytes_size = self
                 .current_bytes_size
                 .saturating_sub(tx.metered_bytes_size());
-            self.tx_id_to_storage_id.remove(&tx.id());
+            let tx_id = tx.id();
+            self.tx_id_to_storage_id.remove(&tx_id);
+            for owner in transaction_owners(tx) {
+                if let Some(owner_txs) = self.owners_transactions.get_mut(&owner) {
+                    owner_txs.remove(&tx_id);
+                    if owner_txs.is_empty() {
+                        self.owners_transactions.remove(&owner);
+                    }
+                }
+            }
+            self.max_gas_price_transactions
+                .remove(&(tx.max_gas_price(), tx_id));
             self.collision_manager.on_removed_transaction(tx);
             self.selection_algorithm
                 .on_removed_transaction(storage_entry);
@@ -582,6 +955,36 @@ where
     }
 }
 
+/// Returns the owners of the coins and messages spent by the transaction.
+fn transaction_owners(tx: &PoolTransaction) -> HashSet<Address> {
+    tx.inputs()
+        .iter()
+        .filter_map(|input| match input {
+            Input::CoinSigned(CoinSigned { owner, .. })
+            | Input::CoinPredicate(CoinPredicate { owner, .. }) => Some(*owner),
+            Input::MessageCoinSigned(MessageCoinSigned { recipient, .. })
+            | Input::MessageCoinPredicate(MessageCoinPredicate { recipient, .. })
+            | Input::MessageDataSigned(MessageDataSigned { recipient, .. })
+            | Input::MessageDataPredicate(MessageDataPredicate { recipient, .. }) => {
+                Some(*recipient)
+            }
+            Input::Contract(_) => None,
+        })
+        .collect()
+}
+
+/// Returns up to `limit` ids from the ordered `tx_ids` in the `direction`.
+fn take_in_direction<'a>(
+    tx_ids: impl DoubleEndedIterator<Item = &'a TxId>,
+    direction: IterDirection,
+    limit: usize,
+) -> Vec<TxId> {
+    match direction {
+        IterDirection::Forward => tx_ids.take(limit).copied().collect(),
+        IterDirection::Reverse => tx_ids.rev().take(limit).copied().collect(),
+    }
+}
+
 pub struct NotEnoughSpace {
     gas_left: u64,
     bytes_left: usize,
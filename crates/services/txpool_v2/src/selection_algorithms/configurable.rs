@@ -0,0 +1,134 @@
+use crate::{
+    config::SelectionAlgorithmType,
+    storage::{
+        RemovedTransactions,
+        StorageData,
+    },
+};
+
+use super::{
+    fifo::FifoSelection,
+    knapsack::KnapsackSelection,
+    ratio_tip_gas::{
+        RatioTipGasSelection,
+        RatioTipGasSelectionAlgorithmStorage,
+    },
+    Constraints,
+    SelectionAlgorithm,
+};
+
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+/// The selection algorithm chosen by the `SelectionAlgorithmType` from the configuration.
+pub enum ConfigurableSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    RatioTipGas(RatioTipGasSelection<S>),
+    Fifo(FifoSelection<S>),
+    Knapsack(KnapsackSelection<S>),
+}
+
+impl<S> ConfigurableSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    pub fn new(selection_algorithm: SelectionAlgorithmType) -> Self {
+        match selection_algorithm {
+            SelectionAlgorithmType::RatioTipGas => {
+                Self::RatioTipGas(RatioTipGasSelection::new())
+            }
+            SelectionAlgorithmType::Fifo => Self::Fifo(FifoSelection::new()),
+            SelectionAlgorithmType::Knapsack => Self::Knapsack(KnapsackSelection::new()),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::RatioTipGas(selection) => selection.is_empty(),
+            Self::Fifo(selection) => selection.is_empty(),
+            Self::Knapsack(selection) => selection.is_empty(),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        match self {
+            Self::RatioTipGas(selection) => selection.assert_integrity(expected_txs),
+            Self::Fifo(selection) => selection.assert_integrity(expected_txs),
+            Self::Knapsack(selection) => selection.assert_integrity(expected_txs),
+        }
+    }
+}
+
+impl<S> SelectionAlgorithm for ConfigurableSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+    S::StorageIndex: Copy,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.gather_best_txs(constraints, storage)
+            }
+            Self::Fifo(selection) => selection.gather_best_txs(constraints, storage),
+            Self::Knapsack(selection) => selection.gather_best_txs(constraints, storage),
+        }
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Fifo(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+            Self::Knapsack(selection) => {
+                selection.new_executable_transaction(storage_id, store_entry)
+            }
+        }
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        match self {
+            Self::RatioTipGas(selection) => selection.number_of_executable_transactions(),
+            Self::Fifo(selection) => selection.number_of_executable_transactions(),
+            Self::Knapsack(selection) => selection.number_of_executable_transactions(),
+        }
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        // All algorithms evict transactions by the tip/gas ratio,
+        // so the iterator is the same for each of them.
+        let executable_transactions = match self {
+            Self::RatioTipGas(selection) => selection,
+            Self::Fifo(selection) => selection.executable_transactions(),
+            Self::Knapsack(selection) => selection.executable_transactions(),
+        };
+        executable_transactions.get_less_worth_txs()
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        match self {
+            Self::RatioTipGas(selection) => {
+                selection.on_removed_transaction(storage_entry)
+            }
+            Self::Fifo(selection) => selection.on_removed_transaction(storage_entry),
+            Self::Knapsack(selection) => selection.on_removed_transaction(storage_entry),
+        }
+    }
+}
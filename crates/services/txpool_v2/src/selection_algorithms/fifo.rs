@@ -0,0 +1,120 @@
+use std::{
+    cmp::Reverse,
+    time::SystemTime,
+};
+
+use fuel_core_types::fuel_tx::TxId;
+
+use crate::storage::{
+    RemovedTransactions,
+    StorageData,
+};
+
+use super::{
+    ratio_tip_gas::{
+        RatioTipGasSelection,
+        RatioTipGasSelectionAlgorithmStorage,
+    },
+    Constraints,
+    SelectionAlgorithm,
+};
+
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+/// The selection algorithm that selects transactions in the order of their arrival
+/// into the pool, regardless of the tip.
+///
+/// The less worth transactions are still defined by the tip/gas ratio,
+/// so the pool evicts the same transactions as with `RatioTipGasSelection`.
+pub struct FifoSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    executable_transactions: RatioTipGasSelection<S>,
+}
+
+impl<S> Default for FifoSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> FifoSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    pub fn new() -> Self {
+        Self {
+            executable_transactions: RatioTipGasSelection::new(),
+        }
+    }
+
+    /// Returns the tracking of the executable transactions sorted by the tip/gas ratio.
+    pub(crate) fn executable_transactions(&self) -> &RatioTipGasSelection<S> {
+        &self.executable_transactions
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.executable_transactions.is_empty()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        self.executable_transactions.assert_integrity(expected_txs)
+    }
+}
+
+impl<S> SelectionAlgorithm for FifoSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+    S::StorageIndex: Copy,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        // The earliest transaction has the highest priority.
+        self.executable_transactions.gather_best_txs_by(
+            constraints,
+            storage,
+            |store_entry| {
+                Reverse::<(SystemTime, TxId)>((
+                    store_entry.creation_instant,
+                    store_entry.transaction.id(),
+                ))
+            },
+        )
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        self.executable_transactions
+            .new_executable_transaction(storage_id, store_entry)
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        self.executable_transactions
+            .number_of_executable_transactions()
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        self.executable_transactions.get_less_worth_txs()
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        self.executable_transactions
+            .on_removed_transaction(storage_entry)
+    }
+}
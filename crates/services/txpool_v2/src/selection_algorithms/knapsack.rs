@@ -0,0 +1,170 @@
+use std::{
+    cmp::{
+        Ordering,
+        Reverse,
+    },
+    time::SystemTime,
+};
+
+use fuel_core_types::fuel_tx::TxId;
+use num_rational::Ratio;
+
+use crate::storage::{
+    RemovedTransactions,
+    StorageData,
+};
+
+use super::{
+    ratio_tip_gas::{
+        RatioTipGasSelection,
+        RatioTipGasSelectionAlgorithmStorage,
+    },
+    Constraints,
+    SelectionAlgorithm,
+};
+
+#[cfg(test)]
+use fuel_core_types::services::txpool::ArcPoolTx;
+
+/// Key used to sort transactions by the tip per used capacity of the block.
+/// It first compares the density, then the creation instant and finally the transaction id.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+struct Key {
+    density: Ratio<u128>,
+    creation_instant: Reverse<SystemTime>,
+    tx_id: TxId,
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.density
+            .cmp(&other.density)
+            .then_with(|| self.creation_instant.cmp(&other.creation_instant))
+            .then_with(|| self.tx_id.cmp(&other.tx_id))
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The knapsack-style selection algorithm that packs the block under both
+/// the gas and the block size constraints.
+///
+/// It is a greedy density heuristic, not an exact knapsack solver: transactions
+/// are selected by their density, the tip divided by the share of the scarcest
+/// resource of the block they consume. It doesn't guarantee the maximal total tip,
+/// a dense transaction may take the space of the packing that pays more.
+/// A transaction is valued together with its dependents, so a cheap transaction
+/// that unlocks profitable dependents is not left behind.
+///
+/// The less worth transactions are still defined by the tip/gas ratio,
+/// so the pool evicts the same transactions as with `RatioTipGasSelection`.
+pub struct KnapsackSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    executable_transactions: RatioTipGasSelection<S>,
+}
+
+impl<S> Default for KnapsackSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> KnapsackSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+{
+    pub fn new() -> Self {
+        Self {
+            executable_transactions: RatioTipGasSelection::new(),
+        }
+    }
+
+    /// Returns the tracking of the executable transactions sorted by the tip/gas ratio.
+    pub(crate) fn executable_transactions(&self) -> &RatioTipGasSelection<S> {
+        &self.executable_transactions
+    }
+
+    #[cfg(test)]
+    pub fn is_empty(&self) -> bool {
+        self.executable_transactions.is_empty()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
+        self.executable_transactions.assert_integrity(expected_txs)
+    }
+
+    fn key(max_gas: u64, max_size: u32, store_entry: &StorageData) -> Key {
+        // The shares of the block are compared with the common denominator
+        // `max_gas * max_size` to avoid the loss of precision.
+        let gas_share = u128::from(store_entry.dependents_cumulative_gas)
+            .saturating_mul(u128::from(max_size));
+        let size_share = (store_entry.dependents_cumulative_bytes_size as u128)
+            .saturating_mul(u128::from(max_gas));
+        let weight = gas_share.max(size_share).max(1);
+
+        Key {
+            density: Ratio::new(
+                u128::from(store_entry.dependents_cumulative_tip),
+                weight,
+            ),
+            creation_instant: Reverse(store_entry.creation_instant),
+            tx_id: store_entry.transaction.id(),
+        }
+    }
+}
+
+impl<S> SelectionAlgorithm for KnapsackSelection<S>
+where
+    S: RatioTipGasSelectionAlgorithmStorage,
+    S::StorageIndex: Copy,
+{
+    type Storage = S;
+    type StorageIndex = S::StorageIndex;
+
+    fn gather_best_txs(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+    ) -> RemovedTransactions {
+        let max_gas = constraints.max_gas;
+        let max_size = constraints.maximum_block_size;
+        self.executable_transactions.gather_best_txs_by(
+            constraints,
+            storage,
+            |store_entry| Self::key(max_gas, max_size, store_entry),
+        )
+    }
+
+    fn new_executable_transaction(
+        &mut self,
+        storage_id: Self::StorageIndex,
+        store_entry: &StorageData,
+    ) {
+        self.executable_transactions
+            .new_executable_transaction(storage_id, store_entry)
+    }
+
+    fn number_of_executable_transactions(&self) -> usize {
+        self.executable_transactions
+            .number_of_executable_transactions()
+    }
+
+    fn get_less_worth_txs(&self) -> impl Iterator<Item = &Self::StorageIndex> {
+        self.executable_transactions.get_less_worth_txs()
+    }
+
+    fn on_removed_transaction(&mut self, storage_entry: &StorageData) {
+        self.executable_transactions
+            .on_removed_transaction(storage_entry)
+    }
+}
@@ -3,6 +3,9 @@ use crate::storage::{
     StorageData,
 };
 
+pub mod configurable;
+pub mod fifo;
+pub mod knapsack;
 pub mod ratio_tip_gas;
 
 /// Constraints that the selection algorithm has to respect.
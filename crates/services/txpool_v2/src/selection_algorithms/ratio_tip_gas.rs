@@ -123,6 +123,87 @@ where
             .remove(&Reverse(key));
     }
 
+    /// Selects the executable transactions starting from the one with the highest
+    /// `priority`. The dependents of a selected transaction become candidates for
+    /// the selection as soon as all of their dependencies are selected.
+    ///
+    /// It allows other selection algorithms to reuse the tracking of the executable
+    /// transactions and only define the order of the selection.
+    pub(crate) fn gather_best_txs_by<P>(
+        &mut self,
+        constraints: Constraints,
+        storage: &mut S,
+        priority: impl Fn(&StorageData) -> P,
+    ) -> RemovedTransactions
+    where
+        P: Ord,
+        S::StorageIndex: Copy,
+    {
+        let mut gas_left = constraints.max_gas;
+        let mut space_left = constraints.maximum_block_size as usize;
+        let mut nb_left = constraints.maximum_txs;
+        let mut result = Vec::new();
+
+        let mut candidates = BTreeMap::new();
+        for storage_id in self.executable_transactions_sorted_tip_gas_ratio.values() {
+            let Some(stored_transaction) = storage.get(storage_id) else {
+                debug_assert!(
+                    false,
+                    "Transaction not found in the storage during `gather_best_txs_by`."
+                );
+                tracing::warn!(
+                    "Transaction not found in the storage during `gather_best_txs_by`."
+                );
+                continue
+            };
+            candidates.insert(priority(stored_transaction), *storage_id);
+        }
+
+        while gas_left > 0 && nb_left > 0 && space_left > 0 {
+            let Some((_, storage_id)) = candidates.pop_last() else {
+                break
+            };
+            let Some(stored_transaction) = storage.get(&storage_id) else {
+                continue
+            };
+
+            let less_price = stored_transaction.transaction.max_gas_price()
+                < constraints.minimal_gas_price;
+            let not_enough_gas = stored_transaction.transaction.max_gas() > gas_left;
+            let too_big_tx =
+                stored_transaction.transaction.metered_bytes_size() > space_left;
+
+            if less_price || not_enough_gas || too_big_tx {
+                continue
+            }
+
+            gas_left = gas_left.saturating_sub(stored_transaction.transaction.max_gas());
+            space_left = space_left
+                .saturating_sub(stored_transaction.transaction.metered_bytes_size());
+            nb_left = nb_left.saturating_sub(1);
+
+            let dependents = storage.get_dependents(&storage_id).collect::<Vec<_>>();
+            debug_assert!(!storage.has_dependencies(&storage_id));
+            let removed = storage.remove(&storage_id).expect(
+                "We just get the transaction from the storage above, it should exist.",
+            );
+            self.on_removed_transaction_inner(Self::key(&removed));
+            result.push(removed);
+
+            for dependent in dependents {
+                if !storage.has_dependencies(&dependent) {
+                    let stored_transaction = storage.get(&dependent).expect(
+                        "We just get the dependent from the storage, it should exist.",
+                    );
+                    candidates.insert(priority(stored_transaction), dependent);
+                    self.new_executable_transaction(dependent, stored_transaction);
+                }
+            }
+        }
+
+        result
+    }
+
     #[cfg(test)]
     pub(crate) fn assert_integrity(&self, expected_txs: &[ArcPoolTx]) {
         let mut expected_txs: HashMap<TxId, ArcPoolTx> = expected_txs
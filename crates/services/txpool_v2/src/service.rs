@@ -12,9 +12,13 @@ use fuel_core_services::{
     StateWatcher,
     SyncProcessor,
 };
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_txpool::{
     collision_manager::basic::BasicCollisionManager,
-    config::Config,
+    config::{
+        BlackList,
+        Config,
+    },
     error::{
         Error,
         RemovedReason,
@@ -30,10 +34,15 @@ use fuel_core_txpool::{
         TxPoolPersistentStorage,
         WasmChecker as WasmCheckerTrait,
     },
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::configurable::ConfigurableSelection,
     service::{
+        future_queue::FutureQueue,
         memory::MemoryPool,
         p2p::P2PExt,
+        persistence::{
+            BlackListPersistence,
+            TransactionPersistence,
+        },
         pruner::TransactionPruner,
         subscriptions::Subscriptions,
         verifications::Verification,
@@ -53,6 +62,7 @@ use fuel_core_txpool::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        Address,
         Transaction,
         TxId,
         UniqueIdentifier,
@@ -71,6 +81,7 @@ use fuel_core_types::{
         },
         txpool::{
             ArcPoolTx,
+            PendingTransaction,
             TransactionStatus,
         },
     },
@@ -98,8 +109,10 @@ use tokio::{
     time::MissedTickBehavior,
 };
 
+mod future_queue;
 pub(crate) mod memory;
 mod p2p;
+mod persistence;
 mod pruner;
 mod subscriptions;
 pub(crate) mod verifications;
@@ -108,7 +121,7 @@ pub type TxPool = Pool<
     GraphStorage,
     <GraphStorage as Storage>::StorageIndex,
     BasicCollisionManager<<GraphStorage as Storage>::StorageIndex>,
-    RatioTipGasSelection<GraphStorage>,
+    ConfigurableSelection<GraphStorage>,
 >;
 
 pub(crate) type Shared<T> = Arc<RwLock<T>>;
@@ -165,6 +178,11 @@ pub enum WritePoolRequest {
     RemoveCoinDependents {
         transactions: Vec<(TxId, String)>,
     },
+    UpdateBlackList {
+        add: BlackList,
+        remove: BlackList,
+        response_channel: oneshot::Sender<Result<BlackList, Error>>,
+    },
 }
 
 pub enum ReadPoolRequest {
@@ -176,6 +194,16 @@ pub enum ReadPoolRequest {
         tx_ids: Vec<TxId>,
         response_channel: oneshot::Sender<Vec<Option<TxInfo>>>,
     },
+    GetPendingTxs {
+        owner: Option<Address>,
+        start: Option<TxId>,
+        direction: IterDirection,
+        limit: usize,
+        response_channel: oneshot::Sender<Vec<PendingTransaction>>,
+    },
+    GetBlackList {
+        response_channel: oneshot::Sender<BlackList>,
+    },
 }
 
 pub struct Task<View> {
@@ -187,6 +215,9 @@ pub struct Task<View> {
     transaction_verifier_process: SyncProcessor,
     p2p_sync_process: AsyncProcessor,
     pruner: TransactionPruner,
+    persistence: Option<TransactionPersistence>,
+    black_list_persistence: Option<BlackListPersistence>,
+    future_queue: Option<Shared<FutureQueue>>,
     pool: Shared<TxPool>,
     current_height: Shared<BlockHeight>,
     tx_sync_history: Shared<HashSet<PeerId>>,
@@ -216,6 +247,8 @@ where
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
+        self.restore_black_list()?;
+        self.restore_transactions();
         Ok(self)
     }
 }
@@ -256,6 +289,11 @@ where
                 TaskNextAction::Continue
             }
 
+            _ = TransactionPersistence::tick(&mut self.persistence) => {
+                self.persist_transactions();
+                TaskNextAction::Continue
+            }
+
             write_pool_request = self.subscriptions.write_pool.recv() => {
                 if let Some(write_pool_request) = write_pool_request {
                     self.process_write(write_pool_request);
@@ -297,6 +335,7 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
+        self.persist_transactions();
         Ok(())
     }
 }
@@ -314,6 +353,19 @@ where
         {
             let mut tx_pool = self.pool.write();
             tx_pool.remove_transaction(executed_transaction);
+
+            // The gas price may grow after the block, so we evict transactions
+            // that can't pay the gas price of the next block anymore.
+            let minimal_gas_price = self.verification.gas_price_provider.next_gas_price();
+            let removed =
+                tx_pool.remove_transactions_with_insufficient_max_fee(minimal_gas_price);
+            for tx in removed {
+                self.shared_state.tx_status_sender.send_squeezed_out(
+                    tx.id(),
+                    Error::Removed(RemovedReason::InsufficientMaxFee),
+                );
+            }
+
             if !tx_pool.is_empty() {
                 self.shared_state.new_txs_notifier.send_replace(());
             }
@@ -323,6 +375,25 @@ where
             let mut block_height = self.current_height.write();
             *block_height = new_height;
         }
+
+        if let Some(future_queue) = &self.future_queue {
+            let mature_txs = future_queue.write().extract_mature(new_height);
+            // The blacklist could be updated while the transactions were waiting for maturity.
+            let (blacklisted, mature_txs): (Vec<_>, Vec<_>) = {
+                let pool = self.pool.read();
+                let black_list = pool.black_list();
+                mature_txs.into_iter().partition(|tx| {
+                    black_list.check_inputs(future_queue::inputs(tx)).is_err()
+                })
+            };
+            for tx in blacklisted {
+                let tx_id = tx.id(&self.chain_id);
+                self.shared_state
+                    .tx_status_sender
+                    .send_squeezed_out(tx_id, Error::Removed(RemovedReason::Blacklisted));
+            }
+            self.insert_transactions(mature_txs);
+        }
     }
 
     fn borrow_txpool(&self, request: BorrowTxPoolRequest) {
@@ -345,6 +416,7 @@ where
                         transaction,
                         None,
                         Some(response_channel),
+                        None,
                     );
 
                     self.transaction_verifier_process
@@ -357,6 +429,18 @@ where
             WritePoolRequest::RemoveCoinDependents { transactions } => {
                 self.manage_remove_coin_dependents(transactions);
             }
+            WritePoolRequest::UpdateBlackList {
+                add,
+                remove,
+                response_channel,
+            } => {
+                let result = self.update_black_list(add, remove);
+                if response_channel.send(result).is_err() {
+                    tracing::error!(
+                        "Failed to send the result back for `UpdateBlackList` request"
+                    );
+                }
+            }
         }
     }
 
@@ -366,7 +450,7 @@ where
                 tracing::error!("Failed to insert transactions: Out of capacity");
                 continue
             };
-            let op = self.insert_transaction(transaction, None, None);
+            let op = self.insert_transaction(transaction, None, None, None);
 
             self.transaction_verifier_process
                 .spawn_reserved(reservation, op);
@@ -378,6 +462,10 @@ where
         transaction: Arc<Transaction>,
         from_peer_info: Option<GossipsubMessageInfo>,
         response_channel: Option<oneshot::Sender<Result<(), Error>>>,
+        // The time of the original submission if the transaction is restored.
+        // The restored transactions are not broadcast, because the peers
+        // already received them before the restart.
+        submitted_time: Option<SystemTime>,
     ) -> impl FnOnce() + Send + 'static {
         let metrics = self.metrics;
         if metrics {
@@ -394,10 +482,23 @@ where
         let time_txs_submitted = self.pruner.time_txs_submitted.clone();
         let tx_id = transaction.id(&self.chain_id);
         let utxo_validation = self.utxo_validation;
+        let future_queue = self.future_queue.clone();
 
         let insert_transaction_thread_pool_op = move || {
             let current_height = *current_height.read();
 
+            // The transaction that is not mature yet is verified as of its maturity
+            // and waits in the future queue instead of the pool.
+            let maturity = future_queue::maturity(&transaction);
+            let future_queue = future_queue
+                .filter(|queue| queue.read().accepts(maturity, current_height))
+                .map(|queue| (queue, transaction.clone()));
+            let verification_height = if future_queue.is_some() {
+                maturity
+            } else {
+                current_height
+            };
+
             // TODO: This should be removed if the checked transactions
             //  can work with Arc in it
             //  (see https://github.com/FuelLabs/fuel-vm/issues/831)
@@ -406,7 +507,7 @@ where
             let result = verification.perform_all_verifications(
                 transaction,
                 &pool,
-                current_height,
+                verification_height,
                 utxo_validation,
             );
 
@@ -416,7 +517,9 @@ where
                     .dec();
             }
 
-            p2p.process_insertion_result(from_peer_info, &result);
+            if submitted_time.is_none() {
+                p2p.process_insertion_result(from_peer_info, &result);
+            }
 
             let checked_tx = match result {
                 Ok(checked_tx) => checked_tx,
@@ -430,6 +533,26 @@ where
                 }
             };
 
+            if let Some((future_queue, transaction)) = future_queue {
+                // The transaction is verified again when it is promoted into the pool.
+                let submitted_time = submitted_time.unwrap_or_else(SystemTime::now);
+                let result = future_queue.write().insert(
+                    maturity,
+                    tx_id,
+                    submitted_time,
+                    transaction,
+                );
+                if let Err(err) = &result {
+                    shared_state
+                        .tx_status_sender
+                        .send_squeezed_out(tx_id, err.clone());
+                }
+                if let Some(channel) = response_channel {
+                    let _ = channel.send(result);
+                }
+                return
+            }
+
             let tx = Arc::new(checked_tx);
 
             let result = {
@@ -444,7 +567,7 @@ where
 
             let removed_txs = match result {
                 Ok(removed_txs) => {
-                    let submitted_time = SystemTime::now();
+                    let submitted_time = submitted_time.unwrap_or_else(SystemTime::now);
                     time_txs_submitted
                         .write()
                         .push_front((submitted_time, tx_id));
@@ -526,7 +649,7 @@ where
             message_id,
             peer_id,
         });
-        let op = self.insert_transaction(Arc::new(tx), info, None);
+        let op = self.insert_transaction(Arc::new(tx), info, None, None);
         self.transaction_verifier_process
             .spawn_reserved(reservation, op);
     }
@@ -631,6 +754,17 @@ where
                 .send_squeezed_out(tx.id(), Error::Removed(RemovedReason::Ttl));
         }
 
+        if let Some(future_queue) = &self.future_queue {
+            let expired = future_queue
+                .write()
+                .remove_expired(SystemTime::now(), self.pruner.txs_ttl);
+            for tx_id in expired {
+                self.shared_state
+                    .tx_status_sender
+                    .send_squeezed_out(tx_id, Error::Removed(RemovedReason::Ttl));
+            }
+        }
+
         {
             // Each time when we prune transactions, clear the history of synchronization
             // to have a chance to sync this transaction again from other peers.
@@ -639,6 +773,133 @@ where
         }
     }
 
+    /// Replaces the blacklist of the pool with the blacklist stored before the restart.
+    /// Once stored, the blacklist is authoritative, so the entries removed at runtime
+    /// don't come back from the configuration.
+    fn restore_black_list(&self) -> anyhow::Result<()> {
+        let Some(persistence) = &self.black_list_persistence else {
+            return Ok(())
+        };
+        if let Some(stored) = persistence.load()? {
+            self.pool.write().set_black_list(stored);
+        }
+        Ok(())
+    }
+
+    /// Applies the runtime changes to the blacklist and removes the transactions
+    /// that became blacklisted from the pool. The new blacklist is persisted first,
+    /// so it is not applied if it can't be stored.
+    fn update_black_list(
+        &self,
+        add: BlackList,
+        remove: BlackList,
+    ) -> Result<BlackList, Error> {
+        let mut black_list = self.pool.read().black_list().clone();
+        black_list.extend(add);
+        black_list.remove(&remove);
+
+        if let Some(persistence) = &self.black_list_persistence {
+            persistence
+                .store(&black_list)
+                .map_err(|e| Error::Storage(format!("{:?}", e)))?;
+        }
+
+        let removed = self.pool.write().set_black_list(black_list.clone());
+        for tx in removed {
+            self.shared_state
+                .tx_status_sender
+                .send_squeezed_out(tx.id(), Error::Removed(RemovedReason::Blacklisted));
+        }
+        if let Some(future_queue) = &self.future_queue {
+            let removed = future_queue.write().remove_blacklisted(&black_list);
+            for tx_id in removed {
+                self.shared_state
+                    .tx_status_sender
+                    .send_squeezed_out(tx_id, Error::Removed(RemovedReason::Blacklisted));
+            }
+        }
+
+        Ok(black_list)
+    }
+
+    /// Re-inserts the transactions stored before the restart in the background.
+    /// The transactions that became invalid are dropped by the verification.
+    fn restore_transactions(&self) {
+        let Some(persistence) = &self.persistence else {
+            return
+        };
+        let transactions = match persistence.load() {
+            Ok(transactions) => transactions,
+            Err(err) => {
+                tracing::error!("Failed to load the persisted transactions: {err}");
+                return
+            }
+        };
+        tracing::info!("Restoring {} persisted transactions", transactions.len());
+
+        let now = SystemTime::now();
+        let inserts: Vec<_> = transactions
+            .into_iter()
+            .filter(|(submitted_time, _)| {
+                let expired = now
+                    .duration_since(*submitted_time)
+                    .map(|elapsed| elapsed >= self.pruner.txs_ttl)
+                    .unwrap_or(false);
+                !expired
+            })
+            .map(|(submitted_time, transaction)| {
+                self.insert_transaction(
+                    Arc::new(transaction),
+                    None,
+                    None,
+                    Some(submitted_time),
+                )
+            })
+            .collect();
+
+        // The transactions are inserted one by one in the order of submission
+        // by a single task, so the dependent transactions are inserted after
+        // their parents.
+        let result = self.transaction_verifier_process.try_spawn(move || {
+            for insert in inserts {
+                insert();
+            }
+        });
+        if result.is_err() {
+            tracing::error!(
+                "Failed to restore the persisted transactions: Out of capacity"
+            );
+        }
+    }
+
+    fn persist_transactions(&self) {
+        let Some(persistence) = &self.persistence else {
+            return
+        };
+        let time_txs_submitted: Vec<_> = self
+            .pruner
+            .time_txs_submitted
+            .read()
+            .iter()
+            .copied()
+            .collect();
+        let transactions: Vec<(SystemTime, Transaction)> = {
+            let pool = self.pool.read();
+            time_txs_submitted
+                .into_iter()
+                .filter_map(|(submitted_time, tx_id)| {
+                    pool.find_one(&tx_id).map(|stored_data| {
+                        (submitted_time, stored_data.transaction.as_ref().into())
+                    })
+                })
+                .collect()
+        };
+
+        if let Err(err) = persistence.store(transactions) {
+            tracing::error!("Failed to persist the transactions: {err}");
+        }
+    }
+
     fn process_read(&self, request: ReadPoolRequest) {
         match request {
             ReadPoolRequest::GetTxIds {
@@ -677,6 +938,31 @@ where
                     );
                 }
             }
+            ReadPoolRequest::GetPendingTxs {
+                owner,
+                start,
+                direction,
+                limit,
+                response_channel,
+            } => {
+                let txs = {
+                    let pool = self.pool.read();
+                    pool.pending_transactions(owner.as_ref(), start, direction, limit)
+                };
+                if response_channel.send(txs).is_err() {
+                    tracing::error!(
+                        "Failed to send the result back for `GetPendingTxs` request"
+                    );
+                }
+            }
+            ReadPoolRequest::GetBlackList { response_channel } => {
+                let black_list = self.pool.read().black_list().clone();
+                if response_channel.send(black_list).is_err() {
+                    tracing::error!(
+                        "Failed to send the result back for `GetBlackList` request"
+                    );
+                }
+            }
         }
     }
 }
@@ -767,6 +1053,27 @@ where
         ttl_timer,
     };
 
+    let persistence = config.persistence.as_ref().map(|persistence| {
+        let mut timer = tokio::time::interval(persistence.interval);
+        timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        TransactionPersistence {
+            path: persistence.path.clone(),
+            timer,
+        }
+    });
+
+    let black_list_persistence = config
+        .black_list_path
+        .clone()
+        .map(|path| BlackListPersistence { path });
+
+    let future_queue = config.future_queue.as_ref().map(|future_queue| {
+        Arc::new(RwLock::new(FutureQueue::new(
+            future_queue.max_txs,
+            future_queue.max_blocks_ahead,
+        )))
+    });
+
     let transaction_verifier_process = SyncProcessor::new(
         "TxPool_TxVerifierProcessor",
         config.heavy_work.number_threads_to_verify_transactions,
@@ -789,7 +1096,7 @@ where
             max_txs_chain_count: config.max_txs_chain_count,
         }),
         BasicCollisionManager::new(),
-        RatioTipGasSelection::new(),
+        ConfigurableSelection::new(config.selection_algorithm),
         config,
     );
 
@@ -801,6 +1108,9 @@ where
         transaction_verifier_process,
         p2p_sync_process,
         pruner,
+        persistence,
+        black_list_persistence,
+        future_queue,
         p2p: Arc::new(p2p),
         current_height: Arc::new(RwLock::new(current_height)),
         pool: Arc::new(RwLock::new(txpool)),
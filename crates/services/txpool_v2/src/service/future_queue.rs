@@ -0,0 +1,172 @@
+use crate::{
+    config::BlackList,
+    error::{
+        Error,
+        InputValidationError,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            Inputs,
+            Maturity,
+        },
+        Input,
+        Transaction,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashSet,
+    },
+    sync::Arc,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+struct QueuedTransaction {
+    tx_id: TxId,
+    submitted_at: SystemTime,
+    transaction: Arc<Transaction>,
+}
+
+/// The queue of verified transactions waiting for their maturity height.
+pub(super) struct FutureQueue {
+    max_txs: usize,
+    max_blocks_ahead: u32,
+    /// The transactions grouped by their maturity.
+    transactions: BTreeMap<BlockHeight, Vec<QueuedTransaction>>,
+    tx_ids: HashSet<TxId>,
+}
+
+impl FutureQueue {
+    pub fn new(max_txs: usize, max_blocks_ahead: u32) -> Self {
+        Self {
+            max_txs,
+            max_blocks_ahead,
+            transactions: BTreeMap::new(),
+            tx_ids: HashSet::new(),
+        }
+    }
+
+    /// Returns `true` if the transaction with the `maturity` is not mature
+    /// at the `current_height`, but can wait for its maturity in the queue.
+    pub fn accepts(&self, maturity: BlockHeight, current_height: BlockHeight) -> bool {
+        let max_height = current_height.saturating_add(self.max_blocks_ahead);
+        maturity > current_height && *maturity <= max_height
+    }
+
+    pub fn insert(
+        &mut self,
+        maturity: BlockHeight,
+        tx_id: TxId,
+        submitted_at: SystemTime,
+        transaction: Arc<Transaction>,
+    ) -> Result<(), Error> {
+        if self.tx_ids.contains(&tx_id) {
+            return Err(Error::InputValidation(InputValidationError::DuplicateTxId(
+                tx_id,
+            )))
+        }
+        if self.tx_ids.len() >= self.max_txs {
+            return Err(Error::FutureQueueFull)
+        }
+        self.tx_ids.insert(tx_id);
+        self.transactions
+            .entry(maturity)
+            .or_default()
+            .push(QueuedTransaction {
+                tx_id,
+                submitted_at,
+                transaction,
+            });
+        Ok(())
+    }
+
+    /// Removes the transactions that are mature at the `height` from the queue.
+    /// The transactions are returned in the order of their maturity.
+    pub fn extract_mature(&mut self, height: BlockHeight) -> Vec<Arc<Transaction>> {
+        let not_mature = match height.succ() {
+            Some(next_height) => self.transactions.split_off(&next_height),
+            None => BTreeMap::new(),
+        };
+        let mature = core::mem::replace(&mut self.transactions, not_mature);
+
+        mature
+            .into_values()
+            .flatten()
+            .map(|queued| {
+                self.tx_ids.remove(&queued.tx_id);
+                queued.transaction
+            })
+            .collect()
+    }
+
+    /// Removes the transactions submitted more than `ttl` before the `now`.
+    pub fn remove_expired(&mut self, now: SystemTime, ttl: Duration) -> Vec<TxId> {
+        self.remove_where(|queued| {
+            now.duration_since(queued.submitted_at)
+                .is_ok_and(|duration| duration >= ttl)
+        })
+    }
+
+    /// Removes the transactions that spend inputs of the `black_list`.
+    pub fn remove_blacklisted(&mut self, black_list: &BlackList) -> Vec<TxId> {
+        self.remove_where(|queued| {
+            black_list
+                .check_inputs(inputs(&queued.transaction))
+                .is_err()
+        })
+    }
+
+    fn remove_where(
+        &mut self,
+        mut predicate: impl FnMut(&QueuedTransaction) -> bool,
+    ) -> Vec<TxId> {
+        let mut removed = vec![];
+        self.transactions.retain(|_, queued_txs| {
+            queued_txs.retain(|queued| {
+                if predicate(queued) {
+                    removed.push(queued.tx_id);
+                    false
+                } else {
+                    true
+                }
+            });
+            !queued_txs.is_empty()
+        });
+        for tx_id in &removed {
+            self.tx_ids.remove(tx_id);
+        }
+        removed
+    }
+}
+
+/// Returns the height starting from which the transaction can be included into the block.
+pub(super) fn maturity(transaction: &Transaction) -> BlockHeight {
+    match transaction {
+        Transaction::Script(tx) => tx.maturity(),
+        Transaction::Create(tx) => tx.maturity(),
+        Transaction::Upgrade(tx) => tx.maturity(),
+        Transaction::Upload(tx) => tx.maturity(),
+        Transaction::Blob(tx) => tx.maturity(),
+        Transaction::Mint(_) => BlockHeight::default(),
+    }
+}
+
+/// Returns the inputs of the transaction.
+pub(super) fn inputs(transaction: &Transaction) -> &[Input] {
+    match transaction {
+        Transaction::Script(tx) => tx.inputs(),
+        Transaction::Create(tx) => tx.inputs(),
+        Transaction::Upgrade(tx) => tx.inputs(),
+        Transaction::Upload(tx) => tx.inputs(),
+        Transaction::Blob(tx) => tx.inputs(),
+        Transaction::Mint(_) => &[],
+    }
+}
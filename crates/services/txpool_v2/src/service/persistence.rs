@@ -0,0 +1,120 @@
+use crate::config::BlackList;
+use fuel_core_types::fuel_tx::Transaction;
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+/// The transaction stored in the file along with its submission time.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct PersistedTransaction {
+    /// Milliseconds since the UNIX epoch.
+    submitted_at: u64,
+    transaction: Transaction,
+}
+
+pub(super) struct TransactionPersistence {
+    pub path: PathBuf,
+    pub timer: tokio::time::Interval,
+}
+
+impl TransactionPersistence {
+    /// Loads the stored transactions ordered by their submission time.
+    /// Returns an empty list if nothing was stored yet.
+    pub fn load(&self) -> anyhow::Result<Vec<(SystemTime, Transaction)>> {
+        let Some(bytes) = read_file(&self.path)? else {
+            return Ok(vec![])
+        };
+        let persisted: Vec<PersistedTransaction> = postcard::from_bytes(&bytes)?;
+
+        let mut transactions = persisted
+            .into_iter()
+            .map(|persisted| {
+                let submitted_at = SystemTime::UNIX_EPOCH
+                    .checked_add(Duration::from_millis(persisted.submitted_at))
+                    .unwrap_or(SystemTime::UNIX_EPOCH);
+                (submitted_at, persisted.transaction)
+            })
+            .collect::<Vec<_>>();
+        transactions.sort_by_key(|(submitted_at, _)| *submitted_at);
+        Ok(transactions)
+    }
+
+    /// Replaces the stored transactions with the `transactions`.
+    pub fn store(
+        &self,
+        transactions: Vec<(SystemTime, Transaction)>,
+    ) -> anyhow::Result<()> {
+        let persisted = transactions
+            .into_iter()
+            .map(|(submitted_at, transaction)| {
+                let submitted_at = submitted_at
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis()
+                    .try_into()
+                    .unwrap_or(u64::MAX);
+                PersistedTransaction {
+                    submitted_at,
+                    transaction,
+                }
+            })
+            .collect::<Vec<_>>();
+        let bytes = postcard::to_allocvec(&persisted)?;
+        write_file(&self.path, bytes)
+    }
+
+    /// Waits for the next tick of the timer, or forever if the persistence is disabled.
+    pub async fn tick(persistence: &mut Option<Self>) {
+        match persistence {
+            Some(persistence) => {
+                persistence.timer.tick().await;
+            }
+            None => futures::future::pending().await,
+        }
+    }
+}
+
+pub(super) struct BlackListPersistence {
+    pub path: PathBuf,
+}
+
+impl BlackListPersistence {
+    /// Loads the stored blacklist. Returns `None` if nothing was stored yet.
+    pub fn load(&self) -> anyhow::Result<Option<BlackList>> {
+        let Some(bytes) = read_file(&self.path)? else {
+            return Ok(None)
+        };
+        Ok(Some(postcard::from_bytes(&bytes)?))
+    }
+
+    /// Replaces the stored blacklist with the `black_list`.
+    pub fn store(&self, black_list: &BlackList) -> anyhow::Result<()> {
+        let bytes = postcard::to_allocvec(black_list)?;
+        write_file(&self.path, bytes)
+    }
+}
+
+/// Reads the file, or returns `None` if it doesn't exist.
+fn read_file(path: &Path) -> anyhow::Result<Option<Vec<u8>>> {
+    match std::fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn write_file(path: &Path, bytes: Vec<u8>) -> anyhow::Result<()> {
+    // Write to the temporary file first to not corrupt
+    // the previous state if the node stops in the middle.
+    let tmp_path = path.with_extension("tmp");
+    std::fs::write(&tmp_path, bytes)?;
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
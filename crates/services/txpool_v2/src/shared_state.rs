@@ -1,14 +1,19 @@
 use std::sync::Arc;
 
 use anyhow::anyhow;
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_types::{
     fuel_tx::{
+        Address,
         Bytes32,
         Transaction,
         TxId,
     },
     fuel_types::BlockHeight,
-    services::txpool::TransactionStatus,
+    services::txpool::{
+        PendingTransaction,
+        TransactionStatus,
+    },
 };
 use parking_lot::RwLockWriteGuard;
 use tokio::sync::{
@@ -19,6 +24,7 @@ use tokio::sync::{
 };
 
 use crate::{
+    config::BlackList,
     error::Error,
     service::{
         BorrowTxPoolRequest,
@@ -130,6 +136,69 @@ impl SharedState {
             .map_err(|_| Error::ServiceCommunicationFailed)
     }
 
+    /// Returns up to `limit` transactions in the pool spending the coins or messages
+    /// of the `owner`(all if `None`) along with their dependencies and dependents
+    /// in the pool. The transactions are ordered by id in the `direction`
+    /// starting from the `start` id(inclusive).
+    pub async fn pending_transactions(
+        &self,
+        owner: Option<Address>,
+        start: Option<TxId>,
+        direction: IterDirection,
+        limit: usize,
+    ) -> Result<Vec<PendingTransaction>, Error> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.read_pool_requests_sender
+            .send(ReadPoolRequest::GetPendingTxs {
+                owner,
+                start,
+                direction,
+                limit,
+                response_channel: result_sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
+    /// Returns the blacklist of the pool.
+    pub async fn black_list(&self) -> Result<BlackList, Error> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.read_pool_requests_sender
+            .send(ReadPoolRequest::GetBlackList {
+                response_channel: result_sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)
+    }
+
+    /// Adds the entries of `add` to the blacklist and removes the entries of `remove`
+    /// from it. The transactions in the pool that became blacklisted are removed.
+    /// Returns the updated blacklist.
+    pub async fn update_black_list(
+        &self,
+        add: BlackList,
+        remove: BlackList,
+    ) -> Result<BlackList, Error> {
+        let (result_sender, result_receiver) = oneshot::channel();
+        self.write_pool_requests_sender
+            .send(WritePoolRequest::UpdateBlackList {
+                add,
+                remove,
+                response_channel: result_sender,
+            })
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?;
+        result_receiver
+            .await
+            .map_err(|_| Error::ServiceCommunicationFailed)?
+    }
+
     /// Get a notifier that is notified when new transactions are added to the pool.
     pub fn get_new_txs_notifier(&self) -> watch::Receiver<()> {
         self.new_txs_notifier.subscribe()
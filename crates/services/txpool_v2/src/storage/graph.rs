@@ -606,6 +606,13 @@ impl Storage for GraphStorage {
         self.get_direct_dependents(index)
     }
 
+    fn get_direct_dependencies(
+        &self,
+        index: Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex> {
+        self.get_direct_dependencies(index)
+    }
+
     fn has_dependencies(&self, index: &Self::StorageIndex) -> bool {
         self.get_direct_dependencies(*index).next().is_some()
     }
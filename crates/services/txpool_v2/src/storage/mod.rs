@@ -83,6 +83,12 @@ pub trait Storage {
         index: Self::StorageIndex,
     ) -> impl Iterator<Item = Self::StorageIndex>;
 
+    /// Get direct dependencies of a transaction.
+    fn get_direct_dependencies(
+        &self,
+        index: Self::StorageIndex,
+    ) -> impl Iterator<Item = Self::StorageIndex>;
+
     /// Returns `true` if the transaction has dependencies.
     fn has_dependencies(&self, index: &Self::StorageIndex) -> bool;
 
@@ -214,18 +214,25 @@ impl AtomicView for MockDBProvider {
 
 #[derive(Debug, Clone)]
 pub struct MockTxPoolGasPrice {
-    pub gas_price: GasPrice,
+    pub gas_price: Arc<Mutex<GasPrice>>,
 }
 
 impl MockTxPoolGasPrice {
     pub fn new(gas_price: GasPrice) -> Self {
-        Self { gas_price }
+        Self {
+            gas_price: Arc::new(Mutex::new(gas_price)),
+        }
+    }
+
+    /// Changes the gas price for all clones of the provider.
+    pub fn set_gas_price(&self, gas_price: GasPrice) {
+        *self.gas_price.lock().unwrap() = gas_price;
     }
 }
 
 impl GasPriceProvider for MockTxPoolGasPrice {
     fn next_gas_price(&self) -> GasPrice {
-        self.gas_price
+        *self.gas_price.lock().unwrap()
     }
 }
 
@@ -340,4 +347,30 @@ impl MockImporter {
         });
         importer
     }
+
+    /// Returns the importer that emits the blocks sent to the `block_provider`.
+    pub fn with_block_provider(
+        block_provider: tokio::sync::mpsc::Receiver<SharedImportResult>,
+    ) -> Self {
+        let block_provider = Mutex::new(Some(block_provider));
+        let mut importer = MockImporter::default();
+        importer.expect_block_events().returning(move || {
+            let block_provider = block_provider
+                .lock()
+                .unwrap()
+                .take()
+                .expect("Block events are requested only once");
+            let stream = fuel_core_services::stream::unfold(
+                block_provider,
+                |mut block_provider| async {
+                    match block_provider.recv().await {
+                        Some(result) => Some((result, block_provider)),
+                        None => core::future::pending().await,
+                    }
+                },
+            );
+            Box::pin(stream)
+        });
+        importer
+    }
 }
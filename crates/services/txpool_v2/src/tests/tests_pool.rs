@@ -2,6 +2,7 @@ use crate::{
     config::{
         Config,
         PoolLimits,
+        SelectionAlgorithmType,
     },
     error::{
         BlacklistedError,
@@ -25,6 +26,7 @@ use crate::{
         },
     },
 };
+use fuel_core_storage::iter::IterDirection;
 use fuel_core_types::{
     fuel_asm::{
         op,
@@ -197,6 +199,93 @@ fn insert__tx2_succeeds_after_dependent_tx1() {
     universe.assert_pool_integrity(&[result1.unwrap().0, result2.unwrap().0]);
 }
 
+#[test]
+fn pending_transactions__returns_dependencies_and_dependents() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let owner = *output.to().unwrap();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 10);
+    let tx1_id = tx1.id(&ChainId::default());
+    let input = unset_input.into_input(UtxoId::new(tx1_id, 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 20);
+    let tx2_id = tx2.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+
+    // When
+    let mut txs = universe.get_pool().read().pending_transactions(
+        None,
+        None,
+        IterDirection::Forward,
+        10,
+    );
+    let owned = universe.get_pool().read().pending_transactions(
+        Some(&owner),
+        None,
+        IterDirection::Forward,
+        10,
+    );
+    let unrelated = universe.get_pool().read().pending_transactions(
+        Some(&Address::new([1; 32])),
+        None,
+        IterDirection::Forward,
+        10,
+    );
+
+    // Then
+    txs.sort_by_key(|tx| tx.dependencies.len());
+    assert_eq!(txs.len(), 2);
+    assert_eq!(txs[0].transaction.id(), tx1_id);
+    assert_eq!(txs[0].dependencies, vec![]);
+    assert_eq!(txs[0].dependents, vec![tx2_id]);
+    assert_eq!(txs[0].dependents_cumulative_tip, 30);
+    assert_eq!(txs[1].transaction.id(), tx2_id);
+    assert_eq!(txs[1].dependencies, vec![tx1_id]);
+    assert_eq!(txs[1].dependents, vec![]);
+    assert_eq!(txs[1].dependents_cumulative_tip, 20);
+    assert_eq!(owned.len(), 1);
+    assert_eq!(owned[0].transaction.id(), tx2_id);
+    assert!(unrelated.is_empty());
+}
+
+#[test]
+fn pending_transactions__paginates_by_id_in_the_direction() {
+    let mut universe = TestPoolUniverse::default();
+    universe.build_pool();
+
+    // Given
+    let mut ids = vec![];
+    for tip in 1..=5 {
+        let tx = universe.build_script_transaction(None, None, tip);
+        ids.push(tx.id(&ChainId::default()));
+        universe.verify_and_insert(tx).unwrap();
+    }
+    ids.sort();
+
+    // When
+    let forward = universe.get_pool().read().pending_transactions(
+        None,
+        Some(ids[1]),
+        IterDirection::Forward,
+        2,
+    );
+    let reverse = universe.get_pool().read().pending_transactions(
+        None,
+        Some(ids[3]),
+        IterDirection::Reverse,
+        3,
+    );
+
+    // Then
+    let forward: Vec<_> = forward.iter().map(|tx| tx.transaction.id()).collect();
+    let reverse: Vec<_> = reverse.iter().map(|tx| tx.transaction.id()).collect();
+    assert_eq!(forward, vec![ids[1], ids[2]]);
+    assert_eq!(reverse, vec![ids[3], ids[2], ids[1]]);
+}
+
 #[test]
 fn insert__tx2_collided_on_contract_id() {
     let mut universe = TestPoolUniverse::default();
@@ -583,6 +672,203 @@ fn insert__tx_bytes_limit() {
     universe.assert_pool_integrity(&[pool_tx]);
 }
 
+#[test]
+fn insert__owner_tx_limit_hit() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        owner_limits: Some(PoolLimits {
+            max_txs: 1,
+            max_bytes_size: usize::MAX,
+            max_gas: u64::MAX,
+        }),
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let (message1, input1) = create_message_predicate_from_message(10_000, 0);
+    let (message2, input2) = create_message_predicate_from_message(10_000, 1);
+    universe.database_mut().insert_message(message1.clone());
+    universe.database_mut().insert_message(message2);
+    let owner = *message1.recipient();
+    let tx1 = universe.build_script_transaction(Some(vec![input1]), None, 10);
+    let tx2 = universe.build_script_transaction(Some(vec![input2]), None, 0);
+    let pool_tx = universe.verify_and_insert(tx1).unwrap().0;
+
+    // When
+    let result2 = universe.verify_and_insert(tx2);
+
+    // Then
+    let err = result2.unwrap_err();
+    assert!(matches!(err, Error::NotInsertedOwnerLimitHit(id) if id == owner));
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
+#[test]
+fn insert__owner_tx_limit_hit_removes_less_worth_tx_of_the_owner() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        owner_limits: Some(PoolLimits {
+            max_txs: 1,
+            max_bytes_size: usize::MAX,
+            max_gas: u64::MAX,
+        }),
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let (message1, input1) = create_message_predicate_from_message(10_000, 0);
+    let (message2, input2) = create_message_predicate_from_message(10_000, 1);
+    universe.database_mut().insert_message(message1);
+    universe.database_mut().insert_message(message2);
+    let tx1 = universe.build_script_transaction(Some(vec![input1]), None, 0);
+    let tx2 = universe.build_script_transaction(Some(vec![input2]), None, 10);
+    let tx3 = universe.build_script_transaction(None, None, 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    let pool_tx3 = universe.verify_and_insert(tx3).unwrap().0;
+
+    // When
+    let result2 = universe.verify_and_insert(tx2);
+
+    // Then
+    let (pool_tx2, removed_txs) = result2.unwrap();
+    assert_eq!(removed_txs.len(), 1);
+    assert_eq!(removed_txs[0].id(), tx1_id);
+    universe.assert_pool_integrity(&[pool_tx3, pool_tx2]);
+}
+
+#[test]
+fn insert__owner_tx_limit_hit_does_not_remove_txs_of_other_owners() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        pool_limits: PoolLimits {
+            max_txs: 2,
+            max_bytes_size: usize::MAX,
+            max_gas: u64::MAX,
+        },
+        owner_limits: Some(PoolLimits {
+            max_txs: 1,
+            max_bytes_size: usize::MAX,
+            max_gas: u64::MAX,
+        }),
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let (message1, input1) = create_message_predicate_from_message(10_000, 0);
+    let (message2, input2) = create_message_predicate_from_message(10_000, 1);
+    universe.database_mut().insert_message(message1);
+    universe.database_mut().insert_message(message2);
+    let tx1 = universe.build_script_transaction(Some(vec![input1]), None, 5);
+    let tx2 = universe.build_script_transaction(Some(vec![input2]), None, 10);
+    let other_owner_tx = universe.build_script_transaction(None, None, 0);
+    let tx1_id = tx1.id(&ChainId::default());
+    universe.verify_and_insert(tx1).unwrap();
+    let pool_other_owner_tx = universe.verify_and_insert(other_owner_tx).unwrap().0;
+
+    // When
+    let result2 = universe.verify_and_insert(tx2);
+
+    // Then
+    let (pool_tx2, removed_txs) = result2.unwrap();
+    assert_eq!(removed_txs.len(), 1);
+    assert_eq!(removed_txs[0].id(), tx1_id);
+    universe.assert_pool_integrity(&[pool_other_owner_tx, pool_tx2]);
+}
+
+#[test]
+fn insert__owner_tx_limit_hit_does_not_remove_dependents_of_other_owners() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        owner_limits: Some(PoolLimits {
+            max_txs: 1,
+            max_bytes_size: usize::MAX,
+            max_gas: u64::MAX,
+        }),
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let (message1, input1) = create_message_predicate_from_message(10_000, 0);
+    let (message2, input2) = create_message_predicate_from_message(10_000, 1);
+    universe.database_mut().insert_message(message1.clone());
+    universe.database_mut().insert_message(message2);
+    let owner = *message1.recipient();
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 =
+        universe.build_script_transaction(Some(vec![input1]), Some(vec![output]), 0);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let other_owner_tx = universe.build_script_transaction(Some(vec![input]), None, 0);
+    let tx2 = universe.build_script_transaction(Some(vec![input2]), None, 10);
+    let pool_tx1 = universe.verify_and_insert(tx1).unwrap().0;
+    let pool_other_owner_tx = universe.verify_and_insert(other_owner_tx).unwrap().0;
+
+    // When
+    let result2 = universe.verify_and_insert(tx2);
+
+    // Then
+    let err = result2.unwrap_err();
+    assert!(matches!(err, Error::NotInsertedOwnerLimitHit(id) if id == owner));
+    universe.assert_pool_integrity(&[pool_tx1, pool_other_owner_tx]);
+}
+
+#[test]
+fn insert__owner_bytes_limit_hit_removes_parent_with_child_of_the_owner() {
+    let mut universe = TestPoolUniverse::default();
+
+    // Given
+    let (message1, input1) = create_message_predicate_from_message(10_000, 0);
+    let (message2, input2) = create_message_predicate_from_message(10_000, 1);
+    let (message3, input3) = create_message_predicate_from_message(10_000, 2);
+    universe.database_mut().insert_message(message1);
+    universe.database_mut().insert_message(message2);
+    universe.database_mut().insert_message(message3);
+    let (output, unset_input) = universe.create_output_and_input();
+    let parent =
+        universe.build_script_transaction(Some(vec![input1]), Some(vec![output]), 0);
+    let input = unset_input.into_input(UtxoId::new(parent.id(&ChainId::default()), 0));
+    // The child alone is more profitable than the new transaction,
+    // but the subtree of the parent is less profitable.
+    let child = universe.build_script_transaction(Some(vec![input, input2]), None, 15);
+    let (output1, _) = universe.create_output_and_input();
+    let (output2, _) = universe.create_output_and_input();
+    let tx = universe.build_script_transaction(
+        Some(vec![input3]),
+        Some(vec![output1, output2]),
+        10,
+    );
+    let parent_bytes = parent.as_script().unwrap().metered_bytes_size();
+    let child_bytes = child.as_script().unwrap().metered_bytes_size();
+    let tx_bytes = tx.as_script().unwrap().metered_bytes_size();
+    // Removing only the bytes of the parent is not enough to fit the new transaction.
+    assert!(tx_bytes > parent_bytes);
+    universe = universe.config(Config {
+        owner_limits: Some(PoolLimits {
+            max_txs: usize::MAX,
+            max_bytes_size: parent_bytes + child_bytes,
+            max_gas: u64::MAX,
+        }),
+        ..Default::default()
+    });
+    universe.build_pool();
+    let parent_id = parent.id(&ChainId::default());
+    let child_id = child.id(&ChainId::default());
+    universe.verify_and_insert(parent).unwrap();
+    universe.verify_and_insert(child).unwrap();
+
+    // When
+    let result = universe.verify_and_insert(tx);
+
+    // Then
+    let (pool_tx, removed_txs) = result.unwrap();
+    let mut removed_ids = removed_txs.iter().map(|tx| tx.id()).collect::<Vec<_>>();
+    removed_ids.sort();
+    let mut expected_ids = vec![parent_id, child_id];
+    expected_ids.sort();
+    assert_eq!(removed_ids, expected_ids);
+    universe.assert_pool_integrity(&[pool_tx]);
+}
+
 #[test]
 fn insert__dependency_chain_length_hit() {
     let mut universe = TestPoolUniverse::default().config(Config {
@@ -810,6 +1096,194 @@ fn get_sorted_out_tx_by_creation_instant() {
     universe.assert_pool_integrity(&[]);
 }
 
+#[test]
+fn get_sorted_out_tx__fifo_selects_in_arrival_order() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection_algorithm: SelectionAlgorithmType::Fifo,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 9);
+    let tx3 = universe.build_script_transaction(None, None, 20);
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    let (tx3, _) = universe.verify_and_insert(tx3).unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints {
+            minimal_gas_price: 0,
+            max_gas: u64::MAX,
+            maximum_txs: 2,
+            maximum_block_size: u32::MAX,
+        });
+
+    // Then
+    assert_eq!(txs.len(), 2, "Should have 2 txs");
+    assert_eq!(txs[0].id(), tx1_id, "First should be tx1");
+    assert_eq!(txs[1].id(), tx2_id, "Second should be tx2");
+    universe.assert_pool_integrity(&[tx3]);
+}
+
+#[test]
+fn get_sorted_out_tx__knapsack_selects_profitable_dependency_chain_first() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection_algorithm: SelectionAlgorithmType::Knapsack,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let (output, unset_input) = universe.create_output_and_input();
+    let tx1 = universe.build_script_transaction(None, Some(vec![output]), 1);
+    let input = unset_input.into_input(UtxoId::new(tx1.id(&ChainId::default()), 0));
+    let tx2 = universe.build_script_transaction(Some(vec![input]), None, 100);
+    let tx3 = universe.build_script_transaction(None, None, 10);
+
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints {
+            minimal_gas_price: 0,
+            max_gas: u64::MAX,
+            maximum_txs: u16::MAX,
+            maximum_block_size: u32::MAX,
+        });
+
+    // Then
+    assert_eq!(txs.len(), 3, "Should have 3 txs");
+    assert_eq!(txs[0].id(), tx1_id, "First should be tx1");
+    assert_eq!(txs[1].id(), tx2_id, "Second should be tx2");
+    assert_eq!(txs[2].id(), tx3_id, "Third should be tx3");
+    universe.assert_pool_integrity(&[]);
+}
+
+#[test]
+fn get_sorted_out_tx__knapsack_prefers_dense_txs_under_block_size() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection_algorithm: SelectionAlgorithmType::Knapsack,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let gas_coin = universe.setup_coin().1;
+    let tx1 = TransactionBuilder::script(vec![], vec![0; 2000])
+        .tip(30)
+        .max_fee_limit(30)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let tx2 = universe.build_script_transaction(None, None, 21);
+    let tx3 = universe.build_script_transaction(None, None, 20);
+
+    let block_size = tx1.as_script().unwrap().metered_bytes_size();
+    let tx2_id = tx2.id(&ChainId::default());
+    let tx3_id = tx3.id(&ChainId::default());
+
+    let (tx1, _) = universe.verify_and_insert(tx1).unwrap();
+    universe.verify_and_insert(tx2).unwrap();
+    universe.verify_and_insert(tx3).unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints {
+            minimal_gas_price: 0,
+            max_gas: u64::MAX,
+            maximum_txs: u16::MAX,
+            maximum_block_size: block_size.try_into().unwrap(),
+        });
+
+    // Then
+    // The big tx1 has the highest tip, but tx2 and tx3 together pay more
+    // and fit into the same block size.
+    assert_eq!(txs.len(), 2, "Should have 2 txs");
+    assert_eq!(txs[0].id(), tx2_id, "First should be tx2");
+    assert_eq!(txs[1].id(), tx3_id, "Second should be tx3");
+    universe.assert_pool_integrity(&[tx1]);
+}
+
+#[test]
+fn get_sorted_out_tx__knapsack_greedy_selection_may_miss_the_optimal_packing() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        selection_algorithm: SelectionAlgorithmType::Knapsack,
+        ..Default::default()
+    });
+    universe.build_pool();
+
+    // Given
+    let small_tip = 20;
+    let big_tip = 30;
+    let small_tx = universe.build_script_transaction(None, None, small_tip);
+    let build_big_tx = |universe: &mut TestPoolUniverse| {
+        let gas_coin = universe.setup_coin().1;
+        TransactionBuilder::script(vec![], vec![0; 2000])
+            .tip(big_tip)
+            .max_fee_limit(big_tip)
+            .script_gas_limit(GAS_LIMIT)
+            .add_input(gas_coin)
+            .finalize_as_transaction()
+    };
+    let big_tx1 = build_big_tx(&mut universe);
+    let big_tx2 = build_big_tx(&mut universe);
+
+    // Only two big transactions fit into the block,
+    // or the small one and one of the big ones.
+    let block_size = big_tx1.as_script().unwrap().metered_bytes_size()
+        + big_tx2.as_script().unwrap().metered_bytes_size();
+    let small_tx_id = small_tx.id(&ChainId::default());
+
+    universe.verify_and_insert(small_tx).unwrap();
+    let (big_tx1, _) = universe.verify_and_insert(big_tx1).unwrap();
+    let (big_tx2, _) = universe.verify_and_insert(big_tx2).unwrap();
+
+    // When
+    let txs = universe
+        .get_pool()
+        .write()
+        .extract_transactions_for_block(Constraints {
+            minimal_gas_price: 0,
+            max_gas: u64::MAX,
+            maximum_txs: u16::MAX,
+            maximum_block_size: block_size.try_into().unwrap(),
+        });
+
+    // Then
+    // The small tx is the densest, so it is selected first and takes the space
+    // of the second big tx, even though both big txs together pay more.
+    assert_eq!(txs.len(), 2, "Should have 2 txs");
+    assert_eq!(txs[0].id(), small_tx_id, "First should be the small tx");
+    let total_tip = txs.iter().map(|tx| tx.tip()).sum::<u64>();
+    assert_eq!(total_tip, small_tip + big_tip);
+    assert!(total_tip < big_tip * 2);
+    let left_tx = if txs[1].id() == big_tx1.id() {
+        big_tx2
+    } else {
+        big_tx1
+    };
+    universe.assert_pool_integrity(&[left_tx]);
+}
+
 #[test]
 fn insert_tx_at_least_min_gas_price() {
     // Given
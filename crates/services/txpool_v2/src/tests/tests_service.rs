@@ -1,15 +1,62 @@
 use fuel_core_services::Service as ServiceTrait;
 use fuel_core_types::{
-    fuel_tx::UniqueIdentifier,
+    blockchain::{
+        block::Block,
+        SealedBlock,
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Maturity,
+            MintGasPrice,
+        },
+        Mint,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+    },
     fuel_types::ChainId,
-    services::txpool::TransactionStatus,
+    services::{
+        block_importer::ImportResult,
+        txpool::TransactionStatus,
+    },
+};
+use std::{
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
 };
-use std::time::Duration;
 use tokio_stream::StreamExt;
 
 use crate::{
-    config::Config,
-    tests::universe::TestPoolUniverse,
+    config::{
+        BlackList,
+        Config,
+        FutureQueueConfig,
+        PersistenceConfig,
+    },
+    error::{
+        Error,
+        RemovedReason,
+    },
+    service::Service,
+    tests::{
+        mocks::{
+            MockDb,
+            MockImporter,
+            MockP2P,
+            MockTxPoolGasPrice,
+        },
+        universe::{
+            TestPoolUniverse,
+            GAS_LIMIT,
+        },
+    },
     tx_status_stream::TxStatusMessage,
 };
 
@@ -319,3 +366,546 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+fn persistence_config(dir: &tempfile::TempDir) -> Config {
+    Config {
+        persistence: Some(PersistenceConfig {
+            path: dir.path().join("txpool"),
+            interval: Duration::from_secs(60),
+        }),
+        ..Default::default()
+    }
+}
+
+/// Waits until the transaction restored in the background appears in the pool.
+async fn wait_for_restored_transaction(service: &Service<MockDb>, tx_id: TxId) {
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while service.shared.find(vec![tx_id]).await.unwrap()[0].is_none() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("The transaction should be restored");
+}
+
+#[tokio::test]
+async fn persisted_transactions_are_restored_after_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default().config(persistence_config(&dir));
+
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    // Given
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service
+        .shared
+        .try_insert(vec![tx1.clone(), tx2.clone()])
+        .unwrap();
+    universe
+        .waiting_txs_insertion(
+            service.shared.new_tx_notification_subscribe(),
+            ids.clone(),
+        )
+        .await;
+    service.stop_and_await().await.unwrap();
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    wait_for_restored_transaction(&service, ids[1]).await;
+
+    // Then
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(
+        out.iter().all(Option::is_some),
+        "All txs should be restored:{out:?}"
+    );
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn persisted_transactions_are_restored_without_broadcasting() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default().config(persistence_config(&dir));
+
+    let tx = universe.build_script_transaction(None, None, 10);
+    let tx_id = tx.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service.shared.insert(tx).await.unwrap();
+    service.stop_and_await().await.unwrap();
+
+    // Given
+    let broadcasts = Arc::new(AtomicUsize::new(0));
+    let mut p2p = MockP2P::new_with_txs(vec![]);
+    let counter = broadcasts.clone();
+    p2p.expect_broadcast_transaction().returning(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    });
+
+    // When
+    let service = universe.build_service(Some(p2p), None);
+    service.start_and_await().await.unwrap();
+    wait_for_restored_transaction(&service, tx_id).await;
+
+    // Then
+    assert_eq!(broadcasts.load(Ordering::SeqCst), 0);
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn persisted_transactions_that_became_invalid_are_dropped_after_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut universe = TestPoolUniverse::default().config(persistence_config(&dir));
+
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service
+        .shared
+        .try_insert(vec![tx1.clone(), tx2.clone()])
+        .unwrap();
+    universe
+        .waiting_txs_insertion(
+            service.shared.new_tx_notification_subscribe(),
+            ids.clone(),
+        )
+        .await;
+    service.stop_and_await().await.unwrap();
+
+    // Given
+    let spent_coin = *tx1.as_script().unwrap().inputs()[0].utxo_id().unwrap();
+    universe
+        .database_mut()
+        .data
+        .lock()
+        .unwrap()
+        .coins
+        .remove(&spent_coin);
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    // The transactions are restored in the order of submission.
+    wait_for_restored_transaction(&service, ids[1]).await;
+
+    // Then
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(out[0].is_none(), "Tx1 should be dropped:{out:?}");
+    assert!(out[1].is_some(), "Tx2 should be restored:{out:?}");
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_black_list__removes_blacklisted_transactions() {
+    let mut universe = TestPoolUniverse::default();
+
+    let tx1 = universe.build_script_transaction(None, None, 10);
+    let tx2 = universe.build_script_transaction(None, None, 20);
+    let ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service
+        .shared
+        .try_insert(vec![tx1.clone(), tx2.clone()])
+        .unwrap();
+    universe
+        .waiting_txs_insertion(
+            service.shared.new_tx_notification_subscribe(),
+            ids.clone(),
+        )
+        .await;
+
+    // Given
+    let blacklisted_coin = *tx1.as_script().unwrap().inputs()[0].utxo_id().unwrap();
+    let add = BlackList::new(vec![], vec![blacklisted_coin], vec![], vec![]);
+
+    // When
+    let black_list = service
+        .shared
+        .update_black_list(add.clone(), Default::default())
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(black_list, add);
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(out[0].is_none(), "Tx1 should be removed:{out:?}");
+    assert!(out[1].is_some(), "Tx2 should stay in the pool:{out:?}");
+    let err = service.shared.insert(tx1).await.unwrap_err();
+    assert!(matches!(err, Error::Blacklisted(_)), "{err:?}");
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_black_list__is_persisted_across_restarts() {
+    let dir = tempfile::tempdir().unwrap();
+    let universe = TestPoolUniverse::default().config(Config {
+        black_list_path: Some(dir.path().join("blacklist")),
+        ..Default::default()
+    });
+    let owner = [1; 32].into();
+    let removed_owner = [2; 32].into();
+
+    // Given
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service
+        .shared
+        .update_black_list(
+            BlackList::new(vec![owner, removed_owner], vec![], vec![], vec![]),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+    service
+        .shared
+        .update_black_list(
+            Default::default(),
+            BlackList::new(vec![removed_owner], vec![], vec![], vec![]),
+        )
+        .await
+        .unwrap();
+    service.stop_and_await().await.unwrap();
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Then
+    let black_list = service.shared.black_list().await.unwrap();
+    assert_eq!(
+        black_list,
+        BlackList::new(vec![owner], vec![], vec![], vec![])
+    );
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_black_list__removed_entries_of_the_config_stay_removed_after_restart() {
+    let dir = tempfile::tempdir().unwrap();
+    let owner = [1; 32].into();
+    let removed_owner = [2; 32].into();
+    let universe = TestPoolUniverse::default().config(Config {
+        black_list: BlackList::new(vec![removed_owner], vec![], vec![], vec![]),
+        black_list_path: Some(dir.path().join("blacklist")),
+        ..Default::default()
+    });
+
+    // Given
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    service
+        .shared
+        .update_black_list(
+            BlackList::new(vec![owner], vec![], vec![], vec![]),
+            BlackList::new(vec![removed_owner], vec![], vec![], vec![]),
+        )
+        .await
+        .unwrap();
+    service.stop_and_await().await.unwrap();
+
+    // When
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Then
+    let black_list = service.shared.black_list().await.unwrap();
+    assert_eq!(
+        black_list,
+        BlackList::new(vec![owner], vec![], vec![], vec![])
+    );
+    service.stop_and_await().await.unwrap();
+}
+
+fn import_result_at_height(height: u32) -> Arc<ImportResult> {
+    let mut block = Block::default();
+    block.header_mut().set_block_height(height.into());
+    let sealed_block = SealedBlock {
+        entity: block,
+        consensus: Default::default(),
+    };
+    Arc::new(ImportResult::new_from_local(sealed_block, vec![], vec![]))
+}
+
+#[tokio::test]
+async fn insert__not_mature_tx_is_promoted_into_pool_at_maturity() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        future_queue: Some(FutureQueueConfig {
+            max_txs: 10,
+            max_blocks_ahead: 10,
+        }),
+        ..Default::default()
+    });
+    let (block_sender, block_receiver) = tokio::sync::mpsc::channel(10);
+    let importer = MockImporter::with_block_provider(block_receiver);
+
+    let mut tx = universe.build_script_transaction(None, None, 10);
+    tx.as_script_mut().unwrap().set_maturity(2.into());
+    let tx_id = tx.id(&Default::default());
+
+    let service = universe.build_service(None, Some(importer));
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service.shared.insert(tx).await.unwrap();
+    let found = service.shared.find(vec![tx_id]).await.unwrap();
+    assert!(
+        found[0].is_none(),
+        "Not mature tx should not be in the pool"
+    );
+    let new_tx_notification = service.shared.new_tx_notification_subscribe();
+
+    // When
+    block_sender.send(import_result_at_height(1)).await.unwrap();
+    block_sender.send(import_result_at_height(2)).await.unwrap();
+
+    // Then
+    universe
+        .waiting_txs_insertion(new_tx_notification, vec![tx_id])
+        .await;
+    let found = service.shared.find(vec![tx_id]).await.unwrap();
+    assert!(found[0].is_some(), "Mature tx should be in the pool");
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__not_mature_tx_is_rejected_when_future_queue_is_full() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        future_queue: Some(FutureQueueConfig {
+            max_txs: 1,
+            max_blocks_ahead: 10,
+        }),
+        ..Default::default()
+    });
+
+    let mut tx1 = universe.build_script_transaction(None, None, 10);
+    tx1.as_script_mut().unwrap().set_maturity(2.into());
+    let mut tx2 = universe.build_script_transaction(None, None, 20);
+    tx2.as_script_mut().unwrap().set_maturity(2.into());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // Given
+    service.shared.insert(tx1).await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx2).await;
+
+    // Then
+    assert!(matches!(result, Err(Error::FutureQueueFull)));
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn insert__tx_beyond_max_blocks_ahead_is_rejected() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        future_queue: Some(FutureQueueConfig {
+            max_txs: 10,
+            max_blocks_ahead: 10,
+        }),
+        ..Default::default()
+    });
+
+    let mut tx = universe.build_script_transaction(None, None, 10);
+    tx.as_script_mut().unwrap().set_maturity(11.into());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+
+    // When
+    let result = service.shared.insert(tx).await;
+
+    // Then
+    assert!(
+        result.is_err(),
+        "Tx too far in the future should be rejected"
+    );
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn not_mature_tx_is_removed_from_future_queue_after_ttl() {
+    const TIMEOUT: u64 = 2;
+    let mut universe = TestPoolUniverse::default().config(Config {
+        ttl_check_interval: Duration::from_secs(1),
+        max_txs_ttl: Duration::from_secs(TIMEOUT),
+        future_queue: Some(FutureQueueConfig {
+            max_txs: 10,
+            max_blocks_ahead: 10,
+        }),
+        ..Default::default()
+    });
+
+    let mut tx = universe.build_script_transaction(None, None, 10);
+    tx.as_script_mut().unwrap().set_maturity(2.into());
+    let tx_id = tx.id(&Default::default());
+
+    let service = universe.build_service(None, None);
+    service.start_and_await().await.unwrap();
+    let mut tx_subscribe_updates = service.shared.tx_update_subscribe(tx_id).unwrap();
+
+    // Given
+    service.shared.insert(tx).await.unwrap();
+
+    // When
+    tokio::time::sleep(Duration::from_secs(TIMEOUT)).await;
+    tokio::time::sleep(Duration::from_secs(TIMEOUT)).await;
+
+    // Then
+    let update = tx_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: "Transaction is removed: Transaction expired because it exceeded the configured time to live `tx-pool-ttl`."
+                .to_string()
+        }),
+        "Not mature tx should be squeezed out after the TTL"
+    );
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn update_black_list__removes_blacklisted_not_mature_tx_from_future_queue() {
+    let mut universe = TestPoolUniverse::default().config(Config {
+        future_queue: Some(FutureQueueConfig {
+            max_txs: 10,
+            max_blocks_ahead: 10,
+        }),
+        ..Default::default()
+    });
+    let (block_sender, block_receiver) = tokio::sync::mpsc::channel(10);
+    let importer = MockImporter::with_block_provider(block_receiver);
+
+    let mut tx1 = universe.build_script_transaction(None, None, 10);
+    tx1.as_script_mut().unwrap().set_maturity(2.into());
+    let mut tx2 = universe.build_script_transaction(None, None, 20);
+    tx2.as_script_mut().unwrap().set_maturity(2.into());
+    let ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    let service = universe.build_service(None, Some(importer));
+    service.start_and_await().await.unwrap();
+    let mut tx1_subscribe_updates = service.shared.tx_update_subscribe(ids[0]).unwrap();
+    service.shared.insert(tx1.clone()).await.unwrap();
+    service.shared.insert(tx2).await.unwrap();
+
+    // Given
+    let blacklisted_coin = *tx1.as_script().unwrap().inputs()[0].utxo_id().unwrap();
+    let add = BlackList::new(vec![], vec![blacklisted_coin], vec![], vec![]);
+
+    // When
+    service
+        .shared
+        .update_black_list(add, Default::default())
+        .await
+        .unwrap();
+
+    // Then
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: Error::Removed(RemovedReason::Blacklisted).to_string(),
+        }),
+        "Blacklisted tx should be squeezed out of the future queue"
+    );
+    let new_tx_notification = service.shared.new_tx_notification_subscribe();
+    block_sender.send(import_result_at_height(1)).await.unwrap();
+    block_sender.send(import_result_at_height(2)).await.unwrap();
+    universe
+        .waiting_txs_insertion(new_tx_notification, vec![ids[1]])
+        .await;
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(
+        out[0].is_none(),
+        "Blacklisted tx should not be promoted:{out:?}"
+    );
+    assert!(out[1].is_some(), "Tx2 should be promoted:{out:?}");
+    service.stop_and_await().await.unwrap();
+}
+
+#[tokio::test]
+async fn import_block__removes_transactions_that_cannot_pay_increased_gas_price() {
+    let mut universe = TestPoolUniverse::default();
+    let (block_sender, block_receiver) = tokio::sync::mpsc::channel(10);
+    let importer = MockImporter::with_block_provider(block_receiver);
+    let gas_price_provider = MockTxPoolGasPrice::new(0);
+
+    let tx1 = universe.build_script_transaction(None, None, 0);
+    let (_, gas_coin) = universe.setup_coin();
+    let tx2 = TransactionBuilder::script(vec![], vec![])
+        .max_fee_limit(1_000_000)
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let ids = vec![tx1.id(&Default::default()), tx2.id(&Default::default())];
+
+    let service = universe.build_service_with_gas_price(
+        None,
+        Some(importer),
+        gas_price_provider.clone(),
+    );
+    service.start_and_await().await.unwrap();
+    service
+        .shared
+        .try_insert(vec![tx1.clone(), tx2.clone()])
+        .unwrap();
+    universe
+        .waiting_txs_insertion(
+            service.shared.new_tx_notification_subscribe(),
+            ids.clone(),
+        )
+        .await;
+    let mut tx1_subscribe_updates = service.shared.tx_update_subscribe(ids[0]).unwrap();
+
+    // Given
+    let out = service.shared.find(ids.clone()).await.unwrap();
+    let tx1_max_gas_price = out[0].as_ref().unwrap().tx().max_gas_price();
+    let tx2_max_gas_price = out[1].as_ref().unwrap().tx().max_gas_price();
+    assert!(tx1_max_gas_price < tx2_max_gas_price);
+    // The gas price of the imported block is still affordable for both transactions,
+    // but the gas price of the next block is not affordable for the first one.
+    let mut mint = Mint::default();
+    *mint.gas_price_mut() = tx1_max_gas_price;
+    gas_price_provider.set_gas_price(tx1_max_gas_price.saturating_add(1));
+    let mut block = Block::default();
+    block.header_mut().set_block_height(1.into());
+    block.transactions_mut().push(mint.into());
+    let sealed_block = SealedBlock {
+        entity: block,
+        consensus: Default::default(),
+    };
+
+    // When
+    block_sender
+        .send(Arc::new(ImportResult::new_from_local(
+            sealed_block,
+            vec![],
+            vec![],
+        )))
+        .await
+        .unwrap();
+
+    // Then
+    let update = tx1_subscribe_updates.next().await.unwrap();
+    assert_eq!(
+        update,
+        TxStatusMessage::Status(TransactionStatus::SqueezedOut {
+            reason: Error::Removed(RemovedReason::InsufficientMaxFee).to_string()
+        }),
+    );
+    let out = service.shared.find(ids).await.unwrap();
+    assert!(out[0].is_none(), "Tx1 should be removed:{out:?}");
+    assert!(out[1].is_some(), "Tx2 should stay in the pool:{out:?}");
+    service.stop_and_await().await.unwrap();
+}
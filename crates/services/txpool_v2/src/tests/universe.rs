@@ -62,7 +62,7 @@ use crate::{
     error::Error,
     new_service,
     pool::Pool,
-    selection_algorithms::ratio_tip_gas::RatioTipGasSelection,
+    selection_algorithms::configurable::ConfigurableSelection,
     service::{
         memory::MemoryPool,
         verifications::Verification,
@@ -134,7 +134,7 @@ impl TestPoolUniverse {
                 max_txs_chain_count: self.config.max_txs_chain_count,
             }),
             BasicCollisionManager::new(),
-            RatioTipGasSelection::new(),
+            ConfigurableSelection::new(self.config.selection_algorithm),
             self.config.clone(),
         )));
         self.pool = Some(pool.clone());
@@ -145,7 +145,15 @@ impl TestPoolUniverse {
         p2p: Option<MockP2P>,
         importer: Option<MockImporter>,
     ) -> Service<MockDb> {
-        let gas_price = 0;
+        self.build_service_with_gas_price(p2p, importer, MockTxPoolGasPrice::new(0))
+    }
+
+    pub fn build_service_with_gas_price(
+        &self,
+        p2p: Option<MockP2P>,
+        importer: Option<MockImporter>,
+        gas_price_provider: MockTxPoolGasPrice,
+    ) -> Service<MockDb> {
         let mut p2p = p2p.unwrap_or_else(|| MockP2P::new_with_txs(vec![]));
         // set default handlers for p2p methods after test is set up, so they will be last on the FIFO
         // ordering of methods handlers: https://docs.rs/mockall/0.12.1/mockall/index.html#matching-multiple-calls
@@ -157,7 +165,6 @@ impl TestPoolUniverse {
             .returning(|| Box::pin(fuel_core_services::stream::pending()));
 
         let importer = importer.unwrap_or_else(|| MockImporter::with_blocks(vec![]));
-        let gas_price_provider = MockTxPoolGasPrice::new(gas_price);
         let mut consensus_parameters_provider =
             MockConsensusParametersProvider::default();
         consensus_parameters_provider
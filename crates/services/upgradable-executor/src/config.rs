@@ -1,5 +1,6 @@
 use fuel_core_executor::executor::ExecutionOptions;
 use fuel_core_types::blockchain::header::StateTransitionBytecodeVersion;
+use std::path::PathBuf;
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
@@ -13,6 +14,32 @@ pub struct Config {
     /// When a block version matches the native executor version, we use
     /// the native executor; otherwise, we use the WASM executor.
     pub native_executor_version: Option<StateTransitionBytecodeVersion>,
+    /// The way the native executor validates blocks.
+    pub validation_mode: ValidationMode,
+    /// The directory where the compiled WASM modules are cached between restarts.
+    /// If it is `None`, modules are cached only in memory.
+    pub module_cache_path: Option<PathBuf>,
+    /// Validates each block of the native executor version both natively and
+    /// in the WASM executor and reports the mismatch of the errors, storage changes,
+    /// events and transaction statuses.
+    /// It requires the `wasm-executor` feature.
+    pub verify_executor_parity: bool,
+}
+
+/// The mode of the block validation by the native executor.
+/// The parallel modes require the `parallel` feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Transactions of the block are executed one by one.
+    #[default]
+    Sequential,
+    /// Independent transactions of the block are executed in parallel.
+    #[cfg(feature = "parallel")]
+    Parallel,
+    /// The block is validated both sequentially and in parallel, and the results
+    /// are compared. The result of the sequential validation is used.
+    #[cfg(feature = "parallel")]
+    Compare,
 }
 
 impl From<&Config> for ExecutionOptions {
@@ -20,6 +47,8 @@ impl From<&Config> for ExecutionOptions {
         Self {
             extra_tx_checks: value.utxo_validation_default,
             backtrace: value.backtrace,
+            trace: None,
+            record_access_list: false,
         }
     }
 }
@@ -1,6 +1,15 @@
-use crate::config::Config;
+use crate::config::{
+    Config,
+    ValidationMode,
+};
 #[cfg(feature = "wasm-executor")]
-use crate::error::UpgradableError;
+use crate::{
+    error::UpgradableError,
+    module_cache::{
+        ModuleCache,
+        ModulePrecompiler,
+    },
+};
 
 use fuel_core_executor::{
     executor::{
@@ -29,18 +38,35 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         header::{
+            PartialBlockHeader,
             StateTransitionBytecodeVersion,
             LATEST_STATE_TRANSITION_VERSION,
         },
     },
-    fuel_tx::Transaction,
+    fuel_asm::{
+        PanicReason,
+        Word,
+    },
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        Input,
+        Output,
+        Receipt,
+        Transaction,
+    },
     fuel_types::BlockHeight,
     services::{
         block_producer::Components,
         executor::{
+            DryRunOptions,
             Error as ExecutorError,
             ExecutionResult,
+            MissingInputs,
             Result as ExecutorResult,
+            TransactionExecutionResult,
             TransactionExecutionStatus,
             ValidationResult,
         },
@@ -49,6 +75,8 @@ use fuel_core_types::{
 };
 use std::sync::Arc;
 
+#[cfg(feature = "wasm-executor")]
+use fuel_core_metrics::executor_metrics::executor_metrics;
 #[cfg(feature = "wasm-executor")]
 use fuel_core_storage::{
     not_found,
@@ -57,6 +85,10 @@ use fuel_core_storage::{
         StateTransitionBytecodeVersions,
         UploadedBytecodes,
     },
+    transactional::{
+        ConflictPolicy,
+        StorageTransaction,
+    },
     StorageAsRef,
 };
 #[cfg(any(test, feature = "test-helpers"))]
@@ -64,6 +96,15 @@ use fuel_core_types::blockchain::block::PartialFuelBlock;
 #[cfg(any(test, feature = "test-helpers"))]
 use fuel_core_types::services::executor::UncommittedResult;
 #[cfg(feature = "wasm-executor")]
+use fuel_core_types::{
+    fuel_tx::field::{
+        BytecodeRoot,
+        SubsectionIndex,
+        SubsectionsNumber,
+    },
+    fuel_vm::UploadedBytecode,
+};
+#[cfg(feature = "wasm-executor")]
 use fuel_core_wasm_executor::utils::{
     convert_from_v0_execution_result,
     convert_from_v1_execution_result,
@@ -97,6 +138,10 @@ pub struct Executor<S, R> {
     cached_modules: parking_lot::Mutex<
         std::collections::HashMap<StateTransitionBytecodeVersion, wasmtime::Module>,
     >,
+    #[cfg(feature = "wasm-executor")]
+    module_cache: Option<ModuleCache>,
+    #[cfg(feature = "wasm-executor")]
+    module_precompiler: Option<ModulePrecompiler>,
 }
 
 #[cfg(feature = "wasm-executor")]
@@ -186,18 +231,26 @@ impl<S, R> Executor<S, R> {
         relayer_view_provider: R,
         config: Config,
     ) -> Self {
+        #[cfg(feature = "wasm-executor")]
+        let engine = private::DEFAULT_ENGINE.get_or_init(wasmtime::Engine::default);
+        #[cfg(feature = "wasm-executor")]
+        let module_cache = Self::open_module_cache(&config);
+        #[cfg(feature = "wasm-executor")]
+        let module_precompiler = Self::spawn_module_precompiler(engine, &module_cache);
         Self {
             storage_view_provider,
             relayer_view_provider,
             config: Arc::new(config),
             #[cfg(feature = "wasm-executor")]
-            engine: private::DEFAULT_ENGINE
-                .get_or_init(wasmtime::Engine::default)
-                .clone(),
+            engine: engine.clone(),
             #[cfg(feature = "wasm-executor")]
             execution_strategy: ExecutionStrategy::Native,
             #[cfg(feature = "wasm-executor")]
             cached_modules: Default::default(),
+            #[cfg(feature = "wasm-executor")]
+            module_cache,
+            #[cfg(feature = "wasm-executor")]
+            module_precompiler,
         }
     }
 
@@ -213,6 +266,8 @@ impl<S, R> Executor<S, R> {
                 .expect("Failed to validate the WASM bytecode")
         });
 
+        let module_cache = Self::open_module_cache(&config);
+        let module_precompiler = Self::spawn_module_precompiler(engine, &module_cache);
         Self {
             storage_view_provider,
             relayer_view_provider,
@@ -222,6 +277,45 @@ impl<S, R> Executor<S, R> {
                 module: module.clone(),
             },
             cached_modules: Default::default(),
+            module_cache,
+            module_precompiler,
+        }
+    }
+
+    #[cfg(feature = "wasm-executor")]
+    fn compiled_underlying_executor(
+        engine: &wasmtime::Engine,
+    ) -> &'static wasmtime::Module {
+        private::COMPILED_UNDERLYING_EXECUTOR.get_or_init(|| {
+            wasmtime::Module::new(engine, crate::WASM_BYTECODE)
+                .expect("Failed to validate the WASM bytecode")
+        })
+    }
+
+    #[cfg(feature = "wasm-executor")]
+    fn open_module_cache(config: &Config) -> Option<ModuleCache> {
+        let path = config.module_cache_path.as_ref()?;
+        match ModuleCache::open(path) {
+            Ok(cache) => Some(cache),
+            Err(err) => {
+                tracing::warn!("Failed to open the WASM module cache at {path:?}: {err}");
+                None
+            }
+        }
+    }
+
+    #[cfg(feature = "wasm-executor")]
+    fn spawn_module_precompiler(
+        engine: &wasmtime::Engine,
+        module_cache: &Option<ModuleCache>,
+    ) -> Option<ModulePrecompiler> {
+        let cache = module_cache.clone()?;
+        match ModulePrecompiler::spawn(engine.clone(), cache) {
+            Ok(precompiler) => Some(precompiler),
+            Err(err) => {
+                tracing::warn!("Failed to spawn the WASM module precompiler: {err}");
+                None
+            }
         }
     }
 }
@@ -324,15 +418,45 @@ where
         TxSource: TransactionsSource + Send + Sync + 'static,
     {
         let options = self.config.as_ref().into();
-        self.produce_inner(components, options, false)
+        let result = self.produce_inner(components, options, false)?;
+
+        // The transactions source can't be replayed, so the produced block
+        // is validated by both executors instead.
+        #[cfg(feature = "wasm-executor")]
+        if self.config.verify_executor_parity {
+            let block = &result.result().block;
+            let options = self.config.as_ref().into();
+            let validation = self.validate_inner(block, options);
+            self.verify_parity(block, &validation);
+        }
+
+        #[cfg(feature = "wasm-executor")]
+        let result = {
+            let (result, changes) = result.into();
+            let changes =
+                self.precompile_uploaded_modules(result.block.transactions(), changes);
+            Uncommitted::new(result, changes)
+        };
+
+        Ok(result)
     }
 
     /// Executes the block and returns the result of the execution without committing
     /// the changes in the dry run mode.
+    ///
+    /// If `options.trace` is set, the statuses contain the instruction-level trace of
+    /// the transactions. The tracing is supported only by the native executor.
+    ///
+    /// The statuses contain the access list of the transactions when they are executed
+    /// by the native executor. If `options.discover_missing_inputs` is set, the failed
+    /// transactions are executed again with the contract inputs and variable outputs
+    /// whose absence caused the failure, and the added inputs and outputs are reported
+    /// as missing.
     pub fn dry_run(
         &self,
         component: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
+        dry_run_options: DryRunOptions,
     ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation =
@@ -341,22 +465,72 @@ where
         let options = ExecutionOptions {
             extra_tx_checks: utxo_validation,
             backtrace: self.config.backtrace,
+            trace: dry_run_options.trace,
+            record_access_list: true,
         };
 
+        let Components {
+            header_to_produce,
+            transactions_source: transactions,
+            gas_price,
+            ..
+        } = component;
+
+        let mut tx_status = self.dry_run_transactions(
+            header_to_produce,
+            transactions.clone(),
+            gas_price,
+            options,
+        )?;
+        if dry_run_options.discover_missing_inputs {
+            self.discover_missing_inputs(
+                header_to_produce,
+                transactions,
+                gas_price,
+                &mut tx_status,
+            );
+        }
+
+        Ok(tx_status)
+    }
+
+    fn dry_run_transactions(
+        &self,
+        header_to_produce: PartialBlockHeader,
+        transactions: Vec<Transaction>,
+        gas_price: u64,
+        options: ExecutionOptions,
+    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+        let trace = options.trace.is_some();
         let component = Components {
-            header_to_produce: component.header_to_produce,
-            transactions_source: OnceTransactionsSource::new(
-                component.transactions_source,
-            ),
+            header_to_produce,
+            transactions_source: OnceTransactionsSource::new(transactions),
             coinbase_recipient: Default::default(),
-            gas_price: component.gas_price,
+            gas_price,
+        };
+
+        let result = if trace {
+            let block_version = component
+                .header_to_produce
+                .state_transition_bytecode_version;
+            let native_executor_version = self.native_executor_version();
+            if block_version != native_executor_version {
+                return Err(ExecutorError::Other(format!(
+                    "The tracing is supported only by the native executor. \
+                    The block version `{block_version}` is different from \
+                    the native executor version `{native_executor_version}`"
+                )))
+            }
+            self.native_produce_inner(component, options, true)?
+        } else {
+            self.produce_inner(component, options, true)?
         };
 
         let ExecutionResult {
             skipped_transactions,
             tx_status,
             ..
-        } = self.produce_inner(component, options, true)?.into_result();
+        } = result.into_result();
 
         // If one of the transactions fails, return an error.
         if let Some((_, err)) = skipped_transactions.into_iter().next() {
@@ -366,12 +540,100 @@ where
         Ok(tx_status)
     }
 
+    /// Adds the missing inputs and outputs to the failed transactions one by one
+    /// until they stop failing because of their absence. The statuses keep
+    /// the results of the original transactions.
+    fn discover_missing_inputs(
+        &self,
+        header_to_produce: PartialBlockHeader,
+        mut transactions: Vec<Transaction>,
+        gas_price: u64,
+        tx_status: &mut [TransactionExecutionStatus],
+    ) {
+        // The modified transactions have different ids, so their signatures
+        // are invalid, and they are executed without extra checks.
+        let options = ExecutionOptions {
+            extra_tx_checks: false,
+            backtrace: false,
+            trace: None,
+            record_access_list: false,
+        };
+        let mut missing_inputs = vec![MissingInputs::default(); transactions.len()];
+        let mut results: Vec<_> = tx_status
+            .iter()
+            .map(|status| status.result.clone())
+            .collect();
+
+        for _ in 0..MAX_MISSING_INPUTS_DISCOVERY_ATTEMPTS {
+            let mut modified = false;
+            for ((transaction, result), missing_inputs) in transactions
+                .iter_mut()
+                .zip(results.iter())
+                .zip(missing_inputs.iter_mut())
+            {
+                if let TransactionExecutionResult::Failed { receipts, .. } = result {
+                    modified |= add_missing_input(transaction, receipts, missing_inputs);
+                }
+            }
+
+            if !modified {
+                break
+            }
+
+            match self.dry_run_transactions(
+                header_to_produce,
+                transactions.clone(),
+                gas_price,
+                options.clone(),
+            ) {
+                Ok(tx_status) => {
+                    results = tx_status.into_iter().map(|status| status.result).collect();
+                }
+                // The modified transaction may become invalid, for example,
+                // because its fee doesn't cover the added inputs.
+                Err(_) => break,
+            }
+        }
+
+        for (status, missing_inputs) in tx_status.iter_mut().zip(missing_inputs) {
+            if !missing_inputs.is_empty() {
+                status.missing_inputs = Some(missing_inputs);
+            }
+        }
+    }
+
     pub fn validate(
         &self,
         block: &Block,
     ) -> ExecutorResult<Uncommitted<ValidationResult, Changes>> {
         let options = self.config.as_ref().into();
-        self.validate_inner(block, options)
+        let result = self.validate_inner(block, options);
+
+        #[cfg(feature = "wasm-executor")]
+        if self.config.verify_executor_parity {
+            self.verify_parity(block, &result);
+        }
+
+        let result = result?;
+
+        #[cfg(feature = "wasm-executor")]
+        let result = {
+            let (result, changes) = result.into();
+            let changes = self.precompile_uploaded_modules(block.transactions(), changes);
+            Uncommitted::new(result, changes)
+        };
+
+        Ok(result)
+    }
+
+    /// Returns the height of the state used by the dry run of the block at `height`.
+    /// The dry run at the height of the already committed block is executed on top of
+    /// the state at the previous block. Otherwise, the latest state is used.
+    fn dry_run_previous_block_height(&self, height: &BlockHeight) -> Option<BlockHeight> {
+        let latest_height = self.storage_view_provider.latest_height()?;
+        height
+            .pred()
+            .filter(|previous_height| previous_height < &latest_height)
     }
 
     #[cfg(feature = "wasm-executor")]
@@ -516,7 +778,7 @@ where
             block.header_to_produce.height().pred()
         } else {
             // TODO: https://github.com/FuelLabs/fuel-core/issues/2062
-            None
+            self.dry_run_previous_block_height(block.header_to_produce.height())
         };
 
         let instance_without_input =
@@ -613,7 +875,7 @@ where
             block.header_to_produce.height().pred()
         } else {
             // TODO: https://github.com/FuelLabs/fuel-core/issues/2062
-            None
+            self.dry_run_previous_block_height(block.header_to_produce.height())
         };
         let relayer = self.relayer_view_provider.latest_view()?;
 
@@ -632,18 +894,52 @@ where
         &self,
         block: &Block,
         options: ExecutionOptions,
+    ) -> ExecutorResult<Uncommitted<ValidationResult, Changes>> {
+        match self.config.validation_mode {
+            ValidationMode::Sequential => {
+                self.native_validate_in_mode(block, options, false)
+            }
+            #[cfg(feature = "parallel")]
+            ValidationMode::Parallel => {
+                self.native_validate_in_mode(block, options, true)
+            }
+            #[cfg(feature = "parallel")]
+            ValidationMode::Compare => {
+                let sequential =
+                    self.native_validate_in_mode(block, options.clone(), false);
+                let parallel = self.native_validate_in_mode(block, options, true);
+                compare_validation_results(&sequential, &parallel)?;
+                sequential
+            }
+        }
+    }
+
+    #[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+    fn native_validate_in_mode(
+        &self,
+        block: &Block,
+        options: ExecutionOptions,
+        parallel: bool,
     ) -> ExecutorResult<Uncommitted<ValidationResult, Changes>> {
         let previous_block_height = block.header().height().pred();
         let relayer = self.relayer_view_provider.latest_view()?;
 
         if let Some(previous_block_height) = previous_block_height {
             let database = self.storage_view_provider.view_at(&previous_block_height)?;
-            ExecutionInstance::new(relayer, database, options)
-                .validate_without_commit(block)
+            let instance = ExecutionInstance::new(relayer, database, options);
+            #[cfg(feature = "parallel")]
+            if parallel {
+                return instance.validate_in_parallel_without_commit(block)
+            }
+            instance.validate_without_commit(block)
         } else {
             let database = self.storage_view_provider.latest_view()?;
-            ExecutionInstance::new(relayer, database, options)
-                .validate_without_commit(block)
+            let instance = ExecutionInstance::new(relayer, database, options);
+            #[cfg(feature = "parallel")]
+            if parallel {
+                return instance.validate_in_parallel_without_commit(block)
+            }
+            instance.validate_without_commit(block)
         }
     }
 
@@ -692,8 +988,134 @@ where
             return Ok(module.clone());
         }
 
-        wasmtime::Module::new(&self.engine, bytecode)
-            .map_err(|e| UpgradableError::InvalidWasm(e.to_string()))
+        if let Some(module) = self
+            .module_cache
+            .as_ref()
+            .and_then(|cache| cache.load(&self.engine, &bytecode_root))
+        {
+            return Ok(module)
+        }
+
+        let module = wasmtime::Module::new(&self.engine, bytecode)
+            .map_err(|e| UpgradableError::InvalidWasm(e.to_string()))?;
+        if let Some(cache) = &self.module_cache {
+            cache.store(&self.engine, &bytecode_root, &module);
+        }
+        Ok(module)
+    }
+
+    /// Validates the block by the executor that wasn't used to get the `result`
+    /// and reports the mismatch of the results through logs and metrics.
+    ///
+    /// Only blocks of the native executor version can be executed both ways.
+    #[cfg(feature = "wasm-executor")]
+    fn verify_parity(
+        &self,
+        block: &Block,
+        result: &ExecutorResult<Uncommitted<ValidationResult, Changes>>,
+    ) {
+        let block_version = block.header().state_transition_bytecode_version;
+        if block_version != self.native_executor_version() {
+            return
+        }
+
+        let options: ExecutionOptions = self.config.as_ref().into();
+        let other;
+        let (native, wasm) = match &self.execution_strategy {
+            ExecutionStrategy::Native => {
+                let module = self.get_module(block_version).unwrap_or_else(|_| {
+                    Self::compiled_underlying_executor(&self.engine).clone()
+                });
+                other = self.wasm_validate_inner(&module, block, options);
+                (result, &other)
+            }
+            ExecutionStrategy::Wasm { .. } => {
+                other = self.native_validate_inner(block, options);
+                (&other, result)
+            }
+        };
+
+        let metrics = executor_metrics();
+        metrics.parity_checks.inc();
+        if let Some(reason) = parity_mismatch(native, wasm) {
+            metrics.parity_mismatches.inc();
+            tracing::error!(
+                "The native and the WASM executors disagree on the block {} ({}): {reason}",
+                block.header().height(),
+                block.id(),
+            );
+        }
+    }
+
+    /// Queues the bytecodes completed by the `Upload` transactions for
+    /// the compilation into the on-disk cache. It allows the node to switch to
+    /// the new version without waiting for the compilation after the upgrade.
+    ///
+    /// The bytecode is completed by the same block, so it is read with
+    /// the `changes` of the block applied.
+    #[cfg(feature = "wasm-executor")]
+    fn precompile_uploaded_modules(
+        &self,
+        transactions: &[Transaction],
+        changes: Changes,
+    ) -> Changes {
+        let (Some(cache), Some(precompiler)) =
+            (&self.module_cache, &self.module_precompiler)
+        else {
+            return changes
+        };
+
+        let roots = transactions
+            .iter()
+            .filter_map(|transaction| match transaction {
+                // The subsections are uploaded in order, so the last one completes the bytecode.
+                Transaction::Upload(upload)
+                    if upload.subsection_index().saturating_add(1)
+                        == *upload.subsections_number() =>
+                {
+                    Some(*upload.bytecode_root())
+                }
+                _ => None,
+            })
+            .filter(|root| !cache.contains(&self.engine, root))
+            .collect::<Vec<_>>();
+        if roots.is_empty() {
+            return changes
+        }
+
+        let latest_view = match self.storage_view_provider.latest_view() {
+            Ok(latest_view) => latest_view,
+            Err(err) => {
+                tracing::warn!("Failed to read the uploaded bytecode: {err}");
+                return changes
+            }
+        };
+        let view = StorageTransaction::transaction(
+            latest_view,
+            ConflictPolicy::Overwrite,
+            changes,
+        );
+
+        for root in roots {
+            let bytecode = match view.storage::<UploadedBytecodes>().get(&root) {
+                Ok(Some(bytecode)) => bytecode.into_owned(),
+                Ok(None) => continue,
+                Err(err) => {
+                    tracing::warn!("Failed to read the uploaded bytecode {root}: {err}");
+                    continue
+                }
+            };
+            let UploadedBytecode::Completed(bytecode) = bytecode else {
+                continue
+            };
+            if bytecode == crate::WASM_BYTECODE {
+                continue
+            }
+
+            precompiler.precompile(root, bytecode);
+        }
+
+        view.into_changes()
     }
 
     /// Returns the compiled WASM module of the state transition function.
@@ -735,6 +1157,169 @@ where
     }
 }
 
+/// The maximum number of the additional dry runs performed to discover
+/// the inputs and outputs missing in the failed transactions.
+const MAX_MISSING_INPUTS_DISCOVERY_ATTEMPTS: usize = 10;
+
+/// The revert signal used by the Sway standard library when the transfer
+/// to an address fails because of the missing variable output.
+const FAILED_TRANSFER_TO_ADDRESS_SIGNAL: Word = 0xffff_ffff_ffff_0001;
+
+/// Adds to the script the contract input or the variable output whose absence
+/// caused the failure described by the `receipts`.
+/// Returns `false` if the failure has another reason.
+fn add_missing_input(
+    transaction: &mut Transaction,
+    receipts: &[Receipt],
+    missing_inputs: &mut MissingInputs,
+) -> bool {
+    let Transaction::Script(script) = transaction else {
+        return false
+    };
+
+    for receipt in receipts {
+        match receipt {
+            Receipt::Panic {
+                reason,
+                contract_id: Some(contract_id),
+                ..
+            } if *reason.reason() == PanicReason::ContractNotInInputs => {
+                let Ok(input_index) = u16::try_from(script.inputs().len()) else {
+                    return false
+                };
+                if missing_inputs.contracts.contains(contract_id) {
+                    return false
+                }
+                script.inputs_mut().push(Input::contract(
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    *contract_id,
+                ));
+                script.outputs_mut().push(Output::contract(
+                    input_index,
+                    Default::default(),
+                    Default::default(),
+                ));
+                missing_inputs.contracts.push(*contract_id);
+                return true
+            }
+            Receipt::Panic { reason, .. }
+                if *reason.reason() == PanicReason::OutputNotFound =>
+            {
+                script.outputs_mut().push(Output::variable(
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ));
+                missing_inputs.variable_outputs =
+                    missing_inputs.variable_outputs.saturating_add(1);
+                return true
+            }
+            Receipt::Revert { ra, .. } if *ra == FAILED_TRANSFER_TO_ADDRESS_SIGNAL => {
+                script.outputs_mut().push(Output::variable(
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ));
+                missing_inputs.variable_outputs =
+                    missing_inputs.variable_outputs.saturating_add(1);
+                return true
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Checks that the parallel validation of the block has the same outcome
+/// as the sequential one. Equal storage changes mean equal state roots.
+#[cfg(feature = "parallel")]
+fn compare_validation_results(
+    sequential: &ExecutorResult<Uncommitted<ValidationResult, Changes>>,
+    parallel: &ExecutorResult<Uncommitted<ValidationResult, Changes>>,
+) -> ExecutorResult<()> {
+    let mismatch = |reason: &str| {
+        ExecutorError::Other(format!(
+            "The parallel validation of the block mismatches the sequential one: {reason}"
+        ))
+    };
+
+    match (sequential, parallel) {
+        (Ok(sequential), Ok(parallel)) => {
+            if sequential.changes() != parallel.changes() {
+                return Err(mismatch("storage changes differ"))
+            }
+
+            if !same_tx_status(
+                &sequential.result().tx_status,
+                &parallel.result().tx_status,
+            ) {
+                return Err(mismatch("transaction statuses differ"))
+            }
+
+            Ok(())
+        }
+        // Both validations rejected the block.
+        (Err(_), Err(_)) => Ok(()),
+        (Ok(_), Err(err)) => Err(mismatch(&format!(
+            "only the parallel validation failed: {err}"
+        ))),
+        (Err(err), Ok(_)) => Err(mismatch(&format!(
+            "only the sequential validation failed: {err}"
+        ))),
+    }
+}
+
+/// Compares the outcome of the transactions, ignoring the data recorded
+/// only by the native executor, like the trace and the access list.
+fn same_tx_status(
+    first: &[TransactionExecutionStatus],
+    second: &[TransactionExecutionStatus],
+) -> bool {
+    first.len() == second.len()
+        && first.iter().zip(second.iter()).all(|(first, second)| {
+            first.id == second.id
+                && first.result.receipts() == second.result.receipts()
+                && first.result.total_gas() == second.result.total_gas()
+                && first.result.total_fee() == second.result.total_fee()
+        })
+}
+
+/// Returns the reason why the results of the native and the WASM
+/// executors differ, or `None` if they are the same.
+#[cfg(feature = "wasm-executor")]
+fn parity_mismatch(
+    native: &ExecutorResult<Uncommitted<ValidationResult, Changes>>,
+    wasm: &ExecutorResult<Uncommitted<ValidationResult, Changes>>,
+) -> Option<String> {
+    match (native, wasm) {
+        (Ok(native), Ok(wasm)) => {
+            if native.changes() != wasm.changes() {
+                Some("storage changes differ".to_string())
+            } else if native.result().events != wasm.result().events {
+                Some("events differ".to_string())
+            } else if !same_tx_status(
+                &native.result().tx_status,
+                &wasm.result().tx_status,
+            ) {
+                Some("transaction statuses differ".to_string())
+            } else {
+                None
+            }
+        }
+        (Err(native), Err(wasm)) if native == wasm => None,
+        (Err(native), Err(wasm)) => Some(format!(
+            "the native executor failed with `{native}`, \
+            while the WASM executor failed with `{wasm}`"
+        )),
+        (Ok(_), Err(err)) => Some(format!("only the WASM executor failed: {err}")),
+        (Err(err), Ok(_)) => Some(format!("only the native executor failed: {err}")),
+    }
+}
+
 #[allow(clippy::cast_possible_truncation)]
 #[allow(unexpected_cfgs)] // for cfg(coverage)
 #[cfg(test)]
@@ -1103,6 +1688,30 @@ mod test {
             assert_eq!(Ok(()), result);
         }
 
+        #[test]
+        fn verify_parity__native_and_wasm_executors_agree_on_valid_block() {
+            let storage = storage();
+            let config = Config {
+                verify_executor_parity: true,
+                ..Default::default()
+            };
+            let metrics = executor_metrics();
+
+            // Given
+            let executor = Executor::native(storage, DisabledRelayer, config);
+            let block = valid_block(Executor::<Storage, DisabledRelayer>::VERSION);
+            let checks_before = metrics.parity_checks.get();
+            let mismatches_before = metrics.parity_mismatches.get();
+
+            // When
+            let result = executor.validate(&block).map(|_| ());
+
+            // Then
+            assert_eq!(Ok(()), result);
+            assert!(metrics.parity_checks.get() > checks_before);
+            assert_eq!(metrics.parity_mismatches.get(), mismatches_before);
+        }
+
         // The test verifies that `Executor::get_module` method caches the compiled WASM module.
         // If it doesn't cache the modules, the test will fail with a timeout.
         #[test]
@@ -9,6 +9,11 @@ pub mod executor;
 
 #[cfg(feature = "wasm-executor")]
 pub mod instance;
+#[cfg(feature = "wasm-executor")]
+pub mod module_cache;
+
+#[cfg(all(test, not(feature = "wasm-executor")))]
+use tempfile as _;
 
 /// The WASM version of the underlying [`fuel_core_executor::executor::ExecutionInstance`].
 #[cfg(feature = "wasm-executor")]
@@ -0,0 +1,278 @@
+//! The on-disk cache of the compiled WASM modules of the state transition function.
+//!
+//! Each module is stored in its own file named after the bytecode root and
+//! the compatibility hash of the `wasmtime::Engine`. The hash changes with the
+//! version and the configuration of the `wasmtime`, so modules compiled by
+//! another version are never loaded. The file starts with the checksum of
+//! the serialized module, which is verified before the deserialization.
+//!
+//! The cache directory must be writable only by the node, since the content
+//! of the file is executed as a native code.
+
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_tx::Bytes32,
+};
+use std::{
+    hash::{
+        DefaultHasher,
+        Hash,
+        Hasher as _,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::mpsc::{
+        self,
+        SyncSender,
+        TrySendError,
+    },
+};
+use wasmtime::{
+    Engine,
+    Module,
+};
+
+const CHECKSUM_SIZE: usize = Bytes32::LEN;
+const FILE_EXTENSION: &str = "cwasm";
+/// The maximum number of bytecodes waiting for the precompilation.
+/// The bytecodes that don't fit are compiled on the first use instead.
+const PRECOMPILE_QUEUE_SIZE: usize = 4;
+
+/// The directory with the compiled WASM modules.
+#[derive(Debug, Clone)]
+pub struct ModuleCache {
+    path: PathBuf,
+}
+
+impl ModuleCache {
+    /// Opens the cache in the `path`, creating the directory if it doesn't exist.
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        std::fs::create_dir_all(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Returns `true` if the module compiled from the bytecode
+    /// with the `bytecode_root` is cached for the `engine`.
+    pub fn contains(&self, engine: &Engine, bytecode_root: &Bytes32) -> bool {
+        self.file_path(engine, bytecode_root).exists()
+    }
+
+    /// Loads the module compiled from the bytecode with the `bytecode_root`.
+    ///
+    /// The file that fails the integrity check or the deserialization is removed
+    /// from the cache, so the module is compiled and stored again.
+    pub fn load(&self, engine: &Engine, bytecode_root: &Bytes32) -> Option<Module> {
+        let path = self.file_path(engine, bytecode_root);
+        let content = match std::fs::read(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(err) => {
+                tracing::warn!("Failed to read the cached module {path:?}: {err}");
+                return None
+            }
+        };
+
+        match Self::deserialize(engine, &content) {
+            Ok(module) => {
+                tracing::debug!(
+                    "Loaded the compiled module {bytecode_root} from {path:?}"
+                );
+                Some(module)
+            }
+            Err(err) => {
+                tracing::warn!("Removing the invalid cached module {path:?}: {err}");
+                if let Err(err) = std::fs::remove_file(&path) {
+                    tracing::warn!("Failed to remove the cached module {path:?}: {err}");
+                }
+                None
+            }
+        }
+    }
+
+    /// Stores the `module` compiled from the bytecode with the `bytecode_root`.
+    /// Failures are only logged, since the module can always be compiled again.
+    pub fn store(&self, engine: &Engine, bytecode_root: &Bytes32, module: &Module) {
+        if let Err(err) = self.try_store(engine, bytecode_root, module) {
+            tracing::warn!("Failed to cache the compiled module {bytecode_root}: {err}");
+        }
+    }
+
+    fn try_store(
+        &self,
+        engine: &Engine,
+        bytecode_root: &Bytes32,
+        module: &Module,
+    ) -> anyhow::Result<()> {
+        let serialized = module.serialize()?;
+        let checksum = Hasher::hash(&serialized);
+
+        let mut content =
+            Vec::with_capacity(CHECKSUM_SIZE.saturating_add(serialized.len()));
+        content.extend_from_slice(checksum.as_ref());
+        content.extend_from_slice(&serialized);
+
+        // Write into the temporary file first, so a reader never sees
+        // a partially written module.
+        let path = self.file_path(engine, bytecode_root);
+        let tmp_path = path.with_extension(format!("{FILE_EXTENSION}.tmp"));
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, &path)?;
+        Ok(())
+    }
+
+    fn deserialize(engine: &Engine, content: &[u8]) -> anyhow::Result<Module> {
+        if content.len() < CHECKSUM_SIZE {
+            anyhow::bail!("The file is too short")
+        }
+        let (checksum, serialized) = content.split_at(CHECKSUM_SIZE);
+        if Hasher::hash(serialized).as_ref() != checksum {
+            anyhow::bail!("The checksum doesn't match the content")
+        }
+
+        // SAFETY: The content was produced by `Module::serialize` of the engine
+        // with the same compatibility hash, and the checksum confirms that
+        // it wasn't corrupted since then.
+        unsafe { Module::deserialize(engine, serialized) }
+    }
+
+    fn file_path(&self, engine: &Engine, bytecode_root: &Bytes32) -> PathBuf {
+        let mut hasher = DefaultHasher::new();
+        engine.precompile_compatibility_hash().hash(&mut hasher);
+        let compatibility_hash = hasher.finish();
+
+        self.path.join(format!(
+            "{bytecode_root:x}-{compatibility_hash:016x}.{FILE_EXTENSION}"
+        ))
+    }
+}
+
+/// Compiles the bytecodes into the [`ModuleCache`] one by one on a single
+/// background thread. The thread stops when all clones of the precompiler are dropped.
+#[derive(Debug, Clone)]
+pub struct ModulePrecompiler {
+    sender: SyncSender<(Bytes32, Vec<u8>)>,
+}
+
+impl ModulePrecompiler {
+    /// Spawns the thread that stores the modules compiled by the `engine` in the `cache`.
+    pub fn spawn(engine: Engine, cache: ModuleCache) -> std::io::Result<Self> {
+        let (sender, receiver) = mpsc::sync_channel(PRECOMPILE_QUEUE_SIZE);
+        std::thread::Builder::new()
+            .name("wasm-module-precompiler".to_string())
+            .spawn(move || {
+                for (bytecode_root, bytecode) in receiver {
+                    if cache.contains(&engine, &bytecode_root) {
+                        continue
+                    }
+                    match Module::new(&engine, bytecode) {
+                        Ok(module) => cache.store(&engine, &bytecode_root, &module),
+                        Err(err) => {
+                            tracing::warn!(
+                                "Failed to precompile the bytecode {bytecode_root}: {err}"
+                            );
+                        }
+                    }
+                }
+            })?;
+        Ok(Self { sender })
+    }
+
+    /// Queues the `bytecode` with the `bytecode_root` for the compilation.
+    /// The bytecode is skipped if the queue is full.
+    pub fn precompile(&self, bytecode_root: Bytes32, bytecode: Vec<u8>) {
+        match self.sender.try_send((bytecode_root, bytecode)) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                tracing::warn!(
+                    "The precompilation queue is full, the bytecode {bytecode_root} \
+                    will be compiled on the first use"
+                );
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                tracing::warn!(
+                    "The precompilation thread stopped, the bytecode {bytecode_root} \
+                    will be compiled on the first use"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The empty WASM module.
+    const BYTECODE: &[u8] = b"\0asm\x01\0\0\0";
+
+    #[test]
+    fn load__returns_stored_module() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ModuleCache::open(dir.path()).unwrap();
+        let engine = Engine::default();
+        let module = Module::new(&engine, BYTECODE).unwrap();
+        let root = Bytes32::from([1; 32]);
+        cache.store(&engine, &root, &module);
+
+        // When
+        let loaded = cache.load(&engine, &root);
+
+        // Then
+        assert!(cache.contains(&engine, &root));
+        let loaded = loaded.expect("The module should be cached");
+        assert_eq!(loaded.serialize().unwrap(), module.serialize().unwrap());
+    }
+
+    #[test]
+    fn load__removes_corrupted_module() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ModuleCache::open(dir.path()).unwrap();
+        let engine = Engine::default();
+        let module = Module::new(&engine, BYTECODE).unwrap();
+        let root = Bytes32::from([1; 32]);
+        cache.store(&engine, &root, &module);
+
+        let path = cache.file_path(&engine, &root);
+        let mut content = std::fs::read(&path).unwrap();
+        let last = content.last_mut().unwrap();
+        *last = last.wrapping_add(1);
+        std::fs::write(&path, content).unwrap();
+
+        // When
+        let loaded = cache.load(&engine, &root);
+
+        // Then
+        assert!(loaded.is_none());
+        assert!(!cache.contains(&engine, &root));
+    }
+
+    #[test]
+    fn precompile__stores_compiled_module() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let cache = ModuleCache::open(dir.path()).unwrap();
+        let engine = Engine::default();
+        let precompiler =
+            ModulePrecompiler::spawn(engine.clone(), cache.clone()).unwrap();
+        let root = Bytes32::from([1; 32]);
+
+        // When
+        precompiler.precompile(root, BYTECODE.to_vec());
+
+        // Then
+        let started = std::time::Instant::now();
+        while !cache.contains(&engine, &root) {
+            assert!(
+                started.elapsed() < std::time::Duration::from_secs(10),
+                "The module should be precompiled"
+            );
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    }
+}
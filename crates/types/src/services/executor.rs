@@ -15,6 +15,10 @@ use crate::{
             transaction::RelayedTransactionId,
         },
     },
+    fuel_asm::{
+        RawInstruction,
+        Word,
+    },
     fuel_tx::{
         Receipt,
         TxId,
@@ -22,6 +26,7 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        AssetId,
         BlockHeight,
         Bytes32,
         ContractId,
@@ -126,7 +131,7 @@ impl<DatabaseTransaction> UncommittedResult<DatabaseTransaction> {
 }
 
 /// The event represents some internal state changes caused by the block execution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// Imported a new spendable message from the relayer.
@@ -185,6 +190,19 @@ pub struct TransactionExecutionStatus {
     pub id: Bytes32,
     /// The result of the executed transaction.
     pub result: TransactionExecutionResult,
+    /// The instruction-level trace of the transaction execution.
+    /// It is recorded only by the native executor when the tracing is requested,
+    /// so it is never a part of the serialized status.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub trace: Option<Vec<TraceRecord>>,
+    /// The contracts, storage slots and assets accessed by the transaction.
+    /// It is recorded only by the native executor during the dry run.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub access_list: Option<AccessList>,
+    /// The inputs and outputs missing in the failed transaction.
+    /// It is discovered only during the dry run.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub missing_inputs: Option<MissingInputs>,
 }
 
 /// The result of transaction execution.
@@ -256,6 +274,97 @@ impl TransactionExecutionResult {
     }
 }
 
+/// The record about the single instruction executed by the VM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TraceRecord {
+    /// The contract that owns the executed code, or `None` for the script code.
+    pub contract_id: Option<ContractId>,
+    /// The program counter relative to the start of the script or contract code.
+    pub pc: Word,
+    /// The raw executed instruction.
+    pub instruction: RawInstruction,
+    /// The gas consumed by the instruction.
+    pub gas_used: Word,
+    /// The storage slots read or written by the instruction.
+    pub storage_access: Option<StorageAccess>,
+}
+
+/// The access to the contract storage performed by the instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StorageAccess {
+    /// The kind of the access.
+    pub kind: StorageAccessKind,
+    /// The key of the first accessed slot.
+    pub key: Bytes32,
+    /// The number of sequential slots accessed starting from the `key`.
+    pub slots: Word,
+}
+
+/// The kind of the access to the contract storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageAccessKind {
+    /// The slots were read.
+    Read,
+    /// The slots were written.
+    Write,
+    /// The slots were cleared.
+    Clear,
+}
+
+/// The contract storage accessed by the transaction during the execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccessList {
+    /// The contracts whose code, state or balances were accessed.
+    pub contracts: Vec<ContractId>,
+    /// The storage slots of the contracts that were read or written.
+    pub storage_slots: Vec<(ContractId, Bytes32)>,
+    /// The assets whose contract balances were accessed or which were transferred.
+    pub assets: Vec<AssetId>,
+}
+
+/// The inputs and outputs which should be added to the transaction
+/// to avoid the failure caused by their absence.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MissingInputs {
+    /// The contracts called by the transaction without the `Input::Contract`.
+    pub contracts: Vec<ContractId>,
+    /// The number of missing `Output::Variable`.
+    pub variable_outputs: u16,
+}
+
+impl MissingInputs {
+    /// Returns `true` if nothing is missing.
+    pub fn is_empty(&self) -> bool {
+        self.contracts.is_empty() && self.variable_outputs == 0
+    }
+}
+
+/// The settings of the instruction-level tracing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceConfig {
+    /// The only transaction to trace. All transactions are traced if it is `None`.
+    pub tx_id: Option<TxId>,
+    /// The maximum number of the instructions recorded for one transaction.
+    /// The execution fails if the traced transaction executes more instructions.
+    pub max_steps: usize,
+}
+
+/// The optional features of the dry run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DryRunOptions {
+    /// Record the instruction-level trace of the transactions.
+    pub trace: Option<TraceConfig>,
+    /// Execute the failed transactions again with the contract inputs and
+    /// variable outputs whose absence caused the failure, to report them
+    /// as missing. It requires up to `10` additional dry runs.
+    pub discover_missing_inputs: bool,
+}
+
 #[allow(missing_docs)]
 #[derive(Debug, Clone, PartialEq, derive_more::Display, derive_more::From)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -339,6 +448,13 @@ pub enum Error {
     /// It is possible to occur untyped errors in the case of the upgrade.
     #[display(fmt = "Occurred untyped error: {_0}")]
     Other(String),
+    #[display(
+        fmt = "The trace of the transaction({transaction_id:#x}) exceeded the limit of {max_steps} steps"
+    )]
+    TraceLimitExceeded {
+        transaction_id: Bytes32,
+        max_steps: usize,
+    },
 }
 
 impl From<Error> for anyhow::Error {
@@ -1,9 +1,13 @@
 //! Types related to GraphQL API service.
 
-use crate::fuel_types::{
-    Address,
-    AssetId,
-    ContractId,
+use crate::{
+    fuel_merkle::sparse,
+    fuel_types::{
+        Address,
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
 };
 
 /// The cumulative balance(`amount`) of the `Owner` of `asset_id`.
@@ -21,3 +25,12 @@ pub type AddressBalance = Balance<Address, u128>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId, u64>;
+
+/// The sparse Merkle proof of the contract's storage slot.
+#[derive(Debug, Clone)]
+pub struct ContractStateProof {
+    /// The state root of the contract against which the proof was generated.
+    pub state_root: Bytes32,
+    /// The inclusion proof if the slot is set, otherwise the exclusion proof.
+    pub proof: sparse::proof::Proof,
+}
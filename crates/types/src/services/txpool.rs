@@ -318,6 +318,21 @@ impl From<&PoolTransaction> for CheckedTransaction {
     }
 }
 
+/// The transaction in the pool with its position in the dependency graph of the pool.
+#[derive(Debug, Clone)]
+pub struct PendingTransaction {
+    /// The transaction.
+    pub transaction: ArcPoolTx,
+    /// The time when the transaction was added to the pool.
+    pub submitted_at: Tai64,
+    /// The cumulative tip of the transaction and all of its dependents in the pool.
+    pub dependents_cumulative_tip: Word,
+    /// The ids of transactions in the pool that this transaction depends on.
+    pub dependencies: Vec<TxId>,
+    /// The ids of transactions in the pool that depend on this transaction.
+    pub dependents: Vec<TxId>,
+}
+
 /// The status of the transaction during its life from the tx pool until the block.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
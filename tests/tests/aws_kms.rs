@@ -47,8 +47,14 @@ async fn can_get_sealed_block_from_poa_produced_block_when_signing_with_kms() {
 
     // stop the node and just grab the database
     let db_path = driver.kill().await;
-    let db = CombinedDatabase::open(db_path.path(), 1024 * 1024, Default::default(), 512)
-        .unwrap();
+    let db = CombinedDatabase::open(
+        db_path.path(),
+        1024 * 1024,
+        Default::default(),
+        512,
+        &Default::default(),
+    )
+    .unwrap();
 
     let view = db.on_chain().latest_view().unwrap();
 
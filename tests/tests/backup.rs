@@ -0,0 +1,87 @@
+use fuel_core::combined_database::CombinedDatabase;
+use fuel_core_storage::transactional::HistoricalView;
+use fuel_core_types::fuel_types::BlockHeight;
+use tempfile::tempdir;
+use test_helpers::fuel_core_driver::FuelCoreDriver;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn backup_database__can_be_restored_and_used_by_new_node() -> anyhow::Result<()> {
+    const HEIGHTS: u32 = 10;
+    let driver = FuelCoreDriver::spawn(&["--debug", "--poa-instant", "true"]).await?;
+    driver.client.produce_blocks(HEIGHTS, None).await?;
+    let backup_dir = tempdir()?;
+    let backup_path = backup_dir.path().join("backup");
+
+    // Given
+    let backup_height = driver
+        .client
+        .backup_database(backup_path.to_str().unwrap())
+        .await?;
+    assert_eq!(backup_height, BlockHeight::new(HEIGHTS));
+    driver.client.produce_blocks(HEIGHTS, None).await?;
+    driver.kill().await;
+
+    // When
+    let restored_dir = tempdir()?;
+    let restored_height =
+        CombinedDatabase::restore(&backup_path, restored_dir.path(), 1024 * 1024, -1)?;
+
+    // Then
+    assert_eq!(restored_height, BlockHeight::new(HEIGHTS));
+    let restored_driver = FuelCoreDriver::spawn_with_directory(
+        restored_dir,
+        &["--debug", "--poa-instant", "true"],
+    )
+    .await?;
+    let database = &restored_driver.node.shared.database;
+    assert_eq!(
+        database.on_chain().latest_height(),
+        Some(BlockHeight::new(HEIGHTS))
+    );
+    assert_eq!(
+        database.off_chain().latest_height(),
+        Some(BlockHeight::new(HEIGHTS))
+    );
+    let new_height = restored_driver.client.produce_blocks(1, None).await?;
+    assert_eq!(new_height, BlockHeight::new(HEIGHTS + 1));
+
+    restored_driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn backup_database__fails_without_debug() -> anyhow::Result<()> {
+    let driver = FuelCoreDriver::spawn(&["--poa-instant", "true"]).await?;
+    let backup_dir = tempdir()?;
+
+    // When
+    let result = driver
+        .client
+        .backup_database(backup_dir.path().to_str().unwrap())
+        .await;
+
+    // Then
+    assert!(result.is_err());
+
+    driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn backup_database__fails_if_directory_is_not_empty() -> anyhow::Result<()> {
+    let driver = FuelCoreDriver::spawn(&["--debug", "--poa-instant", "true"]).await?;
+    let backup_dir = tempdir()?;
+    std::fs::write(backup_dir.path().join("file"), b"data")?;
+
+    // When
+    let result = driver
+        .client
+        .backup_database(backup_dir.path().to_str().unwrap())
+        .await;
+
+    // Then
+    assert!(result.is_err());
+
+    driver.kill().await;
+    Ok(())
+}
@@ -348,6 +348,57 @@ async fn missing_first_and_last_parameters_returns_an_error() {
     assert!(result.contains("The queries for the whole range is not supported"));
 }
 
+#[tokio::test]
+async fn subscribe_blocks__returns_stored_blocks_and_then_new_blocks() {
+    use futures::StreamExt;
+
+    let node = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // Given
+    client.produce_blocks(3, None).await.unwrap();
+    let stream = client.subscribe_blocks(Some(1u32.into())).await.unwrap();
+    let mut stream = Box::pin(stream);
+
+    // When
+    client.produce_blocks(2, None).await.unwrap();
+
+    // Then
+    let mut heights = vec![];
+    for _ in 0..5 {
+        let block = stream.next().await.unwrap().unwrap();
+        // Every block contains at least the mint transaction.
+        assert!(!block.transactions.is_empty());
+        heights.push(block.header.height);
+    }
+    assert_eq!(heights, vec![1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn subscribe_blocks__fails_when_too_many_stored_blocks_requested() {
+    use futures::StreamExt;
+
+    let mut config = Config::local_node();
+    config.graphql_config.max_replayed_blocks = 2;
+    let node = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // Given
+    client.produce_blocks(3, None).await.unwrap();
+
+    // When
+    let result = match client.subscribe_blocks(Some(1u32.into())).await {
+        Ok(stream) => Box::pin(stream).next().await.unwrap().map(|_| ()),
+        Err(err) => Err(err),
+    };
+
+    // Then
+    let err = result.expect_err("Three stored blocks exceed the limit");
+    assert!(err
+        .to_string()
+        .contains("can replay at most 2 stored blocks"));
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use crate::helpers::{
     TestContext,
     TestSetupBuilder,
@@ -17,9 +19,14 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    schema::tx::transparent_receipt::ReceiptType,
+    types::{
+        ContractStorageSlotsFilter,
+        TransactionStatus,
+    },
     FuelClient,
 };
+use fuel_core_poa::service::Mode;
 use fuel_core_storage::tables::Coins;
 use fuel_core_types::{
     fuel_asm::*,
@@ -29,12 +36,19 @@ use fuel_core_types::{
         checked_transaction::IntoChecked,
         *,
     },
+    services::executor::{
+        MissingInputs,
+        StorageAccessKind,
+        TransactionExecutionResult,
+    },
 };
 use rand::SeedableRng;
+use std::iter;
 
 use fuel_core::chain_config::{
     CoinConfig,
     ContractConfig,
+    ContractStateConfig,
     StateConfig,
 };
 use rstest::rstest;
@@ -438,3 +452,526 @@ async fn can_get_genesis_contract_salt() {
 
     assert_eq!(ret.salt, Salt::zeroed());
 }
+
+#[rstest]
+#[tokio::test]
+async fn contract_storage_slots__returns_all_set_slots(
+    #[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
+) {
+    // given
+    let states = (0..5u8)
+        .map(|i| ContractStateConfig {
+            key: key(i),
+            value: vec![i; 32],
+        })
+        .collect::<Vec<_>>();
+    let contract = ContractConfig {
+        states: states.clone(),
+        ..Default::default()
+    };
+    let contract_id = contract.contract_id;
+    let service_config = Config::local_node_with_state_config(StateConfig {
+        contracts: vec![contract],
+        ..Default::default()
+    });
+    let node =
+        FuelService::from_database(Database::<OnChain>::in_memory(), service_config)
+            .await
+            .unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // when
+    let slots = client
+        .contract_storage_slots(
+            &contract_id,
+            None,
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction,
+            },
+        )
+        .await
+        .unwrap();
+
+    // then
+    let mut expected = states
+        .into_iter()
+        .map(|state| (state.key, Some(state.value)))
+        .collect::<Vec<_>>();
+    if direction == PageDirection::Backward {
+        expected.reverse();
+    }
+    let actual = slots
+        .results
+        .into_iter()
+        .map(|slot| (slot.key, slot.value))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+    assert!(!slots.has_next_page);
+}
+
+#[rstest]
+#[tokio::test]
+async fn contract_storage_slots__returns_set_slots_within_range(
+    #[values(PageDirection::Forward, PageDirection::Backward)] direction: PageDirection,
+) {
+    // given
+    let states = (0..5u8)
+        .map(|i| ContractStateConfig {
+            key: key(i),
+            value: vec![i; 32],
+        })
+        .collect::<Vec<_>>();
+    let contract = ContractConfig {
+        states: states.clone(),
+        ..Default::default()
+    };
+    let contract_id = contract.contract_id;
+    let service_config = Config::local_node_with_state_config(StateConfig {
+        contracts: vec![contract],
+        ..Default::default()
+    });
+    let node =
+        FuelService::from_database(Database::<OnChain>::in_memory(), service_config)
+            .await
+            .unwrap();
+    let client = FuelClient::from(node.bound_address);
+
+    // when
+    let slots = client
+        .contract_storage_slots(
+            &contract_id,
+            Some(ContractStorageSlotsFilter::Range {
+                start: Some(key(1)),
+                end: Some(key(3)),
+            }),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction,
+            },
+        )
+        .await
+        .unwrap();
+
+    // then
+    let mut expected = states[1..=3]
+        .iter()
+        .map(|state| (state.key, Some(state.value.clone())))
+        .collect::<Vec<_>>();
+    if direction == PageDirection::Backward {
+        expected.reverse();
+    }
+    let actual = slots
+        .results
+        .into_iter()
+        .map(|slot| (slot.key, slot.value))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, expected);
+    assert!(!slots.has_next_page);
+}
+
+#[tokio::test]
+async fn contract_storage_slots_with_proof__returns_inclusion_and_exclusion_proofs() {
+    // given
+    let states = (0..5u8)
+        .map(|i| ContractStateConfig {
+            key: key(i),
+            value: vec![i; 32],
+        })
+        .collect::<Vec<_>>();
+    let contract = ContractConfig {
+        states,
+        ..Default::default()
+    };
+    let contract_id = contract.contract_id;
+    let service_config = Config::local_node_with_state_config(StateConfig {
+        contracts: vec![contract],
+        ..Default::default()
+    });
+    let node =
+        FuelService::from_database(Database::<OnChain>::in_memory(), service_config)
+            .await
+            .unwrap();
+    let client = FuelClient::from(node.bound_address);
+    let existing_key = key(1);
+    let missing_key = key(42);
+
+    // when
+    let slots = client
+        .contract_storage_slots_with_proof(
+            &contract_id,
+            Some(ContractStorageSlotsFilter::Keys(vec![
+                missing_key,
+                existing_key,
+            ])),
+            PaginationRequest {
+                cursor: None,
+                results: 10,
+                direction: PageDirection::Forward,
+            },
+        )
+        .await
+        .unwrap();
+
+    // then
+    assert_eq!(slots.results.len(), 2);
+    let existing = &slots.results[0];
+    assert_eq!(existing.key, existing_key);
+    assert_eq!(existing.value, Some(vec![1; 32]));
+    let existing_proof = existing.proof.as_ref().unwrap();
+    assert!(existing_proof.inclusion);
+
+    let missing = &slots.results[1];
+    assert_eq!(missing.key, missing_key);
+    assert_eq!(missing.value, None);
+    let missing_proof = missing.proof.as_ref().unwrap();
+    assert!(!missing_proof.inclusion);
+    assert_eq!(existing_proof.state_root, missing_proof.state_root);
+}
+
+#[tokio::test]
+async fn receipts_by_contract__returns_receipts_of_the_called_contract() {
+    // given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![
+        op::log(RegId::ONE, RegId::ONE, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let (_, contract_id) =
+        test_builder.setup_contract(contract_code.into_iter().collect(), vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(
+        script.into_iter().collect(),
+        Call::new(contract_id, 0, 0).to_bytes(),
+    )
+    .script_gas_limit(1_000_000)
+    .add_input(Input::contract(
+        UtxoId::new(Bytes32::zeroed(), 0),
+        Bytes32::zeroed(),
+        Bytes32::zeroed(),
+        TxPointer::default(),
+        contract_id,
+    ))
+    .add_fee_input()
+    .add_output(Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()))
+    .finalize_as_transaction();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+
+    // when
+    let all_receipts = client
+        .receipts_by_contract(&contract_id, None, None, None, request.clone())
+        .await
+        .unwrap();
+    let logs = client
+        .receipts_by_contract(
+            &contract_id,
+            Some(vec![ReceiptType::Log]),
+            None,
+            None,
+            request,
+        )
+        .await
+        .unwrap();
+
+    // then
+    let tx_id = tx.id(&Default::default());
+    assert!(all_receipts
+        .results
+        .iter()
+        .all(|receipt| receipt.transaction_id == tx_id));
+    let all_receipts = all_receipts
+        .results
+        .into_iter()
+        .map(|receipt| receipt.receipt)
+        .collect::<Vec<_>>();
+    assert!(matches!(
+        all_receipts.as_slice(),
+        [Receipt::Call { to, .. }, Receipt::Log { id, .. }]
+            if to == &contract_id && id == &contract_id
+    ));
+    assert_eq!(logs.results.len(), 1);
+    assert!(matches!(logs.results[0].receipt, Receipt::Log { .. }));
+}
+
+#[tokio::test]
+async fn trace_transaction__records_storage_write_of_the_called_contract() {
+    // given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![op::sww(RegId::ZERO, 0x10, RegId::ONE), op::ret(RegId::ONE)];
+    let (_, contract_id) =
+        test_builder.setup_contract(contract_code.into_iter().collect(), vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let tx = TransactionBuilder::script(
+        script.into_iter().collect(),
+        Call::new(contract_id, 0, 0).to_bytes(),
+    )
+    .script_gas_limit(1_000_000)
+    .add_input(Input::contract(
+        UtxoId::new(Bytes32::zeroed(), 0),
+        Bytes32::zeroed(),
+        Bytes32::zeroed(),
+        TxPointer::default(),
+        contract_id,
+    ))
+    .add_fee_input()
+    .add_output(Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()))
+    .finalize_as_transaction();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let tx_id = tx.id(&Default::default());
+
+    // when
+    let traced = client
+        .trace_transaction(&tx_id)
+        .await
+        .unwrap()
+        .expect("The transaction is committed");
+
+    // then
+    assert_eq!(traced.id, tx_id);
+    let trace = traced.trace.expect("The trace is requested");
+    let script_opcodes = trace
+        .iter()
+        .filter(|record| record.contract_id.is_none())
+        .map(|record| Instruction::try_from(record.instruction).unwrap().opcode())
+        .collect::<Vec<_>>();
+    assert_eq!(script_opcodes, vec![Opcode::GTF, Opcode::CALL, Opcode::RET]);
+    let contract_records = trace
+        .iter()
+        .filter(|record| record.contract_id == Some(contract_id))
+        .collect::<Vec<_>>();
+    assert_eq!(contract_records.len(), 2);
+    let storage_access = contract_records[0]
+        .storage_access
+        .expect("`SWW` writes to the storage");
+    assert_eq!(storage_access.kind, StorageAccessKind::Write);
+    assert_eq!(storage_access.slots, 1);
+    assert!(contract_records[0].gas_used > 0);
+}
+
+async fn produce_block_with_long_and_short_scripts(
+    max_trace_steps: usize,
+) -> (FuelService, FuelClient, Transaction, Transaction) {
+    let mut config = Config::local_node();
+    config.graphql_config.max_trace_steps = max_trace_steps;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let long_script = iter::repeat(op::noop())
+        .take(10)
+        .chain(iter::once(op::ret(RegId::ONE)));
+    let long_tx = TransactionBuilder::script(long_script.collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_fee_input()
+        .finalize_as_transaction();
+    let short_tx =
+        TransactionBuilder::script(iter::once(op::ret(RegId::ONE)).collect(), vec![])
+            .script_gas_limit(1_000_000)
+            .add_fee_input()
+            .finalize_as_transaction();
+    srv.shared
+        .poa_adapter
+        .manually_produce_blocks(
+            None,
+            Mode::BlockWithTransactions(vec![long_tx.clone(), short_tx.clone()]),
+        )
+        .await
+        .unwrap();
+
+    (srv, client, long_tx, short_tx)
+}
+
+#[tokio::test]
+async fn trace_transaction__does_not_trace_preceding_transactions() {
+    // given
+    let max_trace_steps = 5;
+    let (_srv, client, _, short_tx) =
+        produce_block_with_long_and_short_scripts(max_trace_steps).await;
+
+    // when
+    let traced = client
+        .trace_transaction(&short_tx.id(&Default::default()))
+        .await
+        .unwrap()
+        .expect("The transaction is committed");
+
+    // then
+    let trace = traced.trace.expect("The trace is requested");
+    assert_eq!(trace.len(), 1);
+}
+
+#[tokio::test]
+async fn trace_transaction__fails_when_trace_exceeds_max_steps() {
+    // given
+    let max_trace_steps = 5;
+    let (_srv, client, long_tx, _) =
+        produce_block_with_long_and_short_scripts(max_trace_steps).await;
+
+    // when
+    let result = client
+        .trace_transaction(&long_tx.id(&Default::default()))
+        .await;
+
+    // then
+    let error = result.expect_err("The trace exceeds the limit");
+    assert!(error.to_string().contains("exceeded the limit of 5 steps"));
+}
+
+fn call_contract_script_builder(contract_id: ContractId) -> TransactionBuilder<Script> {
+    let script = vec![
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let mut builder = TransactionBuilder::script(
+        script.into_iter().collect(),
+        Call::new(contract_id, 0, 0).to_bytes(),
+    );
+    builder.script_gas_limit(1_000_000).add_fee_input();
+    builder
+}
+
+#[tokio::test]
+async fn dry_run__returns_access_list_of_the_called_contract() {
+    // given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![op::sww(RegId::ZERO, 0x10, RegId::ONE), op::ret(RegId::ONE)];
+    let (_, contract_id) =
+        test_builder.setup_contract(contract_code.into_iter().collect(), vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let tx = call_contract_script_builder(contract_id)
+        .add_input(Input::contract(
+            UtxoId::new(Bytes32::zeroed(), 0),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            contract_id,
+        ))
+        .add_output(Output::contract(1, Bytes32::zeroed(), Bytes32::zeroed()))
+        .finalize_as_transaction();
+
+    // when
+    let status = client
+        .dry_run_opt(&[tx], Some(false), None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    // then
+    assert!(matches!(
+        status.result,
+        TransactionExecutionResult::Success { .. }
+    ));
+    assert_eq!(status.missing_inputs, None);
+    let access_list = status.access_list.expect("The native executor is used");
+    assert_eq!(access_list.contracts, vec![contract_id]);
+    assert_eq!(access_list.storage_slots.len(), 1);
+    assert_eq!(access_list.storage_slots[0].0, contract_id);
+}
+
+#[tokio::test]
+async fn dry_run__returns_contract_missing_in_inputs() {
+    // given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![op::ret(RegId::ONE)];
+    let (_, contract_id) =
+        test_builder.setup_contract(contract_code.into_iter().collect(), vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let tx = call_contract_script_builder(contract_id).finalize_as_transaction();
+
+    // when
+    let status = client
+        .dry_run_with_missing_inputs(&[tx], Some(false), None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    // then
+    assert!(matches!(
+        status.result,
+        TransactionExecutionResult::Failed { .. }
+    ));
+    assert_eq!(
+        status.missing_inputs,
+        Some(MissingInputs {
+            contracts: vec![contract_id],
+            variable_outputs: 0,
+        })
+    );
+}
+
+#[tokio::test]
+async fn dry_run__does_not_discover_missing_inputs_unless_requested() {
+    // given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    let contract_code = vec![op::ret(RegId::ONE)];
+    let (_, contract_id) =
+        test_builder.setup_contract(contract_code.into_iter().collect(), vec![], None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    let tx = call_contract_script_builder(contract_id).finalize_as_transaction();
+
+    // when
+    let status = client
+        .dry_run_opt(&[tx], Some(false), None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    // then
+    assert!(matches!(
+        status.result,
+        TransactionExecutionResult::Failed { .. }
+    ));
+    assert_eq!(status.missing_inputs, None);
+}
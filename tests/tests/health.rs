@@ -28,9 +28,14 @@ async fn can_restart_node() {
 
     // start node once
     {
-        let database =
-            Database::open_rocksdb(tmp_dir.path(), None, Default::default(), 512)
-                .unwrap();
+        let database = Database::open_rocksdb(
+            tmp_dir.path(),
+            None,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let first_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -41,9 +46,14 @@ async fn can_restart_node() {
     }
 
     {
-        let database =
-            Database::open_rocksdb(tmp_dir.path(), None, Default::default(), 512)
-                .unwrap();
+        let database = Database::open_rocksdb(
+            tmp_dir.path(),
+            None,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let _second_startup = FuelService::from_database(database, Config::local_node())
             .await
             .unwrap();
@@ -57,9 +67,14 @@ async fn can_restart_node_with_transactions() {
 
     {
         // Given
-        let database =
-            CombinedDatabase::open(tmp_dir.path(), capacity, Default::default(), 512)
-                .unwrap();
+        let database = CombinedDatabase::open(
+            tmp_dir.path(),
+            capacity,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let service = FuelService::from_combined_database(database, Config::local_node())
             .await
             .unwrap();
@@ -76,9 +91,14 @@ async fn can_restart_node_with_transactions() {
 
     {
         // When
-        let database =
-            CombinedDatabase::open(tmp_dir.path(), capacity, Default::default(), 512)
-                .unwrap();
+        let database = CombinedDatabase::open(
+            tmp_dir.path(),
+            capacity,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let service = FuelService::from_combined_database(database, Config::local_node())
             .await
             .unwrap();
@@ -1,6 +1,8 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+#[cfg(not(feature = "only-p2p"))]
+mod backup;
 #[cfg(not(feature = "only-p2p"))]
 mod balances;
 #[cfg(not(feature = "only-p2p"))]
@@ -46,6 +48,8 @@ mod recovery;
 #[cfg(not(feature = "only-p2p"))]
 mod regenesis;
 #[cfg(not(feature = "only-p2p"))]
+mod reindex;
+#[cfg(not(feature = "only-p2p"))]
 mod relayer;
 #[cfg(not(feature = "only-p2p"))]
 mod snapshot;
@@ -94,3 +94,30 @@ async fn metrics_include_real_query_name_instead_of_alias() {
         .unwrap();
     assert!(!resp.contains(ALIAS))
 }
+
+#[tokio::test]
+async fn database_stats__reports_columns_of_all_databases() {
+    let mut config = Config::local_node();
+    let tmp_dir = TempDir::new().unwrap();
+    config.combined_db_config.database_path = tmp_dir.path().to_path_buf();
+    config.combined_db_config.database_type = DbType::RocksDb;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    client.produce_blocks(1, None).await.unwrap();
+
+    // When
+    let stats = client.database_stats().await.unwrap();
+
+    // Then
+    srv.send_stop_signal_and_await_shutdown().await.unwrap();
+    let names = stats
+        .iter()
+        .map(|stats| stats.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["on_chain", "off_chain", "relayer", "gas_price"]);
+    let on_chain = &stats[0];
+    assert!(!on_chain.columns.is_empty());
+    assert!(on_chain.columns.iter().any(|column| column.writes > 0));
+}
@@ -0,0 +1,187 @@
+use clap::Parser;
+use fuel_core::combined_database::CombinedDatabase;
+use fuel_core_client::client::FuelClient;
+use fuel_core_storage::{
+    column::Column,
+    iter::IteratorOverTable,
+    kv_store::KeyValueMutate,
+    tables::Coins,
+    transactional::{
+        HistoricalView,
+        IntoTransaction,
+    },
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        AssetId,
+    },
+    fuel_types::BlockHeight,
+};
+use test_helpers::fuel_core_driver::FuelCoreDriver;
+
+const HEIGHTS: u32 = 10;
+const NODE_ARGS: &[&str] = &[
+    "--debug",
+    "--poa-instant",
+    "true",
+    "--state-rewind-duration",
+    "7d",
+];
+
+fn genesis_coin_owner(database: &CombinedDatabase) -> (Address, AssetId) {
+    let (_, coin) = database
+        .on_chain()
+        .iter_all::<Coins>(None)
+        .next()
+        .expect("The snapshot should have coins")
+        .unwrap();
+    (*coin.owner(), *coin.asset_id())
+}
+
+async fn balance(client: &FuelClient, (owner, asset_id): &(Address, AssetId)) -> u64 {
+    client.balance(owner, Some(asset_id)).await.unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex__rebuilds_off_chain_database_from_blocks() -> anyhow::Result<()> {
+    let driver = FuelCoreDriver::spawn(NODE_ARGS).await?;
+    driver.client.produce_blocks(HEIGHTS, None).await?;
+    let owner = genesis_coin_owner(&driver.node.shared.database);
+    let expected_balance = balance(&driver.client, &owner).await;
+    let temp_dir = driver.kill().await;
+
+    // Given
+    std::fs::remove_dir_all(temp_dir.path().join("off_chain"))?;
+
+    // When
+    let args = ["_IGNORED_", "--db-path", temp_dir.path().to_str().unwrap()];
+    let command = fuel_core_bin::cli::reindex::Command::parse_from(args);
+    fuel_core_bin::cli::reindex::exec(command).await?;
+
+    // Then
+    let reindexed_driver =
+        FuelCoreDriver::spawn_with_directory(temp_dir, NODE_ARGS).await?;
+    let database = &reindexed_driver.node.shared.database;
+    assert_eq!(
+        database.off_chain().latest_height(),
+        Some(BlockHeight::new(HEIGHTS))
+    );
+    assert_eq!(
+        balance(&reindexed_driver.client, &owner).await,
+        expected_balance
+    );
+    let new_height = reindexed_driver.client.produce_blocks(1, None).await?;
+    assert_eq!(new_height, BlockHeight::new(HEIGHTS + 1));
+
+    reindexed_driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reindex__keeps_off_chain_database_if_blocks_are_missing() -> anyhow::Result<()> {
+    let driver = FuelCoreDriver::spawn(NODE_ARGS).await?;
+    driver.client.produce_blocks(HEIGHTS, None).await?;
+    let temp_dir = driver.kill().await;
+
+    // Given
+    {
+        let database = CombinedDatabase::open(
+            temp_dir.path(),
+            1024 * 1024,
+            Default::default(),
+            512,
+            &Default::default(),
+        )?;
+        // The merklized table doesn't support the removal, so the raw key is deleted.
+        let mut transaction = database.on_chain().clone().into_transaction();
+        let missing_height = BlockHeight::new(HEIGHTS / 2);
+        transaction.delete(&missing_height.to_bytes(), Column::FuelBlocks)?;
+        database
+            .on_chain()
+            .commit_changes_outside_of_block(transaction.into_changes())?;
+    }
+
+    // When
+    let args = ["_IGNORED_", "--db-path", temp_dir.path().to_str().unwrap()];
+    let command = fuel_core_bin::cli::reindex::Command::parse_from(args);
+    let result = fuel_core_bin::cli::reindex::exec(command).await;
+
+    // Then
+    let err = result.expect_err("The reindexing should fail without the block");
+    assert!(err.to_string().contains("is missing"), "{err}");
+    assert!(!temp_dir
+        .path()
+        .join(fuel_core::service::reindex::STAGING_DIRECTORY)
+        .exists());
+    let database = CombinedDatabase::open(
+        temp_dir.path(),
+        1024 * 1024,
+        Default::default(),
+        512,
+        &Default::default(),
+    )?;
+    assert_eq!(
+        database.off_chain().latest_height_from_metadata()?,
+        Some(BlockHeight::new(HEIGHTS))
+    );
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn auto_reindex__rebuilds_missing_off_chain_database_on_start_up(
+) -> anyhow::Result<()> {
+    let driver = FuelCoreDriver::spawn(NODE_ARGS).await?;
+    driver.client.produce_blocks(HEIGHTS, None).await?;
+    let owner = genesis_coin_owner(&driver.node.shared.database);
+    let expected_balance = balance(&driver.client, &owner).await;
+    let temp_dir = driver.kill().await;
+
+    // Given
+    std::fs::remove_dir_all(temp_dir.path().join("off_chain"))?;
+
+    // When
+    let mut args = NODE_ARGS.to_vec();
+    args.push("--auto-reindex");
+    let reindexed_driver = FuelCoreDriver::spawn_with_directory(temp_dir, &args).await?;
+
+    // Then
+    let database = &reindexed_driver.node.shared.database;
+    assert_eq!(
+        database.off_chain().latest_height(),
+        Some(BlockHeight::new(HEIGHTS))
+    );
+    assert_eq!(
+        balance(&reindexed_driver.client, &owner).await,
+        expected_balance
+    );
+
+    reindexed_driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn auto_reindex__keeps_complete_off_chain_database() -> anyhow::Result<()> {
+    let driver = FuelCoreDriver::spawn(NODE_ARGS).await?;
+    driver.client.produce_blocks(HEIGHTS, None).await?;
+    let temp_dir = driver.kill().await;
+
+    // When
+    let mut args = NODE_ARGS.to_vec();
+    args.push("--auto-reindex");
+    let driver = FuelCoreDriver::spawn_with_directory(temp_dir, &args).await?;
+
+    // Then
+    let database = &driver.node.shared.database;
+    let config = &driver.node.shared.config.combined_db_config;
+    assert!(!fuel_core::service::reindex::is_reindex_required(
+        database, config
+    )?);
+    assert_eq!(
+        database.off_chain().latest_height(),
+        Some(BlockHeight::new(HEIGHTS))
+    );
+
+    driver.kill().await;
+    Ok(())
+}
@@ -330,9 +330,14 @@ async fn can_restart_node_with_relayer_data() {
 
     {
         // Given
-        let database =
-            CombinedDatabase::open(tmp_dir.path(), capacity, Default::default(), 512)
-                .unwrap();
+        let database = CombinedDatabase::open(
+            tmp_dir.path(),
+            capacity,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
 
         let service = FuelService::from_combined_database(database, config.clone())
             .await
@@ -350,9 +355,14 @@ async fn can_restart_node_with_relayer_data() {
 
     {
         // When
-        let database =
-            CombinedDatabase::open(tmp_dir.path(), capacity, Default::default(), 512)
-                .unwrap();
+        let database = CombinedDatabase::open(
+            tmp_dir.path(),
+            capacity,
+            Default::default(),
+            512,
+            &Default::default(),
+        )
+        .unwrap();
         let service = FuelService::from_combined_database(database, config)
             .await
             .unwrap();
@@ -11,6 +11,7 @@ use fuel_core::{
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
     fuel_tx::{
+        Address,
         AssetId,
         Input,
         Output,
@@ -18,8 +19,10 @@ use fuel_core_types::{
         TransactionBuilder,
         TxId,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::BlockHeight,
+    services::executor::TransactionExecutionResult,
 };
 use futures::StreamExt;
 use itertools::Itertools;
@@ -63,6 +66,22 @@ fn transfer_transaction(min_amount: u64, rng: &mut StdRng) -> Transaction {
     builder.finalize_as_transaction()
 }
 
+fn transfer_to(owner: Address, amount: u64, rng: &mut StdRng) -> Transaction {
+    let mut builder = TransactionBuilder::script(vec![], vec![]);
+    builder.add_input(Input::coin_predicate(
+        rng.gen(),
+        rng.gen(),
+        amount,
+        AssetId::BASE,
+        Default::default(),
+        0,
+        vec![0],
+        vec![],
+    ));
+    builder.add_output(Output::coin(owner, amount, AssetId::BASE));
+    builder.finalize_as_transaction()
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn validate_block_at_any_height__only_transfers() -> anyhow::Result<()> {
     let mut rng = StdRng::seed_from_u64(1234);
@@ -296,3 +315,194 @@ async fn rollback_to__should_work_with_empty_gas_price_database() -> anyhow::Res
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn balance_at__returns_balance_at_the_requested_height() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let node = &driver.node;
+
+    // Given
+    const AMOUNT: u64 = 123456;
+    let owner: Address = rng.gen();
+    let tx = transfer_to(owner, AMOUNT, &mut rng);
+    let result = node.submit_and_await_commit(tx).await.unwrap();
+    assert!(matches!(result, TransactionStatus::Success(_)));
+    let first_height = driver.client.chain_info().await?.latest_block.header.height;
+    let tx = transfer_to(owner, AMOUNT, &mut rng);
+    let result = node.submit_and_await_commit(tx).await.unwrap();
+    assert!(matches!(result, TransactionStatus::Success(_)));
+
+    // When
+    let balance_at_first_height = driver
+        .client
+        .balance_at(&owner, Some(&AssetId::BASE), first_height.into())
+        .await?;
+    let latest_balance = driver.client.balance(&owner, Some(&AssetId::BASE)).await?;
+
+    // Then
+    assert_eq!(balance_at_first_height, AMOUNT);
+    assert_eq!(latest_balance, 2 * AMOUNT);
+
+    driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn coin_at__returns_none_before_the_coin_was_created() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let node = &driver.node;
+
+    // Given
+    produce_block_with_tx(&mut rng, &driver.client).await;
+    let height_before = driver.client.chain_info().await?.latest_block.header.height;
+    let tx = transfer_to(rng.gen(), 123456, &mut rng);
+    let utxo_id = UtxoId::new(tx.id(&Default::default()), 0);
+    let result = node.submit_and_await_commit(tx).await.unwrap();
+    assert!(matches!(result, TransactionStatus::Success(_)));
+
+    // When
+    let coin_before = driver
+        .client
+        .coin_at(&utxo_id, height_before.into())
+        .await?;
+    let coin_now = driver.client.coin(&utxo_id).await?;
+
+    // Then
+    assert!(coin_before.is_none());
+    assert!(coin_now.is_some());
+
+    driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn dry_run_at__executes_transaction_on_the_state_before_the_block(
+) -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let node = &driver.node;
+
+    // Given
+    let tx = transfer_to(rng.gen(), 123456, &mut rng);
+    let result = node.submit_and_await_commit(tx.clone()).await.unwrap();
+    assert!(matches!(result, TransactionStatus::Success(_)));
+    let block_height = driver.client.chain_info().await?.latest_block.header.height;
+    produce_block_with_tx(&mut rng, &driver.client).await;
+
+    // When
+    let statuses = driver
+        .client
+        .dry_run_at(&[tx], None, None, block_height.into())
+        .await?;
+
+    // Then
+    assert_eq!(statuses.len(), 1);
+    assert!(matches!(
+        statuses[0].result,
+        TransactionExecutionResult::Success { .. }
+    ));
+
+    driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn balance_at__fails_with_oldest_available_height_when_history_is_pruned(
+) -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "2s",
+    ])
+    .await?;
+
+    // Given
+    const TOTAL_BLOCKS: u64 = 10;
+    for _ in 0..TOTAL_BLOCKS {
+        produce_block_with_tx(&mut rng, &driver.client).await;
+    }
+
+    // When
+    let result = driver
+        .client
+        .balance_at(&rng.gen(), Some(&AssetId::BASE), 1u32.into())
+        .await;
+
+    // Then
+    let err = result.expect_err("History for the height 1 should be pruned");
+    assert!(
+        err.to_string().contains("the oldest available height is"),
+        "Unexpected error: {err}"
+    );
+
+    driver.kill().await;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn verify__stored_blocks_are_valid() -> anyhow::Result<()> {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let driver = FuelCoreDriver::spawn_feeless(&[
+        "--debug",
+        "--poa-instant",
+        "true",
+        "--state-rewind-duration",
+        "7d",
+    ])
+    .await?;
+    let node = &driver.node;
+
+    // Given
+    const TOTAL_BLOCKS: u32 = 50;
+    const MIN_AMOUNT: u64 = 123456;
+    for _ in 0..TOTAL_BLOCKS {
+        let tx = transfer_transaction(MIN_AMOUNT, &mut rng);
+        let result = node.submit_and_await_commit(tx).await.unwrap();
+        assert!(matches!(result, TransactionStatus::Success(_)));
+    }
+    let temp_dir = driver.kill().await;
+
+    // When
+    let to = TOTAL_BLOCKS.to_string();
+    let args = [
+        "_IGNORED_",
+        "--db-path",
+        temp_dir.path().to_str().unwrap(),
+        "--from",
+        "0",
+        "--to",
+        to.as_str(),
+    ];
+    let command = fuel_core_bin::cli::verify::Command::parse_from(args);
+    let result = fuel_core_bin::cli::verify::exec(command).await;
+
+    // Then
+    result.expect("All stored blocks should be valid");
+    Ok(())
+}
@@ -4,12 +4,20 @@ use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::Blacklist,
+};
 use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
     fuel_tx::*,
+    fuel_types::ChainId,
 };
 use itertools::Itertools;
 use rand::{
@@ -72,3 +80,143 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn pending_transactions__returns_transactions_with_dependencies() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.trigger = Trigger::Never;
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let amount = 1000;
+    let script: Vec<u8> = op::ret(RegId::ONE).to_bytes().into_iter().collect();
+
+    // given
+    let tx1 = TransactionBuilder::script(script.clone(), vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret,
+            rng.gen(),
+            amount,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::coin(owner, amount, Default::default()))
+        .finalize();
+    let tx1_id = tx1.id(&ChainId::default());
+    let tx2 = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret,
+            UtxoId::new(tx1_id, 0),
+            amount,
+            Default::default(),
+            Default::default(),
+        )
+        .add_output(Output::change(owner, 0, Default::default()))
+        .finalize();
+    let tx2_id = tx2.id(&ChainId::default());
+    test_builder.config_coin_inputs_from_transactions(&[&tx1]);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    client.submit(&tx1.into()).await.unwrap();
+    client.submit(&tx2.into()).await.unwrap();
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+
+    // when
+    let pending = client.pending_transactions(request.clone()).await.unwrap();
+    let owned = client
+        .pending_transactions_by_owner(&owner, request.clone())
+        .await
+        .unwrap();
+    let not_owned = client
+        .pending_transactions_by_owner(&Address::new([1; 32]), request)
+        .await
+        .unwrap();
+
+    // then
+    let pending_tx1 = pending.results.iter().find(|tx| tx.id == tx1_id).unwrap();
+    let pending_tx2 = pending.results.iter().find(|tx| tx.id == tx2_id).unwrap();
+    assert_eq!(pending.results.len(), 2);
+    assert_eq!(pending_tx1.dependencies, vec![]);
+    assert_eq!(pending_tx1.dependents, vec![tx2_id]);
+    assert_eq!(pending_tx2.dependencies, vec![tx1_id]);
+    assert_eq!(pending_tx2.dependents, vec![]);
+    assert_eq!(owned.results, pending.results);
+    assert!(not_owned.results.is_empty());
+}
+
+#[tokio::test]
+async fn add_to_blacklist__removes_pending_transactions_and_rejects_new_ones() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.trigger = Trigger::Never;
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let script: Vec<u8> = op::ret(RegId::ONE).to_bytes().into_iter().collect();
+    let transactions = (1..=2)
+        .map(|i| {
+            TransactionBuilder::script(script.clone(), vec![])
+                .script_gas_limit(10_000)
+                .add_unsigned_coin_input(
+                    secret,
+                    rng.gen(),
+                    1000 + i,
+                    Default::default(),
+                    Default::default(),
+                )
+                .finalize()
+        })
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+    client
+        .submit(&transactions[0].clone().into())
+        .await
+        .unwrap();
+
+    // given
+    let blacklist = Blacklist {
+        owners: vec![owner],
+        ..Default::default()
+    };
+
+    // when
+    let updated = client.add_to_blacklist(blacklist.clone()).await.unwrap();
+
+    // then
+    assert_eq!(updated, blacklist);
+    assert_eq!(client.blacklist().await.unwrap(), blacklist);
+    let pending = client
+        .pending_transactions(PaginationRequest {
+            cursor: None,
+            results: 10,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+    assert!(pending.results.is_empty());
+    let result = client.submit(&transactions[1].clone().into()).await;
+    assert!(result.is_err());
+
+    // when
+    let updated = client.remove_from_blacklist(blacklist).await.unwrap();
+
+    // then
+    assert_eq!(updated, Blacklist::default());
+    client
+        .submit(&transactions[1].clone().into())
+        .await
+        .unwrap();
+}